@@ -20,22 +20,25 @@ fn main() {
     let (connection, handle) = new_connection().unwrap();
     spawn(move || Core::new().unwrap().run(connection));
 
-    // Get the list of links
-    let links = handle.link().get().execute().wait().unwrap();
-
-    for link in links {
-        // Find the link with the name provided as argument, and delete it
-        if link.name().unwrap() == link_name {
-            println!("deleting link {}", link_name);
-            let req = handle.link().del(link.index());
-            match req.execute().wait() {
-                Ok(()) => println!("done"),
-                Err(e) => eprintln!("error: {}", e),
-            }
+    // Get the link with the name provided as argument, and delete it
+    let link = match handle
+        .link()
+        .get()
+        .match_name(link_name.clone())
+        .execute()
+        .wait()
+    {
+        Ok(link) => link,
+        Err(e) => {
+            eprintln!("cannot get link {}: {}", link_name, e);
             return;
         }
+    };
+    println!("deleting link {}", link_name);
+    match handle.link().del(link.index()).execute().wait() {
+        Ok(()) => println!("done"),
+        Err(e) => eprintln!("error: {}", e),
     }
-    eprintln!("link {} not found", link_name);
 }
 
 fn usage() {
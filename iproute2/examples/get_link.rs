@@ -0,0 +1,40 @@
+extern crate futures;
+extern crate iproute2;
+extern crate tokio_core;
+
+use std::env;
+
+use futures::Future;
+use tokio_core::reactor::Core;
+
+use iproute2::new_connection;
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    if args.len() != 2 {
+        eprintln!("usage: cargo run --example get_link -- <link name>");
+        return;
+    }
+    let link_name = args[1].clone();
+
+    let (connection, handle) = new_connection().unwrap();
+    let mut core = Core::new().unwrap();
+    core.handle().spawn(connection.map_err(|_| ()));
+
+    // Resolve the name of the link into an index. The second resolution is served from the cache.
+    let resolver = handle.link().resolver().cached();
+    let index = match core.run(resolver.resolve(&link_name)) {
+        Ok(index) => index,
+        Err(e) => {
+            eprintln!("cannot resolve {}: {}", link_name, e);
+            return;
+        }
+    };
+    assert_eq!(core.run(resolver.resolve(&link_name)).unwrap(), index);
+
+    // Retrieve the link by index, without dumping all the links
+    let link = core
+        .run(handle.link().get().match_index(index).execute())
+        .unwrap();
+    println!("{}: {:?}", index, link);
+}
@@ -0,0 +1,50 @@
+extern crate futures;
+extern crate iproute2;
+extern crate tokio_core;
+
+use futures::{Future, Stream};
+use iproute2::{new_network_cache, CacheChange};
+use tokio_core::reactor::Core;
+
+fn main() {
+    // Create a cache of the links, addresses and routes, and the manager that keeps it up to date
+    let (manager, cache) = new_network_cache().unwrap();
+
+    // The manager will run in an event loop
+    let mut core = Core::new().unwrap();
+    core.handle()
+        .spawn(manager.map_err(|e| eprintln!("cache manager failed: {}", e)));
+
+    // Print the changes of the cache as they happen
+    let changes = cache.changes();
+    let monitor = changes.for_each(|change| {
+        match change {
+            CacheChange::NewLink(link) => {
+                println!("new link {}: {}", link.index(), link.name().unwrap_or(""))
+            }
+            CacheChange::DelLink(link) => {
+                println!(
+                    "deleted link {}: {}",
+                    link.index(),
+                    link.name().unwrap_or("")
+                )
+            }
+            CacheChange::NewAddress(address) => {
+                println!("new address on link {}", address.header.index)
+            }
+            CacheChange::DelAddress(address) => {
+                println!("deleted address on link {}", address.header.index)
+            }
+            CacheChange::NewRoute(_) | CacheChange::DelRoute(_) => {}
+            CacheChange::Synced => println!(
+                "synced: {} links, {} addresses, {} routes",
+                cache.links().len(),
+                cache.addresses().len(),
+                cache.routes().len()
+            ),
+        }
+        Ok(())
+    });
+
+    core.run(monitor).unwrap();
+}
@@ -0,0 +1,39 @@
+extern crate futures;
+extern crate iproute2;
+extern crate rtnetlink;
+extern crate tokio_core;
+
+use futures::{Future, Stream};
+use iproute2::{new_multicast_connection, Link};
+use rtnetlink::constants::RTNLGRP_LINK;
+use rtnetlink::RtnlMessage;
+use tokio_core::reactor::Core;
+
+fn main() {
+    // Create a netlink connection that receives the link notifications
+    let (connection, _handle, notifications) = new_multicast_connection(&[RTNLGRP_LINK]).unwrap();
+
+    // The connection will run in an event loop
+    let mut core = Core::new().unwrap();
+    core.handle().spawn(connection.map_err(|_| ()));
+
+    // Print the links as they are created, modified and deleted
+    let monitor = notifications.for_each(|msg| {
+        let (event, link) = match msg.into_parts().1 {
+            RtnlMessage::NewLink(link) => ("new", link),
+            RtnlMessage::DelLink(link) => ("deleted", link),
+            _ => return Ok(()),
+        };
+        let link = Link::from_link_message(link).unwrap();
+        println!(
+            "{} link {}: {}",
+            event,
+            link.index(),
+            link.name().unwrap_or("")
+        );
+        Ok(())
+    });
+
+    // Run the monitor on the event loop
+    core.run(monitor).unwrap();
+}
@@ -0,0 +1,54 @@
+extern crate futures;
+extern crate iproute2;
+extern crate rtnetlink;
+extern crate tokio_core;
+
+use std::env;
+
+use futures::Future;
+use tokio_core::reactor::Core;
+
+use iproute2::{
+    new_connection, DesiredAddress, DesiredLink, DesiredRoute, DesiredRule, DesiredState,
+};
+use rtnetlink::LinkInfoKind;
+
+fn main() {
+    let dry_run = env::args().any(|arg| arg == "--dry-run");
+
+    let (connection, handle) = new_connection().unwrap();
+    let mut core = Core::new().unwrap();
+    core.handle().spawn(connection.map_err(|_| ()));
+
+    // A bridge with an address and a macvlan link on top of it, a route through a gateway on the
+    // bridge network, and a rule that looks the routes of the bridge network up in table 100
+    let state = DesiredState::new()
+        .link(DesiredLink::new("br-rs".into(), LinkInfoKind::Bridge).up())
+        .link(
+            DesiredLink::new("mv-rs".into(), LinkInfoKind::MacVlan)
+                .parent("br-rs".into())
+                .up(),
+        )
+        .address(DesiredAddress::new(
+            "br-rs".into(),
+            "10.100.0.1".parse().unwrap(),
+            24,
+        ))
+        .route(
+            DesiredRoute::new("10.101.0.0".parse().unwrap(), 16)
+                .gateway("10.100.0.254".parse().unwrap()),
+        )
+        .rule(DesiredRule::new(1000, 100).source("10.100.0.0".parse().unwrap(), 24));
+
+    let request = handle.reconcile(state);
+    let result = if dry_run {
+        core.run(request.plan())
+    } else {
+        core.run(request.execute())
+    };
+    match result {
+        Ok(ref plan) if plan.is_empty() => println!("nothing to do"),
+        Ok(plan) => print!("{}", plan),
+        Err(e) => eprintln!("{}", e),
+    }
+}
@@ -0,0 +1,40 @@
+extern crate futures;
+extern crate iproute2;
+extern crate tokio_core;
+
+use std::time::Duration;
+
+use futures::{Future, Stream};
+use iproute2::new_connection;
+use tokio_core::reactor::Core;
+
+fn main() {
+    // Create a netlink connection, and a handle to send requests via this connection
+    let (connection, handle) = new_connection().unwrap();
+
+    // The connection will run in an event loop
+    let mut core = Core::new().unwrap();
+    core.handle().spawn(connection.map_err(|_| ()));
+
+    // Print the rates of each link every second
+    let sampler = handle
+        .stats()
+        .sampler(Duration::from_secs(1), &core.handle())
+        .unwrap();
+    let request = sampler.for_each(|samples| {
+        for sample in samples {
+            println!(
+                "{}: rx {:.0} B/s {:.0} pkt/s, tx {:.0} B/s {:.0} pkt/s",
+                sample.index(),
+                sample.rx_bytes_rate(),
+                sample.rx_packets_rate(),
+                sample.tx_bytes_rate(),
+                sample.tx_packets_rate()
+            );
+        }
+        Ok(())
+    });
+
+    // Run the sampler on the event loop
+    core.run(request).unwrap();
+}
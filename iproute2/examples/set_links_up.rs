@@ -0,0 +1,56 @@
+extern crate futures;
+extern crate iproute2;
+extern crate rtnetlink;
+extern crate tokio_core;
+
+use std::env;
+
+use futures::Future;
+use tokio_core::reactor::Core;
+
+use iproute2::new_connection;
+use rtnetlink::constants::IFF_UP;
+use rtnetlink::{LinkFlags, LinkMessage, NetlinkMessage, RtnlMessage};
+
+fn main() {
+    let names: Vec<String> = env::args().skip(1).collect();
+    if names.is_empty() {
+        eprintln!("usage: cargo run --example set_links_up -- <link name>...");
+        return;
+    }
+
+    let (connection, handle) = new_connection().unwrap();
+    let mut core = Core::new().unwrap();
+    core.handle().spawn(connection.map_err(|_| ()));
+
+    let links = core.run(handle.link().get().execute()).unwrap();
+
+    // Build one RTM_SETLINK request per link, and send them all in a single batch
+    let mut batch = handle.batch();
+    let mut batch_names = vec![];
+    for name in names {
+        let index = match links.iter().find(|link| link.name() == Some(name.as_str())) {
+            Some(link) => link.index(),
+            None => {
+                eprintln!("{}: link not found", name);
+                continue;
+            }
+        };
+        let mut message = LinkMessage::new();
+        message
+            .header_mut()
+            .set_index(index)
+            .set_flags(LinkFlags::from(IFF_UP))
+            .set_change_mask(LinkFlags::from(IFF_UP));
+        batch = batch.request(NetlinkMessage::from(RtnlMessage::SetLink(message)));
+        batch_names.push(name);
+    }
+
+    let results = core.run(batch.execute()).unwrap();
+    for (name, result) in batch_names.iter().zip(results) {
+        match result {
+            Ok(()) => println!("{}: up", name),
+            Err(e) => println!("{}: {}", name, e),
+        }
+    }
+}
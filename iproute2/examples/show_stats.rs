@@ -0,0 +1,36 @@
+extern crate futures;
+extern crate iproute2;
+extern crate tokio_core;
+
+use futures::Future;
+use iproute2::new_connection;
+use tokio_core::reactor::Core;
+
+fn main() {
+    // Create a netlink connection, and a handle to send requests via this connection
+    let (connection, handle) = new_connection().unwrap();
+
+    // The connection will run in an event loop
+    let mut core = Core::new().unwrap();
+    core.handle().spawn(connection.map_err(|_| ()));
+
+    // Print the counters of each link, without dumping the links
+    let request = handle.stats().get().execute().and_then(|stats| {
+        for link_stats in stats {
+            if let Some(counters) = link_stats.counters() {
+                println!(
+                    "{}: rx {} packets ({} bytes), tx {} packets ({} bytes)",
+                    link_stats.index(),
+                    counters.rx_packets,
+                    counters.rx_bytes,
+                    counters.tx_packets,
+                    counters.tx_bytes
+                );
+            }
+        }
+        Ok(())
+    });
+
+    // Run the request on the event loop
+    core.run(request).unwrap();
+}
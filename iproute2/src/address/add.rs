@@ -0,0 +1,137 @@
+use std::net::{IpAddr, Ipv4Addr};
+
+use futures::future::{self, Either};
+use futures::{Future, Stream};
+
+use rtnetlink::constants::{NLM_F_ACK, NLM_F_CREATE, NLM_F_EXCL, NLM_F_REQUEST};
+use rtnetlink::{AddressMessage, AddressNla, NetlinkFlags, NetlinkMessage, RtnlMessage};
+
+use super::{address_message, AddressDelRequest, AddressGetRequest};
+use connection::ConnectionHandle;
+use errors::NetlinkIpError;
+
+use {Stream2Ack, Stream2Vec};
+
+lazy_static! {
+    // Flags for `ip address add`
+    static ref ADD_FLAGS: NetlinkFlags =
+        NetlinkFlags::from(NLM_F_REQUEST | NLM_F_ACK | NLM_F_EXCL | NLM_F_CREATE);
+}
+
+pub struct AddressAddRequest {
+    handle: ConnectionHandle,
+    message: AddressMessage,
+}
+
+impl AddressAddRequest {
+    pub(crate) fn new(
+        handle: ConnectionHandle,
+        index: u32,
+        address: IpAddr,
+        prefix_len: u8,
+    ) -> Self {
+        let message = address_message(index, address, prefix_len);
+        AddressAddRequest { handle, message }
+    }
+
+    /// Execute the request
+    pub fn execute(self) -> impl Future<Item = (), Error = NetlinkIpError> {
+        let AddressAddRequest {
+            mut handle,
+            message,
+        } = self;
+        let mut req = NetlinkMessage::from(RtnlMessage::NewAddress(message));
+        req.header_mut().set_flags(*ADD_FLAGS);
+        Stream2Ack::new(handle.request(req))
+    }
+
+    /// Execute the request with the `NLM_F_ECHO` flag, and return the address that has been
+    /// added, as reported by the kernel.
+    ///
+    /// Kernels older than 6.3 don't echo new addresses. The address is then retrieved with a dump
+    /// of the addresses of the link, which fails with
+    /// [`NetlinkIpError::AddressNotFound`](enum.NetlinkIpError.html#variant.AddressNotFound) if
+    /// the address was removed in the meantime.
+    pub fn execute_echo(self) -> impl Future<Item = AddressMessage, Error = NetlinkIpError> {
+        let AddressAddRequest {
+            mut handle,
+            message,
+        } = self;
+        let fallback = AddressGetRequest::new(handle.clone())
+            .family(message.header.family)
+            .link_index(message.header.index);
+        let prefix_len = message.header.prefix_len;
+        let address = message
+            .nlas
+            .iter()
+            .find(|nla| match **nla {
+                AddressNla::Address(_) => true,
+                _ => false,
+            })
+            .cloned();
+        let mut req = NetlinkMessage::from(RtnlMessage::NewAddress(message));
+        let mut flags = *ADD_FLAGS;
+        flags.set_echo();
+        req.header_mut().set_flags(flags);
+        Stream2Vec::new(handle.request(req).map(|msg| {
+            if msg.is_error() {
+                return Err(NetlinkIpError::NetlinkError(msg));
+            }
+            if !msg.is_new_address() {
+                return Err(NetlinkIpError::UnexpectedMessage(msg));
+            }
+
+            if let (_, RtnlMessage::NewAddress(message)) = msg.into_parts() {
+                Ok(message)
+            } else {
+                // We checked that msg.is_new_address() above, so the should not be reachable.
+                unreachable!();
+            }
+        }))
+        .and_then(move |mut addresses| match addresses.pop() {
+            Some(address) => Either::A(future::ok(address)),
+            None => Either::B(fallback.execute().and_then(move |addresses| {
+                addresses
+                    .into_iter()
+                    .find(|message| {
+                        message.header.prefix_len == prefix_len
+                            && address
+                                .as_ref()
+                                .map_or(true, |nla| message.nlas.contains(nla))
+                    })
+                    .ok_or(NetlinkIpError::AddressNotFound)
+            })),
+        })
+    }
+
+    /// Return a request that removes the address added by this request
+    pub(crate) fn inverse(&self) -> AddressDelRequest {
+        let mut request = AddressDelRequest::new(
+            self.handle.clone(),
+            self.message.header.index,
+            IpAddr::V4(Ipv4Addr::UNSPECIFIED),
+            0,
+        );
+        {
+            let message = request.message_mut();
+            message.header = self.message.header.clone();
+            // Only keep the attributes that identify the address
+            message.nlas = self
+                .message
+                .nlas
+                .iter()
+                .filter(|nla| match **nla {
+                    AddressNla::Address(_) | AddressNla::Local(_) => true,
+                    _ => false,
+                })
+                .cloned()
+                .collect();
+        }
+        request
+    }
+
+    /// Return a mutable reference to the request
+    pub fn message_mut(&mut self) -> &mut AddressMessage {
+        &mut self.message
+    }
+}
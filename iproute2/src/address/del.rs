@@ -0,0 +1,64 @@
+use std::net::{IpAddr, Ipv4Addr};
+
+use futures::Future;
+
+use rtnetlink::constants::{NLM_F_ACK, NLM_F_REQUEST};
+use rtnetlink::{AddressMessage, NetlinkFlags, NetlinkMessage, RtnlMessage};
+
+use super::{address_message, AddressAddRequest};
+use connection::ConnectionHandle;
+use errors::NetlinkIpError;
+
+use Stream2Ack;
+
+lazy_static! {
+    // Flags for `ip address del`
+    static ref DEL_FLAGS: NetlinkFlags = NetlinkFlags::from(NLM_F_REQUEST | NLM_F_ACK);
+}
+
+pub struct AddressDelRequest {
+    handle: ConnectionHandle,
+    message: AddressMessage,
+}
+
+impl AddressDelRequest {
+    pub(crate) fn new(
+        handle: ConnectionHandle,
+        index: u32,
+        address: IpAddr,
+        prefix_len: u8,
+    ) -> Self {
+        let message = address_message(index, address, prefix_len);
+        AddressDelRequest { handle, message }
+    }
+
+    /// Execute the request
+    pub fn execute(self) -> impl Future<Item = (), Error = NetlinkIpError> {
+        let AddressDelRequest {
+            mut handle,
+            message,
+        } = self;
+        let mut req = NetlinkMessage::from(RtnlMessage::DelAddress(message));
+        req.header_mut().set_flags(*DEL_FLAGS);
+        Stream2Ack::new(handle.request(req))
+    }
+
+    /// Return a request that adds back the address removed by this request. Only the attributes
+    /// of this request are restored: the label, broadcast address and lifetimes the address had
+    /// are lost, unless they were set in this request.
+    pub(crate) fn inverse(&self) -> AddressAddRequest {
+        let mut request = AddressAddRequest::new(
+            self.handle.clone(),
+            self.message.header.index,
+            IpAddr::V4(Ipv4Addr::UNSPECIFIED),
+            0,
+        );
+        *request.message_mut() = self.message.clone();
+        request
+    }
+
+    /// Return a mutable reference to the request
+    pub fn message_mut(&mut self) -> &mut AddressMessage {
+        &mut self.message
+    }
+}
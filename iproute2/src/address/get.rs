@@ -0,0 +1,76 @@
+use futures::{Future, Stream};
+
+use rtnetlink::constants::{NLM_F_DUMP, NLM_F_REQUEST};
+use rtnetlink::{AddressMessage, NetlinkFlags, NetlinkMessage, RtnlMessage};
+
+use connection::ConnectionHandle;
+use errors::NetlinkIpError;
+
+use Stream2Vec;
+
+lazy_static! {
+    // Flags for `ip address show`
+    static ref GET_FLAGS: NetlinkFlags = NetlinkFlags::from(NLM_F_REQUEST | NLM_F_DUMP);
+}
+
+pub struct AddressGetRequest {
+    handle: ConnectionHandle,
+    message: AddressMessage,
+}
+
+impl AddressGetRequest {
+    pub(crate) fn new(handle: ConnectionHandle) -> Self {
+        let message = AddressMessage::default();
+        AddressGetRequest { handle, message }
+    }
+
+    /// Execute the request
+    pub fn execute(self) -> impl Future<Item = Vec<AddressMessage>, Error = NetlinkIpError> {
+        let AddressGetRequest {
+            mut handle,
+            message,
+        } = self;
+        let family = message.header.family;
+        let index = message.header.index;
+        let mut req = NetlinkMessage::from(RtnlMessage::GetAddress(message));
+        req.header_mut().set_flags(*GET_FLAGS);
+        Stream2Vec::new(handle.request(req).map(move |msg| {
+            if !msg.is_new_address() {
+                return Err(NetlinkIpError::UnexpectedMessage(msg));
+            }
+
+            if let (_, RtnlMessage::NewAddress(message)) = msg.into_parts() {
+                Ok(message)
+            } else {
+                // We checked that msg.is_new_address() above, so the should not be reachable.
+                unreachable!();
+            }
+        }))
+        .map(move |addresses| {
+            // Kernels that don't support strict checking ignore the link index filter
+            addresses
+                .into_iter()
+                .filter(|address| family == 0 || address.header.family == family)
+                .filter(|address| index == 0 || address.header.index == index)
+                .collect()
+        })
+    }
+
+    /// Return a mutable reference to the request
+    pub fn message_mut(&mut self) -> &mut AddressMessage {
+        &mut self.message
+    }
+
+    /// Only retrieve the addresses of the given address family (`AF_INET` or `AF_INET6`)
+    pub fn family(mut self, family: u8) -> Self {
+        self.message.header.family = family;
+        self
+    }
+
+    /// Only retrieve the addresses of the link with index `index` (equivalent to
+    /// `ip address show dev <name>`)
+    pub fn link_index(mut self, index: u32) -> Self {
+        self.message.header.index = index;
+        self
+    }
+}
@@ -0,0 +1,29 @@
+use std::net::IpAddr;
+
+use connection::ConnectionHandle;
+
+use super::{AddressAddRequest, AddressDelRequest, AddressGetRequest};
+
+pub struct AddressHandle(ConnectionHandle);
+
+impl AddressHandle {
+    pub fn new(handle: ConnectionHandle) -> Self {
+        AddressHandle(handle)
+    }
+
+    /// Retrieve the list of addresses (equivalent to `ip address show`)
+    pub fn get(&self) -> AddressGetRequest {
+        AddressGetRequest::new(self.0.clone())
+    }
+
+    /// Add an address to a link (equivalent to `ip address add <address>/<prefix_len> dev <link>`)
+    pub fn add(&self, index: u32, address: IpAddr, prefix_len: u8) -> AddressAddRequest {
+        AddressAddRequest::new(self.0.clone(), index, address, prefix_len)
+    }
+
+    /// Remove an address from a link (equivalent to
+    /// `ip address del <address>/<prefix_len> dev <link>`)
+    pub fn del(&self, index: u32, address: IpAddr, prefix_len: u8) -> AddressDelRequest {
+        AddressDelRequest::new(self.0.clone(), index, address, prefix_len)
+    }
+}
@@ -0,0 +1,39 @@
+use std::net::IpAddr;
+
+use rtnetlink::constants::{AF_INET, AF_INET6};
+use rtnetlink::{AddressMessage, AddressNla};
+
+mod handle;
+pub use self::handle::*;
+
+mod add;
+pub use self::add::*;
+
+mod del;
+pub use self::del::*;
+
+mod get;
+pub use self::get::*;
+
+/// Build the message identifying the address `address/prefix_len` of link `index`
+fn address_message(index: u32, address: IpAddr, prefix_len: u8) -> AddressMessage {
+    let mut message = AddressMessage::default();
+    message.header.index = index;
+    message.header.prefix_len = prefix_len;
+    match address {
+        IpAddr::V4(address) => {
+            message.header.family = AF_INET as u8;
+            // Like `ip address`, use the same address for the local and peer addresses
+            let bytes = address.octets().to_vec();
+            message.nlas.push(AddressNla::Local(bytes.clone()));
+            message.nlas.push(AddressNla::Address(bytes));
+        }
+        IpAddr::V6(address) => {
+            message.header.family = AF_INET6 as u8;
+            message
+                .nlas
+                .push(AddressNla::Address(address.octets().to_vec()));
+        }
+    }
+    message
+}
@@ -0,0 +1,115 @@
+use futures::Future;
+
+use rtnetlink::constants::{
+    AF_BRIDGE, BRIDGE_FLAGS_MASTER, BRIDGE_FLAGS_SELF, BRIDGE_VLAN_INFO_PVID,
+    BRIDGE_VLAN_INFO_UNTAGGED, NLM_F_ACK, NLM_F_REQUEST,
+};
+use rtnetlink::{BridgeVlanInfo, LinkMessage, NetlinkFlags, NetlinkMessage, RtnlMessage};
+
+use super::{vlan_nla, BridgeVlanDelRequest};
+use connection::ConnectionHandle;
+use errors::NetlinkIpError;
+
+use Stream2Ack;
+
+lazy_static! {
+    // Flags for `bridge vlan add`
+    static ref ADD_FLAGS: NetlinkFlags = NetlinkFlags::from(NLM_F_REQUEST | NLM_F_ACK);
+}
+
+pub struct BridgeVlanAddRequest {
+    handle: ConnectionHandle,
+    message: LinkMessage,
+    vlan: BridgeVlanInfo,
+    last_vid: Option<u16>,
+    flags: Option<u16>,
+}
+
+impl BridgeVlanAddRequest {
+    pub(crate) fn new(handle: ConnectionHandle, index: u32, vid: u16) -> Self {
+        let mut message = LinkMessage::new();
+        message
+            .header_mut()
+            .set_address_family(AF_BRIDGE as u8)
+            .set_index(index);
+        BridgeVlanAddRequest {
+            handle,
+            message,
+            vlan: BridgeVlanInfo::new(vid, 0),
+            last_vid: None,
+            flags: None,
+        }
+    }
+
+    /// Execute the request
+    pub fn execute(self) -> impl Future<Item = (), Error = NetlinkIpError> {
+        let BridgeVlanAddRequest {
+            mut handle,
+            mut message,
+            vlan,
+            last_vid,
+            flags,
+        } = self;
+        message.append_nla(vlan_nla(vlan, last_vid, flags));
+        let mut req = NetlinkMessage::from(RtnlMessage::SetLink(message));
+        req.header_mut().set_flags(*ADD_FLAGS);
+        Stream2Ack::new(handle.request(req))
+    }
+
+    /// Return a request that removes the VLANs added by this request
+    pub(crate) fn inverse(&self) -> BridgeVlanDelRequest {
+        let index = self.message.header().index();
+        let mut request = BridgeVlanDelRequest::new(self.handle.clone(), index, self.vlan.vid);
+        if let Some(last_vid) = self.last_vid {
+            request = request.range(last_vid);
+        }
+        let flags = self.flags.unwrap_or(0);
+        if flags & BRIDGE_FLAGS_MASTER != 0 {
+            request = request.master();
+        }
+        if flags & BRIDGE_FLAGS_SELF != 0 {
+            request = request.self_();
+        }
+        request
+    }
+
+    /// Return a mutable reference to the request
+    pub fn message_mut(&mut self) -> &mut LinkMessage {
+        &mut self.message
+    }
+
+    /// Make this VLAN the PVID of the port, ie the VLAN assigned to untagged ingress traffic
+    /// (equivalent to `bridge vlan add dev DEV vid VID pvid`)
+    pub fn pvid(mut self) -> Self {
+        self.vlan.flags |= BRIDGE_VLAN_INFO_PVID;
+        self
+    }
+
+    /// Send the egress traffic of this VLAN untagged (equivalent to `bridge vlan add dev DEV vid
+    /// VID untagged`)
+    pub fn untagged(mut self) -> Self {
+        self.vlan.flags |= BRIDGE_VLAN_INFO_UNTAGGED;
+        self
+    }
+
+    /// Add all the VLANs from `vid` to `last_vid` (equivalent to `bridge vlan add dev DEV vid
+    /// VID-LAST_VID`)
+    pub fn range(mut self, last_vid: u16) -> Self {
+        self.last_vid = Some(last_vid);
+        self
+    }
+
+    /// Configure the VLAN on the bridge the port is enslaved to. This is the default (equivalent
+    /// to `bridge vlan add dev DEV vid VID master`)
+    pub fn master(mut self) -> Self {
+        self.flags = Some(self.flags.unwrap_or(0) | BRIDGE_FLAGS_MASTER);
+        self
+    }
+
+    /// Configure the VLAN on the port device itself, if it supports it (equivalent to `bridge
+    /// vlan add dev DEV vid VID self`)
+    pub fn self_(mut self) -> Self {
+        self.flags = Some(self.flags.unwrap_or(0) | BRIDGE_FLAGS_SELF);
+        self
+    }
+}
@@ -0,0 +1,62 @@
+use futures::{Future, Stream};
+
+use rtnetlink::constants::{AF_BRIDGE, NLM_F_DUMP, NLM_F_REQUEST, RTEXT_FILTER_BRVLAN};
+use rtnetlink::{LinkLayerType, LinkMessage, LinkNla, NetlinkFlags, NetlinkMessage, RtnlMessage};
+
+use super::BridgePortVlans;
+use connection::ConnectionHandle;
+use errors::NetlinkIpError;
+
+use Stream2Vec;
+
+lazy_static! {
+    // Flags for `bridge vlan show`
+    static ref GET_FLAGS: NetlinkFlags = NetlinkFlags::from(NLM_F_REQUEST | NLM_F_DUMP);
+}
+
+pub struct BridgeVlanGetRequest {
+    handle: ConnectionHandle,
+    message: LinkMessage,
+}
+
+impl BridgeVlanGetRequest {
+    pub(crate) fn new(handle: ConnectionHandle) -> Self {
+        let mut message = LinkMessage::new();
+        message.header_mut().set_address_family(AF_BRIDGE as u8);
+        // With strict checking, the kernel rejects the requests with a non-zero link layer type
+        // (0 is ARPHRD_NETROM).
+        message
+            .header_mut()
+            .set_link_layer_type(LinkLayerType::Netrom);
+        // Without this filter, the kernel does not include the VLANs in the dump
+        message.append_nla(LinkNla::ExtMask(RTEXT_FILTER_BRVLAN));
+        BridgeVlanGetRequest { handle, message }
+    }
+
+    /// Execute the request
+    pub fn execute(self) -> impl Future<Item = Vec<BridgePortVlans>, Error = NetlinkIpError> {
+        let BridgeVlanGetRequest {
+            mut handle,
+            message,
+        } = self;
+        let mut req = NetlinkMessage::from(RtnlMessage::GetLink(message));
+        req.header_mut().set_flags(*GET_FLAGS);
+        Stream2Vec::new(handle.request(req).map(move |msg| {
+            if !msg.is_new_link() {
+                return Err(NetlinkIpError::UnexpectedMessage(msg));
+            }
+
+            if let (_, RtnlMessage::NewLink(link_message)) = msg.into_parts() {
+                Ok(BridgePortVlans::from_link_message(link_message))
+            } else {
+                // We checked that msg.is_new_link() above, so the should not be reachable.
+                unreachable!();
+            }
+        }))
+    }
+
+    /// Return a mutable reference to the request
+    pub fn message_mut(&mut self) -> &mut LinkMessage {
+        &mut self.message
+    }
+}
@@ -0,0 +1,28 @@
+use connection::ConnectionHandle;
+
+use super::{BridgeVlanAddRequest, BridgeVlanDelRequest, BridgeVlanGetRequest};
+
+pub struct BridgeVlanHandle(ConnectionHandle);
+
+impl BridgeVlanHandle {
+    pub fn new(handle: ConnectionHandle) -> Self {
+        BridgeVlanHandle(handle)
+    }
+
+    /// Add a VLAN to the bridge port with the given index (equivalent to `bridge vlan add dev DEV
+    /// vid VID`)
+    pub fn add(&self, index: u32, vid: u16) -> BridgeVlanAddRequest {
+        BridgeVlanAddRequest::new(self.0.clone(), index, vid)
+    }
+
+    /// Remove a VLAN from the bridge port with the given index (equivalent to `bridge vlan del
+    /// dev DEV vid VID`)
+    pub fn del(&self, index: u32, vid: u16) -> BridgeVlanDelRequest {
+        BridgeVlanDelRequest::new(self.0.clone(), index, vid)
+    }
+
+    /// Retrieve the VLANs of all the bridge ports (equivalent to `bridge vlan show`)
+    pub fn get(&self) -> BridgeVlanGetRequest {
+        BridgeVlanGetRequest::new(self.0.clone())
+    }
+}
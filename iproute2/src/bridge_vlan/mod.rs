@@ -0,0 +1,91 @@
+use rtnetlink::constants::{BRIDGE_VLAN_INFO_RANGE_BEGIN, BRIDGE_VLAN_INFO_RANGE_END};
+use rtnetlink::{BridgeVlanInfo, LinkAfBridgeNla, LinkMessage, LinkNla};
+
+mod handle;
+pub use self::handle::*;
+
+mod add;
+pub use self::add::*;
+
+mod del;
+pub use self::del::*;
+
+mod get;
+pub use self::get::*;
+
+/// The VLANs of a bridge port, or of a bridge itself (equivalent to an entry of `bridge vlan
+/// show`)
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BridgePortVlans {
+    /// Index of the link
+    index: u32,
+    /// Name of the link
+    name: Option<String>,
+    /// Index of the bridge the link is enslaved to. This is not set for the bridge itself.
+    master_index: Option<u32>,
+    /// VLANs configured on the link
+    vlans: Vec<BridgeVlanInfo>,
+}
+
+impl BridgePortVlans {
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_ref().map(String::as_ref)
+    }
+
+    pub fn master_index(&self) -> Option<u32> {
+        self.master_index
+    }
+
+    pub fn vlans(&self) -> &[BridgeVlanInfo] {
+        self.vlans.as_slice()
+    }
+
+    pub fn from_link_message(value: LinkMessage) -> Self {
+        let (header, nlas) = value.into_parts();
+        let mut port = BridgePortVlans {
+            index: header.index(),
+            ..Default::default()
+        };
+        for nla in nlas {
+            match nla {
+                LinkNla::IfName(name) => port.name = Some(name),
+                LinkNla::Master(index) => port.master_index = Some(index),
+                LinkNla::AfSpecBridge(bridge_nlas) => {
+                    for bridge_nla in bridge_nlas {
+                        if let LinkAfBridgeNla::VlanInfo(info) = bridge_nla {
+                            port.vlans.push(info);
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+        port
+    }
+}
+
+/// Build the `IFLA_AF_SPEC` attribute for a VLAN, or a range of VLANs if `last_vid` is set
+fn vlan_nla(vlan: BridgeVlanInfo, last_vid: Option<u16>, flags: Option<u16>) -> LinkNla {
+    let mut nlas = vec![];
+    if let Some(flags) = flags {
+        nlas.push(LinkAfBridgeNla::Flags(flags));
+    }
+    match last_vid {
+        Some(last_vid) if last_vid != vlan.vid => {
+            nlas.push(LinkAfBridgeNla::VlanInfo(BridgeVlanInfo::new(
+                vlan.vid,
+                vlan.flags | BRIDGE_VLAN_INFO_RANGE_BEGIN,
+            )));
+            nlas.push(LinkAfBridgeNla::VlanInfo(BridgeVlanInfo::new(
+                last_vid,
+                vlan.flags | BRIDGE_VLAN_INFO_RANGE_END,
+            )));
+        }
+        _ => nlas.push(LinkAfBridgeNla::VlanInfo(vlan)),
+    }
+    LinkNla::AfSpecBridge(nlas)
+}
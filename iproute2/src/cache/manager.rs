@@ -0,0 +1,280 @@
+use std::sync::{Arc, Mutex};
+
+use futures::sync::mpsc::UnboundedReceiver;
+use futures::{Async, Future, Poll, Stream};
+
+use rtnetlink::{AddressMessage, NetlinkMessage, RouteMessage};
+
+use super::{CacheChange, CacheState, Tables};
+use address::AddressGetRequest;
+use connection::{Connection, ConnectionHandle};
+use errors::NetlinkIpError;
+use link::{Link, LinkGetRequest};
+use route::RouteGetRequest;
+
+type DumpFuture = Box<
+    dyn Future<Item = (Vec<Link>, Vec<AddressMessage>, Vec<RouteMessage>), Error = NetlinkIpError>,
+>;
+
+/// A future that keeps a [`NetworkCache`](struct.NetworkCache.html) in sync with the kernel. It is
+/// created with [`new_network_cache()`](fn.new_network_cache.html), and must run on an event loop
+/// for the cache to be filled and updated. It runs its own connection, which joins the multicast
+/// groups of the link, address and route notifications.
+///
+/// The manager first dumps the links, addresses and routes, then applies the notifications as they
+/// arrive. Notifications received during a dump are applied once the dump completes. When the
+/// socket receive buffer overflows, notifications are lost, so the cache is filled again from a
+/// new dump, and the differences are reported as changes.
+///
+/// The future completes when the connection is closed, and fails if a dump fails.
+pub struct CacheManager {
+    connection: Connection,
+    handle: ConnectionHandle,
+    notifications: UnboundedReceiver<NetlinkMessage>,
+    state: Arc<Mutex<CacheState>>,
+    dump: Option<DumpFuture>,
+    // Notifications received during the dump in progress
+    queued: Vec<NetlinkMessage>,
+    // Whether notifications were lost during the dump in progress, which must then be made again
+    lost: bool,
+}
+
+impl CacheManager {
+    pub(super) fn new(
+        connection: Connection,
+        handle: ConnectionHandle,
+        notifications: UnboundedReceiver<NetlinkMessage>,
+        state: Arc<Mutex<CacheState>>,
+    ) -> Self {
+        let mut manager = CacheManager {
+            connection,
+            handle,
+            notifications,
+            state,
+            dump: None,
+            queued: vec![],
+            lost: false,
+        };
+        manager.start_dump();
+        manager
+    }
+
+    /// Dump the links, addresses and routes. The kernel only runs one dump at a time per socket,
+    /// so the dumps are made one after the other.
+    fn start_dump(&mut self) {
+        debug!("cache: dumping the links, addresses and routes");
+        let addresses = AddressGetRequest::new(self.handle.clone());
+        let routes = RouteGetRequest::new(self.handle.clone());
+        let dump = LinkGetRequest::new(self.handle.clone())
+            .execute()
+            .and_then(move |links| addresses.execute().map(move |addresses| (links, addresses)))
+            .and_then(move |(links, addresses)| {
+                routes
+                    .execute()
+                    .map(move |routes| (links, addresses, routes))
+            });
+        self.dump = Some(Box::new(dump));
+        self.queued.clear();
+        self.lost = false;
+    }
+
+    fn handle_notification(&mut self, message: NetlinkMessage) {
+        if message.is_overrun() {
+            self.state.lock().unwrap().synced = false;
+            if self.dump.is_some() {
+                // The dump in progress cannot be cancelled: the kernel would keep sending it and
+                // reject a new dump until it completes.
+                self.lost = true;
+            } else {
+                self.start_dump();
+            }
+        } else if self.dump.is_some() {
+            self.queued.push(message);
+        } else {
+            let mut changes = vec![];
+            let mut state = self.state.lock().unwrap();
+            state.tables.apply(message, &mut changes);
+            state.emit(&changes);
+        }
+    }
+
+    /// Handle the result of the dump in progress. Return `false` if notifications were lost during
+    /// the dump, in which case a new dump is started instead of syncing the cache.
+    fn dump_done(
+        &mut self,
+        links: Vec<Link>,
+        addresses: Vec<AddressMessage>,
+        routes: Vec<RouteMessage>,
+    ) -> bool {
+        self.dump = None;
+        if self.lost {
+            debug!("cache: notifications were lost during the dump, dumping again");
+            self.start_dump();
+            return false;
+        }
+        self.sync(links, addresses, routes);
+        true
+    }
+
+    /// Replace the content of the cache with the result of a dump, and apply the notifications
+    /// received in the meantime
+    fn sync(
+        &mut self,
+        links: Vec<Link>,
+        addresses: Vec<AddressMessage>,
+        routes: Vec<RouteMessage>,
+    ) {
+        let mut tables = Tables::from_dump(links, addresses, routes);
+        let mut ignored = vec![];
+        for message in self.queued.drain(..) {
+            tables.apply(message, &mut ignored);
+        }
+        let mut state = self.state.lock().unwrap();
+        let mut changes = state.tables.diff(&tables);
+        changes.push(CacheChange::Synced);
+        debug!("cache: synced, {} changes", changes.len() - 1);
+        state.tables = tables;
+        state.synced = true;
+        state.emit(&changes);
+    }
+}
+
+impl Future for CacheManager {
+    type Item = ();
+    type Error = NetlinkIpError;
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        loop {
+            // The connection reads the socket, and sends the dump requests
+            if let Async::Ready(()) = self.connection.poll().map_err(NetlinkIpError::Io)? {
+                debug!("cache: the connection is closed");
+                return Ok(Async::Ready(()));
+            }
+
+            while let Ok(Async::Ready(Some(message))) = self.notifications.poll() {
+                self.handle_notification(message);
+            }
+
+            let (links, addresses, routes) = match self.dump {
+                Some(ref mut dump) => match dump.poll()? {
+                    Async::Ready(result) => result,
+                    Async::NotReady => return Ok(Async::NotReady),
+                },
+                None => return Ok(Async::NotReady),
+            };
+            if !self.dump_done(links, addresses, routes) {
+                // Poll the connection again to send the new dump request
+                continue;
+            }
+            return Ok(Async::NotReady);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use connection::new_multicast_connection;
+    use rtnetlink::{LinkMessage, LinkNla, RtnlMessage};
+
+    // The connection is never polled: the dumps are completed by the tests
+    fn manager() -> (CacheManager, UnboundedReceiver<CacheChange>) {
+        let (connection, handle, notifications) = new_multicast_connection(&[]).unwrap();
+        let state = Arc::new(Mutex::new(CacheState::default()));
+        let changes = state.lock().unwrap().subscribe();
+        let manager = CacheManager::new(connection, handle, notifications, state);
+        (manager, changes)
+    }
+
+    fn link(index: u32, name: &str) -> Link {
+        let mut link = Link::new();
+        link.set_index(index).set_name(name.into());
+        link
+    }
+
+    fn new_link(index: u32, name: &str) -> NetlinkMessage {
+        let mut message = LinkMessage::new();
+        message.header_mut().set_index(index);
+        message.append_nla(LinkNla::IfName(name.into()));
+        NetlinkMessage::from(RtnlMessage::NewLink(message))
+    }
+
+    fn overrun() -> NetlinkMessage {
+        NetlinkMessage::from(RtnlMessage::Overrun(vec![]))
+    }
+
+    /// Return the changes emitted so far
+    fn emitted(mut changes: UnboundedReceiver<CacheChange>) -> Vec<CacheChange> {
+        changes.close();
+        changes.wait().map(Result::unwrap).collect()
+    }
+
+    fn links(manager: &CacheManager) -> Vec<Link> {
+        let state = manager.state.lock().unwrap();
+        state.tables.links.values().cloned().collect()
+    }
+
+    fn is_synced(manager: &CacheManager) -> bool {
+        manager.state.lock().unwrap().synced
+    }
+
+    #[test]
+    fn notifications_are_queued_during_a_dump() {
+        let (mut manager, changes) = manager();
+        manager.handle_notification(new_link(2, "eth0"));
+        assert!(links(&manager).is_empty());
+        assert!(!is_synced(&manager));
+
+        assert!(manager.dump_done(vec![link(1, "lo")], vec![], vec![]));
+        assert!(manager.dump.is_none());
+        assert!(is_synced(&manager));
+        assert_eq!(links(&manager), vec![link(1, "lo"), link(2, "eth0")]);
+
+        // Without a dump in progress, notifications are applied right away
+        manager.handle_notification(new_link(3, "eth1"));
+        assert_eq!(
+            emitted(changes),
+            vec![
+                CacheChange::NewLink(link(1, "lo")),
+                CacheChange::NewLink(link(2, "eth0")),
+                CacheChange::Synced,
+                CacheChange::NewLink(link(3, "eth1")),
+            ]
+        );
+    }
+
+    #[test]
+    fn dumps_again_after_a_loss() {
+        let (mut manager, changes) = manager();
+        assert!(manager.dump_done(vec![link(1, "lo"), link(2, "eth0")], vec![], vec![]));
+
+        // Lost outside of a dump: dump right away
+        manager.handle_notification(overrun());
+        assert!(!is_synced(&manager));
+        assert!(manager.dump.is_some());
+
+        // Lost during the dump: the result of the dump is discarded, along with the queued
+        // notifications
+        manager.handle_notification(new_link(3, "eth1"));
+        manager.handle_notification(overrun());
+        assert!(!manager.dump_done(vec![link(1, "lo")], vec![], vec![]));
+        assert!(manager.dump.is_some());
+        assert!(manager.queued.is_empty());
+        assert!(!is_synced(&manager));
+        assert_eq!(links(&manager), vec![link(1, "lo"), link(2, "eth0")]);
+
+        assert!(manager.dump_done(vec![link(1, "lo"), link(4, "eth2")], vec![], vec![]));
+        assert!(is_synced(&manager));
+        assert_eq!(
+            emitted(changes),
+            vec![
+                CacheChange::NewLink(link(1, "lo")),
+                CacheChange::NewLink(link(2, "eth0")),
+                CacheChange::Synced,
+                CacheChange::DelLink(link(2, "eth0")),
+                CacheChange::NewLink(link(4, "eth2")),
+                CacheChange::Synced,
+            ]
+        );
+    }
+}
@@ -0,0 +1,690 @@
+use std::collections::BTreeMap;
+use std::io;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use futures::future::{self, Either};
+use futures::sync::mpsc::{unbounded, UnboundedReceiver, UnboundedSender};
+use futures::{Future, Stream};
+
+use rtnetlink::constants::{
+    AF_INET, RTNLGRP_IPV4_IFADDR, RTNLGRP_IPV4_ROUTE, RTNLGRP_IPV6_IFADDR, RTNLGRP_IPV6_ROUTE,
+    RTNLGRP_LINK,
+};
+use rtnetlink::{
+    AddressMessage, AddressNla, LinkMessage, NetlinkMessage, RouteMessage, RouteNla, RouteScope,
+    RtnlMessage,
+};
+
+use connection::new_multicast_connection;
+use errors::NetlinkIpError;
+use link::Link;
+use route::route_table;
+
+mod manager;
+pub use self::manager::*;
+
+/// Multicast groups of the notifications the cache applies
+const GROUPS: [u32; 5] = [
+    RTNLGRP_LINK,
+    RTNLGRP_IPV4_IFADDR,
+    RTNLGRP_IPV6_IFADDR,
+    RTNLGRP_IPV4_ROUTE,
+    RTNLGRP_IPV6_ROUTE,
+];
+
+/// Create a cache of the links, addresses and routes, and the manager that keeps it in sync with
+/// the kernel. The manager is a future (see [`CacheManager`](struct.CacheManager.html)), that
+/// needs to be run on an event loop for the cache to be filled and updated.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// extern crate futures;
+/// extern crate iproute2;
+/// extern crate tokio_core;
+///
+/// use futures::{Future, Stream};
+/// use tokio_core::reactor::Core;
+///
+/// use iproute2::new_network_cache;
+///
+/// fn main() {
+///     let mut core = Core::new().unwrap();
+///     let (manager, cache) = new_network_cache().unwrap();
+///     core.handle().spawn(manager.map_err(|e| eprintln!("{}", e)));
+///
+///     // Wait for the initial dump, then look up the loopback link without any request
+///     core.run(cache.synced()).unwrap();
+///     println!("{:?}", cache.link_by_name("lo").map(|link| link.index()));
+///
+///     // Print the changes as they happen
+///     core.run(cache.changes().for_each(|change| {
+///         println!("{:?}", change);
+///         Ok(())
+///     })).unwrap();
+/// }
+/// ```
+pub fn new_network_cache() -> io::Result<(CacheManager, NetworkCache)> {
+    let (connection, handle, notifications) = new_multicast_connection(&GROUPS)?;
+    let cache = NetworkCache {
+        state: Arc::new(Mutex::new(CacheState::default())),
+    };
+    let manager = CacheManager::new(connection, handle, notifications, cache.state.clone());
+    Ok((manager, cache))
+}
+
+/// A change of the cache, yielded by [`NetworkCache::changes()`](struct.NetworkCache.html#method.changes)
+#[derive(Clone, Debug, PartialEq)]
+pub enum CacheChange {
+    /// A link was created or modified
+    NewLink(Link),
+    /// A link was deleted
+    DelLink(Link),
+    /// An address was added or modified
+    NewAddress(AddressMessage),
+    /// An address was removed
+    DelAddress(AddressMessage),
+    /// A route was added or modified
+    NewRoute(RouteMessage),
+    /// A route was removed
+    DelRoute(RouteMessage),
+    /// The cache was filled from a dump: initially, and after notifications were lost. The
+    /// changes found by the dump are yielded right before.
+    Synced,
+}
+
+/// Identifies an address: link index, family, prefix length and address
+type AddressKey = (u32, u8, u8, Vec<u8>);
+
+/// Identifies a route. The gateway and output link are part of the key because several routes to
+/// the same destination can be appended with `ip route append`.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+struct RouteKey {
+    family: u8,
+    table: u32,
+    tos: u8,
+    destination_length: u8,
+    destination: Vec<u8>,
+    priority: u32,
+    gateway: Vec<u8>,
+    output_interface: u32,
+}
+
+impl RouteKey {
+    fn new(route: &RouteMessage) -> Self {
+        let mut key = RouteKey {
+            family: route.header.address_family,
+            table: route_table(route),
+            tos: route.header.tos,
+            destination_length: route.header.destination_length,
+            destination: vec![],
+            priority: 0,
+            gateway: vec![],
+            output_interface: 0,
+        };
+        for nla in &route.nlas {
+            match *nla {
+                RouteNla::Destination(ref bytes) => key.destination = bytes.clone(),
+                RouteNla::Gateway(ref bytes) => key.gateway = bytes.clone(),
+                RouteNla::Priority(priority) => key.priority = priority,
+                RouteNla::Oif(index) => key.output_interface = index,
+                _ => {}
+            }
+        }
+        key
+    }
+
+    /// Whether a route with this key is replaced by a route with key `other` when the latter is
+    /// created with `NLM_F_REPLACE` (`ip route replace`)
+    fn is_replaced_by(&self, other: &RouteKey) -> bool {
+        self.family == other.family
+            && self.table == other.table
+            && self.tos == other.tos
+            && self.destination_length == other.destination_length
+            && self.destination == other.destination
+            && self.priority == other.priority
+    }
+}
+
+fn address_key(address: &AddressMessage) -> AddressKey {
+    // For IPv4, the local address identifies the address: the other one is the address of the
+    // peer on point-to-point links
+    let mut ip = vec![];
+    for nla in &address.nlas {
+        match *nla {
+            AddressNla::Local(ref bytes) => {
+                ip = bytes.clone();
+                break;
+            }
+            AddressNla::Address(ref bytes) => ip = bytes.clone(),
+            _ => {}
+        }
+    }
+    let header = &address.header;
+    (header.index, header.family, header.prefix_len, ip)
+}
+
+/// Convert a link notification, or return `None` if the cache ignores it
+fn notified_link(message: LinkMessage) -> Option<Link> {
+    let link = match Link::from_link_message(message) {
+        Ok(link) => link,
+        Err(e) => {
+            warn!("cache: ignoring invalid link notification: {}", e);
+            return None;
+        }
+    };
+    // Bridge ports are also notified with AF_BRIDGE messages, which only carry the bridge
+    // attributes of the port
+    if link.address_family() != 0 {
+        return None;
+    }
+    Some(link)
+}
+
+#[derive(Clone, Debug, Default)]
+struct Tables {
+    links: BTreeMap<u32, Link>,
+    addresses: BTreeMap<AddressKey, AddressMessage>,
+    routes: BTreeMap<RouteKey, RouteMessage>,
+}
+
+impl Tables {
+    fn from_dump(
+        links: Vec<Link>,
+        addresses: Vec<AddressMessage>,
+        routes: Vec<RouteMessage>,
+    ) -> Self {
+        Tables {
+            links: links.into_iter().map(|link| (link.index(), link)).collect(),
+            addresses: addresses
+                .into_iter()
+                .map(|address| (address_key(&address), address))
+                .collect(),
+            routes: routes
+                .into_iter()
+                .map(|route| (RouteKey::new(&route), route))
+                .collect(),
+        }
+    }
+
+    /// Apply a notification, and record the changes it made
+    fn apply(&mut self, message: NetlinkMessage, changes: &mut Vec<CacheChange>) {
+        let (header, message) = message.into_parts();
+        match message {
+            RtnlMessage::NewLink(message) => {
+                if let Some(link) = notified_link(message) {
+                    self.new_link(link, changes);
+                }
+            }
+            RtnlMessage::DelLink(message) => {
+                if let Some(link) = notified_link(message) {
+                    self.del_link(&link, changes);
+                }
+            }
+            RtnlMessage::NewAddress(address) => {
+                let key = address_key(&address);
+                if self.addresses.get(&key) != Some(&address) {
+                    self.addresses.insert(key, address.clone());
+                    changes.push(CacheChange::NewAddress(address));
+                }
+            }
+            RtnlMessage::DelAddress(address) => {
+                if let Some(address) = self.addresses.remove(&address_key(&address)) {
+                    changes.push(CacheChange::DelAddress(address));
+                }
+            }
+            RtnlMessage::NewRoute(route) => {
+                let key = RouteKey::new(&route);
+                if header.flags().has_replace() {
+                    self.remove_routes(
+                        |other, _| other != &key && other.is_replaced_by(&key),
+                        changes,
+                    );
+                }
+                if self.routes.get(&key) != Some(&route) {
+                    self.routes.insert(key, route.clone());
+                    changes.push(CacheChange::NewRoute(route));
+                }
+            }
+            RtnlMessage::DelRoute(route) => {
+                if let Some(route) = self.routes.remove(&RouteKey::new(&route)) {
+                    changes.push(CacheChange::DelRoute(route));
+                }
+            }
+            message => debug!("cache: ignoring unexpected notification {:?}", message),
+        }
+    }
+
+    fn new_link(&mut self, link: Link, changes: &mut Vec<CacheChange>) {
+        let index = link.index();
+        let was_up = self
+            .links
+            .get(&index)
+            .map_or(false, |previous| previous.flags().is_up());
+        if was_up && !link.flags().is_up() {
+            // The kernel removes the IPv4 routes of the links that go down without notifying it,
+            // except the local routes of their addresses
+            self.remove_routes(
+                |key, route| {
+                    u16::from(key.family) == AF_INET
+                        && key.output_interface == index
+                        && route.header.scope != RouteScope::Host
+                },
+                changes,
+            );
+        }
+        if self.links.get(&index) != Some(&link) {
+            self.links.insert(index, link.clone());
+            changes.push(CacheChange::NewLink(link));
+        }
+    }
+
+    fn del_link(&mut self, link: &Link, changes: &mut Vec<CacheChange>) {
+        let index = link.index();
+        // The addresses and routes of the link are gone too, and the kernel does not notify the
+        // removal of all of them
+        let addresses: Vec<AddressKey> = self
+            .addresses
+            .keys()
+            .filter(|key| key.0 == index)
+            .cloned()
+            .collect();
+        for key in addresses {
+            if let Some(address) = self.addresses.remove(&key) {
+                changes.push(CacheChange::DelAddress(address));
+            }
+        }
+        self.remove_routes(|key, _| key.output_interface == index, changes);
+        if let Some(link) = self.links.remove(&index) {
+            changes.push(CacheChange::DelLink(link));
+        }
+    }
+
+    fn remove_routes<F>(&mut self, predicate: F, changes: &mut Vec<CacheChange>)
+    where
+        F: Fn(&RouteKey, &RouteMessage) -> bool,
+    {
+        let keys: Vec<RouteKey> = self
+            .routes
+            .iter()
+            .filter(|&(key, route)| predicate(key, route))
+            .map(|(key, _)| key.clone())
+            .collect();
+        for key in keys {
+            if let Some(route) = self.routes.remove(&key) {
+                changes.push(CacheChange::DelRoute(route));
+            }
+        }
+    }
+
+    /// Return the changes that turn `self` into `new`. Removals come first, and routes and
+    /// addresses are removed before their link, and added after it.
+    fn diff(&self, new: &Tables) -> Vec<CacheChange> {
+        let mut changes = vec![];
+        for (key, route) in &self.routes {
+            if !new.routes.contains_key(key) {
+                changes.push(CacheChange::DelRoute(route.clone()));
+            }
+        }
+        for (key, address) in &self.addresses {
+            if !new.addresses.contains_key(key) {
+                changes.push(CacheChange::DelAddress(address.clone()));
+            }
+        }
+        for (index, link) in &self.links {
+            if !new.links.contains_key(index) {
+                changes.push(CacheChange::DelLink(link.clone()));
+            }
+        }
+        for (index, link) in &new.links {
+            if self.links.get(index) != Some(link) {
+                changes.push(CacheChange::NewLink(link.clone()));
+            }
+        }
+        for (key, address) in &new.addresses {
+            if self.addresses.get(key) != Some(address) {
+                changes.push(CacheChange::NewAddress(address.clone()));
+            }
+        }
+        for (key, route) in &new.routes {
+            if self.routes.get(key) != Some(route) {
+                changes.push(CacheChange::NewRoute(route.clone()));
+            }
+        }
+        changes
+    }
+}
+
+#[derive(Debug, Default)]
+struct CacheState {
+    tables: Tables,
+    synced: bool,
+    subscribers: Vec<UnboundedSender<CacheChange>>,
+}
+
+impl CacheState {
+    fn subscribe(&mut self) -> UnboundedReceiver<CacheChange> {
+        let (tx, rx) = unbounded();
+        self.subscribers.push(tx);
+        rx
+    }
+
+    /// Send the changes to the subscribers, and forget the subscribers that are gone
+    fn emit(&mut self, changes: &[CacheChange]) {
+        if changes.is_empty() {
+            return;
+        }
+        self.subscribers.retain(|tx| {
+            changes
+                .iter()
+                .all(|change| tx.unbounded_send(change.clone()).is_ok())
+        });
+    }
+}
+
+/// An in-memory copy of the links, addresses and routes, kept in sync with the kernel by a
+/// [`CacheManager`](struct.CacheManager.html). It is created with
+/// [`new_network_cache()`](fn.new_network_cache.html).
+///
+/// Lookups don't send any request to the kernel. The cache is empty until the initial dump
+/// completes (see [`synced()`](#method.synced)). Cloning the cache is cheap: the clones share the
+/// same data.
+///
+/// The kernel only notifies IPv6 addresses once duplicate address detection completes, so the
+/// tentative addresses that are created after the initial dump are missing from the cache.
+#[derive(Clone, Debug)]
+pub struct NetworkCache {
+    state: Arc<Mutex<CacheState>>,
+}
+
+impl NetworkCache {
+    fn lock(&self) -> MutexGuard<'_, CacheState> {
+        self.state.lock().unwrap()
+    }
+
+    /// Whether the cache reflects the state of the kernel. This is not the case before the
+    /// initial dump completes, nor after notifications were lost until the cache is filled again.
+    pub fn is_synced(&self) -> bool {
+        self.lock().synced
+    }
+
+    /// Return a future that completes once the cache is synced (see
+    /// [`is_synced()`](#method.is_synced)). It fails with
+    /// [`NetlinkIpError::ConnectionClosed`](enum.NetlinkIpError.html#variant.ConnectionClosed)
+    /// if the manager stops before.
+    pub fn synced(&self) -> impl Future<Item = (), Error = NetlinkIpError> {
+        let mut state = self.lock();
+        if state.synced {
+            return Either::A(future::ok(()));
+        }
+        let changes = state.subscribe();
+        Either::B(
+            changes
+                .filter(|change| *change == CacheChange::Synced)
+                .into_future()
+                .then(|result| match result {
+                    Ok((Some(_), _)) => Ok(()),
+                    _ => Err(NetlinkIpError::ConnectionClosed),
+                }),
+        )
+    }
+
+    /// Return a stream of the changes made to the cache from now on. The stream ends when the
+    /// manager stops.
+    pub fn changes(&self) -> UnboundedReceiver<CacheChange> {
+        self.lock().subscribe()
+    }
+
+    /// Return the link with index `index`
+    pub fn link(&self, index: u32) -> Option<Link> {
+        self.lock().tables.links.get(&index).cloned()
+    }
+
+    /// Return the link named `name`
+    pub fn link_by_name(&self, name: &str) -> Option<Link> {
+        self.lock()
+            .tables
+            .links
+            .values()
+            .find(|link| link.name() == Some(name))
+            .cloned()
+    }
+
+    /// Return all the links, sorted by index
+    pub fn links(&self) -> Vec<Link> {
+        self.lock().tables.links.values().cloned().collect()
+    }
+
+    /// Return all the addresses
+    pub fn addresses(&self) -> Vec<AddressMessage> {
+        self.lock().tables.addresses.values().cloned().collect()
+    }
+
+    /// Return the addresses of the link with index `index`
+    pub fn link_addresses(&self, index: u32) -> Vec<AddressMessage> {
+        self.lock()
+            .tables
+            .addresses
+            .values()
+            .filter(|address| address.header.index == index)
+            .cloned()
+            .collect()
+    }
+
+    /// Return the routes of all the routing tables
+    pub fn routes(&self) -> Vec<RouteMessage> {
+        self.lock().tables.routes.values().cloned().collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rtnetlink::constants::{AF_BRIDGE, AF_INET6};
+    use rtnetlink::{LinkFlags, LinkNla, NetlinkFlags, RouteTable};
+
+    fn link_message(index: u32, name: &str, up: bool) -> LinkMessage {
+        let mut message = LinkMessage::new();
+        let mut flags = LinkFlags::new();
+        if up {
+            flags.set_up();
+        }
+        message.header_mut().set_index(index).set_flags(flags);
+        message.append_nla(LinkNla::IfName(name.into()));
+        message
+    }
+
+    fn link(index: u32, name: &str, up: bool) -> Link {
+        Link::from_link_message(link_message(index, name, up)).unwrap()
+    }
+
+    fn address(index: u32, ip: [u8; 4], prefix_len: u8) -> AddressMessage {
+        let mut message = AddressMessage::default();
+        message.header.family = AF_INET as u8;
+        message.header.prefix_len = prefix_len;
+        message.header.index = index;
+        message.nlas.push(AddressNla::Address(ip.to_vec()));
+        message.nlas.push(AddressNla::Local(ip.to_vec()));
+        message
+    }
+
+    fn route(family: u16, destination: &[u8], oif: u32, scope: RouteScope) -> RouteMessage {
+        let mut message = RouteMessage::default();
+        message.header.address_family = family as u8;
+        message.header.destination_length = (destination.len() * 8) as u8;
+        message.header.table = RouteTable::Main;
+        message.header.scope = scope;
+        message
+            .nlas
+            .push(RouteNla::Destination(destination.to_vec()));
+        message.nlas.push(RouteNla::Oif(oif));
+        message
+    }
+
+    fn replace(message: RtnlMessage) -> NetlinkMessage {
+        let mut message = NetlinkMessage::from(message);
+        message
+            .header_mut()
+            .set_flags(*NetlinkFlags::new().set_replace());
+        message
+    }
+
+    struct Case {
+        name: &'static str,
+        tables: Tables,
+        notifications: Vec<NetlinkMessage>,
+        changes: Vec<CacheChange>,
+    }
+
+    #[test]
+    fn apply_cases() {
+        let eth0 = link(2, "eth0", true);
+        let address = address(2, [10, 0, 0, 1], 24);
+        let mut address_modified = address.clone();
+        address_modified.header.scope = 253;
+        let universe = route(AF_INET, &[10, 1, 0, 0], 2, RouteScope::Universe);
+        let host = route(AF_INET, &[10, 0, 0, 1], 2, RouteScope::Host);
+        let ipv6 = route(AF_INET6, &[0xfd; 16], 2, RouteScope::Universe);
+        let other_link = route(AF_INET, &[10, 1, 0, 0], 3, RouteScope::Universe);
+        let mut bridge_port = link_message(2, "eth0", false);
+        bridge_port.header_mut().set_address_family(AF_BRIDGE as u8);
+
+        let cases = vec![
+            Case {
+                name: "new link, notified twice",
+                tables: Tables::default(),
+                notifications: vec![
+                    NetlinkMessage::from(RtnlMessage::NewLink(link_message(2, "eth0", true))),
+                    NetlinkMessage::from(RtnlMessage::NewLink(link_message(2, "eth0", true))),
+                ],
+                changes: vec![CacheChange::NewLink(eth0.clone())],
+            },
+            Case {
+                name: "bridge port notification",
+                tables: Tables::from_dump(vec![eth0.clone()], vec![], vec![]),
+                notifications: vec![NetlinkMessage::from(RtnlMessage::NewLink(bridge_port))],
+                changes: vec![],
+            },
+            Case {
+                name: "deleted link, with its addresses and routes",
+                tables: Tables::from_dump(
+                    vec![link(1, "lo", true), eth0.clone()],
+                    vec![address.clone()],
+                    vec![universe.clone(), other_link.clone()],
+                ),
+                notifications: vec![NetlinkMessage::from(RtnlMessage::DelLink(link_message(
+                    2, "eth0", true,
+                )))],
+                changes: vec![
+                    CacheChange::DelAddress(address.clone()),
+                    CacheChange::DelRoute(universe.clone()),
+                    CacheChange::DelLink(eth0.clone()),
+                ],
+            },
+            Case {
+                name: "link down",
+                tables: Tables::from_dump(
+                    vec![eth0.clone()],
+                    vec![],
+                    vec![
+                        universe.clone(),
+                        host.clone(),
+                        ipv6.clone(),
+                        other_link.clone(),
+                    ],
+                ),
+                notifications: vec![NetlinkMessage::from(RtnlMessage::NewLink(link_message(
+                    2, "eth0", false,
+                )))],
+                changes: vec![
+                    CacheChange::DelRoute(universe.clone()),
+                    CacheChange::NewLink(link(2, "eth0", false)),
+                ],
+            },
+            Case {
+                name: "new, modified and deleted address",
+                tables: Tables::default(),
+                notifications: vec![
+                    NetlinkMessage::from(RtnlMessage::NewAddress(address.clone())),
+                    NetlinkMessage::from(RtnlMessage::NewAddress(address.clone())),
+                    NetlinkMessage::from(RtnlMessage::NewAddress(address_modified.clone())),
+                    NetlinkMessage::from(RtnlMessage::DelAddress(address.clone())),
+                    NetlinkMessage::from(RtnlMessage::DelAddress(address.clone())),
+                ],
+                changes: vec![
+                    CacheChange::NewAddress(address.clone()),
+                    CacheChange::NewAddress(address_modified.clone()),
+                    CacheChange::DelAddress(address_modified.clone()),
+                ],
+            },
+            Case {
+                name: "appended route",
+                tables: Tables::from_dump(vec![], vec![], vec![universe.clone()]),
+                notifications: vec![NetlinkMessage::from(RtnlMessage::NewRoute(
+                    other_link.clone(),
+                ))],
+                changes: vec![CacheChange::NewRoute(other_link.clone())],
+            },
+            Case {
+                name: "replaced route",
+                tables: Tables::from_dump(vec![], vec![], vec![universe.clone(), host.clone()]),
+                notifications: vec![replace(RtnlMessage::NewRoute(other_link.clone()))],
+                changes: vec![
+                    CacheChange::DelRoute(universe.clone()),
+                    CacheChange::NewRoute(other_link.clone()),
+                ],
+            },
+            Case {
+                name: "deleted route",
+                tables: Tables::from_dump(vec![], vec![], vec![universe.clone(), host.clone()]),
+                notifications: vec![
+                    NetlinkMessage::from(RtnlMessage::DelRoute(host.clone())),
+                    NetlinkMessage::from(RtnlMessage::DelRoute(host.clone())),
+                ],
+                changes: vec![CacheChange::DelRoute(host.clone())],
+            },
+        ];
+
+        for case in cases {
+            let mut tables = case.tables;
+            let mut changes = vec![];
+            for notification in case.notifications {
+                tables.apply(notification, &mut changes);
+            }
+            assert_eq!(changes, case.changes, "{}", case.name);
+        }
+    }
+    #[test]
+    fn diff() {
+        let lo = link(1, "lo", true);
+        let eth0 = link(2, "eth0", true);
+        let eth1 = link(3, "eth1", true);
+        let eth0_address = address(2, [10, 0, 0, 1], 24);
+        let eth1_address = address(3, [10, 0, 1, 1], 24);
+        let eth0_route = route(AF_INET, &[10, 1, 0, 0], 2, RouteScope::Universe);
+        let eth1_route = route(AF_INET, &[10, 1, 0, 0], 3, RouteScope::Universe);
+        let old = Tables::from_dump(
+            vec![lo.clone(), eth0.clone()],
+            vec![eth0_address.clone()],
+            vec![eth0_route.clone()],
+        );
+        let new = Tables::from_dump(
+            vec![link(1, "lo", false), eth1.clone()],
+            vec![eth1_address.clone()],
+            vec![eth1_route.clone()],
+        );
+        assert_eq!(
+            old.diff(&new),
+            vec![
+                CacheChange::DelRoute(eth0_route),
+                CacheChange::DelAddress(eth0_address),
+                CacheChange::DelLink(eth0),
+                CacheChange::NewLink(link(1, "lo", false)),
+                CacheChange::NewLink(eth1),
+                CacheChange::NewAddress(eth1_address),
+                CacheChange::NewRoute(eth1_route),
+            ]
+        );
+        assert_eq!(new.diff(&new), vec![]);
+    }
+}
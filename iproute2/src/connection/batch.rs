@@ -0,0 +1,62 @@
+use futures::stream::futures_ordered;
+use futures::{Future, Stream};
+
+use rtnetlink::NetlinkMessage;
+
+use super::{ConnectionHandle, Stream2Ack};
+use errors::NetlinkIpError;
+
+/// A batch of requests, created with
+/// [`ConnectionHandle::batch()`](struct.ConnectionHandle.html#method.batch).
+///
+/// The requests of a batch are passed to the connection together, so they are packed into as few
+/// datagrams as possible. Each request is acknowledged separately by the kernel, and a failed
+/// request does not prevent the following ones from being executed.
+pub struct BatchRequest {
+    handle: ConnectionHandle,
+    messages: Vec<NetlinkMessage>,
+}
+
+impl BatchRequest {
+    pub(crate) fn new(handle: ConnectionHandle) -> Self {
+        BatchRequest {
+            handle,
+            messages: vec![],
+        }
+    }
+
+    /// Add a request to the batch. The `NLM_F_REQUEST` and `NLM_F_ACK` flags are set when the
+    /// batch is executed.
+    pub fn request(mut self, message: NetlinkMessage) -> Self {
+        self.messages.push(message);
+        self
+    }
+
+    /// Number of requests in the batch
+    pub fn len(&self) -> usize {
+        self.messages.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.messages.is_empty()
+    }
+
+    /// Execute the requests. The result contains the outcome of each request, in the order they
+    /// were added: `Ok(())` if the kernel acknowledged it, or the error it returned.
+    pub fn execute(
+        self,
+    ) -> impl Future<Item = Vec<Result<(), NetlinkIpError>>, Error = NetlinkIpError> {
+        let BatchRequest {
+            mut handle,
+            messages,
+        } = self;
+        let acks: Vec<_> = messages
+            .into_iter()
+            .map(|mut message| {
+                message.header_mut().flags_mut().set_request().set_ack();
+                Stream2Ack::new(handle.request(message)).then(Ok)
+            })
+            .collect();
+        futures_ordered(acks).collect()
+    }
+}
@@ -1,15 +1,39 @@
 use errors::NetlinkIpError;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::io;
+use std::time::{Duration, Instant};
 
 use futures::sync::mpsc::{UnboundedReceiver, UnboundedSender};
 use futures::{Async, AsyncSink, Future, Poll, Sink, Stream};
+use tokio_timer::Delay;
 
 use netlink_socket::{Protocol, SocketAddr, TokioSocket};
-use rtnetlink::{NetlinkCodec, NetlinkFramed, NetlinkMessage};
+use rtnetlink::{self, NetlinkCodec, NetlinkFramed, NetlinkMessage, RtnlMessage};
 use std::collections::VecDeque;
 
-type RequestsRx = UnboundedReceiver<(UnboundedSender<NetlinkMessage>, NetlinkMessage)>;
+type NetlinkSocket = NetlinkFramed<NetlinkCodec<NetlinkMessage>>;
+
+pub(crate) type ResponseTx = UnboundedSender<Result<NetlinkMessage, NetlinkIpError>>;
+
+/// A request passed by a [`ConnectionHandle`](struct.ConnectionHandle.html) to the connection
+#[derive(Debug)]
+pub(crate) struct Request {
+    pub(crate) response_tx: ResponseTx,
+    pub(crate) message: NetlinkMessage,
+    pub(crate) timeout: Option<Duration>,
+}
+
+type RequestsRx = UnboundedReceiver<Request>;
+
+struct PendingRequest {
+    response_tx: ResponseTx,
+    deadline: Option<Instant>,
+    // Whether the request is a dump. When the receive buffer is full, the kernel waits for room
+    // to send the rest of a dump, instead of dropping it.
+    dump: bool,
+    // Whether the kernel flagged one of the messages of the response with NLM_F_DUMP_INTR
+    dump_interrupted: bool,
+}
 
 lazy_static! {
     static ref KERNEL_PORT: SocketAddr = SocketAddr::new(0, 0);
@@ -19,32 +43,103 @@ lazy_static! {
 ///
 /// [`ConnectionHandle`](struct.ConnectionHandle.html) are used to pass new requests to the
 /// `Connection`, that in turn, sends them through the netlink socket.
-pub struct Connection {
-    socket: NetlinkFramed<NetlinkCodec<NetlinkMessage>>,
+///
+/// The connection completes once all its handles are dropped and the responses to the pending
+/// requests have been received. A connection that forwards notifications keeps running until the
+/// notifications stream is dropped as well.
+///
+/// The connection fails if reading from or writing to the socket fails, except when the socket
+/// receive buffer overflows: the requests in flight, other than dumps, then fail with
+/// [`NetlinkIpError::Overrun`](enum.NetlinkIpError.html#variant.Overrun), since their responses
+/// may have been dropped, and the connection keeps running.
+pub struct Connection<S = NetlinkSocket> {
+    socket: S,
     sequence_id: u32,
     // This is kind of hacky, and due to an implementation detail of NetlinkFramed.
     // Basically, the Sink API assumes that multiple `start_send()` calls can be done before
     // calling `poll_complete()`, and that the items being sent will be buffered. But NetlinkFramed
     // only buffers one frame, so subsequent calls to `
     requests_buffer: VecDeque<NetlinkMessage>,
-    pending_requests: HashMap<u32, UnboundedSender<NetlinkMessage>>,
+    pending_requests: HashMap<u32, PendingRequest>,
+    // Sequence numbers of the pending requests sent with NLM_F_ECHO
+    echo_requests: HashSet<u32>,
+    port_number: u32,
+    // Fires at the earliest deadline of the pending requests
+    timer: Option<Delay>,
     requests_rx: RequestsRx,
+    notifications_tx: Option<UnboundedSender<NetlinkMessage>>,
     shutting_down: bool,
 }
 
 impl Connection {
-    pub(crate) fn new(requests_rx: RequestsRx) -> io::Result<Self> {
-        let socket = TokioSocket::new(Protocol::Route)?;
+    pub(crate) fn new(
+        requests_rx: RequestsRx,
+        groups: &[u32],
+        notifications_tx: Option<UnboundedSender<NetlinkMessage>>,
+    ) -> io::Result<Self> {
+        let mut socket = TokioSocket::new(Protocol::Route)?;
+        // Binding resets the multicast groups, so bind before joining them
+        trace!("socket: binding");
+        let port_number = socket.bind_auto()?.port_number();
+        for group in groups {
+            trace!("socket: joining multicast group {}", group);
+            socket.add_membership(*group)?;
+        }
+        // Ask the kernel to honor the filters of the dump requests. Older kernels don't support
+        // strict checking: the filters are then applied when the responses are received.
+        if let Err(e) = socket.set_strict_checking(true) {
+            debug!("socket: could not enable strict checking: {}", e);
+        }
         trace!("socket: connecting");
         socket.connect(&KERNEL_PORT)?;
-        Ok(Connection {
-            socket: NetlinkFramed::new(socket, NetlinkCodec::<NetlinkMessage>::new()),
+        let socket = NetlinkFramed::new(socket, NetlinkCodec::<NetlinkMessage>::new());
+        Ok(Connection::from_socket(
+            socket,
+            port_number,
+            requests_rx,
+            notifications_tx,
+        ))
+    }
+
+    /// Set the maximum size of the datagrams the requests are packed into. By default, it is the
+    /// largest datagram the socket accepts.
+    pub fn set_max_datagram_size(&mut self, size: usize) {
+        self.socket.set_max_datagram_size(size);
+    }
+
+    /// Set the maximum number of datagrams sent with a single `sendmmsg` system call. It defaults
+    /// to 1, which means that `sendmmsg` is not used. Since the requests are packed into large
+    /// datagrams, this is mostly useful with a small [maximum datagram
+    /// size](#method.set_max_datagram_size).
+    pub fn set_max_datagrams(&mut self, count: usize) {
+        self.socket.set_max_datagrams(count);
+    }
+}
+
+impl<S> Connection<S>
+where
+    S: Stream<Item = (NetlinkMessage, SocketAddr), Error = rtnetlink::Error>
+        + Sink<SinkItem = (NetlinkMessage, SocketAddr), SinkError = rtnetlink::Error>,
+{
+    /// Create a connection on top of a socket that is already bound and connected to the kernel
+    pub(crate) fn from_socket(
+        socket: S,
+        port_number: u32,
+        requests_rx: RequestsRx,
+        notifications_tx: Option<UnboundedSender<NetlinkMessage>>,
+    ) -> Self {
+        Connection {
+            socket,
             sequence_id: 0,
             pending_requests: HashMap::new(),
-            requests_buffer: VecDeque::with_capacity(1024),
+            echo_requests: HashSet::new(),
+            port_number,
+            timer: None,
+            requests_buffer: VecDeque::new(),
             requests_rx,
+            notifications_tx,
             shutting_down: false,
-        })
+        }
     }
 
     fn prepare_request(&mut self, message: &mut NetlinkMessage) {
@@ -55,16 +150,19 @@ impl Connection {
 
     // FIXME: this should probably return an error when the sink is full and we don't have any more
     // space to buffer the message
-    fn send_request(&mut self, message: NetlinkMessage) -> AsyncSink<NetlinkMessage> {
+    fn send_request(&mut self, message: NetlinkMessage) -> io::Result<AsyncSink<NetlinkMessage>> {
         if !self.requests_buffer.is_empty() {
             trace!("there are already requests waiting for being sent");
-            return AsyncSink::NotReady(message);
+            return Ok(AsyncSink::NotReady(message));
         }
 
-        // FIXME: in futures 0.2, use poll_ready before reading from pending_responses, and
-        // don't panic here.
+        // FIXME: in futures 0.2, use poll_ready before reading from pending_responses
         trace!("sending message: {:?}", message);
-        match self.socket.start_send((message, *KERNEL_PORT)).unwrap() {
+        let sink = self
+            .socket
+            .start_send((message, *KERNEL_PORT))
+            .map_err(io_error)?;
+        Ok(match sink {
             AsyncSink::NotReady((message, _)) => {
                 // The sink is full atm. There is no need to try to call poll_send() because
                 // internally start_send should call it:
@@ -79,45 +177,50 @@ impl Connection {
                 AsyncSink::NotReady(message)
             }
             AsyncSink::Ready => {
-                // NetlinkFramed can only buffer one frame, so to avoid clogging the sink, we need
-                // to flush it as soon as we call start_send(). We don't care about the result
-                // however.
-                let _ = self.socket.poll_complete().unwrap();
-                // Return Ready, because the message is being sent
+                // The message is queued. NetlinkFramed packs the messages queued before the sink
+                // is flushed into as few datagrams as possible, so we only flush once all the
+                // requests have been queued.
                 AsyncSink::Ready
             }
-        }
+        })
     }
 
-    fn process_buffered_requests(&mut self) {
+    fn process_buffered_requests(&mut self) -> io::Result<()> {
         while let Some(message) = self.requests_buffer.pop_front() {
-            match self.send_request(message) {
+            match self.send_request(message)? {
                 AsyncSink::Ready => {}
                 AsyncSink::NotReady(message) => {
                     self.requests_buffer.push_front(message);
-                    return;
+                    return Ok(());
                 }
             }
         }
         trace!("all the buffered requests have been sent");
+        Ok(())
     }
 
-    fn buffer_request(&mut self, request: NetlinkMessage) -> Result<(), NetlinkIpError> {
-        if self.requests_buffer.len() == self.requests_buffer.capacity() {
-            // we should drop the request and return an error
-            unimplemented!()
-        }
+    // The handles limit the number of requests in flight (see `RequestWindow`), so the buffer
+    // cannot grow beyond that limit.
+    fn buffer_request(&mut self, request: NetlinkMessage) {
         self.requests_buffer.push_back(request);
-        Ok(())
     }
 
     fn handle_message(&mut self, message: NetlinkMessage) {
+        if message.is_overrun() {
+            self.handle_overrun();
+            return;
+        }
+
         let seq = message.header().sequence_number();
         let mut close_chan = false;
 
         debug!("handling message {}", seq);
 
-        if let Some(tx) = self.pending_requests.get_mut(&seq) {
+        if let Some(request) = self.pending_requests.get_mut(&seq) {
+            if message.header().flags().has_dump_intr() {
+                request.dump_interrupted = true;
+            }
+            let tx = &request.response_tx;
             if !message.header().flags().has_multipart() {
                 trace!("not a multipart message");
                 close_chan = true;
@@ -125,6 +228,12 @@ impl Connection {
 
             if message.is_done() {
                 trace!("received end of dump message");
+                if request.dump_interrupted {
+                    // The data changed while it was being dumped, so the response may be
+                    // inconsistent.
+                    debug!("dump {} was interrupted", seq);
+                    let _ = tx.unbounded_send(Err(NetlinkIpError::DumpInterrupted));
+                }
                 close_chan = true;
             } else if message.is_noop() {
                 trace!("ignoring NOOP");
@@ -132,7 +241,7 @@ impl Connection {
                 trace!("forwarding error message and closing channel with handle");
                 // If send returns an Err, its because the other side has been dropped, so it
                 // does not really matter.
-                let _ = UnboundedSender::unbounded_send(tx, message);
+                let _ = UnboundedSender::unbounded_send(tx, Ok(message));
                 close_chan = true;
             } else if message.is_ack() {
                 trace!(
@@ -140,16 +249,12 @@ impl Connection {
                     message.header().sequence_number()
                 );
                 close_chan = true;
-            } else if message.is_overrun() {
-                // FIXME: we should obviously NOT panic here but I'm not sure what we
-                // should do. Can we increase the buffer size now? Should we leave that the
-                // users?
-                panic!("overrun: receive buffer is full");
-            } else {
-                tx.unbounded_send(message).expect("FIXME: handle that");
+            } else if tx.unbounded_send(Ok(message)).is_err() {
+                // The response stream has been dropped: the request is cancelled.
+                trace!("the response stream of message {} has been dropped", seq);
+                close_chan = true;
             }
         } else {
-            // FIXME: we should check whether it's an Overrun error maybe?
             trace!(
                 "unknown sequence number {}, ignoring the message",
                 message.header().sequence_number()
@@ -158,18 +263,157 @@ impl Connection {
 
         if close_chan {
             debug!("removing {} from the pending requests", seq);
-            let _ = self.pending_requests.remove(&seq);
+            let _ = self.remove_pending_request(seq);
+        }
+    }
+
+    fn remove_pending_request(&mut self, seq: u32) -> Option<PendingRequest> {
+        let _ = self.echo_requests.remove(&seq);
+        self.pending_requests.remove(&seq)
+    }
+
+    /// Forget the requests whose response stream has been dropped. Any response the kernel sends
+    /// afterward will be ignored.
+    fn remove_cancelled_requests(&mut self) {
+        let cancelled: Vec<u32> = self
+            .pending_requests
+            .iter()
+            .filter(|&(_, request)| request.response_tx.is_closed())
+            .map(|(seq, _)| *seq)
+            .collect();
+        for seq in cancelled {
+            debug!("request {} has been cancelled", seq);
+            let _ = self.remove_pending_request(seq);
         }
     }
 
+    /// Fail the requests whose deadline has passed, and set the timer to the next deadline.
+    fn expire_requests(&mut self) {
+        loop {
+            let now = Instant::now();
+            let expired: Vec<u32> = self
+                .pending_requests
+                .iter()
+                .filter(|&(_, request)| request.deadline.map_or(false, |d| d <= now))
+                .map(|(seq, _)| *seq)
+                .collect();
+            for seq in expired {
+                debug!("request {} timed out", seq);
+                if let Some(request) = self.remove_pending_request(seq) {
+                    let _ = request
+                        .response_tx
+                        .unbounded_send(Err(NetlinkIpError::Timeout));
+                }
+            }
+
+            let next_deadline = self
+                .pending_requests
+                .values()
+                .filter_map(|request| request.deadline)
+                .min();
+            let deadline = match next_deadline {
+                Some(deadline) => deadline,
+                None => {
+                    self.timer = None;
+                    return;
+                }
+            };
+            let mut timer = self.timer.take().unwrap_or_else(|| Delay::new(deadline));
+            timer.reset(deadline);
+            match timer.poll() {
+                Ok(Async::NotReady) => {
+                    self.timer = Some(timer);
+                    return;
+                }
+                // The deadline passed in the meantime
+                Ok(Async::Ready(())) => {}
+                Err(e) => {
+                    // This happens when the connection does not run on a tokio event loop.
+                    warn!("cannot enforce the requests timeouts: {}", e);
+                    return;
+                }
+            }
+        }
+    }
+
+    fn is_echo(&self, message: &NetlinkMessage) -> bool {
+        message.header().port_number() == self.port_number
+            && self
+                .echo_requests
+                .contains(&message.header().sequence_number())
+    }
+
+    fn handle_notification(&mut self, message: NetlinkMessage) {
+        let mut close_chan = false;
+        if let Some(ref tx) = self.notifications_tx {
+            trace!("forwarding notification");
+            // If send returns an Err, the receiver has been dropped: stop forwarding notifications
+            close_chan = tx.unbounded_send(message).is_err();
+        } else {
+            trace!("no notifications channel, ignoring the notification");
+        }
+        if close_chan {
+            debug!("the notifications receiver has been dropped");
+            self.notifications_tx = None;
+        }
+    }
+
+    /// The socket receive buffer overflowed, so the kernel dropped messages. Dumps are not
+    /// affected, because the kernel waits for room in the buffer to send the next messages, but
+    /// the responses of the other pending requests may have been lost. These requests fail, which
+    /// releases their slot in the request window. Notifications were lost too: tell the
+    /// notifications receiver, so that it can dump the state again.
+    fn handle_overrun(&mut self) {
+        warn!("socket: the receive buffer overflowed, responses and notifications were lost");
+        let lost: Vec<u32> = self
+            .pending_requests
+            .iter()
+            .filter(|&(_, request)| !request.dump)
+            .map(|(seq, _)| *seq)
+            .collect();
+        for seq in lost {
+            debug!("request {} failed: its response may have been lost", seq);
+            if let Some(request) = self.remove_pending_request(seq) {
+                let _ = request
+                    .response_tx
+                    .unbounded_send(Err(NetlinkIpError::Overrun));
+            }
+        }
+        let overrun = NetlinkMessage::from(RtnlMessage::Overrun(vec![]));
+        self.handle_notification(overrun);
+    }
+
     fn shutdown(&mut self) {
         debug!("shutting down the connection");
         self.requests_rx.close();
         self.shutting_down = true;
     }
+
+    /// Whether the connection is shutting down, and has nothing left to do
+    fn is_done(&self) -> bool {
+        self.shutting_down
+            && self.pending_requests.is_empty()
+            && self.requests_buffer.is_empty()
+            && self
+                .notifications_tx
+                .as_ref()
+                .map_or(true, |tx| tx.is_closed())
+    }
 }
 
-impl Future for Connection {
+/// Turn the errors of the netlink socket into I/O errors
+fn io_error(error: rtnetlink::Error) -> io::Error {
+    match error {
+        rtnetlink::Error::Io(e) => e,
+        e => io::Error::new(io::ErrorKind::InvalidData, e.to_string()),
+    }
+}
+
+impl<S> Future for Connection<S>
+where
+    S: Stream<Item = (NetlinkMessage, SocketAddr), Error = rtnetlink::Error>
+        + Sink<SinkItem = (NetlinkMessage, SocketAddr), SinkError = rtnetlink::Error>,
+{
     type Item = ();
     type Error = io::Error;
 
@@ -177,10 +421,29 @@ impl Future for Connection {
         debug!("polling connection");
 
         trace!("reading from socket");
-        while let Async::Ready(msg) = self.socket.poll().unwrap() {
-            if let Some((msg, _addr)) = msg {
+        loop {
+            let msg = match self.socket.poll() {
+                Ok(Async::Ready(msg)) => msg,
+                Ok(Async::NotReady) => break,
+                Err(rtnetlink::Error::Io(ref e)) if e.raw_os_error() == Some(libc::ENOBUFS) => {
+                    self.handle_overrun();
+                    continue;
+                }
+                Err(e) => {
+                    error!("failed to read from the netlink socket: {}", e);
+                    return Err(io_error(e));
+                }
+            };
+            if let Some((msg, addr)) = msg {
                 trace!("message received: {:?}", msg);
-                self.handle_message(msg);
+                // Notifications are sent to multicast groups, while responses to our requests are
+                // unicast. The exception is the echo of a request sent with NLM_F_ECHO: the kernel
+                // sends the same buffer to the multicast group and to us.
+                if addr.multicast_groups() != 0 && !self.is_echo(&msg) {
+                    self.handle_notification(msg);
+                } else {
+                    self.handle_message(msg);
+                }
             } else {
                 trace!("socket closed");
                 // XXX: check if there's something else to do?
@@ -188,8 +451,11 @@ impl Future for Connection {
             }
         }
 
+        self.remove_cancelled_requests();
+        self.expire_requests();
+
         trace!("flushing socket");
-        if let Async::NotReady = self.socket.poll_complete().unwrap() {
+        if let Async::NotReady = self.socket.poll_complete().map_err(io_error)? {
             // We do not poll the requests channel if the sink is full to create backpressure. It's
             // ok not to poll because as soon as the sink makes progress, this future will be
             // called.
@@ -197,12 +463,16 @@ impl Future for Connection {
             return Ok(Async::NotReady);
         }
 
-        self.process_buffered_requests();
+        self.process_buffered_requests()?;
         if !self.requests_buffer.is_empty() {
             trace!("there are requests waiting to be sent. Not processing any new request for now");
             return Ok(Async::NotReady);
         }
 
+        if self.is_done() {
+            debug!("the connection is shut down");
+            return Ok(Async::Ready(()));
+        }
         if self.shutting_down {
             // If we're shutting down, we don't accept any more request
             trace!("the connection is shutting down: not trying to get new requests");
@@ -211,17 +481,31 @@ impl Future for Connection {
 
         trace!("polling requests channel");
         while let Async::Ready(request) = self.requests_rx.poll().unwrap() {
-            if let Some((tx_channel, mut msg)) = request {
+            if let Some(request) = request {
                 trace!("request received");
+                let Request {
+                    response_tx,
+                    message: mut msg,
+                    timeout,
+                } = request;
                 self.prepare_request(&mut msg);
-                match self.send_request(msg) {
+                if msg.header().flags().has_echo() {
+                    self.echo_requests.insert(self.sequence_id);
+                }
+                let pending = PendingRequest {
+                    response_tx,
+                    deadline: timeout.map(|timeout| Instant::now() + timeout),
+                    dump: msg.header().flags().has_dump(),
+                    dump_interrupted: false,
+                };
+                self.pending_requests.insert(self.sequence_id, pending);
+                match self.send_request(msg)? {
                     AsyncSink::Ready => {
                         trace!("request sent with sequence id {}", self.sequence_id);
-                        self.pending_requests.insert(self.sequence_id, tx_channel);
                     }
                     AsyncSink::NotReady(msg) => {
                         trace!("buffering message that could not be sent");
-                        self.buffer_request(msg).unwrap();
+                        self.buffer_request(msg);
                     }
                 }
             } else {
@@ -231,10 +515,325 @@ impl Future for Connection {
             }
         }
 
-        // After sending the requests, flush the sink. We don't care about the outcome here
-        let _ = self.socket.poll_complete().unwrap();
+        // After sending the requests, flush the sink. We don't care about the outcome here, unless
+        // it fails
+        let _ = self.socket.poll_complete().map_err(io_error)?;
+
+        // Arm the timer for the requests we just received
+        self.expire_requests();
+
+        if self.is_done() {
+            debug!("the connection is shut down");
+            return Ok(Async::Ready(()));
+        }
 
         trace!("re-registering interest in readiness events for the connection");
         Ok(Async::NotReady)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::num::NonZeroUsize;
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+
+    use futures::executor::{self, Notify, NotifyHandle, Spawn};
+    use futures::sync::mpsc::unbounded;
+    use futures::StartSend;
+    use rtnetlink::constants::{
+        NLM_F_ACK, NLM_F_DUMP, NLM_F_DUMP_INTR, NLM_F_REQUEST, NLM_MULTIPART,
+    };
+    use rtnetlink::{ErrorMessage, LinkMessage, NetlinkFlags};
+
+    use connection::{ConnectionHandle, DEFAULT_DUMP_RETRIES};
+    use Stream2Ack;
+
+    /// A socket that plays the kernel: the test reads the requests sent by the connection, and
+    /// queues the messages the connection receives.
+    #[derive(Clone, Default)]
+    struct MockSocket {
+        sent: Arc<Mutex<VecDeque<NetlinkMessage>>>,
+        received: Arc<Mutex<VecDeque<rtnetlink::Result<NetlinkMessage>>>>,
+    }
+
+    impl MockSocket {
+        fn pop_sent(&self) -> Option<NetlinkMessage> {
+            self.sent.lock().unwrap().pop_front()
+        }
+
+        fn receive(&self, message: rtnetlink::Result<NetlinkMessage>) {
+            self.received.lock().unwrap().push_back(message);
+        }
+    }
+
+    impl Stream for MockSocket {
+        type Item = (NetlinkMessage, SocketAddr);
+        type Error = rtnetlink::Error;
+
+        fn poll(&mut self) -> Poll<Option<Self::Item>, Self::Error> {
+            match self.received.lock().unwrap().pop_front() {
+                Some(Ok(message)) => Ok(Async::Ready(Some((message, *KERNEL_PORT)))),
+                Some(Err(e)) => Err(e),
+                None => Ok(Async::NotReady),
+            }
+        }
+    }
+
+    impl Sink for MockSocket {
+        type SinkItem = (NetlinkMessage, SocketAddr);
+        type SinkError = rtnetlink::Error;
+
+        fn start_send(
+            &mut self,
+            item: Self::SinkItem,
+        ) -> StartSend<Self::SinkItem, Self::SinkError> {
+            self.sent.lock().unwrap().push_back(item.0);
+            Ok(AsyncSink::Ready)
+        }
+
+        fn poll_complete(&mut self) -> Poll<(), Self::SinkError> {
+            Ok(Async::Ready(()))
+        }
+    }
+
+    struct Noop;
+
+    impl Notify for Noop {
+        fn notify(&self, _id: usize) {}
+    }
+
+    fn poll<F: Future>(future: &mut Spawn<F>) -> Poll<F::Item, F::Error> {
+        future.poll_future_notify(&NotifyHandle::from(Arc::new(Noop)), 0)
+    }
+
+    fn connection() -> (Spawn<Connection<MockSocket>>, ConnectionHandle, MockSocket) {
+        let (tx, rx) = unbounded();
+        let socket = MockSocket::default();
+        let connection = Connection::from_socket(socket.clone(), 1, rx, None);
+        (
+            executor::spawn(connection),
+            ConnectionHandle::new(tx),
+            socket,
+        )
+    }
+
+    fn link_del() -> NetlinkMessage {
+        let mut message = NetlinkMessage::from(RtnlMessage::DelLink(LinkMessage::new()));
+        message
+            .header_mut()
+            .set_flags(NetlinkFlags::from(NLM_F_REQUEST | NLM_F_ACK));
+        message
+    }
+
+    fn ack(request: &NetlinkMessage) -> NetlinkMessage {
+        let mut message = NetlinkMessage::from(RtnlMessage::Ack(ErrorMessage {
+            code: 0,
+            header: *request.header(),
+        }));
+        message
+            .header_mut()
+            .set_sequence_number(request.header().sequence_number());
+        message
+    }
+
+    fn link_dump() -> NetlinkMessage {
+        let mut message = NetlinkMessage::from(RtnlMessage::GetLink(LinkMessage::new()));
+        message
+            .header_mut()
+            .set_flags(NetlinkFlags::from(NLM_F_REQUEST | NLM_F_DUMP));
+        message
+    }
+
+    /// Answer a dump request with the link with index `index`, and flag the response as
+    /// interrupted if `interrupted` is true
+    fn dump_response(socket: &MockSocket, request: &NetlinkMessage, index: u32, interrupted: bool) {
+        let mut flags = NLM_MULTIPART;
+        if interrupted {
+            flags |= NLM_F_DUMP_INTR;
+        }
+        let mut link = LinkMessage::new();
+        link.header_mut().set_index(index);
+        for message in [RtnlMessage::NewLink(link), RtnlMessage::Done] {
+            let mut message = NetlinkMessage::from(message);
+            message.header_mut().set_flags(NetlinkFlags::from(flags));
+            message
+                .header_mut()
+                .set_sequence_number(request.header().sequence_number());
+            socket.receive(Ok(message));
+        }
+    }
+
+    #[test]
+    fn completes_once_handles_are_dropped() {
+        let (mut connection, mut handle, socket) = connection();
+        let mut response = executor::spawn(Stream2Ack::new(handle.request(link_del())));
+        drop(handle);
+        assert_eq!(poll(&mut response).unwrap(), Async::NotReady);
+
+        // The pending request keeps the connection running
+        assert_eq!(poll(&mut connection).unwrap(), Async::NotReady);
+        let request = socket.pop_sent().unwrap();
+        socket.receive(Ok(ack(&request)));
+        assert_eq!(poll(&mut connection).unwrap(), Async::NotReady);
+        assert_eq!(poll(&mut response).unwrap(), Async::Ready(()));
+        drop(response);
+        assert_eq!(poll(&mut connection).unwrap(), Async::Ready(()));
+    }
+
+    #[test]
+    fn runs_while_notifications_are_read() {
+        let (tx, rx) = unbounded();
+        let (notifications_tx, notifications_rx) = unbounded();
+        let socket = MockSocket::default();
+        let connection = Connection::from_socket(socket, 1, rx, Some(notifications_tx));
+        let mut connection = executor::spawn(connection);
+        drop(ConnectionHandle::new(tx));
+        assert_eq!(poll(&mut connection).unwrap(), Async::NotReady);
+        drop(notifications_rx);
+        assert_eq!(poll(&mut connection).unwrap(), Async::Ready(()));
+    }
+
+    #[test]
+    fn expire_requests() {
+        let (mut connection, mut handle, socket) = connection();
+        let mut expiring = executor::spawn(Stream2Ack::new(
+            handle.request_with_timeout(link_del(), Some(Duration::from_millis(10))),
+        ));
+        let mut pending = executor::spawn(Stream2Ack::new(
+            handle.request_with_timeout(link_del(), Some(Duration::from_secs(3600))),
+        ));
+        assert_eq!(poll(&mut expiring).unwrap(), Async::NotReady);
+        assert_eq!(poll(&mut pending).unwrap(), Async::NotReady);
+        assert_eq!(poll(&mut connection).unwrap(), Async::NotReady);
+        let expiring_request = socket.pop_sent().unwrap();
+        let pending_request = socket.pop_sent().unwrap();
+
+        // Without an event loop the timer cannot be set, but the deadlines are still checked
+        // whenever the connection is polled
+        thread::sleep(Duration::from_millis(20));
+        assert_eq!(poll(&mut connection).unwrap(), Async::NotReady);
+        match poll(&mut expiring) {
+            Err(NetlinkIpError::Timeout) => {}
+            result => panic!("unexpected result: {:?}", result),
+        }
+        assert_eq!(poll(&mut pending).unwrap(), Async::NotReady);
+
+        // The late acknowledgement of the expired request is ignored
+        socket.receive(Ok(ack(&expiring_request)));
+        socket.receive(Ok(ack(&pending_request)));
+        assert_eq!(poll(&mut connection).unwrap(), Async::NotReady);
+        assert_eq!(poll(&mut pending).unwrap(), Async::Ready(()));
+    }
+
+    #[test]
+    fn interrupted_dump_restarts() {
+        let (mut connection, mut handle, socket) = connection();
+        let mut response = executor::spawn(handle.request(link_dump()).collect());
+        assert_eq!(poll(&mut response).unwrap(), Async::NotReady);
+        assert_eq!(poll(&mut connection).unwrap(), Async::NotReady);
+        let request = socket.pop_sent().unwrap();
+        dump_response(&socket, &request, 1, true);
+        assert_eq!(poll(&mut connection).unwrap(), Async::NotReady);
+
+        // The response of the interrupted dump is discarded, and the request is sent again
+        assert_eq!(poll(&mut response).unwrap(), Async::NotReady);
+        assert_eq!(poll(&mut connection).unwrap(), Async::NotReady);
+        let request = socket.pop_sent().unwrap();
+        dump_response(&socket, &request, 2, false);
+        assert_eq!(poll(&mut connection).unwrap(), Async::NotReady);
+        let messages = match poll(&mut response).unwrap() {
+            Async::Ready(messages) => messages,
+            Async::NotReady => panic!("the dump is not complete"),
+        };
+        assert_eq!(messages.len(), 1);
+        match *messages[0].message() {
+            RtnlMessage::NewLink(ref link) => assert_eq!(link.header().index(), 2),
+            ref message => panic!("unexpected message: {:?}", message),
+        }
+    }
+
+    #[test]
+    fn interrupted_dump_gives_up() {
+        let (mut connection, mut handle, socket) = connection();
+        let mut response = executor::spawn(handle.request(link_dump()).collect());
+        for _ in 0..=DEFAULT_DUMP_RETRIES {
+            assert_eq!(poll(&mut response).unwrap(), Async::NotReady);
+            assert_eq!(poll(&mut connection).unwrap(), Async::NotReady);
+            let request = socket.pop_sent().unwrap();
+            dump_response(&socket, &request, 1, true);
+            assert_eq!(poll(&mut connection).unwrap(), Async::NotReady);
+        }
+        match poll(&mut response) {
+            Err(NetlinkIpError::DumpInterrupted) => {}
+            result => panic!("unexpected result: {:?}", result),
+        }
+        assert_eq!(poll(&mut connection).unwrap(), Async::NotReady);
+        assert!(socket.pop_sent().is_none());
+    }
+
+    #[test]
+    fn window_recovers_from_overrun() {
+        let (mut connection, mut handle, socket) = connection();
+        handle.set_max_in_flight(NonZeroUsize::new(2).unwrap());
+        let mut responses: Vec<_> = (0..3)
+            .map(|_| executor::spawn(Stream2Ack::new(handle.request(link_del()))))
+            .collect();
+
+        // The window is full: only the first two requests are sent
+        for response in &mut responses {
+            assert_eq!(poll(response).unwrap(), Async::NotReady);
+        }
+        assert_eq!(poll(&mut connection).unwrap(), Async::NotReady);
+        assert!(socket.pop_sent().is_some());
+        assert!(socket.pop_sent().is_some());
+        assert!(socket.pop_sent().is_none());
+
+        // The acknowledgements are dropped
+        socket.receive(Err(rtnetlink::Error::Io(io::Error::from_raw_os_error(
+            libc::ENOBUFS,
+        ))));
+        assert_eq!(poll(&mut connection).unwrap(), Async::NotReady);
+        for response in &mut responses[..2] {
+            match poll(response) {
+                Err(NetlinkIpError::Overrun) => {}
+                result => panic!("unexpected result: {:?}", result),
+            }
+        }
+
+        // Their slots are released, so the last request goes through
+        assert_eq!(poll(&mut responses[2]).unwrap(), Async::NotReady);
+        assert_eq!(poll(&mut connection).unwrap(), Async::NotReady);
+        let request = socket.pop_sent().unwrap();
+        socket.receive(Ok(ack(&request)));
+        assert_eq!(poll(&mut connection).unwrap(), Async::NotReady);
+        assert_eq!(poll(&mut responses[2]).unwrap(), Async::Ready(()));
+    }
+
+    #[test]
+    fn overrun_message() {
+        let (mut connection, mut handle, socket) = connection();
+        let mut response = executor::spawn(Stream2Ack::new(handle.request(link_del())));
+        assert_eq!(poll(&mut response).unwrap(), Async::NotReady);
+        assert_eq!(poll(&mut connection).unwrap(), Async::NotReady);
+        assert!(socket.pop_sent().is_some());
+
+        socket.receive(Ok(NetlinkMessage::from(RtnlMessage::Overrun(vec![]))));
+        assert_eq!(poll(&mut connection).unwrap(), Async::NotReady);
+        match poll(&mut response) {
+            Err(NetlinkIpError::Overrun) => {}
+            result => panic!("unexpected result: {:?}", result),
+        }
+    }
+
+    #[test]
+    fn socket_error() {
+        let (mut connection, _handle, socket) = connection();
+        socket.receive(Err(rtnetlink::Error::Io(io::Error::from_raw_os_error(
+            libc::EBADF,
+        ))));
+        let error = poll(&mut connection).unwrap_err();
+        assert_eq!(error.raw_os_error(), Some(libc::EBADF));
+    }
+}
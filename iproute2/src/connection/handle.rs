@@ -1,21 +1,99 @@
+use futures::future::Either;
 use futures::sync::mpsc::{unbounded, UnboundedSender};
 use futures::Stream;
 use rtnetlink::NetlinkMessage;
-use LinkHandle;
+use std::num::NonZeroUsize;
+use std::time::Duration;
+use {
+    AddressHandle, BridgeVlanHandle, DesiredState, FdbHandle, LinkHandle, MdbHandle,
+    ReconcileRequest, RouteHandle, RuleHandle, StatsHandle, Transaction,
+};
 
+use super::{BatchRequest, DumpResponse, Request, RequestWindow, Response, DEFAULT_MAX_IN_FLIGHT};
 use errors::NetlinkIpError;
 
-type RequestsTx = UnboundedSender<(UnboundedSender<NetlinkMessage>, NetlinkMessage)>;
+type RequestsTx = UnboundedSender<Request>;
+
+/// Number of times an interrupted dump is restarted by default
+pub const DEFAULT_DUMP_RETRIES: u32 = 3;
 
 /// A handle to pass requests to a [`Connection`](struct.Connection.html).
+///
+/// Requests can be given a timeout, either for all the requests of a handle with
+/// [`set_timeout()`](#method.set_timeout), or for a single request with
+/// [`request_with_timeout()`](#method.request_with_timeout). When a request times out, its
+/// response stream fails with [`NetlinkIpError::Timeout`](enum.NetlinkIpError.html#variant.Timeout).
+///
+/// Dropping the response stream of a request cancels it: the connection forgets about the request
+/// and ignores the messages the kernel may still send for it.
+///
+/// When the dumped data changes during a dump, the kernel flags the response with
+/// `NLM_F_DUMP_INTR`, because it may be inconsistent. Such dumps are restarted, up to
+/// [`dump_retries()`](#method.dump_retries) times, after which the response stream fails with
+/// [`NetlinkIpError::DumpInterrupted`](enum.NetlinkIpError.html#variant.DumpInterrupted).
+///
+/// At most [`max_in_flight()`](#method.max_in_flight) requests are in flight on a connection at
+/// any time. A request is in flight from the moment it is passed to the connection until its
+/// whole response has been received, or its response stream is dropped. Beyond that limit, the
+/// response streams are not ready until other requests complete, so it is safe to pipeline a large
+/// number of requests, for instance with `Stream::buffer_unordered`.
 #[derive(Clone, Debug)]
 pub struct ConnectionHandle {
-    requests_tx: UnboundedSender<(UnboundedSender<NetlinkMessage>, NetlinkMessage)>,
+    requests_tx: RequestsTx,
+    window: RequestWindow,
+    timeout: Option<Duration>,
+    dump_retries: u32,
 }
 
 impl ConnectionHandle {
     pub(crate) fn new(requests_tx: RequestsTx) -> Self {
-        ConnectionHandle { requests_tx }
+        ConnectionHandle {
+            requests_tx,
+            window: RequestWindow::new(DEFAULT_MAX_IN_FLIGHT),
+            timeout: None,
+            dump_retries: DEFAULT_DUMP_RETRIES,
+        }
+    }
+
+    /// Set the timeout of the requests sent with this handle, and with the handles cloned from it
+    /// afterward. `None`, the default, means that requests never time out.
+    pub fn set_timeout(&mut self, timeout: Option<Duration>) {
+        self.timeout = timeout;
+    }
+
+    /// Timeout of the requests sent with this handle
+    pub fn timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+
+    /// Set how many times the dumps sent with this handle, and with the handles cloned from it
+    /// afterward, are restarted when they get interrupted. It defaults to
+    /// [`DEFAULT_DUMP_RETRIES`](constant.DEFAULT_DUMP_RETRIES.html).
+    pub fn set_dump_retries(&mut self, retries: u32) {
+        self.dump_retries = retries;
+    }
+
+    /// Number of times the interrupted dumps are restarted
+    pub fn dump_retries(&self) -> u32 {
+        self.dump_retries
+    }
+
+    /// Set the maximum number of requests in flight on the connection. Unlike the other settings,
+    /// it applies to all the handles of the connection. It defaults to
+    /// [`DEFAULT_MAX_IN_FLIGHT`](constant.DEFAULT_MAX_IN_FLIGHT.html).
+    ///
+    /// The responses are queued in the socket receive buffer until the connection reads them. If
+    /// too many requests are in flight, the buffer fills up and the kernel drops responses. With
+    /// the default buffer size, this happens with a few dozen links, or a few hundred
+    /// acknowledgements. The requests in flight, other than dumps, then fail with
+    /// [`NetlinkIpError::Overrun`](enum.NetlinkIpError.html#variant.Overrun).
+    pub fn set_max_in_flight(&mut self, max_in_flight: NonZeroUsize) {
+        self.window.set_max_in_flight(max_in_flight.get());
+    }
+
+    /// Maximum number of requests in flight on the connection
+    pub fn max_in_flight(&self) -> usize {
+        self.window.max_in_flight()
     }
 
     /// Send a new request and get the response as a stream of messages. Note that some messages
@@ -23,24 +101,108 @@ impl ConnectionHandle {
     ///
     /// - **acknowledgements**: when an acknowledgement is received, the stream is closed
     /// - **end of dump messages**: similarly, upon receiving an "end of dump" message, the stream is
-    /// closed
+    ///   closed
+    ///
+    /// The response to a dump request (with the `NLM_F_DUMP` flag) is only yielded once the dump
+    /// is complete and consistent.
+    ///
+    /// The request times out after the handle's [`timeout()`](#method.timeout), if any.
     pub fn request(
         &mut self,
         message: NetlinkMessage,
     ) -> impl Stream<Item = NetlinkMessage, Error = NetlinkIpError> {
-        let (tx, rx) = unbounded::<NetlinkMessage>();
-        // Ignore the result. If this failed, `tx` will be dropped when this funtion returns, and
-        // polling rx with fail, carrying the error.
-        debug!("handle: forwarding new request to connection");
-        let _ = UnboundedSender::unbounded_send(&self.requests_tx, (tx, message));
-        rx.map_err(|()| {
-            error!("could not forward new request to connection: the connection is closed");
-            NetlinkIpError::ConnectionClosed
-        })
+        let timeout = self.timeout;
+        self.request_with_timeout(message, timeout)
+    }
+
+    /// Same as [`request()`](#method.request), but with a timeout specific to this request. If
+    /// the whole response has not been received after `timeout`, the stream fails with
+    /// [`NetlinkIpError::Timeout`](enum.NetlinkIpError.html#variant.Timeout). When an
+    /// interrupted dump is restarted, the timeout applies to each attempt.
+    pub fn request_with_timeout(
+        &mut self,
+        message: NetlinkMessage,
+        timeout: Option<Duration>,
+    ) -> impl Stream<Item = NetlinkMessage, Error = NetlinkIpError> {
+        if message.header().flags().has_dump() {
+            let retries = self.dump_retries;
+            Either::A(DumpResponse::new(self.clone(), message, timeout, retries))
+        } else {
+            Either::B(self.send(message, timeout))
+        }
+    }
+
+    pub(crate) fn send(&mut self, message: NetlinkMessage, timeout: Option<Duration>) -> Response {
+        let (tx, rx) = unbounded::<Result<NetlinkMessage, NetlinkIpError>>();
+        let request = Request {
+            response_tx: tx,
+            message,
+            timeout,
+        };
+        Response::new(self.window.clone(), self.requests_tx.clone(), request, rx)
+    }
+
+    /// Create a new batch of requests, to submit many requests at once and get the outcome of
+    /// each of them (see [`BatchRequest`](struct.BatchRequest.html))
+    pub fn batch(&self) -> BatchRequest {
+        BatchRequest::new(self.clone())
+    }
+
+    /// Create a new transaction, to make changes that are rolled back if one of them fails (see
+    /// [`Transaction`](struct.Transaction.html))
+    pub fn transaction(&self) -> Transaction {
+        Transaction::new(self.clone())
+    }
+
+    /// Create a new request to bring the network to the given state (see
+    /// [`ReconcileRequest`](struct.ReconcileRequest.html))
+    pub fn reconcile(&self, state: DesiredState) -> ReconcileRequest {
+        ReconcileRequest::new(self.clone(), state)
     }
 
     /// Create a new handle, specifically for link requests (equivalent to `ip link` commands)
     pub fn link(&self) -> LinkHandle {
         LinkHandle::new(self.clone())
     }
+
+    /// Create a new handle, specifically for bridge VLAN requests (equivalent to `bridge vlan`
+    /// commands)
+    pub fn bridge_vlan(&self) -> BridgeVlanHandle {
+        BridgeVlanHandle::new(self.clone())
+    }
+
+    /// Create a new handle, specifically for bridge forwarding database requests (equivalent to
+    /// `bridge fdb` commands)
+    pub fn fdb(&self) -> FdbHandle {
+        FdbHandle::new(self.clone())
+    }
+
+    /// Create a new handle, specifically for bridge multicast database requests (equivalent to
+    /// `bridge mdb` commands)
+    pub fn mdb(&self) -> MdbHandle {
+        MdbHandle::new(self.clone())
+    }
+
+    /// Create a new handle, specifically for link statistics requests (equivalent to `ip stats`
+    /// commands)
+    pub fn stats(&self) -> StatsHandle {
+        StatsHandle::new(self.clone())
+    }
+
+    /// Create a new handle, specifically for address requests (equivalent to `ip address`
+    /// commands)
+    pub fn address(&self) -> AddressHandle {
+        AddressHandle::new(self.clone())
+    }
+
+    /// Create a new handle, specifically for route requests (equivalent to `ip route` commands)
+    pub fn route(&self) -> RouteHandle {
+        RouteHandle::new(self.clone())
+    }
+
+    /// Create a new handle, specifically for routing rule requests (equivalent to `ip rule`
+    /// commands)
+    pub fn rule(&self) -> RuleHandle {
+        RuleHandle::new(self.clone())
+    }
 }
@@ -1,12 +1,18 @@
+mod batch;
 mod connection;
 mod handle;
+mod response;
 mod utils;
+mod window;
 
+pub use self::batch::*;
 pub use self::connection::*;
 pub use self::handle::*;
+pub(crate) use self::response::*;
 pub(crate) use self::utils::*;
+pub use self::window::*;
 
-use futures::sync::mpsc::{unbounded, UnboundedSender};
+use futures::sync::mpsc::{unbounded, UnboundedReceiver};
 use rtnetlink::NetlinkMessage;
 use std::io;
 
@@ -77,6 +83,57 @@ use std::io;
 /// }
 /// ```
 pub fn new_connection() -> io::Result<(Connection, ConnectionHandle)> {
-    let (tx, rx) = unbounded::<(UnboundedSender<NetlinkMessage>, NetlinkMessage)>();
-    Ok((Connection::new(rx)?, ConnectionHandle::new(tx)))
+    let (tx, rx) = unbounded::<Request>();
+    Ok((Connection::new(rx, &[], None)?, ConnectionHandle::new(tx)))
+}
+
+/// Create a new connection that joins the given multicast groups (see the `RTNLGRP_*` constants),
+/// a handle to pass requests to it, and a stream of the notifications the kernel sends to these
+/// groups, such as `RTM_NEWLINK` and `RTM_DELLINK` messages when links are created, modified or
+/// deleted.
+///
+/// The connection can be used for regular requests as well. Notifications are only received while
+/// the connection is running. If they arrive faster than they are read, the socket receive buffer
+/// overflows and the kernel drops some of them: the stream then yields an `NLMSG_OVERRUN` message
+/// (see `NetlinkMessage::is_overrun()`), after which the state should be dumped again. See
+/// [`NetworkCache`](struct.NetworkCache.html), which does this.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// extern crate futures;
+/// extern crate iproute2;
+/// extern crate rtnetlink;
+/// extern crate tokio_core;
+///
+/// use futures::{Future, Stream};
+/// use tokio_core::reactor::Core;
+///
+/// use iproute2::new_multicast_connection;
+/// use rtnetlink::constants::RTNLGRP_LINK;
+///
+/// fn main() {
+///     let mut core = Core::new().unwrap();
+///     let (connection, _handle, notifications) =
+///         new_multicast_connection(&[RTNLGRP_LINK]).unwrap();
+///     core.handle().spawn(connection.map_err(|_| ()));
+///
+///     // Print the link notifications as they arrive
+///     core.run(notifications.for_each(|msg| {
+///         println!("{:?}", msg);
+///         Ok(())
+///     })).unwrap();
+/// }
+/// ```
+pub fn new_multicast_connection(
+    groups: &[u32],
+) -> io::Result<(
+    Connection,
+    ConnectionHandle,
+    UnboundedReceiver<NetlinkMessage>,
+)> {
+    let (tx, rx) = unbounded::<Request>();
+    let (notifications_tx, notifications_rx) = unbounded::<NetlinkMessage>();
+    let connection = Connection::new(rx, groups, Some(notifications_tx))?;
+    Ok((connection, ConnectionHandle::new(tx), notifications_rx))
 }
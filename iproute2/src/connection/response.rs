@@ -0,0 +1,185 @@
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use futures::sync::mpsc::{UnboundedReceiver, UnboundedSender};
+use futures::{Async, Poll, Stream};
+use rtnetlink::NetlinkMessage;
+
+use super::{ConnectionHandle, Request, RequestWindow};
+use errors::NetlinkIpError;
+
+/// The response to a request. The request is passed to the connection once it gets a slot in the
+/// [`RequestWindow`](struct.RequestWindow.html), and the messages the connection receives in
+/// response are yielded as they arrive.
+pub(crate) struct Response {
+    window: RequestWindow,
+    id: u64,
+    requests_tx: UnboundedSender<Request>,
+    // The request, until it is passed to the connection
+    request: Option<Request>,
+    // Whether the request holds a slot in the window
+    in_flight: bool,
+    // Whether the request could not be passed to the connection because it is closed
+    connection_closed: bool,
+    rx: UnboundedReceiver<Result<NetlinkMessage, NetlinkIpError>>,
+}
+
+impl Response {
+    pub(crate) fn new(
+        window: RequestWindow,
+        requests_tx: UnboundedSender<Request>,
+        request: Request,
+        rx: UnboundedReceiver<Result<NetlinkMessage, NetlinkIpError>>,
+    ) -> Self {
+        let mut response = Response {
+            id: window.new_id(),
+            window,
+            requests_tx,
+            request: Some(request),
+            in_flight: false,
+            connection_closed: false,
+            rx,
+        };
+        // Pass the request to the connection right away if possible. Otherwise, this is done
+        // when the response is polled.
+        if response.window.try_acquire() {
+            response.in_flight = true;
+            // If this fails, the error is returned when the response is polled
+            response.connection_closed = response.pass_request().is_err();
+        }
+        response
+    }
+
+    fn pass_request(&mut self) -> Result<(), NetlinkIpError> {
+        let request = self.request.take().unwrap();
+        debug!("handle: forwarding new request to connection");
+        self.requests_tx.unbounded_send(request).map_err(|_| {
+            error!("could not forward new request to connection: the connection is closed");
+            NetlinkIpError::ConnectionClosed
+        })
+    }
+
+    fn poll_send(&mut self) -> Poll<(), NetlinkIpError> {
+        if self.connection_closed {
+            return Err(NetlinkIpError::ConnectionClosed);
+        }
+        if self.request.is_none() {
+            return Ok(Async::Ready(()));
+        }
+        if !self.window.poll_acquire(self.id) {
+            return Ok(Async::NotReady);
+        }
+        self.in_flight = true;
+        self.pass_request()?;
+        Ok(Async::Ready(()))
+    }
+
+    fn release(&mut self) {
+        if self.in_flight {
+            self.in_flight = false;
+            self.window.release();
+        }
+    }
+}
+
+impl Stream for Response {
+    type Item = NetlinkMessage;
+    type Error = NetlinkIpError;
+
+    fn poll(&mut self) -> Poll<Option<Self::Item>, Self::Error> {
+        match self.poll_send() {
+            Ok(Async::Ready(())) => {}
+            Ok(Async::NotReady) => return Ok(Async::NotReady),
+            Err(e) => {
+                self.release();
+                return Err(e);
+            }
+        }
+        match self.rx.poll() {
+            Ok(Async::Ready(Some(Ok(message)))) => Ok(Async::Ready(Some(message))),
+            Ok(Async::Ready(Some(Err(e)))) => {
+                self.release();
+                Err(e)
+            }
+            Ok(Async::Ready(None)) => {
+                self.release();
+                Ok(Async::Ready(None))
+            }
+            Ok(Async::NotReady) => Ok(Async::NotReady),
+            Err(()) => {
+                self.release();
+                Err(NetlinkIpError::ConnectionClosed)
+            }
+        }
+    }
+}
+
+impl Drop for Response {
+    fn drop(&mut self) {
+        if self.request.is_some() {
+            self.window.cancel(self.id);
+        }
+        self.release();
+    }
+}
+
+/// The response to a dump request. The messages are only yielded once the whole dump has been
+/// received. If the kernel reports that the dump was interrupted, the messages received so far
+/// are discarded and the request is sent again, at most `retries` times.
+pub(crate) struct DumpResponse {
+    handle: ConnectionHandle,
+    request: NetlinkMessage,
+    timeout: Option<Duration>,
+    retries: u32,
+    response: Response,
+    messages: VecDeque<NetlinkMessage>,
+    complete: bool,
+}
+
+impl DumpResponse {
+    pub(crate) fn new(
+        mut handle: ConnectionHandle,
+        request: NetlinkMessage,
+        timeout: Option<Duration>,
+        retries: u32,
+    ) -> Self {
+        let response = handle.send(request.clone(), timeout);
+        DumpResponse {
+            handle,
+            request,
+            timeout,
+            retries,
+            response,
+            messages: VecDeque::new(),
+            complete: false,
+        }
+    }
+}
+
+impl Stream for DumpResponse {
+    type Item = NetlinkMessage;
+    type Error = NetlinkIpError;
+
+    fn poll(&mut self) -> Poll<Option<Self::Item>, Self::Error> {
+        loop {
+            if self.complete {
+                return Ok(Async::Ready(self.messages.pop_front()));
+            }
+            match self.response.poll() {
+                Ok(Async::Ready(Some(message))) => self.messages.push_back(message),
+                Ok(Async::Ready(None)) => self.complete = true,
+                Ok(Async::NotReady) => return Ok(Async::NotReady),
+                Err(NetlinkIpError::DumpInterrupted) if self.retries > 0 => {
+                    debug!(
+                        "dump interrupted, restarting it ({} retries left)",
+                        self.retries
+                    );
+                    self.retries -= 1;
+                    self.messages.clear();
+                    self.response = self.handle.send(self.request.clone(), self.timeout);
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+}
@@ -0,0 +1,116 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+
+use futures::task::{self, Task};
+
+/// Default maximum number of requests in flight on a connection
+pub const DEFAULT_MAX_IN_FLIGHT: usize = 16;
+
+/// Limits the number of requests in flight on a connection, i.e. the requests that have been
+/// passed to the connection and for which the whole response has not been received yet. It is
+/// shared by all the handles of the connection.
+#[derive(Clone, Debug)]
+pub(crate) struct RequestWindow(Arc<Mutex<WindowState>>);
+
+#[derive(Debug)]
+struct WindowState {
+    max_in_flight: usize,
+    in_flight: usize,
+    next_id: u64,
+    // Tasks of the requests waiting for a slot
+    waiters: HashMap<u64, Task>,
+    // Identifiers of the requests waiting for a slot, in the order they asked for one. It may
+    // contain requests that stopped waiting.
+    queue: VecDeque<u64>,
+}
+
+impl RequestWindow {
+    pub(crate) fn new(max_in_flight: usize) -> Self {
+        RequestWindow(Arc::new(Mutex::new(WindowState {
+            max_in_flight,
+            in_flight: 0,
+            next_id: 0,
+            waiters: HashMap::new(),
+            queue: VecDeque::new(),
+        })))
+    }
+
+    pub(crate) fn max_in_flight(&self) -> usize {
+        self.0.lock().unwrap().max_in_flight
+    }
+
+    pub(crate) fn set_max_in_flight(&self, max_in_flight: usize) {
+        let mut state = self.0.lock().unwrap();
+        state.max_in_flight = max_in_flight;
+        // The window may have grown
+        state.notify_waiters();
+    }
+
+    /// Return a new identifier for a request
+    pub(crate) fn new_id(&self) -> u64 {
+        let mut state = self.0.lock().unwrap();
+        state.next_id += 1;
+        state.next_id
+    }
+
+    /// Try to get a slot, unless the window is full or other requests are already waiting for one.
+    pub(crate) fn try_acquire(&self) -> bool {
+        let mut state = self.0.lock().unwrap();
+        if state.waiters.is_empty() && state.in_flight < state.max_in_flight {
+            state.in_flight += 1;
+            return true;
+        }
+        false
+    }
+
+    /// Try to get a slot for request `id`. If the window is full, the current task is notified
+    /// once a slot is released. This must be called from within a task.
+    pub(crate) fn poll_acquire(&self, id: u64) -> bool {
+        let mut state = self.0.lock().unwrap();
+        if state.in_flight < state.max_in_flight {
+            state.waiters.remove(&id);
+            state.in_flight += 1;
+            return true;
+        }
+        trace!(
+            "request window is full, request {} is waiting for a slot",
+            id
+        );
+        if state.waiters.insert(id, task::current()).is_none() {
+            state.queue.push_back(id);
+        }
+        false
+    }
+
+    /// Release the slot of a request whose response is complete
+    pub(crate) fn release(&self) {
+        let mut state = self.0.lock().unwrap();
+        state.in_flight -= 1;
+        state.notify_waiters();
+    }
+
+    /// Stop waiting for a slot for request `id`
+    pub(crate) fn cancel(&self, id: u64) {
+        let mut state = self.0.lock().unwrap();
+        state.waiters.remove(&id);
+        // The request may have been notified of a slot it won't take: pass it on to another one.
+        state.notify_waiters();
+    }
+}
+
+impl WindowState {
+    /// Notify as many waiting requests as there are free slots
+    fn notify_waiters(&mut self) {
+        let mut available = self.max_in_flight.saturating_sub(self.in_flight);
+        while available > 0 {
+            let id = match self.queue.pop_front() {
+                Some(id) => id,
+                None => return,
+            };
+            if let Some(task) = self.waiters.remove(&id) {
+                task.notify();
+                available -= 1;
+            }
+        }
+    }
+}
@@ -1,4 +1,6 @@
+use failure;
 use rtnetlink::NetlinkMessage;
+use std::fmt;
 use std::io;
 
 #[derive(Fail, Debug)]
@@ -22,4 +24,65 @@ pub enum NetlinkIpError {
         display = "Received a link message (RTM_GETLINK, RTM_NEWLINK, RTM_SETLINK or RTMGETLINK) with an invalid hardware address attribute."
     )]
     InvalidLinkAddress(Vec<u8>),
+
+    #[fail(
+        display = "Received a neighbour message (RTM_NEWNEIGH, RTM_DELNEIGH or RTM_GETNEIGH) with an invalid address attribute."
+    )]
+    InvalidNeighbourAddress(Vec<u8>),
+
+    #[fail(display = "The requested link does not exist")]
+    LinkNotFound,
+
+    #[fail(display = "The link with index {} is not a VRF device", _0)]
+    NotAVrf(u32),
+
+    #[fail(display = "The requested address does not exist")]
+    AddressNotFound,
+
+    #[fail(display = "The requested route does not exist")]
+    RouteNotFound,
+
+    #[fail(display = "The request timed out")]
+    Timeout,
+
+    #[fail(display = "The dump was interrupted too many times by changes to the dumped data")]
+    DumpInterrupted,
+
+    #[fail(display = "The socket receive buffer overflowed, so the response was lost")]
+    Overrun,
+
+    #[fail(display = "The change cannot be undone: {}", _0)]
+    Irreversible(String),
+
+    #[fail(display = "Duplicate address detection failed: the address is already in use")]
+    DadFailed,
+}
+
+/// Error returned when a [`Transaction`](struct.Transaction.html) fails. It carries the error
+/// that made the transaction fail, and the errors that occurred while rolling back the changes
+/// that had already been made.
+#[derive(Debug)]
+pub struct TransactionError {
+    pub error: NetlinkIpError,
+    pub rollback_errors: Vec<NetlinkIpError>,
+}
+
+impl failure::Fail for TransactionError {
+    fn cause(&self) -> Option<&dyn failure::Fail> {
+        Some(&self.error)
+    }
+}
+
+impl fmt::Display for TransactionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "The transaction failed: {}", self.error)?;
+        if !self.rollback_errors.is_empty() {
+            write!(
+                f,
+                " ({} changes could not be rolled back)",
+                self.rollback_errors.len()
+            )?;
+        }
+        Ok(())
+    }
 }
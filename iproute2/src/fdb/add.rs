@@ -0,0 +1,171 @@
+use std::net::IpAddr;
+
+use eui48::MacAddress;
+use futures::Future;
+
+use rtnetlink::constants::{
+    AF_BRIDGE, NLM_F_ACK, NLM_F_APPEND, NLM_F_CREATE, NLM_F_EXCL, NLM_F_REQUEST, NTF_MASTER,
+    NTF_SELF, NUD_NOARP, NUD_PERMANENT, NUD_REACHABLE,
+};
+use rtnetlink::{NeighbourMessage, NeighbourNla, NetlinkFlags, NetlinkMessage, RtnlMessage};
+
+use super::{ip_bytes, FdbDelRequest};
+use connection::ConnectionHandle;
+use errors::NetlinkIpError;
+
+use Stream2Ack;
+
+lazy_static! {
+    // Flags for `bridge fdb add`
+    static ref ADD_FLAGS: NetlinkFlags =
+        NetlinkFlags::from(NLM_F_REQUEST | NLM_F_ACK | NLM_F_EXCL | NLM_F_CREATE);
+    // Flags for `bridge fdb append`
+    static ref APPEND_FLAGS: NetlinkFlags =
+        NetlinkFlags::from(NLM_F_REQUEST | NLM_F_ACK | NLM_F_CREATE | NLM_F_APPEND);
+}
+
+pub struct FdbAddRequest {
+    handle: ConnectionHandle,
+    message: NeighbourMessage,
+    append: bool,
+}
+
+impl FdbAddRequest {
+    pub(crate) fn new(
+        handle: ConnectionHandle,
+        index: u32,
+        address: MacAddress,
+        append: bool,
+    ) -> Self {
+        let mut message = NeighbourMessage::default();
+        message.header.family = AF_BRIDGE as u8;
+        message.header.ifindex = index;
+        // like `bridge fdb add`, entries are static by default
+        message.header.state = NUD_NOARP;
+        message
+            .nlas
+            .push(NeighbourNla::LinkLayerAddress(address.as_bytes().to_vec()));
+        FdbAddRequest {
+            handle,
+            message,
+            append,
+        }
+    }
+
+    /// Execute the request
+    pub fn execute(self) -> impl Future<Item = (), Error = NetlinkIpError> {
+        let FdbAddRequest {
+            mut handle,
+            mut message,
+            append,
+        } = self;
+        // like `bridge fdb add`, assume `self` if neither `self` nor `master` is specified
+        if message.header.flags & (NTF_SELF | NTF_MASTER) == 0 {
+            message.header.flags |= NTF_SELF;
+        }
+        let mut req = NetlinkMessage::from(RtnlMessage::NewNeighbour(message));
+        if append {
+            req.header_mut().set_flags(*APPEND_FLAGS);
+        } else {
+            req.header_mut().set_flags(*ADD_FLAGS);
+        }
+        Stream2Ack::new(handle.request(req))
+    }
+
+    /// Return a request that removes the entry added by this request
+    pub(crate) fn inverse(&self) -> FdbDelRequest {
+        let mut request = FdbDelRequest::new(
+            self.handle.clone(),
+            self.message.header.ifindex,
+            MacAddress::nil(),
+        );
+        {
+            let message = request.message_mut();
+            message.header.flags = self.message.header.flags;
+            // Only keep the attributes that identify the entry
+            message.nlas = self
+                .message
+                .nlas
+                .iter()
+                .filter(|nla| match **nla {
+                    NeighbourNla::LinkLayerAddress(_)
+                    | NeighbourNla::Vlan(_)
+                    | NeighbourNla::Destination(_)
+                    | NeighbourNla::Vni(_) => true,
+                    _ => false,
+                })
+                .cloned()
+                .collect();
+        }
+        request
+    }
+
+    /// Return a mutable reference to the request
+    pub fn message_mut(&mut self) -> &mut NeighbourMessage {
+        &mut self.message
+    }
+
+    /// Add the entry to the forwarding database of the device itself. This is the default if
+    /// [`master()`](#method.master) is not called (equivalent to `bridge fdb add ... self`)
+    pub fn self_(mut self) -> Self {
+        self.message.header.flags |= NTF_SELF;
+        self
+    }
+
+    /// Add the entry to the forwarding database of the bridge the device is enslaved to
+    /// (equivalent to `bridge fdb add ... master`)
+    pub fn master(mut self) -> Self {
+        self.message.header.flags |= NTF_MASTER;
+        self
+    }
+
+    /// Make the entry permanent, ie a local address of the bridge (equivalent to `bridge fdb add
+    /// ... permanent`)
+    pub fn permanent(mut self) -> Self {
+        self.message.header.state |= NUD_PERMANENT;
+        self
+    }
+
+    /// Make the entry dynamic, so that it expires (equivalent to `bridge fdb add ... dynamic`)
+    pub fn dynamic(mut self) -> Self {
+        self.message.header.state |= NUD_REACHABLE;
+        self.message.header.state &= !NUD_NOARP;
+        self
+    }
+
+    /// Set the VLAN of the entry (equivalent to `bridge fdb add ... vlan VID`)
+    pub fn vlan(mut self, vlan: u16) -> Self {
+        self.message.nlas.push(NeighbourNla::Vlan(vlan));
+        self
+    }
+
+    /// Set the IP address of the remote VTEP, for VXLAN devices (equivalent to `bridge fdb add
+    /// ... dst IP`)
+    pub fn destination(mut self, destination: IpAddr) -> Self {
+        self.message
+            .nlas
+            .push(NeighbourNla::Destination(ip_bytes(destination)));
+        self
+    }
+
+    /// Set the VXLAN network identifier of the remote VTEP, for VXLAN devices (equivalent to
+    /// `bridge fdb add ... vni VNI`)
+    pub fn vni(mut self, vni: u32) -> Self {
+        self.message.nlas.push(NeighbourNla::Vni(vni));
+        self
+    }
+
+    /// Set the UDP destination port of the remote VTEP, for VXLAN devices (equivalent to `bridge
+    /// fdb add ... port PORT`)
+    pub fn port(mut self, port: u16) -> Self {
+        self.message.nlas.push(NeighbourNla::Port(port));
+        self
+    }
+
+    /// Set the outgoing interface to reach the remote VTEP, for VXLAN devices (equivalent to
+    /// `bridge fdb add ... via DEV`)
+    pub fn via(mut self, index: u32) -> Self {
+        self.message.nlas.push(NeighbourNla::IfIndex(index));
+        self
+    }
+}
@@ -0,0 +1,107 @@
+use std::net::IpAddr;
+
+use eui48::MacAddress;
+use futures::Future;
+
+use rtnetlink::constants::{AF_BRIDGE, NLM_F_ACK, NLM_F_REQUEST, NTF_MASTER, NTF_SELF};
+use rtnetlink::{NeighbourMessage, NeighbourNla, NetlinkFlags, NetlinkMessage, RtnlMessage};
+
+use super::{ip_bytes, FdbAddRequest};
+use connection::ConnectionHandle;
+use errors::NetlinkIpError;
+
+use Stream2Ack;
+
+lazy_static! {
+    // Flags for `bridge fdb del`
+    static ref DEL_FLAGS: NetlinkFlags = NetlinkFlags::from(NLM_F_REQUEST | NLM_F_ACK);
+}
+
+pub struct FdbDelRequest {
+    handle: ConnectionHandle,
+    message: NeighbourMessage,
+}
+
+impl FdbDelRequest {
+    pub(crate) fn new(handle: ConnectionHandle, index: u32, address: MacAddress) -> Self {
+        let mut message = NeighbourMessage::default();
+        message.header.family = AF_BRIDGE as u8;
+        message.header.ifindex = index;
+        message
+            .nlas
+            .push(NeighbourNla::LinkLayerAddress(address.as_bytes().to_vec()));
+        FdbDelRequest { handle, message }
+    }
+
+    /// Execute the request
+    pub fn execute(self) -> impl Future<Item = (), Error = NetlinkIpError> {
+        let FdbDelRequest {
+            mut handle,
+            mut message,
+        } = self;
+        if message.header.flags & (NTF_SELF | NTF_MASTER) == 0 {
+            message.header.flags |= NTF_SELF;
+        }
+        let mut req = NetlinkMessage::from(RtnlMessage::DelNeighbour(message));
+        req.header_mut().set_flags(*DEL_FLAGS);
+        Stream2Ack::new(handle.request(req))
+    }
+
+    /// Return a request that adds back the entry removed by this request. The entry is static,
+    /// like the entries created by [`FdbHandle::add()`](struct.FdbHandle.html#method.add).
+    pub(crate) fn inverse(&self) -> FdbAddRequest {
+        let mut request = FdbAddRequest::new(
+            self.handle.clone(),
+            self.message.header.ifindex,
+            MacAddress::nil(),
+            false,
+        );
+        {
+            let message = request.message_mut();
+            message.header.flags = self.message.header.flags;
+            message.nlas = self.message.nlas.clone();
+        }
+        request
+    }
+
+    /// Return a mutable reference to the request
+    pub fn message_mut(&mut self) -> &mut NeighbourMessage {
+        &mut self.message
+    }
+
+    /// Delete the entry from the forwarding database of the device itself. This is the default
+    /// if [`master()`](#method.master) is not called (equivalent to `bridge fdb del ... self`)
+    pub fn self_(mut self) -> Self {
+        self.message.header.flags |= NTF_SELF;
+        self
+    }
+
+    /// Delete the entry from the forwarding database of the bridge the device is enslaved to
+    /// (equivalent to `bridge fdb del ... master`)
+    pub fn master(mut self) -> Self {
+        self.message.header.flags |= NTF_MASTER;
+        self
+    }
+
+    /// Only delete the entry of the given VLAN (equivalent to `bridge fdb del ... vlan VID`)
+    pub fn vlan(mut self, vlan: u16) -> Self {
+        self.message.nlas.push(NeighbourNla::Vlan(vlan));
+        self
+    }
+
+    /// Only delete the entry pointing to the given remote VTEP, for VXLAN devices (equivalent to
+    /// `bridge fdb del ... dst IP`)
+    pub fn destination(mut self, destination: IpAddr) -> Self {
+        self.message
+            .nlas
+            .push(NeighbourNla::Destination(ip_bytes(destination)));
+        self
+    }
+
+    /// Only delete the entry with the given VXLAN network identifier, for VXLAN devices
+    /// (equivalent to `bridge fdb del ... vni VNI`)
+    pub fn vni(mut self, vni: u32) -> Self {
+        self.message.nlas.push(NeighbourNla::Vni(vni));
+        self
+    }
+}
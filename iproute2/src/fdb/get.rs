@@ -0,0 +1,55 @@
+use futures::{Future, Stream};
+
+use rtnetlink::constants::{AF_BRIDGE, NLM_F_DUMP, NLM_F_REQUEST};
+use rtnetlink::{NeighbourMessage, NetlinkFlags, NetlinkMessage, RtnlMessage};
+
+use super::FdbEntry;
+use connection::ConnectionHandle;
+use errors::NetlinkIpError;
+
+use Stream2Vec;
+
+lazy_static! {
+    // Flags for `bridge fdb show`
+    static ref GET_FLAGS: NetlinkFlags = NetlinkFlags::from(NLM_F_REQUEST | NLM_F_DUMP);
+}
+
+pub struct FdbGetRequest {
+    handle: ConnectionHandle,
+    message: NeighbourMessage,
+}
+
+impl FdbGetRequest {
+    pub(crate) fn new(handle: ConnectionHandle) -> Self {
+        let mut message = NeighbourMessage::default();
+        message.header.family = AF_BRIDGE as u8;
+        FdbGetRequest { handle, message }
+    }
+
+    /// Execute the request
+    pub fn execute(self) -> impl Future<Item = Vec<FdbEntry>, Error = NetlinkIpError> {
+        let FdbGetRequest {
+            mut handle,
+            message,
+        } = self;
+        let mut req = NetlinkMessage::from(RtnlMessage::GetNeighbour(message));
+        req.header_mut().set_flags(*GET_FLAGS);
+        Stream2Vec::new(handle.request(req).map(move |msg| {
+            if !msg.is_new_neighbour() {
+                return Err(NetlinkIpError::UnexpectedMessage(msg));
+            }
+
+            if let (_, RtnlMessage::NewNeighbour(neighbour_message)) = msg.into_parts() {
+                Ok(FdbEntry::from_neighbour_message(neighbour_message)?)
+            } else {
+                // We checked that msg.is_new_neighbour() above, so the should not be reachable.
+                unreachable!();
+            }
+        }))
+    }
+
+    /// Return a mutable reference to the request
+    pub fn message_mut(&mut self) -> &mut NeighbourMessage {
+        &mut self.message
+    }
+}
@@ -0,0 +1,39 @@
+use eui48::MacAddress;
+
+use connection::ConnectionHandle;
+
+use super::{FdbAddRequest, FdbDelRequest, FdbGetRequest};
+
+pub struct FdbHandle(ConnectionHandle);
+
+impl FdbHandle {
+    pub fn new(handle: ConnectionHandle) -> Self {
+        FdbHandle(handle)
+    }
+
+    /// Add a new entry to the forwarding database of the link with the given index, which must
+    /// be a bridge port or a VXLAN device (equivalent to `bridge fdb add ADDRESS dev DEV`)
+    pub fn add(&self, index: u32, address: MacAddress) -> FdbAddRequest {
+        FdbAddRequest::new(self.0.clone(), index, address, false)
+    }
+
+    /// Append an entry to the forwarding database of the link with the given index. Unlike
+    /// [`add()`](#method.add), this does not fail if an entry with the same address exists. This
+    /// is used to add multiple remote VTEPs for the same address on VXLAN devices (equivalent to
+    /// `bridge fdb append ADDRESS dev DEV`)
+    pub fn append(&self, index: u32, address: MacAddress) -> FdbAddRequest {
+        FdbAddRequest::new(self.0.clone(), index, address, true)
+    }
+
+    /// Delete an entry from the forwarding database of the link with the given index
+    /// (equivalent to `bridge fdb del ADDRESS dev DEV`)
+    pub fn del(&self, index: u32, address: MacAddress) -> FdbDelRequest {
+        FdbDelRequest::new(self.0.clone(), index, address)
+    }
+
+    /// Retrieve the forwarding database entries of all the bridges and VXLAN devices (equivalent
+    /// to `bridge fdb show`)
+    pub fn get(&self) -> FdbGetRequest {
+        FdbGetRequest::new(self.0.clone())
+    }
+}
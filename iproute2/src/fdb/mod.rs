@@ -0,0 +1,172 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use eui48::MacAddress;
+use rtnetlink::constants::{NTF_MASTER, NTF_SELF, NUD_NOARP, NUD_PERMANENT};
+use rtnetlink::{NeighbourMessage, NeighbourNla};
+
+use errors::NetlinkIpError;
+
+mod handle;
+pub use self::handle::*;
+
+mod add;
+pub use self::add::*;
+
+mod del;
+pub use self::del::*;
+
+mod get;
+pub use self::get::*;
+
+/// An entry of the forwarding database of a bridge or of a VXLAN device (equivalent to an entry
+/// of `bridge fdb show`)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FdbEntry {
+    /// Index of the link the entry is attached to: a bridge port or a VXLAN device
+    index: u32,
+    /// Hardware address
+    address: MacAddress,
+    /// A combination of `NUD_*` states
+    state: u16,
+    /// A combination of `NTF_*` flags
+    flags: u8,
+    /// Index of the bridge the link is enslaved to
+    master_index: Option<u32>,
+    vlan: Option<u16>,
+    /// For VXLAN devices, IP address of the remote VTEP
+    destination: Option<IpAddr>,
+    /// For VXLAN devices, VXLAN network identifier of the remote VTEP
+    vni: Option<u32>,
+    /// For VXLAN devices, UDP destination port of the remote VTEP
+    port: Option<u16>,
+    /// For VXLAN devices, index of the outgoing interface to reach the remote VTEP
+    via: Option<u32>,
+    attributes: Vec<NeighbourNla>,
+}
+
+impl FdbEntry {
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+
+    pub fn address(&self) -> &MacAddress {
+        &self.address
+    }
+
+    pub fn state(&self) -> u16 {
+        self.state
+    }
+
+    pub fn flags(&self) -> u8 {
+        self.flags
+    }
+
+    pub fn master_index(&self) -> Option<u32> {
+        self.master_index
+    }
+
+    pub fn vlan(&self) -> Option<u16> {
+        self.vlan
+    }
+
+    pub fn destination(&self) -> Option<IpAddr> {
+        self.destination
+    }
+
+    pub fn vni(&self) -> Option<u32> {
+        self.vni
+    }
+
+    pub fn port(&self) -> Option<u16> {
+        self.port
+    }
+
+    pub fn via(&self) -> Option<u32> {
+        self.via
+    }
+
+    /// Attributes that are not exposed by the other methods
+    pub fn attributes(&self) -> &[NeighbourNla] {
+        self.attributes.as_slice()
+    }
+
+    /// Whether the entry is permanent, ie a local address of the bridge
+    pub fn is_permanent(&self) -> bool {
+        self.state & NUD_PERMANENT != 0
+    }
+
+    /// Whether the entry is static, ie it was added by the user and never expires
+    pub fn is_static(&self) -> bool {
+        self.state & NUD_NOARP != 0 && !self.is_permanent()
+    }
+
+    /// Whether the entry belongs to the device itself (`self`)
+    pub fn is_self(&self) -> bool {
+        self.flags & NTF_SELF != 0
+    }
+
+    /// Whether the entry belongs to the bridge the device is enslaved to (`master`)
+    pub fn is_master(&self) -> bool {
+        self.flags & NTF_MASTER != 0
+    }
+
+    pub fn from_neighbour_message(value: NeighbourMessage) -> Result<Self, NetlinkIpError> {
+        let NeighbourMessage { header, nlas } = value;
+        let mut address = None;
+        let mut entry = FdbEntry {
+            index: header.ifindex,
+            address: MacAddress::nil(),
+            state: header.state,
+            flags: header.flags,
+            master_index: None,
+            vlan: None,
+            destination: None,
+            vni: None,
+            port: None,
+            via: None,
+            attributes: vec![],
+        };
+        for nla in nlas {
+            match nla {
+                NeighbourNla::LinkLayerAddress(bytes) => {
+                    address = Some(
+                        MacAddress::from_bytes(&bytes[..])
+                            .map_err(|_| NetlinkIpError::InvalidNeighbourAddress(bytes.clone()))?,
+                    );
+                }
+                NeighbourNla::Destination(bytes) => {
+                    entry.destination = Some(parse_ip(&bytes[..])?);
+                }
+                NeighbourNla::Master(index) => entry.master_index = Some(index),
+                NeighbourNla::Vlan(vlan) => entry.vlan = Some(vlan),
+                NeighbourNla::Vni(vni) => entry.vni = Some(vni),
+                NeighbourNla::Port(port) => entry.port = Some(port),
+                NeighbourNla::IfIndex(index) => entry.via = Some(index),
+                _ => entry.attributes.push(nla),
+            }
+        }
+        entry.address = address.ok_or_else(|| NetlinkIpError::InvalidNeighbourAddress(vec![]))?;
+        Ok(entry)
+    }
+}
+
+fn parse_ip(bytes: &[u8]) -> Result<IpAddr, NetlinkIpError> {
+    if bytes.len() == 4 {
+        let mut octets = [0; 4];
+        octets.copy_from_slice(bytes);
+        Ok(IpAddr::V4(Ipv4Addr::from(octets)))
+    } else if bytes.len() == 16 {
+        let mut octets = [0; 16];
+        octets.copy_from_slice(bytes);
+        Ok(IpAddr::V6(Ipv6Addr::from(octets)))
+    } else {
+        Err(NetlinkIpError::InvalidNeighbourAddress(bytes.to_vec()))
+    }
+}
+
+fn ip_bytes(ip: IpAddr) -> Vec<u8> {
+    match ip {
+        IpAddr::V4(ip) => ip.octets().to_vec(),
+        IpAddr::V6(ip) => ip.octets().to_vec(),
+    }
+}
@@ -111,7 +111,9 @@ extern crate lazy_static;
 extern crate bytes;
 extern crate eui48;
 extern crate futures;
+extern crate libc;
 extern crate tokio_core;
+extern crate tokio_timer;
 
 extern crate netlink_socket;
 extern crate rtnetlink;
@@ -120,10 +122,40 @@ extern crate failure;
 #[macro_use]
 extern crate failure_derive;
 
+#[cfg(feature = "serde_support")]
+extern crate serde;
+#[cfg(feature = "serde_support")]
+#[macro_use]
+extern crate serde_derive;
+#[cfg(all(test, feature = "serde_support"))]
+extern crate serde_json;
+
+mod address;
+mod bridge_vlan;
+mod cache;
 mod connection;
 mod errors;
+mod fdb;
 mod link;
+mod mdb;
+mod route;
+mod rule;
+mod state;
+mod stats;
+mod transaction;
+mod wait;
 
+pub use address::*;
+pub use bridge_vlan::*;
+pub use cache::*;
 pub use connection::*;
 pub use errors::*;
+pub use fdb::*;
 pub use link::*;
+pub use mdb::*;
+pub use route::*;
+pub use rule::*;
+pub use state::*;
+pub use stats::*;
+pub use transaction::*;
+pub use wait::*;
@@ -1,11 +1,18 @@
+use std::net::{Ipv4Addr, Ipv6Addr};
+
 use futures::Future;
 
-use rtnetlink::constants::{IFF_UP, NLM_F_ACK, NLM_F_CREATE, NLM_F_EXCL, NLM_F_REQUEST};
+use rtnetlink::constants::{
+    GRE_KEY, GRE_SEQ, IFF_UP, NLM_F_ACK, NLM_F_CREATE, NLM_F_EXCL, NLM_F_REQUEST,
+};
 use rtnetlink::{
-    LinkFlags, LinkInfo, LinkInfoData, LinkInfoKind, LinkInfoVlan, LinkMessage, LinkNla,
-    NetlinkFlags, NetlinkMessage, RtnlMessage,
+    LinkFlags, LinkInfo, LinkInfoData, LinkInfoGre, LinkInfoIpTun, LinkInfoIpVlan, LinkInfoKind,
+    LinkInfoMacVlan, LinkInfoVlan, LinkInfoVrf, LinkInfoVti, LinkMessage, LinkNla, NetlinkFlags,
+    NetlinkMessage, RtnlMessage,
 };
 
+use super::echo::echo_link;
+use super::{BridgeOptions, Link, LinkGetRequest};
 use connection::ConnectionHandle;
 use errors::NetlinkIpError;
 
@@ -44,6 +51,33 @@ impl LinkAddRequest {
         Stream2Ack::new(handle.request(req))
     }
 
+    /// Execute the request with the `NLM_F_ECHO` flag, and return the link that has been created,
+    /// as reported by the kernel. This avoids dumping the links to find the index of the new link.
+    ///
+    /// Kernels older than 6.3 don't echo the new link. The link is then retrieved by name, which
+    /// fails with [`NetlinkIpError::LinkNotFound`](enum.NetlinkIpError.html#variant.LinkNotFound)
+    /// if the request does not set a name.
+    pub fn execute_echo(self) -> impl Future<Item = Link, Error = NetlinkIpError> {
+        let LinkAddRequest {
+            mut handle,
+            message,
+        } = self;
+        let fallback = message
+            .nlas()
+            .iter()
+            .filter_map(|nla| match *nla {
+                LinkNla::IfName(ref name) => Some(name.clone()),
+                _ => None,
+            })
+            .next()
+            .map(|name| LinkGetRequest::new(handle.clone()).match_name(name));
+        let mut req = NetlinkMessage::from(RtnlMessage::NewLink(message));
+        let mut flags = *ADD_FLAGS;
+        flags.set_echo();
+        req.header_mut().set_flags(flags);
+        echo_link(handle.request(req), fallback)
+    }
+
     /// Return a mutable reference to the request message.
     ///
     /// # Example
@@ -111,6 +145,199 @@ impl LinkAddRequest {
             .up()
     }
 
+    /// Create macvlan on a link.
+    /// This is equivalent to `ip link add name NAME link LINK type macvlan mode MACVLAN_MODE`,
+    /// but instead of specifying a link name (`LINK`), we specify a link index.
+    /// The MACVLAN_MODE argument is one of the `MACVLAN_MODE_*` constants.
+    pub fn macvlan(self, name: String, index: u32, mode: u32) -> Self {
+        self.name(name)
+            .link_info(
+                LinkInfoKind::MacVlan,
+                Some(LinkInfoData::MacVlan(vec![LinkInfoMacVlan::Mode(mode)])),
+            )
+            .append_nla(LinkNla::Link(index))
+            .up()
+    }
+
+    /// Create macvtap on a link.
+    /// This is equivalent to `ip link add name NAME link LINK type macvtap mode MACVTAP_MODE`,
+    /// but instead of specifying a link name (`LINK`), we specify a link index.
+    /// The MACVTAP_MODE argument is one of the `MACVLAN_MODE_*` constants.
+    pub fn macvtap(self, name: String, index: u32, mode: u32) -> Self {
+        self.name(name)
+            .link_info(
+                LinkInfoKind::MacVtap,
+                Some(LinkInfoData::MacVtap(vec![LinkInfoMacVlan::Mode(mode)])),
+            )
+            .append_nla(LinkNla::Link(index))
+            .up()
+    }
+
+    /// Create ipvlan on a link.
+    /// This is equivalent to `ip link add name NAME link LINK type ipvlan mode IPVLAN_MODE`,
+    /// but instead of specifying a link name (`LINK`), we specify a link index.
+    /// The IPVLAN_MODE argument is one of the `IPVLAN_MODE_*` constants.
+    pub fn ipvlan(self, name: String, index: u32, mode: u16) -> Self {
+        self.name(name)
+            .link_info(
+                LinkInfoKind::IpVlan,
+                Some(LinkInfoData::IpVlan(vec![LinkInfoIpVlan::Mode(mode)])),
+            )
+            .append_nla(LinkNla::Link(index))
+            .up()
+    }
+
+    /// Create a GRE tunnel.
+    /// This is equivalent to `ip link add NAME type gre local LOCAL remote REMOTE`.
+    pub fn gre(self, name: String, local: Ipv4Addr, remote: Ipv4Addr) -> Self {
+        let nlas = vec![
+            LinkInfoGre::Local(local.octets().to_vec()),
+            LinkInfoGre::Remote(remote.octets().to_vec()),
+        ];
+        self.name(name)
+            .link_info(LinkInfoKind::GreTun, Some(LinkInfoData::GreTun(nlas)))
+            .up()
+    }
+
+    /// Create a GRE tunnel carrying ethernet frames.
+    /// This is equivalent to `ip link add NAME type gretap local LOCAL remote REMOTE`.
+    pub fn gretap(self, name: String, local: Ipv4Addr, remote: Ipv4Addr) -> Self {
+        let nlas = vec![
+            LinkInfoGre::Local(local.octets().to_vec()),
+            LinkInfoGre::Remote(remote.octets().to_vec()),
+        ];
+        self.name(name)
+            .link_info(LinkInfoKind::GreTap, Some(LinkInfoData::GreTap(nlas)))
+            .up()
+    }
+
+    /// Create a GRE over IPv6 tunnel.
+    /// This is equivalent to `ip link add NAME type ip6gre local LOCAL remote REMOTE`.
+    pub fn ip6gre(self, name: String, local: Ipv6Addr, remote: Ipv6Addr) -> Self {
+        let nlas = vec![
+            LinkInfoGre::Local(local.octets().to_vec()),
+            LinkInfoGre::Remote(remote.octets().to_vec()),
+        ];
+        self.name(name)
+            .link_info(LinkInfoKind::GreTun6, Some(LinkInfoData::GreTun6(nlas)))
+            .up()
+    }
+
+    /// Create a GRE over IPv6 tunnel carrying ethernet frames.
+    /// This is equivalent to `ip link add NAME type ip6gretap local LOCAL remote REMOTE`.
+    pub fn ip6gretap(self, name: String, local: Ipv6Addr, remote: Ipv6Addr) -> Self {
+        let nlas = vec![
+            LinkInfoGre::Local(local.octets().to_vec()),
+            LinkInfoGre::Remote(remote.octets().to_vec()),
+        ];
+        self.name(name)
+            .link_info(LinkInfoKind::GreTap6, Some(LinkInfoData::GreTap6(nlas)))
+            .up()
+    }
+
+    /// Create an ERSPAN tunnel.
+    /// This is equivalent to `ip link add NAME type erspan local LOCAL remote REMOTE erspan INDEX`.
+    ///
+    /// ERSPAN tunnels require a key, so the sequence number and key flags are set, and the
+    /// session id is used as key.
+    pub fn erspan(
+        self,
+        name: String,
+        local: Ipv4Addr,
+        remote: Ipv4Addr,
+        session_id: u32,
+        index: u32,
+    ) -> Self {
+        let nlas = vec![
+            LinkInfoGre::Local(local.octets().to_vec()),
+            LinkInfoGre::Remote(remote.octets().to_vec()),
+            LinkInfoGre::IFlags(GRE_KEY | GRE_SEQ),
+            LinkInfoGre::OFlags(GRE_KEY | GRE_SEQ),
+            LinkInfoGre::IKey(session_id),
+            LinkInfoGre::OKey(session_id),
+            LinkInfoGre::ErspanIndex(index),
+        ];
+        self.name(name)
+            .link_info(LinkInfoKind::Erspan, Some(LinkInfoData::Erspan(nlas)))
+            .up()
+    }
+
+    /// Create an IPv4 over IPv4 tunnel.
+    /// This is equivalent to `ip link add NAME type ipip local LOCAL remote REMOTE`.
+    pub fn ipip(self, name: String, local: Ipv4Addr, remote: Ipv4Addr) -> Self {
+        let nlas = vec![
+            LinkInfoIpTun::Local(local.octets().to_vec()),
+            LinkInfoIpTun::Remote(remote.octets().to_vec()),
+        ];
+        self.name(name)
+            .link_info(LinkInfoKind::IpTun, Some(LinkInfoData::IpTun(nlas)))
+            .up()
+    }
+
+    /// Create an IPv6 over IPv4 tunnel.
+    /// This is equivalent to `ip link add NAME type sit local LOCAL remote REMOTE`.
+    pub fn sit(self, name: String, local: Ipv4Addr, remote: Ipv4Addr) -> Self {
+        let nlas = vec![
+            LinkInfoIpTun::Local(local.octets().to_vec()),
+            LinkInfoIpTun::Remote(remote.octets().to_vec()),
+        ];
+        self.name(name)
+            .link_info(LinkInfoKind::SitTun, Some(LinkInfoData::SitTun(nlas)))
+            .up()
+    }
+
+    /// Create an IPv4 or IPv6 over IPv6 tunnel.
+    /// This is equivalent to `ip link add NAME type ip6tnl local LOCAL remote REMOTE`.
+    pub fn ip6tnl(self, name: String, local: Ipv6Addr, remote: Ipv6Addr) -> Self {
+        let nlas = vec![
+            LinkInfoIpTun::Local(local.octets().to_vec()),
+            LinkInfoIpTun::Remote(remote.octets().to_vec()),
+        ];
+        self.name(name)
+            .link_info(LinkInfoKind::Ip6Tnl, Some(LinkInfoData::Ip6Tnl(nlas)))
+            .up()
+    }
+
+    /// Create a virtual tunnel interface, to be used with IPsec.
+    /// This is equivalent to `ip link add NAME type vti local LOCAL remote REMOTE key KEY`.
+    pub fn vti(self, name: String, local: Ipv4Addr, remote: Ipv4Addr, key: u32) -> Self {
+        let nlas = vec![
+            LinkInfoVti::Local(local.octets().to_vec()),
+            LinkInfoVti::Remote(remote.octets().to_vec()),
+            LinkInfoVti::IKey(key),
+            LinkInfoVti::OKey(key),
+        ];
+        self.name(name)
+            .link_info(LinkInfoKind::Vti, Some(LinkInfoData::Vti(nlas)))
+            .up()
+    }
+
+    /// Create a bridge.
+    /// This is equivalent to `ip link add NAME type bridge [OPTIONS]`.
+    pub fn bridge(self, name: String, options: BridgeOptions) -> Self {
+        let data = options.into_nlas();
+        let data = if data.is_empty() {
+            None
+        } else {
+            Some(LinkInfoData::Bridge(data))
+        };
+        self.name(name).link_info(LinkInfoKind::Bridge, data).up()
+    }
+
+    /// Create a VRF device bound to the given routing table.
+    /// This is equivalent to `ip link add NAME type vrf table TABLE`.
+    ///
+    /// Links can then be enslaved to the VRF with
+    /// [`LinkSetRequest::master()`](struct.LinkSetRequest.html#method.master).
+    pub fn vrf(self, name: String, table: u32) -> Self {
+        self.name(name)
+            .link_info(
+                LinkInfoKind::Vrf,
+                Some(LinkInfoData::Vrf(vec![LinkInfoVrf::TableId(table)])),
+            )
+            .up()
+    }
+
     fn up(mut self) -> Self {
         self.message_mut()
             .header_mut()
@@ -119,7 +346,7 @@ impl LinkAddRequest {
         self
     }
 
-    fn link_info(self, kind: LinkInfoKind, data: Option<LinkInfoData>) -> Self {
+    pub(crate) fn link_info(self, kind: LinkInfoKind, data: Option<LinkInfoData>) -> Self {
         let mut link_info_nlas = vec![LinkInfo::Kind(kind)];
         if let Some(data) = data {
             link_info_nlas.push(LinkInfo::Data(data));
@@ -127,7 +354,7 @@ impl LinkAddRequest {
         self.append_nla(LinkNla::LinkInfo(link_info_nlas))
     }
 
-    fn name(mut self, name: String) -> Self {
+    pub(crate) fn name(mut self, name: String) -> Self {
         self.message.append_nla(LinkNla::IfName(name));
         self
     }
@@ -0,0 +1,298 @@
+use rtnetlink::LinkInfoBridge;
+
+/// Options for bridge links, used when creating a bridge with
+/// [`LinkAddRequest::bridge()`](struct.LinkAddRequest.html#method.bridge), or to change the
+/// options of an existing bridge with
+/// [`LinkSetRequest::bridge()`](struct.LinkSetRequest.html#method.bridge).
+///
+/// Options that are not set are left to the kernel defaults when creating a bridge, and are left
+/// untouched when changing an existing bridge.
+///
+/// # Example
+///
+/// ```rust
+/// use iproute2::BridgeOptions;
+///
+/// let options = BridgeOptions::new()
+///     .stp(true)
+///     .ageing_time(30000)
+///     .vlan_filtering(true)
+///     .vlan_default_pvid(1)
+///     .multicast_snooping(false);
+/// assert_eq!(options.nlas().len(), 5);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BridgeOptions(Vec<LinkInfoBridge>);
+
+impl BridgeOptions {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Return the attributes that will be sent to the kernel
+    pub fn nlas(&self) -> &[LinkInfoBridge] {
+        self.0.as_slice()
+    }
+
+    /// Consume the options and return the attributes that will be sent to the kernel
+    pub fn into_nlas(self) -> Vec<LinkInfoBridge> {
+        self.0
+    }
+
+    /// Append a custom attribute
+    pub fn append_nla(mut self, nla: LinkInfoBridge) -> Self {
+        self.0.push(nla);
+        self
+    }
+
+    /// Enable or disable the spanning tree protocol (equivalent to `stp_state`)
+    pub fn stp(self, enabled: bool) -> Self {
+        self.append_nla(LinkInfoBridge::StpState(enabled as u32))
+    }
+
+    /// Set the bridge priority, used by the spanning tree protocol (equivalent to `priority`)
+    pub fn priority(self, priority: u16) -> Self {
+        self.append_nla(LinkInfoBridge::Priority(priority))
+    }
+
+    /// Set the forwarding delay in hundredths of a second (equivalent to `forward_delay`)
+    pub fn forward_delay(self, delay: u32) -> Self {
+        self.append_nla(LinkInfoBridge::ForwardDelay(delay))
+    }
+
+    /// Set the hello time in hundredths of a second (equivalent to `hello_time`)
+    pub fn hello_time(self, time: u32) -> Self {
+        self.append_nla(LinkInfoBridge::HelloTime(time))
+    }
+
+    /// Set the max message age in hundredths of a second (equivalent to `max_age`)
+    pub fn max_age(self, age: u32) -> Self {
+        self.append_nla(LinkInfoBridge::MaxAge(age))
+    }
+
+    /// Set the FDB entries ageing time in hundredths of a second (equivalent to `ageing_time`)
+    pub fn ageing_time(self, time: u32) -> Self {
+        self.append_nla(LinkInfoBridge::AgeingTime(time))
+    }
+
+    /// Set the group forward mask (equivalent to `group_fwd_mask`)
+    pub fn group_fwd_mask(self, mask: u16) -> Self {
+        self.append_nla(LinkInfoBridge::GroupFwdMask(mask))
+    }
+
+    /// Enable or disable VLAN filtering (equivalent to `vlan_filtering`)
+    pub fn vlan_filtering(self, enabled: bool) -> Self {
+        self.append_nla(LinkInfoBridge::VlanFiltering(enabled as u8))
+    }
+
+    /// Set the VLAN protocol, `ETH_P_8021Q` or `ETH_P_8021AD` (equivalent to `vlan_protocol`)
+    pub fn vlan_protocol(self, protocol: u16) -> Self {
+        self.append_nla(LinkInfoBridge::VlanProtocol(protocol))
+    }
+
+    /// Set the default PVID of new ports (equivalent to `vlan_default_pvid`)
+    pub fn vlan_default_pvid(self, pvid: u16) -> Self {
+        self.append_nla(LinkInfoBridge::VlanDefaultPvid(pvid))
+    }
+
+    /// Enable or disable per-VLAN statistics (equivalent to `vlan_stats_enabled`)
+    pub fn vlan_stats_enabled(self, enabled: bool) -> Self {
+        self.append_nla(LinkInfoBridge::VlanStatsEnabled(enabled as u8))
+    }
+
+    /// Enable or disable per-VLAN per-port statistics (equivalent to `vlan_stats_per_port`)
+    pub fn vlan_stats_per_port(self, enabled: bool) -> Self {
+        self.append_nla(LinkInfoBridge::VlanStatsPerPort(enabled as u8))
+    }
+
+    /// Set the multicast router mode: 0 (disabled), 1 (automatic) or 2 (permanently enabled)
+    /// (equivalent to `mcast_router`)
+    pub fn multicast_router(self, mode: u8) -> Self {
+        self.append_nla(LinkInfoBridge::MulticastRouter(mode))
+    }
+
+    /// Enable or disable IGMP/MLD snooping (equivalent to `mcast_snooping`)
+    pub fn multicast_snooping(self, enabled: bool) -> Self {
+        self.append_nla(LinkInfoBridge::MulticastSnooping(enabled as u8))
+    }
+
+    /// Enable or disable the multicast querier (equivalent to `mcast_querier`)
+    pub fn multicast_querier(self, enabled: bool) -> Self {
+        self.append_nla(LinkInfoBridge::MulticastQuerier(enabled as u8))
+    }
+
+    /// Use the bridge address as source address of the queries (equivalent to
+    /// `mcast_query_use_ifaddr`)
+    pub fn multicast_query_use_ifaddr(self, enabled: bool) -> Self {
+        self.append_nla(LinkInfoBridge::MulticastQueryUseIfaddr(enabled as u8))
+    }
+
+    /// Enable or disable multicast statistics (equivalent to `mcast_stats_enabled`)
+    pub fn multicast_stats_enabled(self, enabled: bool) -> Self {
+        self.append_nla(LinkInfoBridge::MulticastStatsEnabled(enabled as u8))
+    }
+
+    /// Set the IGMP version used by the querier (equivalent to `mcast_igmp_version`)
+    pub fn multicast_igmp_version(self, version: u8) -> Self {
+        self.append_nla(LinkInfoBridge::MulticastIgmpVersion(version))
+    }
+
+    /// Set the MLD version used by the querier (equivalent to `mcast_mld_version`)
+    pub fn multicast_mld_version(self, version: u8) -> Self {
+        self.append_nla(LinkInfoBridge::MulticastMldVersion(version))
+    }
+
+    /// Set the maximum size of the multicast group database (equivalent to `mcast_hash_max`)
+    pub fn multicast_hash_max(self, max: u32) -> Self {
+        self.append_nla(LinkInfoBridge::MulticastHashMax(max))
+    }
+
+    /// Set the number of queries sent after a leave message (equivalent to
+    /// `mcast_last_member_count`)
+    pub fn multicast_last_member_count(self, count: u32) -> Self {
+        self.append_nla(LinkInfoBridge::MulticastLastMemberCount(count))
+    }
+
+    /// Set the number of queries sent at startup (equivalent to `mcast_startup_query_count`)
+    pub fn multicast_startup_query_count(self, count: u32) -> Self {
+        self.append_nla(LinkInfoBridge::MulticastStartupQueryCount(count))
+    }
+
+    /// Set the interval between queries sent after a leave message, in hundredths of a second
+    /// (equivalent to `mcast_last_member_interval`)
+    pub fn multicast_last_member_interval(self, interval: u64) -> Self {
+        self.append_nla(LinkInfoBridge::MulticastLastMemberInterval(interval))
+    }
+
+    /// Set the multicast group membership interval, in hundredths of a second (equivalent to
+    /// `mcast_membership_interval`)
+    pub fn multicast_membership_interval(self, interval: u64) -> Self {
+        self.append_nla(LinkInfoBridge::MulticastMembershipInterval(interval))
+    }
+
+    /// Set the multicast querier interval, in hundredths of a second (equivalent to
+    /// `mcast_querier_interval`)
+    pub fn multicast_querier_interval(self, interval: u64) -> Self {
+        self.append_nla(LinkInfoBridge::MulticastQuerierInterval(interval))
+    }
+
+    /// Set the interval between queries, in hundredths of a second (equivalent to
+    /// `mcast_query_interval`)
+    pub fn multicast_query_interval(self, interval: u64) -> Self {
+        self.append_nla(LinkInfoBridge::MulticastQueryInterval(interval))
+    }
+
+    /// Set the max response time of the queries, in hundredths of a second (equivalent to
+    /// `mcast_query_response_interval`)
+    pub fn multicast_query_response_interval(self, interval: u64) -> Self {
+        self.append_nla(LinkInfoBridge::MulticastQueryResponseInterval(interval))
+    }
+
+    /// Set the interval between queries sent at startup, in hundredths of a second (equivalent
+    /// to `mcast_startup_query_interval`)
+    pub fn multicast_startup_query_interval(self, interval: u64) -> Self {
+        self.append_nla(LinkInfoBridge::MulticastStartupQueryInterval(interval))
+    }
+
+    /// Pass bridged IPv4 traffic to iptables (equivalent to `nf_call_iptables`)
+    pub fn nf_call_iptables(self, enabled: bool) -> Self {
+        self.append_nla(LinkInfoBridge::NfCallIpTables(enabled as u8))
+    }
+
+    /// Pass bridged IPv6 traffic to ip6tables (equivalent to `nf_call_ip6tables`)
+    pub fn nf_call_ip6tables(self, enabled: bool) -> Self {
+        self.append_nla(LinkInfoBridge::NfCallIp6Tables(enabled as u8))
+    }
+
+    /// Pass bridged ARP traffic to arptables (equivalent to `nf_call_arptables`)
+    pub fn nf_call_arptables(self, enabled: bool) -> Self {
+        self.append_nla(LinkInfoBridge::NfCallArpTables(enabled as u8))
+    }
+
+    /// Set the maximum number of learned FDB entries, 0 meaning no limit (equivalent to
+    /// `fdb_max_learned`)
+    pub fn fdb_max_learned(self, max: u32) -> Self {
+        self.append_nla(LinkInfoBridge::FdbMaxLearned(max))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use futures::sync::mpsc::unbounded;
+    use rtnetlink::{LinkInfo, LinkInfoData, LinkInfoKind, LinkNla};
+
+    use connection::ConnectionHandle;
+    use link::{LinkAddRequest, LinkSetRequest};
+
+    fn handle() -> ConnectionHandle {
+        let (tx, _rx) = unbounded();
+        ConnectionHandle::new(tx)
+    }
+
+    fn options() -> BridgeOptions {
+        BridgeOptions::new()
+            .stp(true)
+            .ageing_time(30000)
+            .vlan_filtering(true)
+            .vlan_default_pvid(1)
+            .vlan_stats_per_port(true)
+            .multicast_snooping(false)
+            .fdb_max_learned(256)
+    }
+
+    #[test]
+    fn options_nlas() {
+        assert_eq!(
+            options().into_nlas(),
+            vec![
+                LinkInfoBridge::StpState(1),
+                LinkInfoBridge::AgeingTime(30000),
+                LinkInfoBridge::VlanFiltering(1),
+                LinkInfoBridge::VlanDefaultPvid(1),
+                LinkInfoBridge::VlanStatsPerPort(1),
+                LinkInfoBridge::MulticastSnooping(0),
+                LinkInfoBridge::FdbMaxLearned(256),
+            ]
+        );
+    }
+
+    #[test]
+    fn add_bridge() {
+        let mut request = LinkAddRequest::new(handle()).bridge("br0".into(), options());
+        assert!(request.message_mut().header().flags().is_up());
+        assert_eq!(
+            request.message_mut().nlas(),
+            &[
+                LinkNla::IfName("br0".into()),
+                LinkNla::LinkInfo(vec![
+                    LinkInfo::Kind(LinkInfoKind::Bridge),
+                    LinkInfo::Data(LinkInfoData::Bridge(options().into_nlas())),
+                ]),
+            ]
+        );
+
+        // Without options, the kernel defaults apply
+        let mut request = LinkAddRequest::new(handle()).bridge("br0".into(), BridgeOptions::new());
+        assert_eq!(
+            request.message_mut().nlas(),
+            &[
+                LinkNla::IfName("br0".into()),
+                LinkNla::LinkInfo(vec![LinkInfo::Kind(LinkInfoKind::Bridge)]),
+            ]
+        );
+    }
+
+    #[test]
+    fn set_bridge() {
+        let mut request = LinkSetRequest::new(handle(), 2).bridge(options());
+        assert_eq!(request.message_mut().header().index(), 2);
+        assert_eq!(
+            request.message_mut().nlas(),
+            &[LinkNla::LinkInfo(vec![
+                LinkInfo::Kind(LinkInfoKind::Bridge),
+                LinkInfo::Data(LinkInfoData::Bridge(options().into_nlas())),
+            ])]
+        );
+    }
+}
@@ -1,6 +1,6 @@
 use futures::Future;
 
-use rtnetlink::constants::{NLM_F_ACK, NLM_F_CREATE, NLM_F_EXCL, NLM_F_REQUEST};
+use rtnetlink::constants::{NLM_F_ACK, NLM_F_REQUEST};
 use rtnetlink::{LinkMessage, NetlinkFlags, NetlinkMessage, RtnlMessage};
 
 use connection::ConnectionHandle;
@@ -10,8 +10,7 @@ use Stream2Ack;
 
 lazy_static! {
     // Flags for `ip link del`
-    static ref DEL_FLAGS: NetlinkFlags =
-        NetlinkFlags::from(NLM_F_REQUEST | NLM_F_ACK | NLM_F_EXCL | NLM_F_CREATE);
+    static ref DEL_FLAGS: NetlinkFlags = NetlinkFlags::from(NLM_F_REQUEST | NLM_F_ACK);
 }
 
 pub struct LinkDelRequest {
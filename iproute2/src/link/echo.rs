@@ -0,0 +1,42 @@
+use futures::future::{self, Either};
+use futures::{Future, Stream};
+
+use rtnetlink::{NetlinkMessage, RtnlMessage};
+
+use super::{Link, LinkMatchRequest};
+use errors::NetlinkIpError;
+
+use Stream2Vec;
+
+/// Turn the response to a request sent with `NLM_F_ECHO` into the link reported by the kernel.
+///
+/// Kernels older than 6.3 don't echo link requests, and only acknowledge them. In that case, the
+/// link is retrieved with the `fallback` request, if any.
+pub(crate) fn echo_link<S>(
+    response: S,
+    fallback: Option<LinkMatchRequest>,
+) -> impl Future<Item = Link, Error = NetlinkIpError>
+where
+    S: Stream<Item = NetlinkMessage, Error = NetlinkIpError>,
+{
+    Stream2Vec::new(response.map(|msg| {
+        if msg.is_error() {
+            return Err(NetlinkIpError::NetlinkError(msg));
+        }
+        if !msg.is_new_link() {
+            return Err(NetlinkIpError::UnexpectedMessage(msg));
+        }
+
+        if let (_, RtnlMessage::NewLink(link_message)) = msg.into_parts() {
+            Ok(Link::from_link_message(link_message)?)
+        } else {
+            // We checked that msg.is_new_link() above, so the should not be reachable.
+            unreachable!();
+        }
+    }))
+    .and_then(move |mut links| match (links.pop(), fallback) {
+        (Some(link), _) => Either::A(future::ok(link)),
+        (None, Some(fallback)) => Either::B(Either::A(fallback.execute())),
+        (None, None) => Either::B(Either::B(future::err(NetlinkIpError::LinkNotFound))),
+    })
+}
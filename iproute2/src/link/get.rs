@@ -1,7 +1,11 @@
 use futures::{Future, Stream};
+use libc;
 
-use rtnetlink::constants::{NLM_F_DUMP, NLM_F_REQUEST};
-use rtnetlink::{LinkMessage, NetlinkFlags, NetlinkMessage, RtnlMessage};
+use rtnetlink::constants::{NLM_F_DUMP, NLM_F_REQUEST, RTEXT_FILTER_VF};
+use rtnetlink::{
+    LinkInfo, LinkInfoKind, LinkLayerType, LinkMessage, LinkNla, NetlinkFlags, NetlinkMessage,
+    RtnlMessage,
+};
 
 use super::Link;
 use connection::ConnectionHandle;
@@ -10,19 +14,33 @@ use errors::NetlinkIpError;
 use Stream2Vec;
 
 lazy_static! {
-    // Flags for `ip link get`
+    // Flags for `ip link show`
     static ref GET_FLAGS: NetlinkFlags = NetlinkFlags::from(NLM_F_REQUEST | NLM_F_DUMP);
+    // Flags for `ip link show <name>`
+    static ref MATCH_FLAGS: NetlinkFlags = NetlinkFlags::from(NLM_F_REQUEST);
 }
 
 pub struct LinkGetRequest {
     handle: ConnectionHandle,
     message: LinkMessage,
+    master: Option<u32>,
+    kind: Option<LinkInfoKind>,
 }
 
 impl LinkGetRequest {
     pub(crate) fn new(handle: ConnectionHandle) -> Self {
-        let message = LinkMessage::new();
-        LinkGetRequest { handle, message }
+        let mut message = LinkMessage::new();
+        // With strict checking, the kernel rejects the requests with a non-zero link layer type
+        // (0 is ARPHRD_NETROM).
+        message
+            .header_mut()
+            .set_link_layer_type(LinkLayerType::Netrom);
+        LinkGetRequest {
+            handle,
+            message,
+            master: None,
+            kind: None,
+        }
     }
 
     /// Execute the request
@@ -30,6 +48,8 @@ impl LinkGetRequest {
         let LinkGetRequest {
             mut handle,
             message,
+            master,
+            kind,
         } = self;
         let mut req = NetlinkMessage::from(RtnlMessage::GetLink(message));
         req.header_mut().set_flags(*GET_FLAGS);
@@ -45,6 +65,120 @@ impl LinkGetRequest {
                 unreachable!();
             }
         }))
+        .map(move |links| {
+            // Kernels that don't support strict checking ignore the filters, so filter the links
+            // here too.
+            links
+                .into_iter()
+                .filter(|link| master.is_none() || link.master_index() == master)
+                .filter(|link| kind.is_none() || link.kind() == kind.as_ref())
+                .collect()
+        })
+    }
+
+    /// Return a mutable reference to the request
+    pub fn message_mut(&mut self) -> &mut LinkMessage {
+        &mut self.message
+    }
+
+    /// Ask the kernel to include the SR-IOV virtual functions of the links in the response
+    /// (`IFLA_VFINFO_LIST` and `IFLA_VF_PORTS` attributes)
+    pub fn vf_info(mut self) -> Self {
+        self.message.append_nla(LinkNla::ExtMask(RTEXT_FILTER_VF));
+        self
+    }
+
+    /// Only retrieve the links enslaved to the link with index `index` (equivalent to
+    /// `ip link show master <name>`)
+    pub fn master(mut self, index: u32) -> Self {
+        self.master = Some(index);
+        self.message.append_nla(LinkNla::Master(index));
+        self
+    }
+
+    /// Only retrieve the links of the given kind (equivalent to `ip link show type <kind>`)
+    pub fn kind(mut self, kind: LinkInfoKind) -> Self {
+        self.kind = Some(kind.clone());
+        self.message
+            .append_nla(LinkNla::LinkInfo(vec![LinkInfo::Kind(kind)]));
+        self
+    }
+
+    /// Only retrieve the link with the given name. Instead of dumping all the links, the kernel
+    /// looks the link up and returns it (equivalent to `ip link show <name>`).
+    pub fn match_name(self, name: String) -> LinkMatchRequest {
+        let LinkGetRequest {
+            handle,
+            mut message,
+            ..
+        } = self;
+        remove_dump_filters(&mut message);
+        message.append_nla(LinkNla::IfName(name));
+        LinkMatchRequest { handle, message }
+    }
+
+    /// Only retrieve the link with the given index, without dumping all the links
+    pub fn match_index(self, index: u32) -> LinkMatchRequest {
+        let LinkGetRequest {
+            handle,
+            mut message,
+            ..
+        } = self;
+        remove_dump_filters(&mut message);
+        message.header_mut().set_index(index);
+        LinkMatchRequest { handle, message }
+    }
+}
+
+/// The kernel rejects the dump filters in requests for a single link
+fn remove_dump_filters(message: &mut LinkMessage) {
+    message.nlas_mut().retain(|nla| match *nla {
+        LinkNla::Master(_) | LinkNla::LinkInfo(_) => false,
+        _ => true,
+    });
+}
+
+/// A request for a single link, created with
+/// [`LinkGetRequest::match_name()`](struct.LinkGetRequest.html#method.match_name) or
+/// [`LinkGetRequest::match_index()`](struct.LinkGetRequest.html#method.match_index).
+pub struct LinkMatchRequest {
+    handle: ConnectionHandle,
+    message: LinkMessage,
+}
+
+impl LinkMatchRequest {
+    /// Execute the request. If the link does not exist, the future fails with
+    /// [`NetlinkIpError::LinkNotFound`](enum.NetlinkIpError.html#variant.LinkNotFound), and the
+    /// other errors returned by the kernel are reported as
+    /// [`NetlinkIpError::NetlinkError`](enum.NetlinkIpError.html#variant.NetlinkError).
+    pub fn execute(self) -> impl Future<Item = Link, Error = NetlinkIpError> {
+        let LinkMatchRequest {
+            mut handle,
+            message,
+        } = self;
+        let mut req = NetlinkMessage::from(RtnlMessage::GetLink(message));
+        req.header_mut().set_flags(*MATCH_FLAGS);
+        Stream2Vec::new(handle.request(req).map(move |msg| {
+            if let RtnlMessage::Error(ref err) = *msg.message() {
+                if err.code == -libc::ENODEV {
+                    return Err(NetlinkIpError::LinkNotFound);
+                }
+            }
+            if msg.is_error() {
+                return Err(NetlinkIpError::NetlinkError(msg));
+            }
+            if !msg.is_new_link() {
+                return Err(NetlinkIpError::UnexpectedMessage(msg));
+            }
+
+            if let (_, RtnlMessage::NewLink(link_message)) = msg.into_parts() {
+                Ok(Link::from_link_message(link_message)?)
+            } else {
+                // We checked that msg.is_new_link() above, so the should not be reachable.
+                unreachable!();
+            }
+        }))
+        .and_then(|mut links| links.pop().ok_or(NetlinkIpError::LinkNotFound))
     }
 
     /// Return a mutable reference to the request
@@ -1,6 +1,6 @@
 use connection::ConnectionHandle;
 
-use super::{LinkAddRequest, LinkDelRequest, LinkGetRequest, LinkSetRequest};
+use super::{LinkAddRequest, LinkDelRequest, LinkGetRequest, LinkResolver, LinkSetRequest};
 
 pub struct LinkHandle(ConnectionHandle);
 
@@ -25,4 +25,9 @@ impl LinkHandle {
     pub fn get(&mut self) -> LinkGetRequest {
         LinkGetRequest::new(self.0.clone())
     }
+
+    /// Create a resolver that turns link names into link indices
+    pub fn resolver(&self) -> LinkResolver {
+        LinkResolver::new(self.0.clone())
+    }
 }
@@ -1,6 +1,9 @@
 use errors::NetlinkIpError;
 use eui48::MacAddress;
-use rtnetlink::{LinkFlags, LinkLayerType, LinkMessage, LinkNla, LinkState};
+use rtnetlink::{
+    LinkFlags, LinkInfo, LinkInfoData, LinkInfoKind, LinkInfoVrf, LinkLayerType, LinkMessage,
+    LinkNla, LinkState,
+};
 
 mod handle;
 pub use self::handle::*;
@@ -17,7 +20,18 @@ pub use self::get::*;
 mod set;
 pub use self::set::*;
 
-#[derive(Clone, Debug, Default)]
+mod bridge;
+pub use self::bridge::*;
+
+mod xdp;
+pub use self::xdp::*;
+
+mod resolver;
+pub use self::resolver::*;
+
+mod echo;
+
+#[derive(Clone, Debug, Default, PartialEq)]
 pub struct Link {
     // These attributes are common to all the links, since they are part of the
     // RTM_{GET,SET,DEL,NEW}LINK header.
@@ -171,6 +185,49 @@ impl Link {
         self.attributes.as_mut_slice()
     }
 
+    /// Return the kind of the link (`IFLA_INFO_KIND`), if the kernel reported it. Physical links
+    /// usually don't have a kind.
+    pub fn kind(&self) -> Option<&LinkInfoKind> {
+        for nla in &self.attributes {
+            if let LinkNla::LinkInfo(ref infos) = *nla {
+                for info in infos {
+                    if let LinkInfo::Kind(ref kind) = *info {
+                        return Some(kind);
+                    }
+                }
+            }
+        }
+        None
+    }
+
+    /// If this link is a VRF device, return the routing table it is bound to.
+    pub fn vrf_table(&self) -> Option<u32> {
+        for nla in &self.attributes {
+            if let LinkNla::LinkInfo(ref infos) = *nla {
+                for info in infos {
+                    if let LinkInfo::Data(LinkInfoData::Vrf(ref vrf_nlas)) = *info {
+                        for vrf_nla in vrf_nlas {
+                            if let LinkInfoVrf::TableId(table) = *vrf_nla {
+                                return Some(table);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        None
+    }
+
+    /// Return the XDP programs attached to this link, if the kernel reported them.
+    pub fn xdp(&self) -> Option<LinkXdp> {
+        for nla in &self.attributes {
+            if let LinkNla::Xdp(ref xdp_nlas) = *nla {
+                return Some(LinkXdp::from_nlas(xdp_nlas));
+            }
+        }
+        None
+    }
+
     pub fn set_address_family(&mut self, value: u8) -> &mut Self {
         self.address_family = value;
         self
@@ -257,6 +314,7 @@ impl Link {
         link.set_index(header.index())
             .set_address_family(header.address_family())
             .set_link_layer_type(header.link_layer_type())
+            .set_flags(header.flags())
             .set_change_mask(header.change_mask());
         for nla in nlas.drain(..) {
             let _ = match nla {
@@ -271,6 +329,8 @@ impl Link {
                 LinkNla::IfAlias(alias) => link.set_alias(alias),
                 LinkNla::Mtu(mtu) => link.set_mtu(mtu),
                 LinkNla::Master(index) => link.set_master_index(index),
+                // Keep the attribute, since it is needed to create the link again
+                LinkNla::Link(index) => link.set_parent_index(index).add_attribute(nla),
                 LinkNla::TxQueueLen(length) => link.set_tx_queue_length(length),
                 LinkNla::Promiscuity(promisc) => link.set_promiscuous_mode(promisc != 0),
                 LinkNla::OperState(state) => link.set_operational_state(state),
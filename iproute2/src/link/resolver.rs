@@ -0,0 +1,73 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use futures::future::{self, Either};
+use futures::Future;
+
+use super::LinkGetRequest;
+use connection::ConnectionHandle;
+use errors::NetlinkIpError;
+
+/// Resolve link names into link indices. It is created with
+/// [`LinkHandle::resolver()`](struct.LinkHandle.html#method.resolver).
+///
+/// By default, each resolution is a `RTM_GETLINK` request. With
+/// [`cached()`](#method.cached), the indices are remembered, and the kernel is only queried for
+/// names that have not been resolved yet. Since links can be renamed or deleted, entries can
+/// become stale, and must be removed with [`invalidate()`](#method.invalidate) or
+/// [`clear()`](#method.clear). Clones of a resolver share the same cache.
+#[derive(Clone)]
+pub struct LinkResolver {
+    handle: ConnectionHandle,
+    cache: Option<Arc<Mutex<HashMap<String, u32>>>>,
+}
+
+impl LinkResolver {
+    pub(crate) fn new(handle: ConnectionHandle) -> Self {
+        LinkResolver {
+            handle,
+            cache: None,
+        }
+    }
+
+    /// Cache the resolved indices
+    pub fn cached(mut self) -> Self {
+        self.cache = Some(Arc::new(Mutex::new(HashMap::new())));
+        self
+    }
+
+    /// Return the index of the link with the given name. If the link does not exist, the future
+    /// fails with [`NetlinkIpError::LinkNotFound`](enum.NetlinkIpError.html#variant.LinkNotFound).
+    pub fn resolve(&self, name: &str) -> impl Future<Item = u32, Error = NetlinkIpError> {
+        if let Some(ref cache) = self.cache {
+            if let Some(index) = cache.lock().unwrap().get(name) {
+                return Either::A(future::ok(*index));
+            }
+        }
+
+        let cache = self.cache.clone();
+        let key = name.to_string();
+        let request = LinkGetRequest::new(self.handle.clone()).match_name(name.to_string());
+        Either::B(request.execute().map(move |link| {
+            let index = link.index();
+            if let Some(cache) = cache {
+                cache.lock().unwrap().insert(key, index);
+            }
+            index
+        }))
+    }
+
+    /// Remove a name from the cache
+    pub fn invalidate(&self, name: &str) {
+        if let Some(ref cache) = self.cache {
+            cache.lock().unwrap().remove(name);
+        }
+    }
+
+    /// Remove all the names from the cache
+    pub fn clear(&self) {
+        if let Some(ref cache) = self.cache {
+            cache.lock().unwrap().clear();
+        }
+    }
+}
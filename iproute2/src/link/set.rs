@@ -1,9 +1,19 @@
+use std::os::unix::io::RawFd;
+
 use eui48::MacAddress;
 use futures::Future;
 
-use rtnetlink::constants::{IFF_UP, NLM_F_ACK, NLM_F_CREATE, NLM_F_EXCL, NLM_F_REQUEST};
-use rtnetlink::{LinkFlags, LinkMessage, LinkNla, NetlinkFlags, NetlinkMessage, RtnlMessage};
+use rtnetlink::constants::{
+    AF_BRIDGE, IFF_UP, IFLA_LINKINFO, NLM_F_ACK, NLM_F_CREATE, NLM_F_EXCL, NLM_F_REQUEST,
+};
+use rtnetlink::{
+    BridgePortNla, LinkFlags, LinkInfo, LinkInfoData, LinkInfoKind, LinkMessage, LinkNla,
+    LinkXdpNla, NetlinkFlags, NetlinkMessage, Nla, RtnlMessage, VfInfoNla, VfMac, VfNla, VfRate,
+    VfSetting, VfVlan, VfVlanInfo, VfVlanNla,
+};
 
+use super::echo::echo_link;
+use super::{BridgeOptions, Link, LinkGetRequest, XdpMode, XDP_DETACH_FD};
 use connection::ConnectionHandle;
 use errors::NetlinkIpError;
 
@@ -13,6 +23,9 @@ lazy_static! {
     // Flags for `ip link set`
     static ref SET_FLAGS: NetlinkFlags =
         NetlinkFlags::from(NLM_F_REQUEST | NLM_F_ACK | NLM_F_EXCL | NLM_F_CREATE);
+    // Flags for `ip link set ... type TYPE`. These requests are RTM_NEWLINK messages without
+    // NLM_F_CREATE, so that the kernel changes the existing link.
+    static ref CHANGE_FLAGS: NetlinkFlags = NetlinkFlags::from(NLM_F_REQUEST | NLM_F_ACK);
 }
 
 pub struct LinkSetRequest {
@@ -33,9 +46,38 @@ impl LinkSetRequest {
             mut handle,
             message,
         } = self;
-        let mut req = NetlinkMessage::from(RtnlMessage::SetLink(message));
-        req.header_mut().set_flags(*SET_FLAGS);
-        Stream2Ack::new(handle.request(req))
+        Stream2Ack::new(handle.request(Self::into_request(message)))
+    }
+
+    /// Execute the request with the `NLM_F_ECHO` flag, and return the link as reported by the
+    /// kernel after the change. Kernels that don't echo link changes only acknowledge the request,
+    /// and the link is then retrieved by index.
+    pub fn execute_echo(self) -> impl Future<Item = Link, Error = NetlinkIpError> {
+        let LinkSetRequest {
+            mut handle,
+            message,
+        } = self;
+        let fallback = LinkGetRequest::new(handle.clone()).match_index(message.header().index());
+        let mut req = Self::into_request(message);
+        let mut flags = req.header().flags();
+        flags.set_echo();
+        req.header_mut().set_flags(flags);
+        echo_link(handle.request(req), Some(fallback))
+    }
+
+    fn into_request(message: LinkMessage) -> NetlinkMessage {
+        // RTM_SETLINK ignores IFLA_LINKINFO, so type specific options must be changed with a
+        // RTM_NEWLINK message.
+        let has_link_info = message.nlas().iter().any(|nla| nla.kind() == IFLA_LINKINFO);
+        if has_link_info {
+            let mut req = NetlinkMessage::from(RtnlMessage::NewLink(message));
+            req.header_mut().set_flags(*CHANGE_FLAGS);
+            req
+        } else {
+            let mut req = NetlinkMessage::from(RtnlMessage::SetLink(message));
+            req.header_mut().set_flags(*SET_FLAGS);
+            req
+        }
     }
 
     /// Return a mutable reference to the request
@@ -72,10 +114,278 @@ impl LinkSetRequest {
         self
     }
 
+    /// Enslave the link with the given index to the link with index `master_index`, for instance a
+    /// bridge or a VRF device (equivalent to `ip link set DEV master MASTER`)
+    pub fn master(mut self, master_index: u32) -> Self {
+        self.message.append_nla(LinkNla::Master(master_index));
+        self
+    }
+
+    /// Release the link with the given index from its master (equivalent to `ip link set DEV
+    /// nomaster`)
+    pub fn nomaster(mut self) -> Self {
+        self.message.append_nla(LinkNla::Master(0));
+        self
+    }
+
     /// Set the hardware address of the link with the given index (equivalent to `ip link set DEV address ADDRESS`)
     pub fn address(mut self, address: MacAddress) -> Self {
         self.message
             .append_nla(LinkNla::Address(Vec::from(address.as_bytes())));
         self
     }
+
+    /// Change the options of the bridge with the given index (equivalent to `ip link set DEV
+    /// type bridge [OPTIONS]`)
+    pub fn bridge(mut self, options: BridgeOptions) -> Self {
+        self.message.append_nla(LinkNla::LinkInfo(vec![
+            LinkInfo::Kind(LinkInfoKind::Bridge),
+            LinkInfo::Data(LinkInfoData::Bridge(options.into_nlas())),
+        ]));
+        self
+    }
+
+    /// Set a bridge port attribute on the link with the given index. The link must be enslaved to
+    /// a bridge.
+    ///
+    /// Bridge port attributes are sent in a `AF_BRIDGE` message, which the kernel handles
+    /// separately from regular link messages: other link attributes set on the same request
+    /// (like the name or the mtu) are ignored.
+    pub fn bridge_port_nla(mut self, nla: BridgePortNla) -> Self {
+        self.message
+            .header_mut()
+            .set_address_family(AF_BRIDGE as u8);
+        for link_nla in self.message.nlas_mut().iter_mut() {
+            if let LinkNla::ProtoInfoBridge(ref mut port_nlas) = *link_nla {
+                port_nlas.push(nla);
+                return self;
+            }
+        }
+        self.message.append_nla(LinkNla::ProtoInfoBridge(vec![nla]));
+        self
+    }
+
+    /// Set the STP state of the bridge port, one of the `BR_STATE_*` constants (equivalent to
+    /// `bridge link set dev DEV state STATE`)
+    pub fn port_state(self, state: u8) -> Self {
+        self.bridge_port_nla(BridgePortNla::State(state))
+    }
+
+    /// Set the STP path cost of the bridge port (equivalent to `bridge link set dev DEV cost
+    /// COST`)
+    pub fn port_cost(self, cost: u32) -> Self {
+        self.bridge_port_nla(BridgePortNla::Cost(cost))
+    }
+
+    /// Set the STP priority of the bridge port (equivalent to `bridge link set dev DEV priority
+    /// PRIO`)
+    pub fn port_priority(self, priority: u16) -> Self {
+        self.bridge_port_nla(BridgePortNla::Priority(priority))
+    }
+
+    /// Block or allow STP BPDUs received on the bridge port (equivalent to `bridge link set dev
+    /// DEV guard {on|off}`)
+    pub fn port_guard(self, enabled: bool) -> Self {
+        self.bridge_port_nla(BridgePortNla::Guard(enabled as u8))
+    }
+
+    /// Allow or forbid traffic to be sent back out of the bridge port it was received on
+    /// (equivalent to `bridge link set dev DEV hairpin {on|off}`)
+    pub fn port_hairpin(self, enabled: bool) -> Self {
+        self.bridge_port_nla(BridgePortNla::Mode(enabled as u8))
+    }
+
+    /// Forbid the bridge port to become the root port (equivalent to `bridge link set dev DEV
+    /// root_block {on|off}`)
+    pub fn port_root_block(self, enabled: bool) -> Self {
+        self.bridge_port_nla(BridgePortNla::Protect(enabled as u8))
+    }
+
+    /// Enable or disable multicast fast leave on the bridge port (equivalent to `bridge link set
+    /// dev DEV fastleave {on|off}`)
+    pub fn port_fast_leave(self, enabled: bool) -> Self {
+        self.bridge_port_nla(BridgePortNla::FastLeave(enabled as u8))
+    }
+
+    /// Enable or disable learning of source addresses on the bridge port (equivalent to `bridge
+    /// link set dev DEV learning {on|off}`)
+    pub fn port_learning(self, enabled: bool) -> Self {
+        self.bridge_port_nla(BridgePortNla::Learning(enabled as u8))
+    }
+
+    /// Enable or disable flooding of unknown unicast traffic on the bridge port (equivalent to
+    /// `bridge link set dev DEV flood {on|off}`)
+    pub fn port_flood(self, enabled: bool) -> Self {
+        self.bridge_port_nla(BridgePortNla::UnicastFlood(enabled as u8))
+    }
+
+    /// Enable or disable flooding of unknown multicast traffic on the bridge port (equivalent to
+    /// `bridge link set dev DEV mcast_flood {on|off}`)
+    pub fn port_mcast_flood(self, enabled: bool) -> Self {
+        self.bridge_port_nla(BridgePortNla::MulticastFlood(enabled as u8))
+    }
+
+    /// Enable or disable flooding of broadcast traffic on the bridge port (equivalent to `bridge
+    /// link set dev DEV bcast_flood {on|off}`)
+    pub fn port_bcast_flood(self, enabled: bool) -> Self {
+        self.bridge_port_nla(BridgePortNla::BroadcastFlood(enabled as u8))
+    }
+
+    /// Enable or disable proxy ARP on the bridge port (equivalent to `bridge link set dev DEV
+    /// proxy_arp {on|off}`)
+    pub fn port_proxy_arp(self, enabled: bool) -> Self {
+        self.bridge_port_nla(BridgePortNla::ProxyArp(enabled as u8))
+    }
+
+    /// Enable or disable ARP and ND suppression on the bridge port (equivalent to `bridge link
+    /// set dev DEV neigh_suppress {on|off}`)
+    pub fn port_neigh_suppress(self, enabled: bool) -> Self {
+        self.bridge_port_nla(BridgePortNla::NeighSuppress(enabled as u8))
+    }
+
+    /// Isolate the bridge port, so that it cannot communicate with other isolated ports
+    /// (equivalent to `bridge link set dev DEV isolated {on|off}`)
+    pub fn port_isolated(self, enabled: bool) -> Self {
+        self.bridge_port_nla(BridgePortNla::Isolated(enabled as u8))
+    }
+
+    /// Set an attribute of a SR-IOV virtual function of the link. Attributes that apply to the
+    /// same virtual function are grouped in the same `IFLA_VF_INFO` attribute.
+    pub fn vf_nla(mut self, nla: VfNla) -> Self {
+        let vf = nla.vf();
+        for link_nla in self.message.nlas_mut().iter_mut() {
+            if let LinkNla::VfInfoList(ref mut infos) = *link_nla {
+                for info in infos.iter_mut() {
+                    if let VfInfoNla::Info(ref mut vf_nlas) = *info {
+                        if vf.is_some() && vf_nlas.iter().any(|vf_nla| vf_nla.vf() == vf) {
+                            vf_nlas.push(nla);
+                            return self;
+                        }
+                    }
+                }
+                infos.push(VfInfoNla::Info(vec![nla]));
+                return self;
+            }
+        }
+        self.message
+            .append_nla(LinkNla::VfInfoList(vec![VfInfoNla::Info(vec![nla])]));
+        self
+    }
+
+    /// Set the MAC address of a virtual function (equivalent to `ip link set DEV vf VF mac
+    /// ADDRESS`)
+    pub fn vf_mac(self, vf: u32, address: MacAddress) -> Self {
+        let mut bytes = [0; 6];
+        bytes.copy_from_slice(address.as_bytes());
+        self.vf_nla(VfNla::Mac(VfMac::new(vf, bytes)))
+    }
+
+    /// Set the 802.1Q VLAN and the priority of the traffic of a virtual function. A VLAN of 0
+    /// disables VLAN tagging (equivalent to `ip link set DEV vf VF vlan VLAN qos QOS`)
+    pub fn vf_vlan(self, vf: u32, vlan: u16, qos: u8) -> Self {
+        self.vf_nla(VfNla::Vlan(VfVlan {
+            vf,
+            vlan: u32::from(vlan),
+            qos: u32::from(qos),
+        }))
+    }
+
+    /// Set the VLAN, the priority and the VLAN protocol (`ETH_P_8021Q` or `ETH_P_8021AD`) of
+    /// the traffic of a virtual function (equivalent to `ip link set DEV vf VF vlan VLAN qos QOS
+    /// proto PROTO`)
+    pub fn vf_vlan_protocol(self, vf: u32, vlan: u16, qos: u8, protocol: u16) -> Self {
+        self.vf_nla(VfNla::VlanList(vec![VfVlanNla::Info(VfVlanInfo {
+            vf,
+            vlan: u32::from(vlan),
+            qos: u32::from(qos),
+            protocol,
+        })]))
+    }
+
+    /// Set the minimum and maximum transmit rate of a virtual function, in Mbit/s. 0 means no
+    /// limit (equivalent to `ip link set DEV vf VF min_tx_rate MIN max_tx_rate MAX`)
+    pub fn vf_rate(self, vf: u32, min_tx_rate: u32, max_tx_rate: u32) -> Self {
+        self.vf_nla(VfNla::Rate(VfRate {
+            vf,
+            min_tx_rate,
+            max_tx_rate,
+        }))
+    }
+
+    /// Enable or disable MAC spoofing checks on a virtual function (equivalent to `ip link set
+    /// DEV vf VF spoofchk {on|off}`)
+    pub fn vf_spoof_check(self, vf: u32, enabled: bool) -> Self {
+        self.vf_nla(VfNla::SpoofCheck(VfSetting {
+            vf,
+            setting: enabled as u32,
+        }))
+    }
+
+    /// Trust or untrust a virtual function, allowing it to perform privileged operations like
+    /// enabling promiscuous mode (equivalent to `ip link set DEV vf VF trust {on|off}`)
+    pub fn vf_trust(self, vf: u32, enabled: bool) -> Self {
+        self.vf_nla(VfNla::Trust(VfSetting {
+            vf,
+            setting: enabled as u32,
+        }))
+    }
+
+    /// Set the link state of a virtual function, one of the `IFLA_VF_LINK_STATE_*` constants
+    /// (equivalent to `ip link set DEV vf VF state {auto|enable|disable}`)
+    pub fn vf_link_state(self, vf: u32, state: u32) -> Self {
+        self.vf_nla(VfNla::LinkState(VfSetting { vf, setting: state }))
+    }
+
+    /// Allow or forbid a virtual function to query the RSS configuration of the physical
+    /// function (equivalent to `ip link set DEV vf VF query_rss {on|off}`)
+    pub fn vf_query_rss(self, vf: u32, enabled: bool) -> Self {
+        self.vf_nla(VfNla::RssQueryEnabled(VfSetting {
+            vf,
+            setting: enabled as u32,
+        }))
+    }
+
+    /// Add an attribute to the `IFLA_XDP` attribute of the request
+    pub fn xdp_nla(mut self, nla: LinkXdpNla) -> Self {
+        for link_nla in self.message.nlas_mut().iter_mut() {
+            if let LinkNla::Xdp(ref mut xdp_nlas) = *link_nla {
+                xdp_nlas.push(nla);
+                return self;
+            }
+        }
+        self.message.append_nla(LinkNla::Xdp(vec![nla]));
+        self
+    }
+
+    /// Add `XDP_FLAGS_*` flags to the XDP request, for instance `XDP_FLAGS_UPDATE_IF_NOEXIST` so
+    /// that attaching a program fails if one is already attached.
+    pub fn xdp_flags(mut self, flags: u32) -> Self {
+        for link_nla in self.message.nlas_mut().iter_mut() {
+            if let LinkNla::Xdp(ref mut xdp_nlas) = *link_nla {
+                for xdp_nla in xdp_nlas.iter_mut() {
+                    if let LinkXdpNla::Flags(ref mut value) = *xdp_nla {
+                        *value |= flags;
+                        return self;
+                    }
+                }
+            }
+        }
+        self.xdp_nla(LinkXdpNla::Flags(flags))
+    }
+
+    /// Attach the BPF program referred to by the given file descriptor to the link (equivalent
+    /// to `ip link set DEV {xdp|xdpgeneric|xdpdrv|xdpoffload} fd FD`)
+    pub fn xdp_attach(self, fd: RawFd, mode: XdpMode) -> Self {
+        let request = self.xdp_nla(LinkXdpNla::Fd(fd));
+        match mode.flags() {
+            0 => request,
+            flags => request.xdp_flags(flags),
+        }
+    }
+
+    /// Detach the XDP program attached to the link in the given mode (equivalent to `ip link set
+    /// DEV {xdp|xdpgeneric|xdpdrv|xdpoffload} off`)
+    pub fn xdp_detach(self, mode: XdpMode) -> Self {
+        self.xdp_attach(XDP_DETACH_FD, mode)
+    }
 }
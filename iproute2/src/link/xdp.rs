@@ -0,0 +1,105 @@
+use std::os::unix::io::RawFd;
+
+use rtnetlink::constants::{
+    XDP_ATTACHED_DRV, XDP_ATTACHED_HW, XDP_ATTACHED_MULTI, XDP_ATTACHED_NONE, XDP_ATTACHED_SKB,
+    XDP_FLAGS_DRV_MODE, XDP_FLAGS_HW_MODE, XDP_FLAGS_SKB_MODE,
+};
+use rtnetlink::LinkXdpNla;
+
+/// Mode in which an XDP program is attached to a link
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum XdpMode {
+    /// Let the kernel choose: native mode if the driver supports it, generic mode otherwise
+    /// (equivalent to `ip link set DEV xdp`)
+    Auto,
+    /// Generic mode, that works with any driver (equivalent to `ip link set DEV xdpgeneric`)
+    Generic,
+    /// Native mode, run by the driver (equivalent to `ip link set DEV xdpdrv`)
+    Driver,
+    /// Offloaded to the hardware (equivalent to `ip link set DEV xdpoffload`)
+    Offload,
+}
+
+impl XdpMode {
+    /// The `XDP_FLAGS_*` flag corresponding to this mode
+    pub fn flags(self) -> u32 {
+        match self {
+            XdpMode::Auto => 0,
+            XdpMode::Generic => XDP_FLAGS_SKB_MODE,
+            XdpMode::Driver => XDP_FLAGS_DRV_MODE,
+            XdpMode::Offload => XDP_FLAGS_HW_MODE,
+        }
+    }
+}
+
+/// XDP programs attached to a link (equivalent to the `prog/xdp` line of `ip link show`)
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LinkXdp {
+    attached: u8,
+    prog_id: Option<u32>,
+    drv_prog_id: Option<u32>,
+    skb_prog_id: Option<u32>,
+    hw_prog_id: Option<u32>,
+}
+
+impl LinkXdp {
+    pub fn from_nlas(nlas: &[LinkXdpNla]) -> Self {
+        let mut xdp = LinkXdp::default();
+        for nla in nlas {
+            match *nla {
+                LinkXdpNla::Attached(value) => xdp.attached = value,
+                LinkXdpNla::ProgId(id) => xdp.prog_id = Some(id),
+                LinkXdpNla::DrvProgId(id) => xdp.drv_prog_id = Some(id),
+                LinkXdpNla::SkbProgId(id) => xdp.skb_prog_id = Some(id),
+                LinkXdpNla::HwProgId(id) => xdp.hw_prog_id = Some(id),
+                _ => {}
+            }
+        }
+        xdp
+    }
+
+    /// How the programs are attached, one of the `XDP_ATTACHED_*` constants
+    pub fn attached(&self) -> u8 {
+        self.attached
+    }
+
+    /// Whether at least one program is attached
+    pub fn is_attached(&self) -> bool {
+        self.attached != XDP_ATTACHED_NONE
+    }
+
+    /// Identifier of the program attached in the given mode. For `XdpMode::Auto`, return the
+    /// identifier of the program if a single one is attached.
+    pub fn prog_id(&self, mode: XdpMode) -> Option<u32> {
+        // Kernels older than 4.18 only send IFLA_XDP_PROG_ID, so fall back to it when a single
+        // program is attached in the requested mode.
+        let (id, attached) = match mode {
+            XdpMode::Auto => return self.prog_id,
+            XdpMode::Generic => (self.skb_prog_id, XDP_ATTACHED_SKB),
+            XdpMode::Driver => (self.drv_prog_id, XDP_ATTACHED_DRV),
+            XdpMode::Offload => (self.hw_prog_id, XDP_ATTACHED_HW),
+        };
+        if id.is_some() {
+            id
+        } else if self.attached == attached {
+            self.prog_id
+        } else {
+            None
+        }
+    }
+
+    /// Identifiers of all the attached programs
+    pub fn prog_ids(&self) -> Vec<u32> {
+        if self.attached == XDP_ATTACHED_MULTI {
+            vec![self.drv_prog_id, self.skb_prog_id, self.hw_prog_id]
+                .into_iter()
+                .flatten()
+                .collect()
+        } else {
+            self.prog_id.into_iter().collect()
+        }
+    }
+}
+
+/// File descriptor used to detach the XDP program of a link
+pub(crate) const XDP_DETACH_FD: RawFd = -1;
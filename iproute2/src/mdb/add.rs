@@ -0,0 +1,84 @@
+use std::net::IpAddr;
+
+use futures::Future;
+
+use rtnetlink::constants::{
+    AF_BRIDGE, MDB_PERMANENT, NLM_F_ACK, NLM_F_CREATE, NLM_F_EXCL, NLM_F_REQUEST,
+};
+use rtnetlink::{MdbEntry, MdbMessage, MdbNla, NetlinkFlags, NetlinkMessage, RtnlMessage};
+
+use super::MdbDelRequest;
+use connection::ConnectionHandle;
+use errors::NetlinkIpError;
+
+use Stream2Ack;
+
+lazy_static! {
+    // Flags for `bridge mdb add`
+    static ref ADD_FLAGS: NetlinkFlags =
+        NetlinkFlags::from(NLM_F_REQUEST | NLM_F_ACK | NLM_F_EXCL | NLM_F_CREATE);
+}
+
+pub struct MdbAddRequest {
+    handle: ConnectionHandle,
+    message: MdbMessage,
+    entry: MdbEntry,
+}
+
+impl MdbAddRequest {
+    pub(crate) fn new(handle: ConnectionHandle, bridge: u32, port: u32, group: IpAddr) -> Self {
+        let mut message = MdbMessage::default();
+        message.header.family = AF_BRIDGE as u8;
+        message.header.ifindex = bridge;
+        MdbAddRequest {
+            handle,
+            message,
+            entry: MdbEntry::new(port, group),
+        }
+    }
+
+    /// Execute the request
+    pub fn execute(self) -> impl Future<Item = (), Error = NetlinkIpError> {
+        let MdbAddRequest {
+            mut handle,
+            mut message,
+            entry,
+        } = self;
+        message.nlas.push(MdbNla::SetEntry(entry));
+        let mut req = NetlinkMessage::from(RtnlMessage::NewMdb(message));
+        req.header_mut().set_flags(*ADD_FLAGS);
+        Stream2Ack::new(handle.request(req))
+    }
+
+    /// Return a request that removes the entry added by this request
+    pub(crate) fn inverse(&self) -> MdbDelRequest {
+        let entry = self.entry;
+        // The entry was built from an IP address, so the protocol is known
+        let group = entry.group().unwrap();
+        MdbDelRequest::new(
+            self.handle.clone(),
+            self.message.header.ifindex,
+            entry.ifindex,
+            group,
+        )
+        .vlan(entry.vid)
+    }
+
+    /// Return a mutable reference to the request
+    pub fn message_mut(&mut self) -> &mut MdbMessage {
+        &mut self.message
+    }
+
+    /// Make the membership permanent. By default, it expires after the bridge multicast
+    /// membership interval (equivalent to `bridge mdb add ... permanent`)
+    pub fn permanent(mut self) -> Self {
+        self.entry.state = MDB_PERMANENT;
+        self
+    }
+
+    /// Set the VLAN of the membership (equivalent to `bridge mdb add ... vid VID`)
+    pub fn vlan(mut self, vlan: u16) -> Self {
+        self.entry.vid = vlan;
+        self
+    }
+}
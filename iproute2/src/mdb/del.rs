@@ -0,0 +1,74 @@
+use std::net::IpAddr;
+
+use futures::Future;
+
+use rtnetlink::constants::{AF_BRIDGE, NLM_F_ACK, NLM_F_REQUEST};
+use rtnetlink::{MdbEntry, MdbMessage, MdbNla, NetlinkFlags, NetlinkMessage, RtnlMessage};
+
+use super::MdbAddRequest;
+use connection::ConnectionHandle;
+use errors::NetlinkIpError;
+
+use Stream2Ack;
+
+lazy_static! {
+    // Flags for `bridge mdb del`
+    static ref DEL_FLAGS: NetlinkFlags = NetlinkFlags::from(NLM_F_REQUEST | NLM_F_ACK);
+}
+
+pub struct MdbDelRequest {
+    handle: ConnectionHandle,
+    message: MdbMessage,
+    entry: MdbEntry,
+}
+
+impl MdbDelRequest {
+    pub(crate) fn new(handle: ConnectionHandle, bridge: u32, port: u32, group: IpAddr) -> Self {
+        let mut message = MdbMessage::default();
+        message.header.family = AF_BRIDGE as u8;
+        message.header.ifindex = bridge;
+        MdbDelRequest {
+            handle,
+            message,
+            entry: MdbEntry::new(port, group),
+        }
+    }
+
+    /// Execute the request
+    pub fn execute(self) -> impl Future<Item = (), Error = NetlinkIpError> {
+        let MdbDelRequest {
+            mut handle,
+            mut message,
+            entry,
+        } = self;
+        message.nlas.push(MdbNla::SetEntry(entry));
+        let mut req = NetlinkMessage::from(RtnlMessage::DelMdb(message));
+        req.header_mut().set_flags(*DEL_FLAGS);
+        Stream2Ack::new(handle.request(req))
+    }
+
+    /// Return a request that adds back the entry removed by this request. The entry is temporary.
+    pub(crate) fn inverse(&self) -> MdbAddRequest {
+        let entry = self.entry;
+        // The entry was built from an IP address, so the protocol is known
+        let group = entry.group().unwrap();
+        MdbAddRequest::new(
+            self.handle.clone(),
+            self.message.header.ifindex,
+            entry.ifindex,
+            group,
+        )
+        .vlan(entry.vid)
+    }
+
+    /// Return a mutable reference to the request
+    pub fn message_mut(&mut self) -> &mut MdbMessage {
+        &mut self.message
+    }
+
+    /// Set the VLAN of the membership (equivalent to `bridge mdb del ... vid VID`)
+    pub fn vlan(mut self, vlan: u16) -> Self {
+        self.entry.vid = vlan;
+        self
+    }
+}
@@ -0,0 +1,59 @@
+use futures::{Future, Stream};
+
+use rtnetlink::constants::{AF_BRIDGE, NLM_F_DUMP, NLM_F_REQUEST};
+use rtnetlink::{MdbMessage, NetlinkFlags, NetlinkMessage, RtnlMessage};
+
+use super::BridgeMdb;
+use connection::ConnectionHandle;
+use errors::NetlinkIpError;
+
+use Stream2Vec;
+
+lazy_static! {
+    // Flags for `bridge mdb show`
+    static ref GET_FLAGS: NetlinkFlags = NetlinkFlags::from(NLM_F_REQUEST | NLM_F_DUMP);
+}
+
+pub struct MdbGetRequest {
+    handle: ConnectionHandle,
+    message: MdbMessage,
+}
+
+impl MdbGetRequest {
+    pub(crate) fn new(handle: ConnectionHandle) -> Self {
+        let mut message = MdbMessage::default();
+        message.header.family = AF_BRIDGE as u8;
+        MdbGetRequest { handle, message }
+    }
+
+    /// Execute the request. The kernel may split the database of a bridge over several
+    /// messages, so several items may have the same bridge index.
+    pub fn execute(self) -> impl Future<Item = Vec<BridgeMdb>, Error = NetlinkIpError> {
+        let MdbGetRequest {
+            mut handle,
+            message,
+        } = self;
+        let mut req = NetlinkMessage::from(RtnlMessage::GetMdb(message));
+        req.header_mut().set_flags(*GET_FLAGS);
+        Stream2Vec::new(handle.request(req).map(move |msg| {
+            // The kernel answers mdb dumps with RTM_GETMDB messages, while notifications are
+            // RTM_NEWMDB messages. Accept both.
+            if !msg.is_new_mdb() && !msg.is_get_mdb() {
+                return Err(NetlinkIpError::UnexpectedMessage(msg));
+            }
+
+            match msg.into_parts() {
+                (_, RtnlMessage::NewMdb(mdb_message)) | (_, RtnlMessage::GetMdb(mdb_message)) => {
+                    Ok(BridgeMdb::from_mdb_message(mdb_message))
+                }
+                // We checked the message type above, so this should not be reachable.
+                _ => unreachable!(),
+            }
+        }))
+    }
+
+    /// Return a mutable reference to the request
+    pub fn message_mut(&mut self) -> &mut MdbMessage {
+        &mut self.message
+    }
+}
@@ -0,0 +1,30 @@
+use std::net::IpAddr;
+
+use connection::ConnectionHandle;
+
+use super::{MdbAddRequest, MdbDelRequest, MdbGetRequest};
+
+pub struct MdbHandle(ConnectionHandle);
+
+impl MdbHandle {
+    pub fn new(handle: ConnectionHandle) -> Self {
+        MdbHandle(handle)
+    }
+
+    /// Add the port with index `port` of the bridge with index `bridge` to the given multicast
+    /// group (equivalent to `bridge mdb add dev BRIDGE port PORT grp GROUP`)
+    pub fn add(&self, bridge: u32, port: u32, group: IpAddr) -> MdbAddRequest {
+        MdbAddRequest::new(self.0.clone(), bridge, port, group)
+    }
+
+    /// Remove the port with index `port` of the bridge with index `bridge` from the given
+    /// multicast group (equivalent to `bridge mdb del dev BRIDGE port PORT grp GROUP`)
+    pub fn del(&self, bridge: u32, port: u32, group: IpAddr) -> MdbDelRequest {
+        MdbDelRequest::new(self.0.clone(), bridge, port, group)
+    }
+
+    /// Retrieve the multicast database of all the bridges (equivalent to `bridge mdb show`)
+    pub fn get(&self) -> MdbGetRequest {
+        MdbGetRequest::new(self.0.clone())
+    }
+}
@@ -0,0 +1,165 @@
+use std::net::IpAddr;
+
+use rtnetlink::{
+    MdbEntry, MdbEntryAttrNla, MdbEntryNla, MdbGroupNla, MdbMessage, MdbNla, MdbRouterNla,
+    MdbRouterPortNla,
+};
+
+mod handle;
+pub use self::handle::*;
+
+mod add;
+pub use self::add::*;
+
+mod del;
+pub use self::del::*;
+
+mod get;
+pub use self::get::*;
+
+/// Multicast database of a bridge (equivalent to the output of `bridge mdb show dev BRIDGE`)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BridgeMdb {
+    /// Index of the bridge
+    index: u32,
+    groups: Vec<MdbGroup>,
+    router_ports: Vec<MdbRouterPort>,
+}
+
+impl BridgeMdb {
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+
+    /// Multicast group memberships of the bridge ports
+    pub fn groups(&self) -> &[MdbGroup] {
+        self.groups.as_slice()
+    }
+
+    /// Ports behind which a multicast router was detected or configured
+    pub fn router_ports(&self) -> &[MdbRouterPort] {
+        self.router_ports.as_slice()
+    }
+
+    pub fn from_mdb_message(value: MdbMessage) -> Self {
+        let mut mdb = BridgeMdb {
+            index: value.header.ifindex,
+            groups: vec![],
+            router_ports: vec![],
+        };
+        for nla in value.nlas {
+            match nla {
+                MdbNla::Mdb(nlas) => {
+                    for nla in nlas {
+                        if let MdbGroupNla::Entry(nlas) = nla {
+                            for nla in nlas {
+                                if let MdbEntryNla::Info(entry, attributes) = nla {
+                                    mdb.groups.push(MdbGroup::new(entry, attributes));
+                                }
+                            }
+                        }
+                    }
+                }
+                MdbNla::Router(nlas) => {
+                    for nla in nlas {
+                        if let MdbRouterNla::Port(index, attributes) = nla {
+                            mdb.router_ports.push(MdbRouterPort::new(index, attributes));
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+        mdb
+    }
+}
+
+/// Membership of a bridge port to a multicast group
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MdbGroup {
+    entry: MdbEntry,
+    timer: Option<u32>,
+}
+
+impl MdbGroup {
+    fn new(entry: MdbEntry, attributes: Vec<MdbEntryAttrNla>) -> Self {
+        let mut timer = None;
+        for nla in attributes {
+            if let MdbEntryAttrNla::Timer(value) = nla {
+                timer = Some(value);
+            }
+        }
+        MdbGroup { entry, timer }
+    }
+
+    /// Index of the bridge port
+    pub fn port_index(&self) -> u32 {
+        self.entry.ifindex
+    }
+
+    /// Address of the multicast group, or `None` if it is neither an IPv4 nor an IPv6 address
+    pub fn group(&self) -> Option<IpAddr> {
+        self.entry.group()
+    }
+
+    pub fn vlan(&self) -> u16 {
+        self.entry.vid
+    }
+
+    /// Whether the membership was added by the user (`permanent`), as opposed to learnt by
+    /// snooping IGMP/MLD reports (`temp`)
+    pub fn is_permanent(&self) -> bool {
+        self.entry.is_permanent()
+    }
+
+    /// A combination of the `MDB_FLAGS_*` flags
+    pub fn flags(&self) -> u8 {
+        self.entry.flags
+    }
+
+    /// Remaining time before the membership expires, in hundredths of seconds
+    pub fn timer(&self) -> Option<u32> {
+        self.timer
+    }
+}
+
+/// A bridge port behind which there is a multicast router
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MdbRouterPort {
+    index: u32,
+    timer: Option<u32>,
+    kind: Option<u8>,
+}
+
+impl MdbRouterPort {
+    fn new(index: u32, attributes: Vec<MdbRouterPortNla>) -> Self {
+        let mut port = MdbRouterPort {
+            index,
+            timer: None,
+            kind: None,
+        };
+        for nla in attributes {
+            match nla {
+                MdbRouterPortNla::Timer(value) => port.timer = Some(value),
+                MdbRouterPortNla::Type(value) => port.kind = Some(value),
+                _ => {}
+            }
+        }
+        port
+    }
+
+    /// Index of the bridge port
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+
+    /// Remaining time before the port stops being a router port, in hundredths of seconds
+    pub fn timer(&self) -> Option<u32> {
+        self.timer
+    }
+
+    /// Multicast router type of the port (the `multicast_router` option of the port)
+    pub fn kind(&self) -> Option<u8> {
+        self.kind
+    }
+}
@@ -0,0 +1,198 @@
+use std::net::IpAddr;
+
+use futures::future::{self, Either};
+use futures::{Future, Stream};
+
+use rtnetlink::constants::{NLM_F_ACK, NLM_F_CREATE, NLM_F_EXCL, NLM_F_REQUEST};
+use rtnetlink::{
+    NetlinkFlags, NetlinkMessage, RouteKind, RouteMessage, RouteNla, RouteProtocol, RouteScope,
+    RouteTable, RtnlMessage,
+};
+
+use super::{family, ip_bytes, route_table, RouteDelRequest, RouteGetRequest};
+use connection::ConnectionHandle;
+use errors::NetlinkIpError;
+use link::Link;
+
+use {Stream2Ack, Stream2Vec};
+
+lazy_static! {
+    // Flags for `ip route add`
+    static ref ADD_FLAGS: NetlinkFlags =
+        NetlinkFlags::from(NLM_F_REQUEST | NLM_F_ACK | NLM_F_EXCL | NLM_F_CREATE);
+}
+
+/// A request to create a new route. This is equivalent to the `ip route add` commands.
+///
+/// Like with `ip route add`, the route is a unicast route of the main table by default, and its
+/// protocol is `boot`. Routes without a gateway get the link scope.
+pub struct RouteAddRequest {
+    handle: ConnectionHandle,
+    message: RouteMessage,
+}
+
+impl RouteAddRequest {
+    pub(crate) fn new(handle: ConnectionHandle) -> Self {
+        let mut message = RouteMessage::default();
+        message.header.table = RouteTable::Main;
+        message.header.protocol = RouteProtocol::Boot;
+        message.header.kind = RouteKind::Unicast;
+        RouteAddRequest { handle, message }
+    }
+
+    /// Execute the request
+    pub fn execute(self) -> impl Future<Item = (), Error = NetlinkIpError> {
+        let RouteAddRequest {
+            mut handle,
+            mut message,
+        } = self;
+        set_default_scope(&mut message);
+        let mut req = NetlinkMessage::from(RtnlMessage::NewRoute(message));
+        req.header_mut().set_flags(*ADD_FLAGS);
+        Stream2Ack::new(handle.request(req))
+    }
+
+    /// Execute the request with the `NLM_F_ECHO` flag, and return the route that has been
+    /// created, as reported by the kernel.
+    ///
+    /// Kernels older than 6.3 don't echo new routes. The route is then retrieved with a dump of
+    /// the routing table of the route, which fails with
+    /// [`NetlinkIpError::RouteNotFound`](enum.NetlinkIpError.html#variant.RouteNotFound) if the
+    /// route was removed in the meantime.
+    pub fn execute_echo(self) -> impl Future<Item = RouteMessage, Error = NetlinkIpError> {
+        let RouteAddRequest {
+            mut handle,
+            mut message,
+        } = self;
+        set_default_scope(&mut message);
+        let fallback = RouteGetRequest::new(handle.clone())
+            .family(message.header.address_family)
+            .table(route_table(&message));
+        let request = message.clone();
+        let mut req = NetlinkMessage::from(RtnlMessage::NewRoute(message));
+        let mut flags = *ADD_FLAGS;
+        flags.set_echo();
+        req.header_mut().set_flags(flags);
+        Stream2Vec::new(handle.request(req).map(|msg| {
+            if msg.is_error() {
+                return Err(NetlinkIpError::NetlinkError(msg));
+            }
+            if !msg.is_new_route() {
+                return Err(NetlinkIpError::UnexpectedMessage(msg));
+            }
+
+            if let (_, RtnlMessage::NewRoute(message)) = msg.into_parts() {
+                Ok(message)
+            } else {
+                // We checked that msg.is_new_route() above, so the should not be reachable.
+                unreachable!();
+            }
+        }))
+        .and_then(move |mut routes| match routes.pop() {
+            Some(route) => Either::A(future::ok(route)),
+            None => Either::B(fallback.execute().and_then(move |routes| {
+                routes
+                    .into_iter()
+                    .find(|route| same_route(route, &request))
+                    .ok_or(NetlinkIpError::RouteNotFound)
+            })),
+        })
+    }
+
+    /// Return a request that removes the route added by this request
+    pub(crate) fn inverse(&self) -> RouteDelRequest {
+        RouteDelRequest::new(self.handle.clone(), self.message.clone())
+    }
+
+    /// Return a mutable reference to the request
+    pub fn message_mut(&mut self) -> &mut RouteMessage {
+        &mut self.message
+    }
+
+    /// Set the destination of the route. Without destination, the route is a default route.
+    pub fn destination(mut self, address: IpAddr, prefix_len: u8) -> Self {
+        self.message.header.address_family = family(address);
+        self.message.header.destination_length = prefix_len;
+        self.message
+            .nlas
+            .push(RouteNla::Destination(ip_bytes(address)));
+        self
+    }
+
+    /// Set the gateway of the route (equivalent to `ip route add ... via <gateway>`)
+    pub fn gateway(mut self, address: IpAddr) -> Self {
+        self.message.header.address_family = family(address);
+        self.message.nlas.push(RouteNla::Gateway(ip_bytes(address)));
+        self
+    }
+
+    /// Set the output link of the route (equivalent to `ip route add ... dev <link>`)
+    pub fn output_interface(mut self, index: u32) -> Self {
+        self.message.nlas.push(RouteNla::Oif(index));
+        self
+    }
+
+    /// Set the routing table of the route (equivalent to `ip route add ... table <table>`)
+    pub fn table(mut self, table: u32) -> Self {
+        self.message.header.table = if table < 256 {
+            RouteTable::from(table as u8)
+        } else {
+            RouteTable::Unspec
+        };
+        self.message.nlas.push(RouteNla::Table(table));
+        self
+    }
+
+    /// Place the route in the routing table of the given VRF device (equivalent to
+    /// `ip route add vrf <vrf> ...`). This fails with
+    /// [`NetlinkIpError::NotAVrf`](enum.NetlinkIpError.html#variant.NotAVrf) if the link is not
+    /// a VRF device.
+    pub fn vrf(self, vrf: &Link) -> Result<Self, NetlinkIpError> {
+        match vrf.vrf_table() {
+            Some(table) => Ok(self.table(table)),
+            None => Err(NetlinkIpError::NotAVrf(vrf.index())),
+        }
+    }
+
+    /// Set the priority of the route (equivalent to `ip route add ... metric <priority>`)
+    pub fn priority(mut self, priority: u32) -> Self {
+        self.message.nlas.push(RouteNla::Priority(priority));
+        self
+    }
+
+    /// Set the protocol of the route (equivalent to `ip route add ... proto <protocol>`)
+    pub fn protocol(mut self, protocol: RouteProtocol) -> Self {
+        self.message.header.protocol = protocol;
+        self
+    }
+
+    /// Set the type of the route (equivalent to `ip route add <type> ...`)
+    pub fn kind(mut self, kind: RouteKind) -> Self {
+        self.message.header.kind = kind;
+        self
+    }
+}
+
+/// Like `ip route add`, give the link scope to unicast routes without a gateway
+fn set_default_scope(message: &mut RouteMessage) {
+    let has_gateway = message.nlas.iter().any(|nla| match *nla {
+        RouteNla::Gateway(_) => true,
+        _ => false,
+    });
+    if message.header.kind == RouteKind::Unicast && !has_gateway {
+        message.header.scope = RouteScope::Link;
+    }
+}
+
+/// Whether `route` has the same prefix, gateway, output link and priority as `request`, ie
+/// whether it is the route `request` created.
+fn same_route(route: &RouteMessage, request: &RouteMessage) -> bool {
+    route.header.destination_length == request.header.destination_length
+        && request.nlas.iter().all(|nla| match *nla {
+            RouteNla::Destination(_)
+            | RouteNla::Gateway(_)
+            | RouteNla::Oif(_)
+            | RouteNla::Priority(_) => route.nlas.contains(nla),
+            _ => true,
+        })
+}
@@ -0,0 +1,73 @@
+use futures::Future;
+
+use rtnetlink::constants::{NLM_F_ACK, NLM_F_REQUEST};
+use rtnetlink::{NetlinkFlags, NetlinkMessage, RouteMessage, RouteNla, RouteScope, RtnlMessage};
+
+use super::RouteAddRequest;
+use connection::ConnectionHandle;
+use errors::NetlinkIpError;
+
+use Stream2Ack;
+
+lazy_static! {
+    // Flags for `ip route del`
+    static ref DEL_FLAGS: NetlinkFlags = NetlinkFlags::from(NLM_F_REQUEST | NLM_F_ACK);
+}
+
+pub struct RouteDelRequest {
+    handle: ConnectionHandle,
+    message: RouteMessage,
+}
+
+impl RouteDelRequest {
+    pub(crate) fn new(handle: ConnectionHandle, route: RouteMessage) -> Self {
+        let RouteMessage { mut header, nlas } = route;
+        // Like `ip route del`, match routes of any scope
+        header.scope = RouteScope::Nowhere;
+        // Only keep the attributes that identify the route
+        let nlas = nlas
+            .into_iter()
+            .filter(|nla| match *nla {
+                RouteNla::Destination(_)
+                | RouteNla::Source(_)
+                | RouteNla::Gateway(_)
+                | RouteNla::Oif(_)
+                | RouteNla::Priority(_)
+                | RouteNla::Table(_) => true,
+                _ => false,
+            })
+            .collect();
+        let message = RouteMessage { header, nlas };
+        RouteDelRequest { handle, message }
+    }
+
+    /// Execute the request
+    pub fn execute(self) -> impl Future<Item = (), Error = NetlinkIpError> {
+        let RouteDelRequest {
+            mut handle,
+            message,
+        } = self;
+        let mut req = NetlinkMessage::from(RtnlMessage::DelRoute(message));
+        req.header_mut().set_flags(*DEL_FLAGS);
+        Stream2Ack::new(handle.request(req))
+    }
+
+    /// Return a request that adds back the route removed by this request. The route gets the
+    /// destination, source, gateway, output link, priority, table, protocol and type of this
+    /// request, but its other attributes, like its metrics, are lost.
+    pub(crate) fn inverse(&self) -> RouteAddRequest {
+        let mut request = RouteAddRequest::new(self.handle.clone());
+        {
+            let message = request.message_mut();
+            *message = self.message.clone();
+            // Routes without a gateway get the link scope when the request is executed
+            message.header.scope = RouteScope::Universe;
+        }
+        request
+    }
+
+    /// Return a mutable reference to the request
+    pub fn message_mut(&mut self) -> &mut RouteMessage {
+        &mut self.message
+    }
+}
@@ -0,0 +1,133 @@
+use futures::{Future, Stream};
+
+use rtnetlink::constants::{NLM_F_DUMP, NLM_F_REQUEST};
+use rtnetlink::{
+    NetlinkFlags, NetlinkMessage, RouteKind, RouteMessage, RouteNla, RouteProtocol, RouteTable,
+    RtnlMessage,
+};
+
+use connection::ConnectionHandle;
+use errors::NetlinkIpError;
+use link::Link;
+
+use Stream2Vec;
+
+lazy_static! {
+    // Flags for `ip route show table all`
+    static ref GET_FLAGS: NetlinkFlags = NetlinkFlags::from(NLM_F_REQUEST | NLM_F_DUMP);
+}
+
+pub struct RouteGetRequest {
+    handle: ConnectionHandle,
+    message: RouteMessage,
+    table: Option<u32>,
+}
+
+/// Return the routing table of a route. The table in the header is only 8 bits, so tables above
+/// 255 are only in the `RTA_TABLE` attribute.
+pub fn route_table(route: &RouteMessage) -> u32 {
+    for nla in &route.nlas {
+        if let RouteNla::Table(table) = *nla {
+            return table;
+        }
+    }
+    u32::from(u8::from(route.header.table))
+}
+
+impl RouteGetRequest {
+    pub(crate) fn new(handle: ConnectionHandle) -> Self {
+        let message = RouteMessage::default();
+        RouteGetRequest {
+            handle,
+            message,
+            table: None,
+        }
+    }
+
+    /// Execute the request
+    pub fn execute(self) -> impl Future<Item = Vec<RouteMessage>, Error = NetlinkIpError> {
+        let RouteGetRequest {
+            mut handle,
+            message,
+            table,
+        } = self;
+        let family = message.header.address_family;
+        let protocol = message.header.protocol;
+        let kind = message.header.kind;
+        let mut req = NetlinkMessage::from(RtnlMessage::GetRoute(message));
+        req.header_mut().set_flags(*GET_FLAGS);
+        Stream2Vec::new(handle.request(req).map(move |msg| {
+            if !msg.is_new_route() {
+                return Err(NetlinkIpError::UnexpectedMessage(msg));
+            }
+
+            if let (_, RtnlMessage::NewRoute(message)) = msg.into_parts() {
+                Ok(message)
+            } else {
+                // We checked that msg.is_new_route() above, so the should not be reachable.
+                unreachable!();
+            }
+        }))
+        .map(move |routes| {
+            // Kernels that don't support strict checking ignore the filters, so filter the routes
+            // here too.
+            routes
+                .into_iter()
+                .filter(|route| family == 0 || route.header.address_family == family)
+                .filter(|route| table.is_none() || Some(route_table(route)) == table)
+                .filter(|route| {
+                    protocol == RouteProtocol::Unspec || route.header.protocol == protocol
+                })
+                .filter(|route| kind == RouteKind::Unspec || route.header.kind == kind)
+                .collect()
+        })
+    }
+
+    /// Return a mutable reference to the request
+    pub fn message_mut(&mut self) -> &mut RouteMessage {
+        &mut self.message
+    }
+
+    /// Only retrieve the routes of the given address family (`AF_INET` or `AF_INET6`)
+    pub fn family(mut self, family: u8) -> Self {
+        self.message.header.address_family = family;
+        self
+    }
+
+    /// Only retrieve the routes of the given routing table (equivalent to
+    /// `ip route show table <table>`)
+    pub fn table(mut self, table: u32) -> Self {
+        self.table = Some(table);
+        self.message.header.table = if table < 256 {
+            RouteTable::from(table as u8)
+        } else {
+            RouteTable::Unspec
+        };
+        self.message.nlas.push(RouteNla::Table(table));
+        self
+    }
+
+    /// Only retrieve the routes of the routing table of the given VRF device (equivalent to
+    /// `ip route show vrf <vrf>`). This fails with
+    /// [`NetlinkIpError::NotAVrf`](enum.NetlinkIpError.html#variant.NotAVrf) if the link is not
+    /// a VRF device.
+    pub fn vrf(self, vrf: &Link) -> Result<Self, NetlinkIpError> {
+        match vrf.vrf_table() {
+            Some(table) => Ok(self.table(table)),
+            None => Err(NetlinkIpError::NotAVrf(vrf.index())),
+        }
+    }
+
+    /// Only retrieve the routes installed by the given protocol (equivalent to
+    /// `ip route show proto <protocol>`)
+    pub fn protocol(mut self, protocol: RouteProtocol) -> Self {
+        self.message.header.protocol = protocol;
+        self
+    }
+
+    /// Only retrieve the routes of the given type (equivalent to `ip route show type <type>`)
+    pub fn kind(mut self, kind: RouteKind) -> Self {
+        self.message.header.kind = kind;
+        self
+    }
+}
@@ -0,0 +1,28 @@
+use rtnetlink::RouteMessage;
+
+use connection::ConnectionHandle;
+
+use super::{RouteAddRequest, RouteDelRequest, RouteGetRequest};
+
+pub struct RouteHandle(ConnectionHandle);
+
+impl RouteHandle {
+    pub fn new(handle: ConnectionHandle) -> Self {
+        RouteHandle(handle)
+    }
+
+    /// Retrieve the list of routes (equivalent to `ip route show table all`)
+    pub fn get(&self) -> RouteGetRequest {
+        RouteGetRequest::new(self.0.clone())
+    }
+
+    /// Create a new route (equivalent to `ip route add`)
+    pub fn add(&self) -> RouteAddRequest {
+        RouteAddRequest::new(self.0.clone())
+    }
+
+    /// Delete a route, as returned by [`get()`](#method.get) (equivalent to `ip route del`)
+    pub fn del(&self, route: RouteMessage) -> RouteDelRequest {
+        RouteDelRequest::new(self.0.clone(), route)
+    }
+}
@@ -0,0 +1,29 @@
+use std::net::IpAddr;
+
+use rtnetlink::constants::{AF_INET, AF_INET6};
+
+mod handle;
+pub use self::handle::*;
+
+mod add;
+pub use self::add::*;
+
+mod del;
+pub use self::del::*;
+
+mod get;
+pub use self::get::*;
+
+pub(crate) fn family(address: IpAddr) -> u8 {
+    match address {
+        IpAddr::V4(_) => AF_INET as u8,
+        IpAddr::V6(_) => AF_INET6 as u8,
+    }
+}
+
+pub(crate) fn ip_bytes(address: IpAddr) -> Vec<u8> {
+    match address {
+        IpAddr::V4(address) => address.octets().to_vec(),
+        IpAddr::V6(address) => address.octets().to_vec(),
+    }
+}
@@ -0,0 +1,159 @@
+use std::net::IpAddr;
+
+use futures::Future;
+
+use rtnetlink::constants::{
+    AF_INET, FR_ACT_TO_TBL, NLM_F_ACK, NLM_F_CREATE, NLM_F_EXCL, NLM_F_REQUEST, RT_TABLE_MAIN,
+};
+use rtnetlink::{NetlinkFlags, NetlinkMessage, RouteProtocol, RtnlMessage, RuleMessage, RuleNla};
+
+use connection::ConnectionHandle;
+use errors::NetlinkIpError;
+use link::Link;
+use route::{family, ip_bytes};
+
+use Stream2Ack;
+
+lazy_static! {
+    // Flags for `ip rule add`
+    static ref ADD_FLAGS: NetlinkFlags =
+        NetlinkFlags::from(NLM_F_REQUEST | NLM_F_ACK | NLM_F_EXCL | NLM_F_CREATE);
+}
+
+/// A request to create a new routing rule. This is equivalent to the `ip rule add` commands.
+///
+/// Like with `ip rule add`, the rule is an IPv4 rule that looks the main table up by default.
+pub struct RuleAddRequest {
+    handle: ConnectionHandle,
+    message: RuleMessage,
+}
+
+impl RuleAddRequest {
+    pub(crate) fn new(handle: ConnectionHandle) -> Self {
+        let mut message = RuleMessage::default();
+        message.header.family = AF_INET as u8;
+        message.header.table = RT_TABLE_MAIN;
+        message.header.action = FR_ACT_TO_TBL;
+        RuleAddRequest { handle, message }
+    }
+
+    /// Execute the request
+    pub fn execute(self) -> impl Future<Item = (), Error = NetlinkIpError> {
+        let RuleAddRequest {
+            mut handle,
+            message,
+        } = self;
+        let mut req = NetlinkMessage::from(RtnlMessage::NewRule(message));
+        req.header_mut().set_flags(*ADD_FLAGS);
+        Stream2Ack::new(handle.request(req))
+    }
+
+    /// Return a mutable reference to the request
+    pub fn message_mut(&mut self) -> &mut RuleMessage {
+        &mut self.message
+    }
+
+    /// Set the address family of the rule (`AF_INET` or `AF_INET6`). This is only needed for
+    /// rules that match neither a source nor a destination prefix.
+    pub fn family(mut self, family: u8) -> Self {
+        self.message.header.family = family;
+        self
+    }
+
+    /// Set the priority of the rule (equivalent to `ip rule add ... priority <priority>`)
+    pub fn priority(mut self, priority: u32) -> Self {
+        self.message.nlas.push(RuleNla::Priority(priority));
+        self
+    }
+
+    /// Only match the packets from the given prefix (equivalent to `ip rule add from <prefix>`)
+    pub fn source(mut self, address: IpAddr, prefix_len: u8) -> Self {
+        self.message.header.family = family(address);
+        self.message.header.src_len = prefix_len;
+        self.message.nlas.push(RuleNla::Source(ip_bytes(address)));
+        self
+    }
+
+    /// Only match the packets to the given prefix (equivalent to `ip rule add to <prefix>`)
+    pub fn destination(mut self, address: IpAddr, prefix_len: u8) -> Self {
+        self.message.header.family = family(address);
+        self.message.header.dst_len = prefix_len;
+        self.message
+            .nlas
+            .push(RuleNla::Destination(ip_bytes(address)));
+        self
+    }
+
+    /// Only match the packets received on the given link (equivalent to
+    /// `ip rule add iif <name>`)
+    pub fn input_interface(mut self, name: String) -> Self {
+        self.message.nlas.push(RuleNla::IifName(name));
+        self
+    }
+
+    /// Only match the packets sent on the given link (equivalent to `ip rule add oif <name>`)
+    pub fn output_interface(mut self, name: String) -> Self {
+        self.message.nlas.push(RuleNla::OifName(name));
+        self
+    }
+
+    /// Only match the packets with the given firewall mark (equivalent to
+    /// `ip rule add fwmark <mark>`)
+    pub fn fwmark(mut self, mark: u32) -> Self {
+        self.message.nlas.push(RuleNla::FwMark(mark));
+        self
+    }
+
+    /// Set the routing table looked up by the rule (equivalent to `ip rule add ... table <table>`)
+    pub fn table(mut self, table: u32) -> Self {
+        self.message.header.table = if table < 256 { table as u8 } else { 0 };
+        self.message.nlas.retain(|nla| match *nla {
+            RuleNla::Table(_) => false,
+            _ => true,
+        });
+        self.message.nlas.push(RuleNla::Table(table));
+        self
+    }
+
+    /// Look the routing table of the given VRF device up (equivalent to
+    /// `ip rule add ... table <table of the vrf>`). This fails with
+    /// [`NetlinkIpError::NotAVrf`](enum.NetlinkIpError.html#variant.NotAVrf) if the link is not
+    /// a VRF device.
+    pub fn vrf(self, vrf: &Link) -> Result<Self, NetlinkIpError> {
+        match vrf.vrf_table() {
+            Some(table) => Ok(self.table(table)),
+            None => Err(NetlinkIpError::NotAVrf(vrf.index())),
+        }
+    }
+
+    /// Look the routing table of the VRF device the packets go through up (equivalent to
+    /// `ip rule add l3mdev`). This is the rule that makes VRF devices work.
+    pub fn l3mdev(mut self) -> Self {
+        self.message.header.table = 0;
+        self.message.nlas.retain(|nla| match *nla {
+            RuleNla::Table(_) => false,
+            _ => true,
+        });
+        self.message.nlas.push(RuleNla::L3MDev(1));
+        self
+    }
+
+    /// Set the protocol that installs the rule (equivalent to `ip rule add ... protocol <protocol>`)
+    pub fn protocol(mut self, protocol: RouteProtocol) -> Self {
+        self.message
+            .nlas
+            .push(RuleNla::Protocol(u8::from(protocol)));
+        self
+    }
+
+    /// Set the action of the rule, one of the `FR_ACT_*` constants (equivalent to
+    /// `ip rule add ... { blackhole | unreachable | prohibit | nop }`). The default is
+    /// `FR_ACT_TO_TBL`.
+    pub fn action(mut self, action: u8) -> Self {
+        if action != FR_ACT_TO_TBL {
+            self.message.header.table = 0;
+        }
+        self.message.header.action = action;
+        self
+    }
+}
@@ -0,0 +1,41 @@
+use futures::Future;
+
+use rtnetlink::constants::{NLM_F_ACK, NLM_F_REQUEST};
+use rtnetlink::{NetlinkFlags, NetlinkMessage, RtnlMessage, RuleMessage};
+
+use connection::ConnectionHandle;
+use errors::NetlinkIpError;
+
+use Stream2Ack;
+
+lazy_static! {
+    // Flags for `ip rule del`
+    static ref DEL_FLAGS: NetlinkFlags = NetlinkFlags::from(NLM_F_REQUEST | NLM_F_ACK);
+}
+
+pub struct RuleDelRequest {
+    handle: ConnectionHandle,
+    message: RuleMessage,
+}
+
+impl RuleDelRequest {
+    pub(crate) fn new(handle: ConnectionHandle, message: RuleMessage) -> Self {
+        RuleDelRequest { handle, message }
+    }
+
+    /// Execute the request
+    pub fn execute(self) -> impl Future<Item = (), Error = NetlinkIpError> {
+        let RuleDelRequest {
+            mut handle,
+            message,
+        } = self;
+        let mut req = NetlinkMessage::from(RtnlMessage::DelRule(message));
+        req.header_mut().set_flags(*DEL_FLAGS);
+        Stream2Ack::new(handle.request(req))
+    }
+
+    /// Return a mutable reference to the request
+    pub fn message_mut(&mut self) -> &mut RuleMessage {
+        &mut self.message
+    }
+}
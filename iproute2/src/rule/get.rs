@@ -0,0 +1,92 @@
+use futures::{Future, Stream};
+
+use rtnetlink::constants::{NLM_F_DUMP, NLM_F_REQUEST};
+use rtnetlink::{NetlinkFlags, NetlinkMessage, RtnlMessage, RuleMessage};
+
+use super::rule_table;
+use connection::ConnectionHandle;
+use errors::NetlinkIpError;
+use link::Link;
+
+use Stream2Vec;
+
+lazy_static! {
+    // Flags for `ip rule show`
+    static ref GET_FLAGS: NetlinkFlags = NetlinkFlags::from(NLM_F_REQUEST | NLM_F_DUMP);
+}
+
+pub struct RuleGetRequest {
+    handle: ConnectionHandle,
+    message: RuleMessage,
+    table: Option<u32>,
+}
+
+impl RuleGetRequest {
+    pub(crate) fn new(handle: ConnectionHandle) -> Self {
+        RuleGetRequest {
+            handle,
+            message: RuleMessage::default(),
+            table: None,
+        }
+    }
+
+    /// Execute the request
+    pub fn execute(self) -> impl Future<Item = Vec<RuleMessage>, Error = NetlinkIpError> {
+        let RuleGetRequest {
+            mut handle,
+            message,
+            table,
+        } = self;
+        let family = message.header.family;
+        let mut req = NetlinkMessage::from(RtnlMessage::GetRule(message));
+        req.header_mut().set_flags(*GET_FLAGS);
+        Stream2Vec::new(handle.request(req).map(move |msg| {
+            if !msg.is_new_rule() {
+                return Err(NetlinkIpError::UnexpectedMessage(msg));
+            }
+
+            if let (_, RtnlMessage::NewRule(message)) = msg.into_parts() {
+                Ok(message)
+            } else {
+                // We checked that msg.is_new_rule() above, so the should not be reachable.
+                unreachable!();
+            }
+        }))
+        .map(move |rules| {
+            rules
+                .into_iter()
+                .filter(|rule| family == 0 || rule.header.family == family)
+                .filter(|rule| table.is_none() || Some(rule_table(rule)) == table)
+                .collect()
+        })
+    }
+
+    /// Return a mutable reference to the request
+    pub fn message_mut(&mut self) -> &mut RuleMessage {
+        &mut self.message
+    }
+
+    /// Only retrieve the rules of the given address family (`AF_INET` or `AF_INET6`)
+    pub fn family(mut self, family: u8) -> Self {
+        self.message.header.family = family;
+        self
+    }
+
+    /// Only retrieve the rules that point to the given routing table (equivalent to
+    /// `ip rule show table <table>`)
+    pub fn table(mut self, table: u32) -> Self {
+        self.table = Some(table);
+        self
+    }
+
+    /// Only retrieve the rules that point to the routing table of the given VRF device
+    /// (equivalent to `ip rule show vrf <vrf>`). This fails with
+    /// [`NetlinkIpError::NotAVrf`](enum.NetlinkIpError.html#variant.NotAVrf) if the link is not
+    /// a VRF device.
+    pub fn vrf(self, vrf: &Link) -> Result<Self, NetlinkIpError> {
+        match vrf.vrf_table() {
+            Some(table) => Ok(self.table(table)),
+            None => Err(NetlinkIpError::NotAVrf(vrf.index())),
+        }
+    }
+}
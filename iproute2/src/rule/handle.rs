@@ -0,0 +1,29 @@
+use rtnetlink::RuleMessage;
+
+use connection::ConnectionHandle;
+
+use super::{RuleAddRequest, RuleDelRequest, RuleGetRequest};
+
+pub struct RuleHandle(ConnectionHandle);
+
+impl RuleHandle {
+    pub fn new(handle: ConnectionHandle) -> Self {
+        RuleHandle(handle)
+    }
+
+    /// Retrieve the list of routing rules (equivalent to `ip rule show`)
+    pub fn get(&self) -> RuleGetRequest {
+        RuleGetRequest::new(self.0.clone())
+    }
+
+    /// Create a new routing rule (equivalent to `ip rule add`)
+    pub fn add(&self) -> RuleAddRequest {
+        RuleAddRequest::new(self.0.clone())
+    }
+
+    /// Delete a routing rule, as returned by [`get()`](#method.get) (equivalent to
+    /// `ip rule del`)
+    pub fn del(&self, rule: RuleMessage) -> RuleDelRequest {
+        RuleDelRequest::new(self.0.clone(), rule)
+    }
+}
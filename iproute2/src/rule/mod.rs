@@ -0,0 +1,24 @@
+use rtnetlink::{RuleMessage, RuleNla};
+
+mod handle;
+pub use self::handle::*;
+
+mod add;
+pub use self::add::*;
+
+mod del;
+pub use self::del::*;
+
+mod get;
+pub use self::get::*;
+
+/// Return the routing table of a rule. The table in the header is only 8 bits, so tables above
+/// 255 are only in the `FRA_TABLE` attribute.
+pub fn rule_table(rule: &RuleMessage) -> u32 {
+    for nla in &rule.nlas {
+        if let RuleNla::Table(table) = *nla {
+            return table;
+        }
+    }
+    u32::from(rule.header.table)
+}
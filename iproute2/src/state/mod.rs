@@ -0,0 +1,450 @@
+use std::fmt;
+use std::net::IpAddr;
+
+use rtnetlink::constants::{AF_INET, AF_INET6, RT_TABLE_MAIN};
+use rtnetlink::{LinkInfoData, LinkInfoKind};
+
+mod plan;
+pub use self::plan::*;
+
+mod reconcile;
+pub use self::reconcile::*;
+
+/// The desired state of the network: links, addresses, routes and routing rules. It is applied
+/// with [`ConnectionHandle::reconcile()`](struct.ConnectionHandle.html#method.reconcile), which
+/// describes how the links, addresses, routes and rules that are not part of the state are
+/// handled.
+///
+/// With the `serde_support` feature, the state can be serialized and deserialized, for instance
+/// to read it from a configuration file. The options of the veth links cannot be serialized,
+/// since they hold the whole link message of the peer.
+///
+/// # Example
+///
+/// ```rust
+/// extern crate iproute2;
+/// extern crate rtnetlink;
+///
+/// use iproute2::{DesiredAddress, DesiredLink, DesiredRoute, DesiredRule, DesiredState};
+/// use rtnetlink::{LinkInfoData, LinkInfoKind, LinkInfoVlan};
+///
+/// fn main() {
+///     let state = DesiredState::new()
+///         .link(DesiredLink::new("br0".into(), LinkInfoKind::Bridge).mtu(1400).up())
+///         .link(DesiredLink::existing("eth1".into()).master("br0".into()).up())
+///         .link(
+///             DesiredLink::new("eth1.10".into(), LinkInfoKind::Vlan)
+///                 .parent("eth1".into())
+///                 .options(LinkInfoData::Vlan(vec![LinkInfoVlan::Id(10)]))
+///                 .up(),
+///         )
+///         .address(DesiredAddress::new("br0".into(), "10.0.0.1".parse().unwrap(), 24))
+///         .route(
+///             DesiredRoute::new("10.1.0.0".parse().unwrap(), 16)
+///                 .gateway("10.0.0.254".parse().unwrap())
+///                 .table(100),
+///         )
+///         .rule(DesiredRule::new(1000, 100).input_interface("eth1.10".into()));
+///     assert_eq!(state.links.len(), 3);
+/// }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde_support", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde_support", serde(default))]
+pub struct DesiredState {
+    pub links: Vec<DesiredLink>,
+    pub addresses: Vec<DesiredAddress>,
+    pub routes: Vec<DesiredRoute>,
+    pub rules: Vec<DesiredRule>,
+}
+
+impl DesiredState {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Add a link to the state
+    pub fn link(mut self, link: DesiredLink) -> Self {
+        self.links.push(link);
+        self
+    }
+
+    /// Add an address to the state
+    pub fn address(mut self, address: DesiredAddress) -> Self {
+        self.addresses.push(address);
+        self
+    }
+
+    /// Add a route to the state
+    pub fn route(mut self, route: DesiredRoute) -> Self {
+        self.routes.push(route);
+        self
+    }
+
+    /// Add a routing rule to the state
+    pub fn rule(mut self, rule: DesiredRule) -> Self {
+        self.rules.push(rule);
+        self
+    }
+}
+
+/// A link of the desired state. The attributes that are `None` are left as they are.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde_support", derive(Serialize, Deserialize))]
+pub struct DesiredLink {
+    pub name: String,
+    /// Kind of the link. Links without a kind, such as physical links, are never created.
+    pub kind: Option<LinkInfoKind>,
+    /// Options of the kind of the link. They are only used when the link is created.
+    pub options: Option<LinkInfoData>,
+    /// Name of the parent link, for links that are created on top of another link like vlan and
+    /// macvlan links. The parent must come before the link in the state if it is created too.
+    pub parent: Option<String>,
+    pub mtu: Option<u32>,
+    pub up: Option<bool>,
+    /// Name of the master of the link
+    pub master: Option<String>,
+}
+
+impl DesiredLink {
+    /// A link of the given kind, which is created if it does not exist
+    pub fn new(name: String, kind: LinkInfoKind) -> Self {
+        DesiredLink {
+            name,
+            kind: Some(kind),
+            options: None,
+            parent: None,
+            mtu: None,
+            up: None,
+            master: None,
+        }
+    }
+
+    /// A link that must already exist, such as a physical link
+    pub fn existing(name: String) -> Self {
+        DesiredLink {
+            name,
+            kind: None,
+            options: None,
+            parent: None,
+            mtu: None,
+            up: None,
+            master: None,
+        }
+    }
+
+    /// Set the options of the kind of the link, used when the link is created
+    pub fn options(mut self, options: LinkInfoData) -> Self {
+        self.options = Some(options);
+        self
+    }
+
+    /// Set the name of the parent link, used when the link is created
+    pub fn parent(mut self, parent: String) -> Self {
+        self.parent = Some(parent);
+        self
+    }
+
+    pub fn mtu(mut self, mtu: u32) -> Self {
+        self.mtu = Some(mtu);
+        self
+    }
+
+    pub fn up(mut self) -> Self {
+        self.up = Some(true);
+        self
+    }
+
+    pub fn down(mut self) -> Self {
+        self.up = Some(false);
+        self
+    }
+
+    /// Set the name of the master of the link
+    pub fn master(mut self, master: String) -> Self {
+        self.master = Some(master);
+        self
+    }
+}
+
+/// An address of the desired state
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde_support", derive(Serialize, Deserialize))]
+pub struct DesiredAddress {
+    /// Name of the link of the address
+    pub link: String,
+    pub address: IpAddr,
+    pub prefix_len: u8,
+}
+
+impl DesiredAddress {
+    pub fn new(link: String, address: IpAddr, prefix_len: u8) -> Self {
+        DesiredAddress {
+            link,
+            address,
+            prefix_len,
+        }
+    }
+}
+
+/// Formats the address like `ip address`: `<address>/<prefix_len> dev <link>`
+impl fmt::Display for DesiredAddress {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}/{} dev {}", self.address, self.prefix_len, self.link)
+    }
+}
+
+/// A unicast route of the desired state
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde_support", derive(Serialize, Deserialize))]
+pub struct DesiredRoute {
+    pub destination: IpAddr,
+    pub prefix_len: u8,
+    pub gateway: Option<IpAddr>,
+    /// Name of the output link of the route
+    pub link: Option<String>,
+    #[cfg_attr(feature = "serde_support", serde(default = "main_table"))]
+    pub table: u32,
+    /// Priority of the route. If it is `None`, routes of any priority match, and new routes get
+    /// the default priority.
+    pub metric: Option<u32>,
+}
+
+impl DesiredRoute {
+    /// A route to `destination/prefix_len`, in the main routing table
+    pub fn new(destination: IpAddr, prefix_len: u8) -> Self {
+        DesiredRoute {
+            destination,
+            prefix_len,
+            gateway: None,
+            link: None,
+            table: u32::from(RT_TABLE_MAIN),
+            metric: None,
+        }
+    }
+
+    pub fn gateway(mut self, gateway: IpAddr) -> Self {
+        self.gateway = Some(gateway);
+        self
+    }
+
+    /// Set the name of the output link of the route
+    pub fn link(mut self, link: String) -> Self {
+        self.link = Some(link);
+        self
+    }
+
+    pub fn table(mut self, table: u32) -> Self {
+        self.table = table;
+        self
+    }
+
+    pub fn metric(mut self, metric: u32) -> Self {
+        self.metric = Some(metric);
+        self
+    }
+
+    /// Whether `route` is a route of the kernel that matches this route
+    fn matches(&self, route: &DesiredRoute) -> bool {
+        self.destination == route.destination
+            && self.prefix_len == route.prefix_len
+            && self.gateway == route.gateway
+            && self.table == route.table
+            && (self.link.is_none() || self.link == route.link)
+            && (self.metric.is_none() || self.metric == route.metric)
+    }
+}
+
+/// Formats the route like `ip route`: `<destination>/<prefix_len> [via <gateway>] [dev <link>]
+/// [table <table>] [metric <metric>]`. The main table is omitted.
+impl fmt::Display for DesiredRoute {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}/{}", self.destination, self.prefix_len)?;
+        if let Some(gateway) = self.gateway {
+            write!(f, " via {}", gateway)?;
+        }
+        if let Some(ref link) = self.link {
+            write!(f, " dev {}", link)?;
+        }
+        if self.table != u32::from(RT_TABLE_MAIN) {
+            write!(f, " table {}", self.table)?;
+        }
+        if let Some(metric) = self.metric {
+            write!(f, " metric {}", metric)?;
+        }
+        Ok(())
+    }
+}
+
+/// A routing rule of the desired state, that looks a routing table up. The rules that match
+/// packets in other ways, or that have another action, are not supported.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde_support", derive(Serialize, Deserialize))]
+pub struct DesiredRule {
+    /// Priority of the rule. Rules are evaluated by increasing priority.
+    pub priority: u32,
+    /// Address family of the rule. It is `AF_INET` by default, and set by
+    /// [`source()`](#method.source) and [`destination()`](#method.destination).
+    #[cfg_attr(feature = "serde_support", serde(default = "ipv4_family"))]
+    pub family: u8,
+    /// Source prefix matched by the rule, with its length
+    pub source: Option<(IpAddr, u8)>,
+    /// Destination prefix matched by the rule, with its length
+    pub destination: Option<(IpAddr, u8)>,
+    /// Name of the input link matched by the rule
+    pub input_interface: Option<String>,
+    /// Name of the output link matched by the rule
+    pub output_interface: Option<String>,
+    pub fwmark: Option<u32>,
+    /// Routing table looked up by the rule. If it is `None`, this is an `l3mdev` rule, that looks
+    /// the table of the VRF device the packets go through up.
+    pub table: Option<u32>,
+}
+
+impl DesiredRule {
+    /// An IPv4 rule that looks `table` up for all the packets
+    pub fn new(priority: u32, table: u32) -> Self {
+        DesiredRule {
+            priority,
+            family: AF_INET as u8,
+            source: None,
+            destination: None,
+            input_interface: None,
+            output_interface: None,
+            fwmark: None,
+            table: Some(table),
+        }
+    }
+
+    /// An IPv4 rule that looks the table of the VRF devices up (`ip rule add l3mdev`)
+    pub fn l3mdev(priority: u32) -> Self {
+        DesiredRule {
+            table: None,
+            ..DesiredRule::new(priority, 0)
+        }
+    }
+
+    /// Set the address family of the rule, for rules that match neither a source nor a
+    /// destination prefix
+    pub fn family(mut self, family: u8) -> Self {
+        self.family = family;
+        self
+    }
+
+    pub fn source(mut self, address: IpAddr, prefix_len: u8) -> Self {
+        self.family = ip_family(address);
+        self.source = Some((address, prefix_len));
+        self
+    }
+
+    pub fn destination(mut self, address: IpAddr, prefix_len: u8) -> Self {
+        self.family = ip_family(address);
+        self.destination = Some((address, prefix_len));
+        self
+    }
+
+    /// Set the name of the input link matched by the rule
+    pub fn input_interface(mut self, name: String) -> Self {
+        self.input_interface = Some(name);
+        self
+    }
+
+    /// Set the name of the output link matched by the rule
+    pub fn output_interface(mut self, name: String) -> Self {
+        self.output_interface = Some(name);
+        self
+    }
+
+    pub fn fwmark(mut self, mark: u32) -> Self {
+        self.fwmark = Some(mark);
+        self
+    }
+}
+
+/// Formats the rule like `ip rule`: `from { <prefix> | all } [to <prefix>] [iif <link>]
+/// [oif <link>] [fwmark <mark>] { lookup <table> | l3mdev } priority <priority>`
+impl fmt::Display for DesiredRule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.source {
+            Some((address, prefix_len)) => write!(f, "from {}/{}", address, prefix_len)?,
+            None => write!(f, "from all")?,
+        }
+        if let Some((address, prefix_len)) = self.destination {
+            write!(f, " to {}/{}", address, prefix_len)?;
+        }
+        if let Some(ref link) = self.input_interface {
+            write!(f, " iif {}", link)?;
+        }
+        if let Some(ref link) = self.output_interface {
+            write!(f, " oif {}", link)?;
+        }
+        if let Some(mark) = self.fwmark {
+            write!(f, " fwmark {:#x}", mark)?;
+        }
+        match self.table {
+            Some(table) => write!(f, " lookup {}", table)?,
+            None => write!(f, " l3mdev")?,
+        }
+        write!(f, " priority {}", self.priority)
+    }
+}
+
+fn ip_family(address: IpAddr) -> u8 {
+    match address {
+        IpAddr::V4(_) => AF_INET as u8,
+        IpAddr::V6(_) => AF_INET6 as u8,
+    }
+}
+
+#[cfg(feature = "serde_support")]
+fn main_table() -> u32 {
+    u32::from(RT_TABLE_MAIN)
+}
+
+#[cfg(feature = "serde_support")]
+fn ipv4_family() -> u8 {
+    AF_INET as u8
+}
+
+#[cfg(all(test, feature = "serde_support"))]
+mod test {
+    use super::*;
+    use rtnetlink::LinkInfoVlan;
+    use serde_json;
+
+    #[test]
+    fn serde_round_trip() {
+        let state = DesiredState::new()
+            .link(
+                DesiredLink::new("vlan10".into(), LinkInfoKind::Vlan)
+                    .parent("eth0".into())
+                    .options(LinkInfoData::Vlan(vec![LinkInfoVlan::Id(10)]))
+                    .up(),
+            )
+            .address(DesiredAddress::new(
+                "vlan10".into(),
+                "10.0.0.1".parse().unwrap(),
+                24,
+            ))
+            .route(DesiredRoute::new("10.1.0.0".parse().unwrap(), 16).table(100))
+            .rule(DesiredRule::new(1000, 100).input_interface("vlan10".into()));
+        let json = serde_json::to_string(&state).unwrap();
+        let parsed: DesiredState = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, state);
+    }
+
+    #[test]
+    fn deserialize_defaults() {
+        let json = r#"{
+            "routes": [{"destination": "10.1.0.0", "prefix_len": 16}],
+            "rules": [{"priority": 1000, "table": 100}]
+        }"#;
+        let state: DesiredState = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            state,
+            DesiredState::new()
+                .route(DesiredRoute::new("10.1.0.0".parse().unwrap(), 16))
+                .rule(DesiredRule::new(1000, 100))
+        );
+    }
+}
@@ -0,0 +1,682 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use rtnetlink::constants::{AF_INET, AF_INET6, FIB_RULE_INVERT, FR_ACT_TO_TBL, RT_SCOPE_UNIVERSE};
+use rtnetlink::{
+    AddressMessage, AddressNla, RouteMessage, RouteNla, RouteProtocol, RuleMessage, RuleNla,
+};
+
+use super::{DesiredAddress, DesiredLink, DesiredRoute, DesiredRule, DesiredState};
+use errors::NetlinkIpError;
+use link::Link;
+use route::route_table;
+use rule::rule_table;
+
+/// A change made to bring the network to a desired state
+#[derive(Clone, Debug, PartialEq)]
+pub enum Change {
+    /// Delete a link, in order to create it again with another kind or parent
+    LinkDel(String),
+    /// Create a link, with its kind, options, parent and MTU
+    LinkAdd(DesiredLink),
+    /// Change the MTU, state or master of a link
+    LinkSet {
+        name: String,
+        mtu: Option<u32>,
+        up: Option<bool>,
+        master: Option<String>,
+    },
+    AddressDel(DesiredAddress),
+    AddressAdd(DesiredAddress),
+    /// Delete a route. The message is the route, as dumped by the kernel.
+    RouteDel(DesiredRoute, RouteMessage),
+    RouteAdd(DesiredRoute),
+    /// Delete a rule. The message is the rule, as dumped by the kernel.
+    RuleDel(DesiredRule, RuleMessage),
+    RuleAdd(DesiredRule),
+}
+
+/// Formats the change as the equivalent `ip` command
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Change::LinkDel(ref name) => write!(f, "ip link del {}", name),
+            Change::LinkAdd(ref link) => {
+                write!(f, "ip link add ")?;
+                if let Some(ref parent) = link.parent {
+                    write!(f, "link {} ", parent)?;
+                }
+                write!(f, "{}", link.name)?;
+                if let Some(ref kind) = link.kind {
+                    write!(f, " type {}", kind.as_str())?;
+                }
+                if let Some(mtu) = link.mtu {
+                    write!(f, " mtu {}", mtu)?;
+                }
+                Ok(())
+            }
+            Change::LinkSet {
+                ref name,
+                mtu,
+                up,
+                ref master,
+            } => {
+                write!(f, "ip link set {}", name)?;
+                if let Some(mtu) = mtu {
+                    write!(f, " mtu {}", mtu)?;
+                }
+                if let Some(ref master) = *master {
+                    write!(f, " master {}", master)?;
+                }
+                match up {
+                    Some(true) => write!(f, " up"),
+                    Some(false) => write!(f, " down"),
+                    None => Ok(()),
+                }
+            }
+            Change::AddressDel(ref address) => write!(f, "ip address del {}", address),
+            Change::AddressAdd(ref address) => write!(f, "ip address add {}", address),
+            Change::RouteDel(ref route, _) => write!(f, "ip route del {}", route),
+            Change::RouteAdd(ref route) => write!(f, "ip route add {}", route),
+            Change::RuleDel(ref rule, _) => write!(f, "{} del {}", ip_rule(rule), rule),
+            Change::RuleAdd(ref rule) => write!(f, "{} add {}", ip_rule(rule), rule),
+        }
+    }
+}
+
+/// The changes that bring the network to a desired state, in the order they are made. It is
+/// displayed as the equivalent `ip` commands, one per line.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Plan {
+    changes: Vec<Change>,
+}
+
+impl Plan {
+    pub fn changes(&self) -> &[Change] {
+        &self.changes
+    }
+
+    /// Whether the network is already in the desired state
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    pub(crate) fn into_changes(self) -> Vec<Change> {
+        self.changes
+    }
+}
+
+impl fmt::Display for Plan {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for change in &self.changes {
+            writeln!(f, "{}", change)?;
+        }
+        Ok(())
+    }
+}
+
+// IPv6 rules without prefixes need `ip -6`
+fn ip_rule(rule: &DesiredRule) -> &'static str {
+    if u16::from(rule.family) == AF_INET6 {
+        "ip -6 rule"
+    } else {
+        "ip rule"
+    }
+}
+
+/// Compute the changes that bring the given links, addresses, routes and rules to `state`. The
+/// routes and rules of the given protocol are the ones that are managed.
+pub(crate) fn compute_plan(
+    state: &DesiredState,
+    links: &[Link],
+    addresses: &[AddressMessage],
+    routes: &[RouteMessage],
+    rules: &[RuleMessage],
+    protocol: RouteProtocol,
+) -> Result<Plan, NetlinkIpError> {
+    let links_by_name: HashMap<&str, &Link> = links
+        .iter()
+        .filter_map(|link| link.name().map(|name| (name, link)))
+        .collect();
+    let names: HashMap<u32, &str> = links
+        .iter()
+        .filter_map(|link| link.name().map(|name| (link.index(), name)))
+        .collect();
+
+    let mut changes = vec![];
+
+    // Find the links that must be created. The addresses and routes of the links that are created
+    // again are lost, and the links they were enslaved to get a new index.
+    let mut existing = HashMap::new();
+    let mut created = HashSet::new();
+    let mut deleted = HashSet::new();
+    for desired in &state.links {
+        let parent = desired.parent.as_deref();
+        let parent_changed = |link: &Link| match parent {
+            Some(parent) => {
+                created.contains(parent)
+                    || link.parent_index() != links_by_name.get(parent).map(|p| p.index())
+            }
+            None => false,
+        };
+        match (
+            links_by_name.get(desired.name.as_str()),
+            desired.kind.as_ref(),
+        ) {
+            (None, None) => return Err(NetlinkIpError::LinkNotFound),
+            (None, Some(_)) => {}
+            // The kernel deletes the link with its parent
+            (Some(_), Some(_)) if parent.map_or(false, |parent| deleted.contains(parent)) => {
+                deleted.insert(desired.name.as_str());
+            }
+            (Some(link), Some(kind)) if link.kind() != Some(kind) || parent_changed(link) => {
+                changes.push(Change::LinkDel(desired.name.clone()));
+                deleted.insert(desired.name.as_str());
+            }
+            (Some(link), _) => {
+                existing.insert(desired.name.as_str(), *link);
+                continue;
+            }
+        }
+        created.insert(desired.name.as_str());
+    }
+
+    // Create the links, then set the state and master of all the links, since masters may be
+    // links that are created
+    let mut link_changes = vec![];
+    for desired in &state.links {
+        let (mtu, up, master) = match existing.get(desired.name.as_str()) {
+            None => {
+                changes.push(Change::LinkAdd(desired.clone()));
+                (None, desired.up, desired.master.clone())
+            }
+            Some(link) => {
+                let master_changed = |master: &String| {
+                    created.contains(master.as_str())
+                        || link.master_index()
+                            != links_by_name.get(master.as_str()).map(|m| m.index())
+                };
+                (
+                    desired.mtu.filter(|mtu| link.mtu() != Some(*mtu)),
+                    desired.up.filter(|up| link.flags().is_up() != *up),
+                    desired.master.clone().filter(master_changed),
+                )
+            }
+        };
+        if mtu.is_some() || up.is_some() || master.is_some() {
+            link_changes.push(Change::LinkSet {
+                name: desired.name.clone(),
+                mtu,
+                up,
+                master,
+            });
+        }
+    }
+    changes.extend(link_changes);
+
+    // The addresses of the links of the state are exactly the desired ones, except the addresses
+    // that are not of universe scope, like IPv6 link-local addresses
+    let managed: HashSet<&str> = state.links.iter().map(|link| link.name.as_str()).collect();
+    let live_addresses: Vec<DesiredAddress> = addresses
+        .iter()
+        .filter(|address| address.header.scope == RT_SCOPE_UNIVERSE)
+        .filter_map(|address| {
+            let link = names.get(&address.header.index)?;
+            Some(DesiredAddress::new(
+                link.to_string(),
+                address_ip(address)?,
+                address.header.prefix_len,
+            ))
+        })
+        .filter(|address| !created.contains(address.link.as_str()))
+        .collect();
+    for address in &live_addresses {
+        if managed.contains(address.link.as_str()) && !state.addresses.contains(address) {
+            changes.push(Change::AddressDel(address.clone()));
+        }
+    }
+    for address in &state.addresses {
+        if !live_addresses.contains(address) {
+            changes.push(Change::AddressAdd(address.clone()));
+        }
+    }
+
+    // The routes of the managed protocol are exactly the desired ones
+    let mut live_routes: Vec<(DesiredRoute, &RouteMessage)> = routes
+        .iter()
+        .filter(|route| route.header.protocol == protocol)
+        .filter_map(|route| Some((desired_route(route, &names)?, route)))
+        .filter(|(route, _)| match route.link {
+            Some(ref link) => !created.contains(link.as_str()),
+            None => true,
+        })
+        .collect();
+    let mut route_additions = vec![];
+    for desired in &state.routes {
+        match live_routes
+            .iter()
+            .position(|(route, _)| desired.matches(route))
+        {
+            Some(position) => {
+                live_routes.remove(position);
+            }
+            None => route_additions.push(Change::RouteAdd(desired.clone())),
+        }
+    }
+    for (route, message) in live_routes {
+        changes.push(Change::RouteDel(route, message.clone()));
+    }
+    changes.extend(route_additions);
+
+    // The rules of the managed protocol are exactly the desired ones
+    let protocol = u8::from(protocol);
+    let mut live_rules: Vec<(DesiredRule, &RuleMessage)> = rules
+        .iter()
+        .filter(|rule| rule_protocol(rule) == protocol)
+        .filter_map(|rule| Some((desired_rule(rule)?, rule)))
+        .collect();
+    let mut rule_additions = vec![];
+    for desired in &state.rules {
+        match live_rules.iter().position(|(rule, _)| rule == desired) {
+            Some(position) => {
+                live_rules.remove(position);
+            }
+            None => rule_additions.push(Change::RuleAdd(desired.clone())),
+        }
+    }
+    for (rule, message) in live_rules {
+        changes.push(Change::RuleDel(rule, message.clone()));
+    }
+    changes.extend(rule_additions);
+
+    Ok(Plan { changes })
+}
+
+/// Return the address of an address message. For IPv4, this is the local address: the other one
+/// is the address of the peer on point-to-point links.
+fn address_ip(address: &AddressMessage) -> Option<IpAddr> {
+    let mut ip = None;
+    for nla in &address.nlas {
+        match *nla {
+            AddressNla::Local(ref bytes) => return parse_ip(bytes),
+            AddressNla::Address(ref bytes) => ip = parse_ip(bytes),
+            _ => {}
+        }
+    }
+    ip
+}
+
+/// Convert a route of the kernel into a `DesiredRoute`, with the name of its output link
+fn desired_route(route: &RouteMessage, names: &HashMap<u32, &str>) -> Option<DesiredRoute> {
+    // Default routes don't have a destination
+    let mut destination = if u16::from(route.header.address_family) == AF_INET {
+        IpAddr::V4(Ipv4Addr::UNSPECIFIED)
+    } else {
+        IpAddr::V6(Ipv6Addr::UNSPECIFIED)
+    };
+    let mut gateway = None;
+    let mut link = None;
+    // The kernel omits the priority of IPv4 routes when it is 0
+    let mut metric = 0;
+    for nla in &route.nlas {
+        match *nla {
+            RouteNla::Destination(ref bytes) => destination = parse_ip(bytes)?,
+            RouteNla::Gateway(ref bytes) => gateway = Some(parse_ip(bytes)?),
+            RouteNla::Oif(index) => link = names.get(&index).map(|name| name.to_string()),
+            RouteNla::Priority(priority) => metric = priority,
+            _ => {}
+        }
+    }
+    Some(DesiredRoute {
+        destination,
+        prefix_len: route.header.destination_length,
+        gateway,
+        link,
+        table: route_table(route),
+        metric: Some(metric),
+    })
+}
+
+fn rule_protocol(rule: &RuleMessage) -> u8 {
+    rule.nlas
+        .iter()
+        .find_map(|nla| match *nla {
+            RuleNla::Protocol(protocol) => Some(protocol),
+            _ => None,
+        })
+        .unwrap_or(0)
+}
+
+/// Convert a rule of the kernel into a `DesiredRule`. This fails for the rules that cannot be
+/// represented, like rules that don't look a table up.
+fn desired_rule(rule: &RuleMessage) -> Option<DesiredRule> {
+    let header = &rule.header;
+    if header.action != FR_ACT_TO_TBL || header.tos != 0 || header.flags & FIB_RULE_INVERT != 0 {
+        return None;
+    }
+    let mut desired = DesiredRule::new(0, rule_table(rule)).family(header.family);
+    for nla in &rule.nlas {
+        match *nla {
+            RuleNla::Priority(priority) => desired.priority = priority,
+            RuleNla::Source(ref bytes) => desired.source = Some((parse_ip(bytes)?, header.src_len)),
+            RuleNla::Destination(ref bytes) => {
+                desired.destination = Some((parse_ip(bytes)?, header.dst_len))
+            }
+            RuleNla::IifName(ref name) => desired.input_interface = Some(name.clone()),
+            RuleNla::OifName(ref name) => desired.output_interface = Some(name.clone()),
+            RuleNla::FwMark(mark) => desired.fwmark = Some(mark),
+            RuleNla::L3MDev(l3mdev) if l3mdev != 0 => desired.table = None,
+            _ => {}
+        }
+    }
+    Some(desired)
+}
+
+fn parse_ip(bytes: &[u8]) -> Option<IpAddr> {
+    if bytes.len() == 4 {
+        let mut octets = [0; 4];
+        octets.copy_from_slice(bytes);
+        Some(IpAddr::V4(Ipv4Addr::from(octets)))
+    } else if bytes.len() == 16 {
+        let mut octets = [0; 16];
+        octets.copy_from_slice(bytes);
+        Some(IpAddr::V6(Ipv6Addr::from(octets)))
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rtnetlink::constants::{
+        AF_INET6, FR_ACT_TO_TBL, RT_SCOPE_HOST, RT_SCOPE_LINK, RT_SCOPE_UNIVERSE,
+    };
+    use rtnetlink::{LinkInfo, LinkInfoKind, LinkNla, RouteKind, RouteScope, RouteTable};
+
+    fn link(index: u32, name: &str, kind: Option<LinkInfoKind>, parent: Option<u32>) -> Link {
+        let mut link = Link::new();
+        link.set_index(index).set_name(name.into()).set_mtu(1500);
+        if let Some(kind) = kind {
+            link.add_attribute(LinkNla::LinkInfo(vec![LinkInfo::Kind(kind)]));
+        }
+        if let Some(parent) = parent {
+            link.set_parent_index(parent);
+        }
+        link
+    }
+
+    fn ip_bytes(ip: IpAddr) -> Vec<u8> {
+        match ip {
+            IpAddr::V4(ip) => ip.octets().to_vec(),
+            IpAddr::V6(ip) => ip.octets().to_vec(),
+        }
+    }
+
+    fn address(index: u32, ip: &str, prefix_len: u8, scope: u8) -> AddressMessage {
+        let ip: IpAddr = ip.parse().unwrap();
+        let mut message = AddressMessage::default();
+        message.header.family = if ip.is_ipv4() { AF_INET } else { AF_INET6 } as u8;
+        message.header.prefix_len = prefix_len;
+        message.header.scope = scope;
+        message.header.index = index;
+        message.nlas.push(AddressNla::Address(ip_bytes(ip)));
+        message
+    }
+
+    fn route(
+        destination: &str,
+        prefix_len: u8,
+        oif: Option<u32>,
+        protocol: RouteProtocol,
+    ) -> RouteMessage {
+        let destination: IpAddr = destination.parse().unwrap();
+        let mut message = RouteMessage::default();
+        message.header.address_family = AF_INET as u8;
+        message.header.destination_length = prefix_len;
+        message.header.table = RouteTable::Main;
+        message.header.protocol = protocol;
+        message.header.scope = RouteScope::Universe;
+        message.header.kind = RouteKind::Unicast;
+        message
+            .nlas
+            .push(RouteNla::Destination(ip_bytes(destination)));
+        if let Some(oif) = oif {
+            message.nlas.push(RouteNla::Oif(oif));
+        }
+        message
+    }
+
+    fn rule(priority: u32, table: Option<u32>, protocol: RouteProtocol) -> RuleMessage {
+        let mut message = RuleMessage::default();
+        message.header.family = AF_INET as u8;
+        message.header.action = FR_ACT_TO_TBL;
+        message.nlas.push(RuleNla::Priority(priority));
+        match table {
+            Some(table) => message.nlas.push(RuleNla::Table(table)),
+            None => message.nlas.push(RuleNla::L3MDev(1)),
+        }
+        message.nlas.push(RuleNla::Protocol(u8::from(protocol)));
+        message
+    }
+
+    fn ip(ip: &str) -> IpAddr {
+        ip.parse().unwrap()
+    }
+
+    struct Case {
+        name: &'static str,
+        state: DesiredState,
+        links: Vec<Link>,
+        addresses: Vec<AddressMessage>,
+        routes: Vec<RouteMessage>,
+        rules: Vec<RuleMessage>,
+        changes: Vec<Change>,
+    }
+
+    impl Default for Case {
+        fn default() -> Self {
+            Case {
+                name: "",
+                state: DesiredState::new(),
+                links: vec![link(1, "lo", None, None), link(2, "eth0", None, None)],
+                addresses: vec![],
+                routes: vec![],
+                rules: vec![],
+                changes: vec![],
+            }
+        }
+    }
+
+    #[test]
+    fn compute_plan_cases() {
+        let bridge = DesiredLink::new("br0".into(), LinkInfoKind::Bridge).up();
+        let bridge_address = DesiredAddress::new("br0".into(), ip("10.0.0.1"), 24);
+        let vlan = DesiredLink::new("vlan10".into(), LinkInfoKind::Vlan).parent("eth0".into());
+        let macvlan = DesiredLink::new("mv0".into(), LinkInfoKind::MacVlan).parent("br0".into());
+        let eth0 = DesiredLink::existing("eth0".into());
+        let eth0_address = DesiredAddress::new("eth0".into(), ip("10.0.0.1"), 24);
+        let stale_route = route("10.2.0.0", 16, None, RouteProtocol::Static);
+
+        let cases = vec![
+            Case {
+                name: "create",
+                state: DesiredState::new()
+                    .link(bridge.clone())
+                    .address(bridge_address.clone()),
+                changes: vec![
+                    Change::LinkAdd(bridge.clone()),
+                    Change::LinkSet {
+                        name: "br0".into(),
+                        mtu: None,
+                        up: Some(true),
+                        master: None,
+                    },
+                    Change::AddressAdd(bridge_address.clone()),
+                ],
+                ..Default::default()
+            },
+            Case {
+                name: "nothing to do",
+                state: DesiredState::new()
+                    .link(eth0.clone().mtu(1500))
+                    .address(eth0_address.clone()),
+                addresses: vec![address(2, "10.0.0.1", 24, RT_SCOPE_UNIVERSE)],
+                ..Default::default()
+            },
+            Case {
+                name: "recreate on kind mismatch",
+                state: DesiredState::new()
+                    .link(bridge.clone())
+                    .address(bridge_address.clone()),
+                links: vec![link(3, "br0", Some(LinkInfoKind::Vrf), None)],
+                // The address and the route of the link are lost with it: they are neither
+                // removed nor kept
+                addresses: vec![address(3, "10.0.0.1", 24, RT_SCOPE_UNIVERSE)],
+                routes: vec![route("10.1.0.0", 16, Some(3), RouteProtocol::Static)],
+                changes: vec![
+                    Change::LinkDel("br0".into()),
+                    Change::LinkAdd(bridge.clone()),
+                    Change::LinkSet {
+                        name: "br0".into(),
+                        mtu: None,
+                        up: Some(true),
+                        master: None,
+                    },
+                    Change::AddressAdd(bridge_address.clone()),
+                ],
+                ..Default::default()
+            },
+            Case {
+                name: "recreate on parent mismatch",
+                state: DesiredState::new().link(vlan.clone()),
+                links: vec![
+                    link(2, "eth0", None, None),
+                    link(3, "eth1", None, None),
+                    link(4, "vlan10", Some(LinkInfoKind::Vlan), Some(3)),
+                ],
+                changes: vec![
+                    Change::LinkDel("vlan10".into()),
+                    Change::LinkAdd(vlan.clone()),
+                ],
+                ..Default::default()
+            },
+            Case {
+                name: "links are deleted with their parent",
+                state: DesiredState::new()
+                    .link(bridge.clone())
+                    .link(macvlan.clone()),
+                links: vec![
+                    link(3, "br0", Some(LinkInfoKind::Vrf), None),
+                    link(4, "mv0", Some(LinkInfoKind::MacVlan), Some(3)),
+                ],
+                changes: vec![
+                    Change::LinkDel("br0".into()),
+                    Change::LinkAdd(bridge.clone()),
+                    Change::LinkAdd(macvlan.clone()),
+                    Change::LinkSet {
+                        name: "br0".into(),
+                        mtu: None,
+                        up: Some(true),
+                        master: None,
+                    },
+                ],
+                ..Default::default()
+            },
+            Case {
+                name: "remove addresses",
+                state: DesiredState::new()
+                    .link(eth0.clone())
+                    .address(eth0_address.clone()),
+                links: vec![link(2, "eth0", None, None), link(3, "eth1", None, None)],
+                addresses: vec![
+                    address(2, "10.0.0.1", 24, RT_SCOPE_UNIVERSE),
+                    address(2, "10.0.0.2", 24, RT_SCOPE_UNIVERSE),
+                    // eth1 is not part of the state
+                    address(3, "10.9.0.1", 24, RT_SCOPE_UNIVERSE),
+                ],
+                changes: vec![Change::AddressDel(DesiredAddress::new(
+                    "eth0".into(),
+                    ip("10.0.0.2"),
+                    24,
+                ))],
+                ..Default::default()
+            },
+            Case {
+                name: "skip non-universe scope",
+                state: DesiredState::new().link(eth0.clone()),
+                addresses: vec![
+                    address(2, "fe80::1", 64, RT_SCOPE_LINK),
+                    address(2, "127.0.0.2", 8, RT_SCOPE_HOST),
+                    address(2, "2001:db8::1", 64, RT_SCOPE_UNIVERSE),
+                ],
+                changes: vec![Change::AddressDel(DesiredAddress::new(
+                    "eth0".into(),
+                    ip("2001:db8::1"),
+                    64,
+                ))],
+                ..Default::default()
+            },
+            Case {
+                name: "route protocol filtering",
+                state: DesiredState::new()
+                    .route(DesiredRoute::new(ip("10.1.0.0"), 16).link("eth0".into()))
+                    .route(DesiredRoute::new(ip("10.5.0.0"), 16)),
+                routes: vec![
+                    route("10.1.0.0", 16, Some(2), RouteProtocol::Static),
+                    stale_route.clone(),
+                    route("10.3.0.0", 16, None, RouteProtocol::Boot),
+                    route("10.4.0.0", 16, Some(2), RouteProtocol::Kernel),
+                ],
+                changes: vec![
+                    Change::RouteDel(DesiredRoute::new(ip("10.2.0.0"), 16).metric(0), stale_route),
+                    Change::RouteAdd(DesiredRoute::new(ip("10.5.0.0"), 16)),
+                ],
+                ..Default::default()
+            },
+            Case {
+                name: "rule protocol filtering",
+                state: DesiredState::new()
+                    .rule(DesiredRule::new(100, 100))
+                    .rule(DesiredRule::l3mdev(150)),
+                rules: vec![
+                    rule(0, Some(255), RouteProtocol::Kernel),
+                    rule(100, Some(100), RouteProtocol::Static),
+                    rule(200, Some(200), RouteProtocol::Static),
+                    rule(300, Some(300), RouteProtocol::Boot),
+                ],
+                changes: vec![
+                    Change::RuleDel(
+                        DesiredRule::new(200, 200),
+                        rule(200, Some(200), RouteProtocol::Static),
+                    ),
+                    Change::RuleAdd(DesiredRule::l3mdev(150)),
+                ],
+                ..Default::default()
+            },
+        ];
+
+        for case in cases {
+            let plan = compute_plan(
+                &case.state,
+                &case.links,
+                &case.addresses,
+                &case.routes,
+                &case.rules,
+                RouteProtocol::Static,
+            )
+            .unwrap();
+            assert_eq!(plan.changes(), &case.changes[..], "{}", case.name);
+        }
+    }
+
+    #[test]
+    fn missing_link_without_kind() {
+        let state = DesiredState::new().link(DesiredLink::existing("eth9".into()));
+        match compute_plan(&state, &[], &[], &[], &[], RouteProtocol::Static) {
+            Err(NetlinkIpError::LinkNotFound) => {}
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,229 @@
+use futures::future::{self, Either, Loop};
+use futures::Future;
+
+use rtnetlink::{LinkNla, RouteProtocol};
+
+use super::{compute_plan, Change, DesiredState, Plan};
+use address::{AddressAddRequest, AddressDelRequest, AddressGetRequest};
+use connection::ConnectionHandle;
+use errors::NetlinkIpError;
+use link::{LinkAddRequest, LinkDelRequest, LinkGetRequest, LinkResolver, LinkSetRequest};
+use route::{RouteAddRequest, RouteDelRequest, RouteGetRequest};
+use rule::{RuleAddRequest, RuleDelRequest, RuleGetRequest};
+
+/// A request to bring the network to a desired state, created with
+/// [`ConnectionHandle::reconcile()`](struct.ConnectionHandle.html#method.reconcile).
+///
+/// The links, addresses, routes and rules are dumped and compared to the desired state, which
+/// gives a [`Plan`](struct.Plan.html): the changes to make. Only the objects the state manages
+/// are changed:
+///
+/// - the links of the state are created if they don't exist, and created again if their kind or
+///   parent is not the desired one. Then their MTU, state and master are changed if needed. The
+///   other links are left alone.
+/// - the addresses of the links of the state are exactly the desired ones: the other addresses of
+///   these links are removed, except the addresses that are not of universe scope, like IPv6
+///   link-local addresses.
+/// - the routes of the managed protocol ([`protocol()`](#method.protocol), `static` by default)
+///   are exactly the desired ones. New routes get this protocol, and the routes of other
+///   protocols are left alone.
+/// - likewise, the routing rules of the managed protocol are exactly the desired ones. The rules
+///   that don't look a routing table up are left alone.
+pub struct ReconcileRequest {
+    handle: ConnectionHandle,
+    state: DesiredState,
+    protocol: RouteProtocol,
+}
+
+impl ReconcileRequest {
+    pub(crate) fn new(handle: ConnectionHandle, state: DesiredState) -> Self {
+        ReconcileRequest {
+            handle,
+            state,
+            protocol: RouteProtocol::Static,
+        }
+    }
+
+    /// Set the protocol of the managed routes and rules
+    pub fn protocol(mut self, protocol: RouteProtocol) -> Self {
+        self.protocol = protocol;
+        self
+    }
+
+    /// Compute the changes to make, without making them (dry run). If a link of the state has no
+    /// kind and does not exist, the future fails with
+    /// [`NetlinkIpError::LinkNotFound`](enum.NetlinkIpError.html#variant.LinkNotFound).
+    pub fn plan(self) -> impl Future<Item = Plan, Error = NetlinkIpError> {
+        let ReconcileRequest {
+            handle,
+            state,
+            protocol,
+        } = self;
+        // The kernel only runs one dump at a time per socket, so the dumps are made one after
+        // the other
+        let addresses = AddressGetRequest::new(handle.clone());
+        let routes = RouteGetRequest::new(handle.clone());
+        let rules = RuleGetRequest::new(handle.clone());
+        LinkGetRequest::new(handle)
+            .execute()
+            .and_then(move |links| addresses.execute().map(move |addresses| (links, addresses)))
+            .and_then(move |(links, addresses)| {
+                routes
+                    .execute()
+                    .map(move |routes| (links, addresses, routes))
+            })
+            .and_then(move |(links, addresses, routes)| {
+                rules
+                    .execute()
+                    .map(move |rules| (links, addresses, routes, rules))
+            })
+            .and_then(move |(links, addresses, routes, rules)| {
+                compute_plan(&state, &links, &addresses, &routes, &rules, protocol)
+            })
+    }
+
+    /// Compute the changes to make, and make them one after the other. The future returns the
+    /// changes that were made. If a change fails, the following ones are not made, and the
+    /// changes already made are not undone.
+    pub fn execute(self) -> impl Future<Item = Plan, Error = NetlinkIpError> {
+        let handle = self.handle.clone();
+        let protocol = self.protocol;
+        self.plan().and_then(move |plan| {
+            let changes = plan.clone().into_changes().into_iter();
+            future::loop_fn(changes, move |mut changes| match changes.next() {
+                Some(change) => Either::A(
+                    apply_change(handle.clone(), protocol, change)
+                        .map(move |()| Loop::Continue(changes)),
+                ),
+                None => Either::B(future::ok(Loop::Break(()))),
+            })
+            .map(move |()| plan)
+        })
+    }
+}
+
+fn apply_change(
+    handle: ConnectionHandle,
+    protocol: RouteProtocol,
+    change: Change,
+) -> Box<dyn Future<Item = (), Error = NetlinkIpError>> {
+    debug!("reconcile: {}", change);
+    let resolver = LinkResolver::new(handle.clone());
+    match change {
+        Change::LinkDel(name) => Box::new(
+            resolver
+                .resolve(&name)
+                .and_then(move |index| LinkDelRequest::new(handle, index).execute()),
+        ),
+        Change::LinkAdd(link) => {
+            let parent = match link.parent {
+                Some(ref parent) => Either::A(resolver.resolve(parent).map(Some)),
+                None => Either::B(future::ok(None)),
+            };
+            Box::new(parent.and_then(move |parent| {
+                let mut request = LinkAddRequest::new(handle).name(link.name);
+                if let Some(kind) = link.kind {
+                    request = request.link_info(kind, link.options);
+                }
+                if let Some(parent) = parent {
+                    request.message_mut().append_nla(LinkNla::Link(parent));
+                }
+                if let Some(mtu) = link.mtu {
+                    request.message_mut().append_nla(LinkNla::Mtu(mtu));
+                }
+                request.execute()
+            }))
+        }
+        Change::LinkSet {
+            name,
+            mtu,
+            up,
+            master,
+        } => {
+            let master = match master {
+                Some(master) => Either::A(resolver.resolve(&master).map(Some)),
+                None => Either::B(future::ok(None)),
+            };
+            Box::new(
+                resolver
+                    .resolve(&name)
+                    .join(master)
+                    .and_then(move |(index, master)| {
+                        let mut request = LinkSetRequest::new(handle, index);
+                        if let Some(mtu) = mtu {
+                            request = request.mtu(mtu);
+                        }
+                        if let Some(master) = master {
+                            request = request.master(master);
+                        }
+                        match up {
+                            Some(true) => request = request.up(),
+                            Some(false) => request = request.down(),
+                            None => {}
+                        }
+                        request.execute()
+                    }),
+            )
+        }
+        Change::AddressDel(address) => {
+            Box::new(resolver.resolve(&address.link).and_then(move |index| {
+                AddressDelRequest::new(handle, index, address.address, address.prefix_len).execute()
+            }))
+        }
+        Change::AddressAdd(address) => {
+            Box::new(resolver.resolve(&address.link).and_then(move |index| {
+                AddressAddRequest::new(handle, index, address.address, address.prefix_len).execute()
+            }))
+        }
+        Change::RouteDel(_, message) => Box::new(RouteDelRequest::new(handle, message).execute()),
+        Change::RouteAdd(route) => {
+            let link = match route.link {
+                Some(ref link) => Either::A(resolver.resolve(link).map(Some)),
+                None => Either::B(future::ok(None)),
+            };
+            Box::new(link.and_then(move |index| {
+                let mut request = RouteAddRequest::new(handle)
+                    .destination(route.destination, route.prefix_len)
+                    .table(route.table)
+                    .protocol(protocol);
+                if let Some(gateway) = route.gateway {
+                    request = request.gateway(gateway);
+                }
+                if let Some(index) = index {
+                    request = request.output_interface(index);
+                }
+                if let Some(metric) = route.metric {
+                    request = request.priority(metric);
+                }
+                request.execute()
+            }))
+        }
+        Change::RuleDel(_, message) => Box::new(RuleDelRequest::new(handle, message).execute()),
+        Change::RuleAdd(rule) => {
+            let mut request = RuleAddRequest::new(handle)
+                .family(rule.family)
+                .priority(rule.priority)
+                .protocol(protocol);
+            if let Some((address, prefix_len)) = rule.source {
+                request = request.source(address, prefix_len);
+            }
+            if let Some((address, prefix_len)) = rule.destination {
+                request = request.destination(address, prefix_len);
+            }
+            if let Some(name) = rule.input_interface {
+                request = request.input_interface(name);
+            }
+            if let Some(name) = rule.output_interface {
+                request = request.output_interface(name);
+            }
+            if let Some(mark) = rule.fwmark {
+                request = request.fwmark(mark);
+            }
+            request = match rule.table {
+                Some(table) => request.table(table),
+                None => request.l3mdev(),
+            };
+            Box::new(request.execute())
+        }
+    }
+}
@@ -0,0 +1,94 @@
+use futures::{Future, Stream};
+
+use rtnetlink::constants::{
+    IFLA_STATS_FILTER_AF_SPEC, IFLA_STATS_FILTER_LINK_64, IFLA_STATS_FILTER_LINK_OFFLOAD_XSTATS,
+    IFLA_STATS_FILTER_LINK_XSTATS, IFLA_STATS_FILTER_LINK_XSTATS_SLAVE, NLM_F_DUMP, NLM_F_REQUEST,
+};
+use rtnetlink::{NetlinkFlags, NetlinkMessage, RtnlMessage, StatsMessage};
+
+use super::LinkStatistics;
+use connection::ConnectionHandle;
+use errors::NetlinkIpError;
+
+use Stream2Vec;
+
+lazy_static! {
+    // Flags for `ip stats show`
+    static ref DUMP_FLAGS: NetlinkFlags = NetlinkFlags::from(NLM_F_REQUEST | NLM_F_DUMP);
+    // Flags for `ip stats show dev LINK`
+    static ref GET_FLAGS: NetlinkFlags = NetlinkFlags::from(NLM_F_REQUEST);
+}
+
+pub struct StatsGetRequest {
+    handle: ConnectionHandle,
+    message: StatsMessage,
+}
+
+impl StatsGetRequest {
+    /// Create a request for the statistics of the link with the given index, or of all the links
+    /// if `index` is `None`. By default, only the 64 bits counters are requested.
+    pub(crate) fn new(handle: ConnectionHandle, index: Option<u32>) -> Self {
+        let mut message = StatsMessage::default();
+        message.header.ifindex = index.unwrap_or(0);
+        message.header.filter_mask = IFLA_STATS_FILTER_LINK_64;
+        StatsGetRequest { handle, message }
+    }
+
+    /// Execute the request. When the statistics of a single link are requested, the result
+    /// contains exactly one item.
+    pub fn execute(self) -> impl Future<Item = Vec<LinkStatistics>, Error = NetlinkIpError> {
+        let StatsGetRequest {
+            mut handle,
+            message,
+        } = self;
+        let flags = if message.header.ifindex == 0 {
+            *DUMP_FLAGS
+        } else {
+            *GET_FLAGS
+        };
+        let mut req = NetlinkMessage::from(RtnlMessage::GetStats(message));
+        req.header_mut().set_flags(flags);
+        Stream2Vec::new(handle.request(req).map(move |msg| {
+            if !msg.is_new_stats() {
+                return Err(NetlinkIpError::UnexpectedMessage(msg));
+            }
+
+            if let (_, RtnlMessage::NewStats(stats_message)) = msg.into_parts() {
+                Ok(LinkStatistics::from_stats_message(stats_message))
+            } else {
+                // We checked that msg.is_new_stats() above, so the should not be reachable.
+                unreachable!();
+            }
+        }))
+    }
+
+    /// Return a mutable reference to the request
+    pub fn message_mut(&mut self) -> &mut StatsMessage {
+        &mut self.message
+    }
+
+    /// Also retrieve the extended statistics specific to the link type (`IFLA_STATS_LINK_XSTATS`)
+    pub fn xstats(mut self) -> Self {
+        self.message.header.filter_mask |= IFLA_STATS_FILTER_LINK_XSTATS;
+        self
+    }
+
+    /// Also retrieve the extended statistics specific to the type of the link master
+    /// (`IFLA_STATS_LINK_XSTATS_SLAVE`)
+    pub fn xstats_slave(mut self) -> Self {
+        self.message.header.filter_mask |= IFLA_STATS_FILTER_LINK_XSTATS_SLAVE;
+        self
+    }
+
+    /// Also retrieve the hardware offload statistics (`IFLA_STATS_LINK_OFFLOAD_XSTATS`)
+    pub fn offload_xstats(mut self) -> Self {
+        self.message.header.filter_mask |= IFLA_STATS_FILTER_LINK_OFFLOAD_XSTATS;
+        self
+    }
+
+    /// Also retrieve the statistics specific to each address family (`IFLA_STATS_AF_SPEC`)
+    pub fn af_spec(mut self) -> Self {
+        self.message.header.filter_mask |= IFLA_STATS_FILTER_AF_SPEC;
+        self
+    }
+}
@@ -0,0 +1,38 @@
+use std::time::Duration;
+
+use tokio_core::reactor::Handle;
+
+use connection::ConnectionHandle;
+use errors::NetlinkIpError;
+
+use super::{StatsGetRequest, StatsSampler};
+
+pub struct StatsHandle(ConnectionHandle);
+
+impl StatsHandle {
+    pub fn new(handle: ConnectionHandle) -> Self {
+        StatsHandle(handle)
+    }
+
+    /// Retrieve the statistics of all the links (equivalent to `ip stats show`). Unlike a link
+    /// dump, only the requested statistics are sent by the kernel.
+    pub fn get(&self) -> StatsGetRequest {
+        StatsGetRequest::new(self.0.clone(), None)
+    }
+
+    /// Retrieve the statistics of the link with index `index` (equivalent to
+    /// `ip stats show dev LINK`)
+    pub fn link(&self, index: u32) -> StatsGetRequest {
+        StatsGetRequest::new(self.0.clone(), Some(index))
+    }
+
+    /// Create a stream that polls the counters of the links every `interval`, and yields the
+    /// deltas and rates of each interval. The timer runs on the event loop `reactor`.
+    pub fn sampler(
+        &self,
+        interval: Duration,
+        reactor: &Handle,
+    ) -> Result<StatsSampler, NetlinkIpError> {
+        StatsSampler::new(self.0.clone(), interval, reactor)
+    }
+}
@@ -0,0 +1,69 @@
+use rtnetlink::{LinkStats64, OffloadXstatsNla, StatsMessage, StatsNla};
+
+mod handle;
+pub use self::handle::*;
+
+mod get;
+pub use self::get::*;
+
+mod sampler;
+pub use self::sampler::*;
+
+/// Statistics of a link (equivalent to the output of `ip stats show dev LINK`)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LinkStatistics {
+    /// Index of the link
+    index: u32,
+    counters: Option<LinkStats64>,
+    cpu_hit: Option<LinkStats64>,
+    attributes: Vec<StatsNla>,
+}
+
+impl LinkStatistics {
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+
+    /// 64 bits counters of the link. These are only present if they were requested (which is the
+    /// case by default).
+    pub fn counters(&self) -> Option<&LinkStats64> {
+        self.counters.as_ref()
+    }
+
+    /// Counters of the packets that hit the CPU instead of being handled by the hardware the link
+    /// is offloaded to. These are only present if the offload statistics were requested, and if
+    /// the link driver supports them.
+    pub fn offload_cpu_hit(&self) -> Option<&LinkStats64> {
+        self.cpu_hit.as_ref()
+    }
+
+    /// All the attributes returned by the kernel, including the extended statistics which are
+    /// not parsed.
+    pub fn attributes(&self) -> &[StatsNla] {
+        self.attributes.as_slice()
+    }
+
+    pub fn from_stats_message(value: StatsMessage) -> Self {
+        let mut stats = LinkStatistics {
+            index: value.header.ifindex,
+            counters: None,
+            cpu_hit: None,
+            attributes: vec![],
+        };
+        for nla in &value.nlas {
+            match *nla {
+                StatsNla::Link64(counters) => stats.counters = Some(counters),
+                StatsNla::LinkOffloadXstats(ref nlas) => {
+                    for nla in nlas {
+                        if let OffloadXstatsNla::CpuHit(counters) = *nla {
+                            stats.cpu_hit = Some(counters);
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+        stats.attributes = value.nlas;
+        stats
+    }
+}
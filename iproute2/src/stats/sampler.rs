@@ -0,0 +1,451 @@
+use std::collections::HashMap;
+use std::mem;
+use std::time::{Duration, Instant};
+
+use eui48::MacAddress;
+use futures::{Async, Future, Poll, Stream};
+use tokio_core::reactor::{Handle, Interval};
+
+use rtnetlink::{LinkStats, LinkStats64};
+
+use super::{LinkStatistics, StatsGetRequest};
+use connection::ConnectionHandle;
+use errors::NetlinkIpError;
+use link::{Link, LinkGetRequest};
+
+type StatsFuture = Box<dyn Future<Item = (Vec<LinkStatistics>, Vec<Link>), Error = NetlinkIpError>>;
+
+/// What tells a link apart from another link that was created with the same index after it was
+/// deleted.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct LinkIdentity {
+    name: Option<String>,
+    address: Option<MacAddress>,
+    parent_index: Option<u32>,
+}
+
+impl<'a> From<&'a Link> for LinkIdentity {
+    fn from(link: &'a Link) -> Self {
+        LinkIdentity {
+            name: link.name().map(String::from),
+            address: link.address().cloned(),
+            parent_index: link.parent_index(),
+        }
+    }
+}
+
+/// Difference between two samples of the counters of a link
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LinkStatsSample {
+    index: u32,
+    interval: Duration,
+    delta: LinkStats64,
+    reset: bool,
+}
+
+impl LinkStatsSample {
+    /// Index of the link
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+
+    /// Time elapsed between the two samples
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    /// Increase of each counter during the interval
+    pub fn delta(&self) -> &LinkStats64 {
+        &self.delta
+    }
+
+    /// Whether the counters of the link went backwards (because the driver reset them), whether
+    /// the link was deleted and re-created with the same index, or whether the link appeared
+    /// during the interval. In all cases, the counters are assumed to have started from 0 during
+    /// the interval, so the delta is the current value of the counters and is only an estimate.
+    pub fn is_reset(&self) -> bool {
+        self.reset
+    }
+
+    pub fn rx_bytes_rate(&self) -> f64 {
+        self.per_second(self.delta.rx_bytes)
+    }
+
+    pub fn tx_bytes_rate(&self) -> f64 {
+        self.per_second(self.delta.tx_bytes)
+    }
+
+    pub fn rx_packets_rate(&self) -> f64 {
+        self.per_second(self.delta.rx_packets)
+    }
+
+    pub fn tx_packets_rate(&self) -> f64 {
+        self.per_second(self.delta.tx_packets)
+    }
+
+    pub fn rx_errors_rate(&self) -> f64 {
+        self.per_second(self.delta.rx_errors)
+    }
+
+    pub fn tx_errors_rate(&self) -> f64 {
+        self.per_second(self.delta.tx_errors)
+    }
+
+    pub fn rx_dropped_rate(&self) -> f64 {
+        self.per_second(self.delta.rx_dropped)
+    }
+
+    pub fn tx_dropped_rate(&self) -> f64 {
+        self.per_second(self.delta.tx_dropped)
+    }
+
+    fn per_second(&self, delta: u64) -> f64 {
+        let secs = self.interval.as_secs() as f64 + f64::from(self.interval.subsec_nanos()) / 1e9;
+        if secs == 0.0 {
+            0.0
+        } else {
+            delta as f64 / secs
+        }
+    }
+}
+
+/// Compute `current - previous` for each counter, or return `None` if a counter went backwards.
+fn counters_delta(current: &LinkStats64, previous: &LinkStats64) -> Option<LinkStats64> {
+    macro_rules! delta {
+        ($($field:ident),*) => {
+            LinkStats {
+                $($field: current.$field.checked_sub(previous.$field)?,)*
+            }
+        };
+    }
+    Some(delta!(
+        rx_packets,
+        tx_packets,
+        rx_bytes,
+        tx_bytes,
+        rx_errors,
+        tx_errors,
+        rx_dropped,
+        tx_dropped,
+        multicast,
+        collisions,
+        rx_length_errors,
+        rx_over_errors,
+        rx_crc_errors,
+        rx_frame_errors,
+        rx_fifo_errors,
+        rx_missed_errors,
+        tx_aborted_errors,
+        tx_carrier_errors,
+        tx_fifo_errors,
+        tx_heartbeat_errors,
+        tx_window_errors,
+        rx_compressed,
+        tx_compressed,
+        rx_nohandler
+    ))
+}
+
+/// A stream that periodically polls the counters of the links, and yields the samples of each
+/// interval. It is created with [`StatsHandle::sampler()`](struct.StatsHandle.html#method.sampler).
+///
+/// The counters of all the links are retrieved with a single dump request. The first request
+/// only records the initial value of the counters, so the first item is yielded after one
+/// interval. Links that disappear are forgotten, and links that appear are reported with
+/// [`LinkStatsSample::is_reset()`](struct.LinkStatsSample.html#method.is_reset) set.
+///
+/// The kernel may give the index of a deleted link to a new link. To detect this, the links are
+/// also dumped at each interval, and a link whose name, hardware address or parent changed is
+/// considered new. A link re-created with the same index, name, address and parent is only
+/// detected if its counters are lower than the previous ones.
+pub struct StatsSampler {
+    handle: ConnectionHandle,
+    interval: Interval,
+    links: Option<Vec<u32>>,
+    previous: HashMap<u32, (LinkStats64, Option<LinkIdentity>)>,
+    last_sample: Option<Instant>,
+    pending: Option<StatsFuture>,
+}
+
+impl StatsSampler {
+    pub(crate) fn new(
+        handle: ConnectionHandle,
+        interval: Duration,
+        reactor: &Handle,
+    ) -> Result<Self, NetlinkIpError> {
+        // Take the first sample right away, so that the first rates are available after one
+        // interval.
+        let interval =
+            Interval::new_at(Instant::now(), interval, reactor).map_err(NetlinkIpError::Io)?;
+        Ok(StatsSampler {
+            handle,
+            interval,
+            links: None,
+            previous: HashMap::new(),
+            last_sample: None,
+            pending: None,
+        })
+    }
+
+    /// Only sample the link with index `index`. This can be called multiple times to sample
+    /// several links. By default, all the links are sampled.
+    pub fn link(mut self, index: u32) -> Self {
+        self.links.get_or_insert_with(Vec::new).push(index);
+        self
+    }
+
+    fn is_selected(&self, index: u32) -> bool {
+        match self.links {
+            Some(ref links) => links.contains(&index),
+            None => true,
+        }
+    }
+
+    /// Compare the new counters with the previous ones. Return `None` for the very first sample.
+    fn sample(
+        &mut self,
+        stats: Vec<LinkStatistics>,
+        links: Vec<Link>,
+    ) -> Option<Vec<LinkStatsSample>> {
+        let now = Instant::now();
+        let interval = self.last_sample.map(|last| now.duration_since(last));
+        self.last_sample = Some(now);
+
+        let mut identities: HashMap<u32, LinkIdentity> = links
+            .iter()
+            .map(|link| (link.index(), LinkIdentity::from(link)))
+            .collect();
+        let mut current = HashMap::new();
+        for link_stats in stats {
+            if !self.is_selected(link_stats.index()) {
+                continue;
+            }
+            if let Some(counters) = link_stats.counters() {
+                let identity = identities.remove(&link_stats.index());
+                current.insert(link_stats.index(), (*counters, identity));
+            }
+        }
+        let previous = mem::replace(&mut self.previous, current);
+        let interval = interval?;
+
+        let mut samples = vec![];
+        for (index, &(counters, ref identity)) in &self.previous {
+            let (delta, reset) = match previous.get(index) {
+                Some(&(previous, ref previous_identity)) if previous_identity == identity => {
+                    match counters_delta(&counters, &previous) {
+                        Some(delta) => (delta, false),
+                        None => (counters, true),
+                    }
+                }
+                _ => (counters, true),
+            };
+            samples.push(LinkStatsSample {
+                index: *index,
+                interval,
+                delta,
+                reset,
+            });
+        }
+        samples.sort_by_key(|sample| sample.index);
+        Some(samples)
+    }
+}
+
+impl Stream for StatsSampler {
+    type Item = Vec<LinkStatsSample>;
+    type Error = NetlinkIpError;
+
+    fn poll(&mut self) -> Poll<Option<Self::Item>, Self::Error> {
+        loop {
+            if let Some(mut pending) = self.pending.take() {
+                match pending.poll()? {
+                    Async::Ready((stats, links)) => {
+                        if let Some(samples) = self.sample(stats, links) {
+                            return Ok(Async::Ready(Some(samples)));
+                        }
+                    }
+                    Async::NotReady => {
+                        self.pending = Some(pending);
+                        return Ok(Async::NotReady);
+                    }
+                }
+            }
+
+            match self.interval.poll().map_err(NetlinkIpError::Io)? {
+                Async::Ready(Some(())) => {
+                    let stats = StatsGetRequest::new(self.handle.clone(), None).execute();
+                    let links = LinkGetRequest::new(self.handle.clone()).execute();
+                    self.pending = Some(Box::new(stats.join(links)));
+                }
+                Async::Ready(None) => return Ok(Async::Ready(None)),
+                Async::NotReady => return Ok(Async::NotReady),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use futures::sync::mpsc::unbounded;
+    use rtnetlink::constants::{IFLA_STATS_FILTER_LINK_64, IFLA_STATS_LINK_64};
+    use rtnetlink::{Parseable, StatsBuffer, StatsMessage, STATS_HEADER_LEN};
+    use tokio_core::reactor::Core;
+
+    // Size of the IFLA_STATS_LINK_64 attribute: a header, and 24 counters
+    const LINK_64_LEN: usize = 4 + 24 * 8;
+
+    /// Build the statistics the kernel returns for link `index`, with the given byte counters
+    fn stats(index: u32, rx_bytes: u64, tx_bytes: u64) -> LinkStatistics {
+        let mut buf = vec![0; STATS_HEADER_LEN + LINK_64_LEN];
+        buf[4..8].copy_from_slice(&index.to_ne_bytes());
+        buf[8..12].copy_from_slice(&IFLA_STATS_FILTER_LINK_64.to_ne_bytes());
+        let nla = &mut buf[STATS_HEADER_LEN..];
+        nla[0..2].copy_from_slice(&(LINK_64_LEN as u16).to_ne_bytes());
+        nla[2..4].copy_from_slice(&IFLA_STATS_LINK_64.to_ne_bytes());
+        // rx_bytes and tx_bytes are the third and fourth counters
+        nla[20..28].copy_from_slice(&rx_bytes.to_ne_bytes());
+        nla[28..36].copy_from_slice(&tx_bytes.to_ne_bytes());
+        let message: StatsMessage = StatsBuffer::new(&buf).parse().unwrap();
+        LinkStatistics::from_stats_message(message)
+    }
+
+    fn counters(rx_bytes: u64, tx_bytes: u64) -> LinkStats64 {
+        *stats(1, rx_bytes, tx_bytes).counters().unwrap()
+    }
+
+    fn link(index: u32, name: &str) -> Link {
+        let mut link = Link::new();
+        link.set_index(index).set_name(name.into());
+        link
+    }
+
+    /// Sample `stats`, along with the links the kernel returns when none of them changed
+    fn sample(
+        sampler: &mut StatsSampler,
+        stats: Vec<LinkStatistics>,
+    ) -> Option<Vec<LinkStatsSample>> {
+        let links = stats
+            .iter()
+            .map(|stats| link(stats.index(), &format!("eth{}", stats.index())))
+            .collect();
+        sampler.sample(stats, links)
+    }
+
+    fn sampler(core: &Core) -> StatsSampler {
+        let (tx, _rx) = unbounded();
+        let handle = ConnectionHandle::new(tx);
+        StatsSampler::new(handle, Duration::from_secs(1), &core.handle()).unwrap()
+    }
+
+    #[test]
+    fn parse_counters() {
+        let counters = counters(1000, 2000);
+        assert_eq!(counters.rx_bytes, 1000);
+        assert_eq!(counters.tx_bytes, 2000);
+        assert_eq!(counters.rx_packets, 0);
+        assert_eq!(counters.rx_nohandler, 0);
+    }
+
+    #[test]
+    fn counters_delta_cases() {
+        let delta = counters_delta(&counters(1500, 2000), &counters(1000, 2000)).unwrap();
+        assert_eq!(delta, counters(500, 0));
+
+        let delta = counters_delta(&counters(1000, 2000), &counters(1000, 2000)).unwrap();
+        assert_eq!(delta, counters(0, 0));
+
+        // A single counter going backwards is a reset
+        assert!(counters_delta(&counters(1500, 100), &counters(1000, 2000)).is_none());
+    }
+
+    #[test]
+    fn sample_links() {
+        let core = Core::new().unwrap();
+        let mut sampler = sampler(&core);
+
+        // The first sample only records the counters
+        let first = vec![stats(1, 1000, 1000), stats(2, 5000, 5000), stats(4, 10, 10)];
+        assert!(sample(&mut sampler, first).is_none());
+
+        // Link 1 keeps counting, the counters of link 2 are reset, link 3 appears, and link 4
+        // disappears
+        let second = vec![stats(1, 1500, 1200), stats(2, 100, 100), stats(3, 50, 50)];
+        let samples = sample(&mut sampler, second).unwrap();
+        let summary: Vec<_> = samples
+            .iter()
+            .map(|sample| (sample.index(), *sample.delta(), sample.is_reset()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (1, counters(500, 200), false),
+                (2, counters(100, 100), true),
+                (3, counters(50, 50), true),
+            ]
+        );
+
+        // Link 4 was forgotten, so it comes back as a new link
+        let third = vec![stats(1, 1500, 1200), stats(4, 20, 20)];
+        let samples = sample(&mut sampler, third).unwrap();
+        let summary: Vec<_> = samples
+            .iter()
+            .map(|sample| (sample.index(), *sample.delta(), sample.is_reset()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![(1, counters(0, 0), false), (4, counters(20, 20), true)]
+        );
+    }
+
+    #[test]
+    fn sample_selected_links() {
+        let core = Core::new().unwrap();
+        let mut sampler = sampler(&core).link(2);
+        let first = vec![stats(1, 1000, 1000), stats(2, 1000, 1000)];
+        assert!(sample(&mut sampler, first).is_none());
+        let second = vec![stats(1, 2000, 2000), stats(2, 3000, 3000)];
+        let samples = sample(&mut sampler, second).unwrap();
+        assert_eq!(samples.len(), 1);
+        assert_eq!(samples[0].index(), 2);
+        assert_eq!(*samples[0].delta(), counters(2000, 2000));
+        assert!(!samples[0].is_reset());
+    }
+
+    #[test]
+    fn sample_recreated_link() {
+        let core = Core::new().unwrap();
+        let mut sampler = sampler(&core);
+        let first = vec![stats(1, 1000, 1000), stats(2, 1000, 1000)];
+        assert!(sample(&mut sampler, first).is_none());
+
+        // Link 2 is deleted, and a new link is created with the same index. Its counters are
+        // higher than the previous ones, but it has another name.
+        let second = vec![stats(1, 1500, 1500), stats(2, 3000, 3000)];
+        let samples = sampler
+            .sample(second, vec![link(1, "eth1"), link(2, "veth0")])
+            .unwrap();
+        let summary: Vec<_> = samples
+            .iter()
+            .map(|sample| (sample.index(), *sample.delta(), sample.is_reset()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (1, counters(500, 500), false),
+                (2, counters(3000, 3000), true),
+            ]
+        );
+
+        // The same link is created again, with the same name but another hardware address
+        let mut recreated = link(2, "veth0");
+        recreated.set_address(MacAddress::new([0x02, 0, 0, 0, 0, 0x02]));
+        let third = vec![stats(1, 1500, 1500), stats(2, 4000, 4000)];
+        let samples = sampler
+            .sample(third, vec![link(1, "eth1"), recreated])
+            .unwrap();
+        assert_eq!(samples[1].index(), 2);
+        assert_eq!(*samples[1].delta(), counters(4000, 4000));
+        assert!(samples[1].is_reset());
+    }
+}
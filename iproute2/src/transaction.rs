@@ -0,0 +1,580 @@
+use std::sync::{Arc, Mutex};
+
+use futures::future::{self, Either, Loop};
+use futures::{Future, IntoFuture};
+
+use rtnetlink::constants::{IFF_UP, IFLA_GROUP, IFLA_INFO_DATA, IFLA_INFO_KIND};
+use rtnetlink::{LinkFlags, LinkMessage, LinkNla, Nla};
+
+use address::{AddressAddRequest, AddressDelRequest};
+use bridge_vlan::{BridgeVlanAddRequest, BridgeVlanDelRequest};
+use connection::ConnectionHandle;
+use errors::{NetlinkIpError, TransactionError};
+use fdb::{FdbAddRequest, FdbDelRequest};
+use link::{Link, LinkAddRequest, LinkDelRequest, LinkGetRequest, LinkSetRequest};
+use mdb::{MdbAddRequest, MdbDelRequest};
+use route::{RouteAddRequest, RouteDelRequest};
+
+/// A request that undoes a change made in a transaction
+enum Undo {
+    // A request that creates a deleted link with its options, and one that creates it with the
+    // default options of its kind, in case the kernel rejects some of the options
+    LinkAdd(LinkAddRequest, LinkAddRequest),
+    LinkSet(LinkSetRequest),
+    LinkDel(LinkDelRequest),
+    AddressAdd(AddressAddRequest),
+    AddressDel(AddressDelRequest),
+    RouteAdd(RouteAddRequest),
+    RouteDel(RouteDelRequest),
+    BridgeVlanAdd(BridgeVlanAddRequest),
+    BridgeVlanDel(BridgeVlanDelRequest),
+    FdbAdd(FdbAddRequest),
+    FdbDel(FdbDelRequest),
+    MdbAdd(MdbAddRequest),
+    MdbDel(MdbDelRequest),
+}
+
+impl Undo {
+    fn execute(self) -> Box<dyn Future<Item = (), Error = NetlinkIpError>> {
+        match self {
+            Undo::LinkAdd(request, fallback) => Box::new(request.execute().or_else(|e| {
+                warn!(
+                    "could not restore a link with its options ({}), restoring it without them",
+                    e
+                );
+                fallback.execute()
+            })),
+            Undo::LinkSet(request) => Box::new(request.execute()),
+            Undo::LinkDel(request) => Box::new(request.execute()),
+            Undo::AddressAdd(request) => Box::new(request.execute()),
+            Undo::AddressDel(request) => Box::new(request.execute()),
+            Undo::RouteAdd(request) => Box::new(request.execute()),
+            Undo::RouteDel(request) => Box::new(request.execute()),
+            Undo::BridgeVlanAdd(request) => Box::new(request.execute()),
+            Undo::BridgeVlanDel(request) => Box::new(request.execute()),
+            Undo::FdbAdd(request) => Box::new(request.execute()),
+            Undo::FdbDel(request) => Box::new(request.execute()),
+            Undo::MdbAdd(request) => Box::new(request.execute()),
+            Undo::MdbDel(request) => Box::new(request.execute()),
+        }
+    }
+}
+
+/// A set of changes that are undone if one of them fails. It is created with
+/// [`ConnectionHandle::transaction()`](struct.ConnectionHandle.html#method.transaction).
+///
+/// Changes are made by passing the usual requests (`LinkAddRequest`, `LinkSetRequest`, etc.) to
+/// the transaction, which records how to undo each change that succeeds. The changes are usually
+/// made in the function passed to [`run()`](#method.run): if the future it returns fails, the
+/// changes made so far are undone, most recent first. The clones of a transaction share the same
+/// changes.
+///
+/// A change is only recorded once the kernel has acknowledged it. Changes that are still pending
+/// when the rollback starts are not undone, so the changes of a transaction should be made one
+/// after the other, rather than joined.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// extern crate futures;
+/// extern crate iproute2;
+/// extern crate tokio_core;
+///
+/// use futures::Future;
+/// use tokio_core::reactor::Core;
+///
+/// use iproute2::{new_connection, BridgeOptions};
+///
+/// fn main() {
+///     let (connection, handle) = new_connection().unwrap();
+///     let mut core = Core::new().unwrap();
+///     core.handle().spawn(connection.map_err(|_| ()));
+///
+///     // Create a bridge and a veth pair, and enslave one end of the pair to the bridge. If any
+///     // step fails, the links that have been created are deleted.
+///     let link = handle.link();
+///     let transaction = handle.transaction().run(move |tx| {
+///         let bridge = link.add().bridge("br0".into(), BridgeOptions::new());
+///         let veth = link.add().veth("veth0".into(), "veth1".into());
+///         let tx2 = tx.clone();
+///         tx.link_add(bridge)
+///             .and_then(move |bridge| tx2.link_add(veth).map(move |veth| (bridge, veth)))
+///             .and_then(move |(bridge, veth)| {
+///                 tx.link_set(link.set(veth.index()).master(bridge.index()))
+///             })
+///     });
+///     if let Err(e) = core.run(transaction) {
+///         eprintln!("{}", e);
+///     }
+/// }
+/// ```
+#[derive(Clone)]
+pub struct Transaction {
+    handle: ConnectionHandle,
+    undo: Arc<Mutex<Vec<Undo>>>,
+}
+
+impl Transaction {
+    pub(crate) fn new(handle: ConnectionHandle) -> Self {
+        Transaction {
+            handle,
+            undo: Arc::new(Mutex::new(vec![])),
+        }
+    }
+
+    /// Create a link, and return it. On rollback, the link is deleted.
+    pub fn link_add(
+        &self,
+        request: LinkAddRequest,
+    ) -> impl Future<Item = Link, Error = NetlinkIpError> {
+        let undo = self.undo.clone();
+        let handle = self.handle.clone();
+        request.execute_echo().map(move |link| {
+            let inverse = LinkDelRequest::new(handle, link.index());
+            undo.lock().unwrap().push(Undo::LinkDel(inverse));
+            link
+        })
+    }
+
+    /// Change a link. On rollback, the flags and attributes that were changed are set back to
+    /// their previous value.
+    ///
+    /// Only the flags, and the name, address, alias, MTU, master, transmit queue length and group
+    /// of a link can be restored. Requests that change anything else fail with
+    /// [`NetlinkIpError::Irreversible`](enum.NetlinkIpError.html#variant.Irreversible), without
+    /// being executed. This includes moving the link to another network namespace, since it
+    /// cannot be moved back from this connection, and changing the options of its kind.
+    pub fn link_set(
+        &self,
+        mut request: LinkSetRequest,
+    ) -> impl Future<Item = (), Error = NetlinkIpError> {
+        let undo = self.undo.clone();
+        let handle = self.handle.clone();
+        let index = request.message_mut().header().index();
+        LinkGetRequest::new(handle.clone())
+            .match_index(index)
+            .execute()
+            .and_then(
+                move |link| match link_set_inverse(handle, &link, request.message_mut()) {
+                    Ok(inverse) => Either::A(request.execute().map(move |()| {
+                        undo.lock().unwrap().push(Undo::LinkSet(inverse));
+                    })),
+                    Err(e) => Either::B(future::err(e)),
+                },
+            )
+    }
+
+    /// Delete a link. On rollback, the link is created again, with the kind, options and
+    /// attributes it had. If the kernel rejects some of the options (some are read-only, others
+    /// depend on the kernel configuration), the link is created with the default options of its
+    /// kind instead. Not everything can be restored: the kernel ignores the alias of new links, and
+    /// the peer of a veth link is lost, for instance. Note that the new link has a different index,
+    /// so the changes made to the link before it was deleted cannot be undone.
+    pub fn link_del(
+        &self,
+        mut request: LinkDelRequest,
+    ) -> impl Future<Item = (), Error = NetlinkIpError> {
+        let undo = self.undo.clone();
+        let handle = self.handle.clone();
+        let index = request.message_mut().header().index();
+        LinkGetRequest::new(handle.clone())
+            .match_index(index)
+            .execute()
+            .and_then(move |link| {
+                let inverse = link_del_inverse(handle.clone(), &link, true);
+                let fallback = link_del_inverse(handle, &link, false);
+                request.execute().map(move |()| {
+                    undo.lock().unwrap().push(Undo::LinkAdd(inverse, fallback));
+                })
+            })
+    }
+
+    /// Add an address to a link. On rollback, it is removed.
+    pub fn address_add(
+        &self,
+        request: AddressAddRequest,
+    ) -> impl Future<Item = (), Error = NetlinkIpError> {
+        let undo = self.undo.clone();
+        let inverse = request.inverse();
+        request.execute().map(move |()| {
+            undo.lock().unwrap().push(Undo::AddressDel(inverse));
+        })
+    }
+
+    /// Remove an address from a link. On rollback, it is added back, with the attributes of the
+    /// request only: its label, broadcast address and lifetimes are lost.
+    pub fn address_del(
+        &self,
+        request: AddressDelRequest,
+    ) -> impl Future<Item = (), Error = NetlinkIpError> {
+        let undo = self.undo.clone();
+        let inverse = request.inverse();
+        request.execute().map(move |()| {
+            undo.lock().unwrap().push(Undo::AddressAdd(inverse));
+        })
+    }
+
+    /// Add a route. On rollback, it is removed.
+    pub fn route_add(
+        &self,
+        request: RouteAddRequest,
+    ) -> impl Future<Item = (), Error = NetlinkIpError> {
+        let undo = self.undo.clone();
+        let inverse = request.inverse();
+        request.execute().map(move |()| {
+            undo.lock().unwrap().push(Undo::RouteDel(inverse));
+        })
+    }
+
+    /// Remove a route. On rollback, it is added back with its destination, source, gateway,
+    /// output link, priority, table, protocol and type, but without its other attributes, like
+    /// its metrics.
+    pub fn route_del(
+        &self,
+        request: RouteDelRequest,
+    ) -> impl Future<Item = (), Error = NetlinkIpError> {
+        let undo = self.undo.clone();
+        let inverse = request.inverse();
+        request.execute().map(move |()| {
+            undo.lock().unwrap().push(Undo::RouteAdd(inverse));
+        })
+    }
+
+    /// Add VLANs to a bridge port. On rollback, they are removed.
+    pub fn bridge_vlan_add(
+        &self,
+        request: BridgeVlanAddRequest,
+    ) -> impl Future<Item = (), Error = NetlinkIpError> {
+        let undo = self.undo.clone();
+        let inverse = request.inverse();
+        request.execute().map(move |()| {
+            undo.lock().unwrap().push(Undo::BridgeVlanDel(inverse));
+        })
+    }
+
+    /// Remove VLANs from a bridge port. On rollback, they are added back, without their `pvid`
+    /// and `untagged` flags.
+    pub fn bridge_vlan_del(
+        &self,
+        request: BridgeVlanDelRequest,
+    ) -> impl Future<Item = (), Error = NetlinkIpError> {
+        let undo = self.undo.clone();
+        let inverse = request.inverse();
+        request.execute().map(move |()| {
+            undo.lock().unwrap().push(Undo::BridgeVlanAdd(inverse));
+        })
+    }
+
+    /// Add a forwarding database entry. On rollback, it is removed.
+    pub fn fdb_add(
+        &self,
+        request: FdbAddRequest,
+    ) -> impl Future<Item = (), Error = NetlinkIpError> {
+        let undo = self.undo.clone();
+        let inverse = request.inverse();
+        request.execute().map(move |()| {
+            undo.lock().unwrap().push(Undo::FdbDel(inverse));
+        })
+    }
+
+    /// Remove a forwarding database entry. On rollback, it is added back as a static entry.
+    pub fn fdb_del(
+        &self,
+        request: FdbDelRequest,
+    ) -> impl Future<Item = (), Error = NetlinkIpError> {
+        let undo = self.undo.clone();
+        let inverse = request.inverse();
+        request.execute().map(move |()| {
+            undo.lock().unwrap().push(Undo::FdbAdd(inverse));
+        })
+    }
+
+    /// Add a multicast database entry. On rollback, it is removed.
+    pub fn mdb_add(
+        &self,
+        request: MdbAddRequest,
+    ) -> impl Future<Item = (), Error = NetlinkIpError> {
+        let undo = self.undo.clone();
+        let inverse = request.inverse();
+        request.execute().map(move |()| {
+            undo.lock().unwrap().push(Undo::MdbDel(inverse));
+        })
+    }
+
+    /// Remove a multicast database entry. On rollback, it is added back as a temporary entry.
+    pub fn mdb_del(
+        &self,
+        request: MdbDelRequest,
+    ) -> impl Future<Item = (), Error = NetlinkIpError> {
+        let undo = self.undo.clone();
+        let inverse = request.inverse();
+        request.execute().map(move |()| {
+            undo.lock().unwrap().push(Undo::MdbAdd(inverse));
+        })
+    }
+
+    /// Make changes with `f`. If the future it returns fails, the changes are rolled back, and the
+    /// error is returned along with the errors that occurred during the rollback. Otherwise, the
+    /// changes are committed.
+    pub fn run<F, R>(self, f: F) -> impl Future<Item = R::Item, Error = TransactionError>
+    where
+        F: FnOnce(Transaction) -> R,
+        R: IntoFuture<Error = NetlinkIpError>,
+    {
+        let transaction = self.clone();
+        f(self).into_future().then(move |result| match result {
+            Ok(item) => {
+                transaction.commit();
+                Either::A(future::ok(item))
+            }
+            Err(error) => Either::B(transaction.rollback().then(move |result| {
+                Err(TransactionError {
+                    error,
+                    rollback_errors: result.err().unwrap_or_default(),
+                })
+            })),
+        })
+    }
+
+    /// Forget the changes made so far, so that they are not rolled back
+    pub fn commit(&self) {
+        self.undo.lock().unwrap().clear();
+    }
+
+    /// Undo the changes made so far, most recent first. If some changes cannot be undone, the
+    /// other ones are still undone, and the future fails with all the errors.
+    pub fn rollback(&self) -> impl Future<Item = (), Error = Vec<NetlinkIpError>> {
+        let undo: Vec<Undo> = self.undo.lock().unwrap().drain(..).collect();
+        future::loop_fn((undo, vec![]), |(mut undo, mut errors)| match undo.pop() {
+            Some(request) => Either::A(request.execute().then(move |result| {
+                if let Err(e) = result {
+                    errors.push(e);
+                }
+                Ok(Loop::Continue((undo, errors)))
+            })),
+            None if errors.is_empty() => Either::B(future::ok(Loop::Break(()))),
+            None => Either::B(future::err(errors)),
+        })
+    }
+}
+
+/// Build a request that restores the flags and attributes of `link` that `change` modifies
+fn link_set_inverse(
+    handle: ConnectionHandle,
+    link: &Link,
+    change: &LinkMessage,
+) -> Result<LinkSetRequest, NetlinkIpError> {
+    let mut inverse = LinkSetRequest::new(handle, link.index());
+    {
+        let message = inverse.message_mut();
+        let flags = change.header().flags();
+        let mask = change.header().change_mask();
+        // Without a change mask, the kernel sets all the flags
+        let previous_flags = if mask.0 == 0 && flags.0 != 0 {
+            link.flags()
+        } else {
+            LinkFlags::from(link.flags().0 & mask.0)
+        };
+        message
+            .header_mut()
+            .set_flags(previous_flags)
+            .set_change_mask(mask);
+
+        for nla in change.nlas() {
+            let previous = previous_nla(link, nla)
+                .ok_or_else(|| NetlinkIpError::Irreversible(format!("{:?}", nla)))?;
+            message.append_nla(previous);
+        }
+    }
+    Ok(inverse)
+}
+
+/// Return the attribute of `link` of the same kind as `nla`, if it can be restored
+fn previous_nla(link: &Link, nla: &LinkNla) -> Option<LinkNla> {
+    match *nla {
+        LinkNla::IfName(_) => link.name().map(|name| LinkNla::IfName(name.to_string())),
+        LinkNla::Address(_) => link
+            .address()
+            .map(|address| LinkNla::Address(address.as_bytes().to_vec())),
+        // Links without alias or master are restored by setting an empty alias, or master 0
+        LinkNla::IfAlias(_) => Some(LinkNla::IfAlias(
+            link.alias().unwrap_or_default().to_string(),
+        )),
+        LinkNla::Master(_) => Some(LinkNla::Master(link.master_index().unwrap_or(0))),
+        LinkNla::Mtu(_) => link.mtu().map(LinkNla::Mtu),
+        LinkNla::TxQueueLen(_) => link.tx_queue_length().map(LinkNla::TxQueueLen),
+        LinkNla::Group(_) => link
+            .attributes()
+            .iter()
+            .find(|nla| nla.kind() == IFLA_GROUP)
+            .cloned(),
+        // A link moved to another namespace cannot be found, let alone moved back, from this
+        // connection
+        LinkNla::NetNsPid(_) | LinkNla::NetnsFd(_) => None,
+        // Changing the options of a link kind, or of a bridge port, cannot be undone
+        LinkNla::LinkInfo(_) => None,
+        _ => None,
+    }
+}
+
+/// Build a request that creates `link` again, with or without the options of its kind
+fn link_del_inverse(handle: ConnectionHandle, link: &Link, with_options: bool) -> LinkAddRequest {
+    let mut inverse = LinkAddRequest::new(handle);
+    {
+        let message = inverse.message_mut();
+        if link.flags().is_up() {
+            message
+                .header_mut()
+                .set_flags(LinkFlags::from(IFF_UP))
+                .set_change_mask(LinkFlags::from(IFF_UP));
+        }
+        if let Some(name) = link.name() {
+            message.append_nla(LinkNla::IfName(name.to_string()));
+        }
+        if let Some(address) = link.address() {
+            message.append_nla(LinkNla::Address(address.as_bytes().to_vec()));
+        }
+        if let Some(mtu) = link.mtu() {
+            message.append_nla(LinkNla::Mtu(mtu));
+        }
+        if let Some(length) = link.tx_queue_length() {
+            message.append_nla(LinkNla::TxQueueLen(length));
+        }
+        if let Some(index) = link.master_index() {
+            message.append_nla(LinkNla::Master(index));
+        }
+        for nla in link.attributes() {
+            match *nla {
+                LinkNla::Link(_) | LinkNla::Group(_) => message.append_nla(nla.clone()),
+                // Keep the kind of the link and its options, but not the options of its port
+                LinkNla::LinkInfo(ref infos) => {
+                    let infos = infos
+                        .iter()
+                        .filter(|info| {
+                            info.kind() == IFLA_INFO_KIND
+                                || (with_options && info.kind() == IFLA_INFO_DATA)
+                        })
+                        .cloned()
+                        .collect();
+                    message.append_nla(LinkNla::LinkInfo(infos));
+                }
+                _ => {}
+            }
+        }
+    }
+    inverse
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use futures::sync::mpsc::unbounded;
+    use rtnetlink::constants::{IFF_PROMISC, IFF_RUNNING};
+    use rtnetlink::{LinkInfo, LinkInfoKind};
+
+    fn handle() -> ConnectionHandle {
+        let (tx, _rx) = unbounded();
+        ConnectionHandle::new(tx)
+    }
+
+    fn link() -> Link {
+        let mut link = Link::new();
+        link.set_index(3)
+            .set_name("eth0".into())
+            .set_mtu(1500)
+            .set_tx_queue_length(1000)
+            .set_flags(LinkFlags::from(IFF_UP | IFF_RUNNING));
+        link
+    }
+
+    #[derive(Default)]
+    struct Case {
+        flags: u32,
+        mask: u32,
+        nlas: Vec<LinkNla>,
+        expected_flags: u32,
+        expected_nlas: Vec<LinkNla>,
+    }
+
+    #[test]
+    fn link_set_inverse_cases() {
+        let cases = vec![
+            // ip link set eth0 down
+            Case {
+                flags: 0,
+                mask: IFF_UP,
+                expected_flags: IFF_UP,
+                ..Default::default()
+            },
+            // ip link set eth0 promisc on
+            Case {
+                flags: IFF_PROMISC,
+                mask: IFF_PROMISC,
+                ..Default::default()
+            },
+            // Without a change mask, all the flags are restored
+            Case {
+                flags: IFF_PROMISC,
+                mask: 0,
+                expected_flags: IFF_UP | IFF_RUNNING,
+                ..Default::default()
+            },
+            Case {
+                nlas: vec![LinkNla::IfName("lan0".into()), LinkNla::Mtu(9000)],
+                expected_nlas: vec![LinkNla::IfName("eth0".into()), LinkNla::Mtu(1500)],
+                ..Default::default()
+            },
+            Case {
+                nlas: vec![LinkNla::TxQueueLen(10)],
+                expected_nlas: vec![LinkNla::TxQueueLen(1000)],
+                ..Default::default()
+            },
+            // A link without master or alias gets them removed
+            Case {
+                nlas: vec![LinkNla::Master(2), LinkNla::IfAlias("uplink".into())],
+                expected_nlas: vec![LinkNla::Master(0), LinkNla::IfAlias("".into())],
+                ..Default::default()
+            },
+        ];
+        for case in cases {
+            let mut change = LinkMessage::new();
+            change
+                .header_mut()
+                .set_flags(LinkFlags::from(case.flags))
+                .set_change_mask(LinkFlags::from(case.mask));
+            for nla in case.nlas {
+                change.append_nla(nla);
+            }
+            let mut inverse = link_set_inverse(handle(), &link(), &change).unwrap();
+            let message = inverse.message_mut();
+            assert_eq!(message.header().index(), 3);
+            assert_eq!(
+                message.header().flags(),
+                LinkFlags::from(case.expected_flags)
+            );
+            assert_eq!(message.header().change_mask(), LinkFlags::from(case.mask));
+            assert_eq!(message.nlas(), &case.expected_nlas[..]);
+        }
+    }
+
+    #[test]
+    fn link_set_inverse_irreversible() {
+        let changes = vec![
+            LinkNla::NetNsPid(1),
+            LinkNla::NetnsFd(4),
+            LinkNla::LinkInfo(vec![LinkInfo::Kind(LinkInfoKind::Bridge)]),
+            // The link has no address to restore
+            LinkNla::Address(vec![0, 1, 2, 3, 4, 5]),
+        ];
+        for nla in changes {
+            let mut change = LinkMessage::new();
+            change.append_nla(LinkNla::Mtu(9000));
+            change.append_nla(nla);
+            match link_set_inverse(handle(), &link(), &change) {
+                Err(NetlinkIpError::Irreversible(_)) => {}
+                Err(e) => panic!("unexpected error: {}", e),
+                Ok(_) => panic!("{:?} should be irreversible", change),
+            }
+        }
+    }
+}
@@ -0,0 +1,430 @@
+use std::net::IpAddr;
+use std::time::{Duration, Instant};
+
+use futures::sync::mpsc::UnboundedReceiver;
+use futures::{Async, Future, Poll, Stream};
+use libc;
+use tokio_timer::Delay;
+
+use rtnetlink::constants::{
+    IFA_F_DADFAILED, IFA_F_TENTATIVE, NLM_F_DUMP, NLM_F_REQUEST, RTNLGRP_IPV4_IFADDR,
+    RTNLGRP_IPV6_IFADDR, RTNLGRP_LINK,
+};
+use rtnetlink::{
+    AddressMessage, AddressNla, LinkLayerType, LinkMessage, LinkState, NetlinkFlags,
+    NetlinkMessage, RtnlMessage,
+};
+
+use connection::{new_multicast_connection, Connection, ConnectionHandle};
+use errors::NetlinkIpError;
+use link::Link;
+
+lazy_static! {
+    // Flags for `ip link show <name>`
+    static ref LINK_FLAGS: NetlinkFlags = NetlinkFlags::from(NLM_F_REQUEST);
+    // Flags for `ip address show`
+    static ref ADDRESS_FLAGS: NetlinkFlags = NetlinkFlags::from(NLM_F_REQUEST | NLM_F_DUMP);
+}
+
+type Condition<T> = Box<dyn Fn(&NetlinkMessage) -> Result<Option<T>, NetlinkIpError>>;
+type CheckStream = Box<dyn Stream<Item = NetlinkMessage, Error = NetlinkIpError>>;
+
+/// A future that waits for a condition on a link or an address, created with
+/// [`wait_link_oper_up()`](fn.wait_link_oper_up.html), [`wait_carrier()`](fn.wait_carrier.html)
+/// or [`wait_address_ready()`](fn.wait_address_ready.html).
+///
+/// The future runs its own connection, which joins the multicast groups of the notifications
+/// before checking the current state, so that no change is missed between the check and the
+/// notifications. If notifications are lost because the socket receive buffer overflowed, the
+/// state is checked again. The future fails with
+/// [`NetlinkIpError::Timeout`](enum.NetlinkIpError.html#variant.Timeout) if the condition is not
+/// met before the timeout, if any.
+pub struct Wait<T> {
+    connection: Connection,
+    handle: ConnectionHandle,
+    notifications: UnboundedReceiver<NetlinkMessage>,
+    request: NetlinkMessage,
+    condition: Condition<T>,
+    check: Option<CheckStream>,
+    // Whether notifications were lost during the check in progress, which must then be made again
+    recheck: bool,
+    timeout: Option<Delay>,
+}
+
+impl<T> Wait<T> {
+    fn new(
+        groups: &[u32],
+        request: NetlinkMessage,
+        condition: Condition<T>,
+        timeout: Option<Duration>,
+    ) -> Result<Self, NetlinkIpError> {
+        let (connection, handle, notifications) =
+            new_multicast_connection(groups).map_err(NetlinkIpError::Io)?;
+        let mut wait = Wait {
+            connection,
+            handle,
+            notifications,
+            request,
+            condition,
+            check: None,
+            recheck: false,
+            timeout: timeout.map(|timeout| Delay::new(Instant::now() + timeout)),
+        };
+        wait.start_check();
+        Ok(wait)
+    }
+
+    fn start_check(&mut self) {
+        let request = self.request.clone();
+        self.check = Some(Box::new(self.handle.request(request)));
+        self.recheck = false;
+    }
+
+    /// Evaluate the condition on a message of the check, or on a notification
+    fn evaluate(&self, message: &NetlinkMessage) -> Result<Option<T>, NetlinkIpError> {
+        if let RtnlMessage::Error(ref err) = *message.message() {
+            if err.code == -libc::ENODEV {
+                return Err(NetlinkIpError::LinkNotFound);
+            }
+            return Err(NetlinkIpError::NetlinkError(message.clone()));
+        }
+        (self.condition)(message)
+    }
+}
+
+impl<T> Future for Wait<T> {
+    type Item = T;
+    type Error = NetlinkIpError;
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        loop {
+            // The connection reads the socket, and sends the check requests
+            if let Async::Ready(()) = self.connection.poll().map_err(NetlinkIpError::Io)? {
+                return Err(NetlinkIpError::ConnectionClosed);
+            }
+
+            while let Ok(Async::Ready(Some(message))) = self.notifications.poll() {
+                if message.is_overrun() {
+                    debug!("wait: notifications were lost, checking the state again");
+                    if self.check.is_some() {
+                        // The kernel rejects a new dump until the one in progress completes
+                        self.recheck = true;
+                    } else {
+                        self.start_check();
+                    }
+                } else if let Some(item) = self.evaluate(&message)? {
+                    return Ok(Async::Ready(item));
+                }
+            }
+
+            if let Some(mut check) = self.check.take() {
+                match check.poll()? {
+                    Async::Ready(Some(message)) => {
+                        if let Some(item) = self.evaluate(&message)? {
+                            return Ok(Async::Ready(item));
+                        }
+                        self.check = Some(check);
+                        continue;
+                    }
+                    Async::Ready(None) => {
+                        if self.recheck {
+                            self.start_check();
+                            // Poll the connection again to send the new request
+                            continue;
+                        }
+                    }
+                    Async::NotReady => self.check = Some(check),
+                }
+            }
+
+            if let Some(ref mut timeout) = self.timeout {
+                match timeout.poll() {
+                    Ok(Async::Ready(())) => return Err(NetlinkIpError::Timeout),
+                    Ok(Async::NotReady) => {}
+                    Err(e) => warn!("cannot enforce the timeout: {}", e),
+                }
+            }
+            return Ok(Async::NotReady);
+        }
+    }
+}
+
+/// Return the link of a link message about the link with index `index`
+fn notified_link(message: &NetlinkMessage, index: u32) -> Result<Option<Link>, NetlinkIpError> {
+    let (is_new, link_message) = match *message.message() {
+        RtnlMessage::NewLink(ref link) => (true, link),
+        RtnlMessage::DelLink(ref link) => (false, link),
+        _ => return Ok(None),
+    };
+    // Bridge ports are also notified with AF_BRIDGE messages, which only carry the bridge
+    // attributes of the port
+    if link_message.header().index() != index || link_message.header().address_family() != 0 {
+        return Ok(None);
+    }
+    if !is_new {
+        return Err(NetlinkIpError::LinkNotFound);
+    }
+    Ok(Some(Link::from_link_message(link_message.clone())?))
+}
+
+fn link_wait<F>(
+    index: u32,
+    timeout: Option<Duration>,
+    condition: F,
+) -> Result<Wait<Link>, NetlinkIpError>
+where
+    F: Fn(&Link) -> bool + 'static,
+{
+    let mut message = LinkMessage::new();
+    // With strict checking, the kernel rejects the requests with a non-zero link layer type
+    message
+        .header_mut()
+        .set_index(index)
+        .set_link_layer_type(LinkLayerType::Netrom);
+    let mut request = NetlinkMessage::from(RtnlMessage::GetLink(message));
+    request.header_mut().set_flags(*LINK_FLAGS);
+    let condition = move |message: &NetlinkMessage| {
+        Ok(notified_link(message, index)?.filter(|link| condition(link)))
+    };
+    Wait::new(&[RTNLGRP_LINK], request, Box::new(condition), timeout)
+}
+
+/// Wait until the link with index `index` is operationally up (`ip link` shows `state UP`), and
+/// return it. Links whose operational state is unknown, like the loopback link, never are.
+///
+/// The future fails with
+/// [`NetlinkIpError::LinkNotFound`](enum.NetlinkIpError.html#variant.LinkNotFound) if the link
+/// does not exist or is deleted, and with
+/// [`NetlinkIpError::Timeout`](enum.NetlinkIpError.html#variant.Timeout) if the link is not up
+/// after `timeout`.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// extern crate futures;
+/// extern crate iproute2;
+/// extern crate tokio_core;
+///
+/// use std::time::Duration;
+///
+/// use futures::Future;
+/// use tokio_core::reactor::Core;
+///
+/// use iproute2::{new_connection, wait_link_oper_up};
+///
+/// fn main() {
+///     let mut core = Core::new().unwrap();
+///     let (connection, handle) = new_connection().unwrap();
+///     core.handle().spawn(connection.map_err(|_| ()));
+///
+///     // Bring the link with index 2 up, and wait for it to be ready
+///     core.run(handle.link().set(2).up().execute()).unwrap();
+///     let wait = wait_link_oper_up(2, Some(Duration::from_secs(10))).unwrap();
+///     core.run(wait).unwrap();
+/// }
+/// ```
+pub fn wait_link_oper_up(
+    index: u32,
+    timeout: Option<Duration>,
+) -> Result<Wait<Link>, NetlinkIpError> {
+    link_wait(index, timeout, |link| {
+        link.operational_state() == Some(LinkState::Up)
+    })
+}
+
+/// Wait until the link with index `index` has a carrier (`ip link` shows `LOWER_UP`), and return
+/// it. It fails like [`wait_link_oper_up()`](fn.wait_link_oper_up.html).
+pub fn wait_carrier(index: u32, timeout: Option<Duration>) -> Result<Wait<Link>, NetlinkIpError> {
+    link_wait(index, timeout, |link| link.flags().is_lower_up())
+}
+
+/// Return the address of an address message, and its flags
+fn address_flags(address: &AddressMessage) -> (Option<&[u8]>, u32) {
+    let mut ip = None;
+    let mut flags = u32::from(address.header.flags);
+    for nla in &address.nlas {
+        match *nla {
+            // For IPv4, the local address is the address of the link: the other one is the
+            // address of the peer on point-to-point links
+            AddressNla::Local(ref bytes) => ip = Some(&bytes[..]),
+            AddressNla::Address(ref bytes) if ip.is_none() => ip = Some(&bytes[..]),
+            AddressNla::Flags(value) => flags = value,
+            _ => {}
+        }
+    }
+    (ip, flags)
+}
+
+/// Return the address of an address message about the address `ip`, if it is usable
+fn ready_address(
+    message: &NetlinkMessage,
+    ip: &[u8],
+) -> Result<Option<AddressMessage>, NetlinkIpError> {
+    let address = match *message.message() {
+        RtnlMessage::NewAddress(ref address) => address,
+        _ => return Ok(None),
+    };
+    let (address_ip, flags) = address_flags(address);
+    if address_ip != Some(ip) {
+        return Ok(None);
+    }
+    if flags & IFA_F_DADFAILED != 0 {
+        return Err(NetlinkIpError::DadFailed);
+    }
+    if flags & IFA_F_TENTATIVE != 0 {
+        return Ok(None);
+    }
+    Ok(Some(address.clone()))
+}
+
+/// Wait until the address `address` is configured on a link and usable, and return it. IPv6
+/// addresses are not usable while the kernel checks that no other host uses them (duplicate
+/// address detection): they are tentative until the check completes. IPv4 addresses are usable
+/// as soon as they are added.
+///
+/// The future fails with [`NetlinkIpError::DadFailed`](enum.NetlinkIpError.html#variant.DadFailed)
+/// if another host uses the address, and with
+/// [`NetlinkIpError::Timeout`](enum.NetlinkIpError.html#variant.Timeout) if the address is not
+/// usable after `timeout`.
+pub fn wait_address_ready(
+    address: IpAddr,
+    timeout: Option<Duration>,
+) -> Result<Wait<AddressMessage>, NetlinkIpError> {
+    let (family, group, bytes) = match address {
+        IpAddr::V4(address) => (
+            libc::AF_INET,
+            RTNLGRP_IPV4_IFADDR,
+            address.octets().to_vec(),
+        ),
+        IpAddr::V6(address) => (
+            libc::AF_INET6,
+            RTNLGRP_IPV6_IFADDR,
+            address.octets().to_vec(),
+        ),
+    };
+    let mut message = AddressMessage::default();
+    message.header.family = family as u8;
+    let mut request = NetlinkMessage::from(RtnlMessage::GetAddress(message));
+    request.header_mut().set_flags(*ADDRESS_FLAGS);
+    let condition = move |message: &NetlinkMessage| ready_address(message, &bytes);
+    Wait::new(&[group], request, Box::new(condition), timeout)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rtnetlink::constants::{AF_BRIDGE, AF_INET, AF_INET6, IFA_F_STABLE_PRIVACY};
+    use rtnetlink::LinkNla;
+
+    fn link_message(index: u32) -> LinkMessage {
+        let mut message = LinkMessage::new();
+        message.header_mut().set_index(index);
+        message.append_nla(LinkNla::IfName("eth0".into()));
+        message
+    }
+
+    fn ipv4_address(nlas: Vec<AddressNla>) -> AddressMessage {
+        let mut message = AddressMessage::default();
+        message.header.family = AF_INET as u8;
+        message.header.prefix_len = 24;
+        message.nlas = nlas;
+        message
+    }
+
+    fn ipv6_address(ip: &[u8], flags: u32) -> AddressMessage {
+        let mut message = AddressMessage::default();
+        message.header.family = AF_INET6 as u8;
+        message.header.prefix_len = 64;
+        message.nlas.push(AddressNla::Address(ip.to_vec()));
+        message.nlas.push(AddressNla::Flags(flags));
+        message
+    }
+
+    #[test]
+    fn notified_link_matches_index() {
+        let message = NetlinkMessage::from(RtnlMessage::NewLink(link_message(2)));
+        let link = notified_link(&message, 2).unwrap().unwrap();
+        assert_eq!(link.index(), 2);
+        assert_eq!(link.name(), Some("eth0"));
+
+        // Notifications about other links are ignored
+        assert!(notified_link(&message, 3).unwrap().is_none());
+        let message = NetlinkMessage::from(RtnlMessage::DelLink(link_message(3)));
+        assert!(notified_link(&message, 2).unwrap().is_none());
+        let message = NetlinkMessage::from(RtnlMessage::NewAddress(AddressMessage::default()));
+        assert!(notified_link(&message, 2).unwrap().is_none());
+    }
+
+    #[test]
+    fn notified_link_ignores_bridge_ports() {
+        let mut port = link_message(2);
+        port.header_mut().set_address_family(AF_BRIDGE as u8);
+        let message = NetlinkMessage::from(RtnlMessage::NewLink(port.clone()));
+        assert!(notified_link(&message, 2).unwrap().is_none());
+        // The port leaving the bridge does not mean that the link is deleted
+        let message = NetlinkMessage::from(RtnlMessage::DelLink(port));
+        assert!(notified_link(&message, 2).unwrap().is_none());
+    }
+
+    #[test]
+    fn notified_link_deleted() {
+        let message = NetlinkMessage::from(RtnlMessage::DelLink(link_message(2)));
+        match notified_link(&message, 2) {
+            Err(NetlinkIpError::LinkNotFound) => {}
+            result => panic!("unexpected result: {:?}", result),
+        }
+    }
+
+    #[test]
+    fn address_flags_prefers_ipv4_local() {
+        let local = vec![10, 0, 0, 1];
+        let peer = vec![10, 0, 0, 2];
+        // The local address is the address of the link, whatever the order of the attributes
+        let address = ipv4_address(vec![
+            AddressNla::Address(peer.clone()),
+            AddressNla::Local(local.clone()),
+        ]);
+        assert_eq!(address_flags(&address).0, Some(&local[..]));
+        let address = ipv4_address(vec![
+            AddressNla::Local(local.clone()),
+            AddressNla::Address(peer.clone()),
+        ]);
+        assert_eq!(address_flags(&address).0, Some(&local[..]));
+        // Without a local address, the address is used
+        let address = ipv4_address(vec![AddressNla::Address(peer.clone())]);
+        assert_eq!(address_flags(&address).0, Some(&peer[..]));
+        assert_eq!(address_flags(&ipv4_address(vec![])).0, None);
+    }
+
+    #[test]
+    fn address_flags_attribute() {
+        // The flags of the header only hold the first 8 flags: IFA_FLAGS has all of them
+        let mut address = ipv4_address(vec![AddressNla::Local(vec![10, 0, 0, 1])]);
+        address.header.flags = IFA_F_TENTATIVE as u8;
+        assert_eq!(address_flags(&address).1, IFA_F_TENTATIVE);
+        address.nlas.push(AddressNla::Flags(IFA_F_STABLE_PRIVACY));
+        assert_eq!(address_flags(&address).1, IFA_F_STABLE_PRIVACY);
+    }
+
+    #[test]
+    fn ready_address_flags() {
+        let ip = [0xfe, 0x80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1];
+        let message =
+            |flags| NetlinkMessage::from(RtnlMessage::NewAddress(ipv6_address(&ip, flags)));
+
+        assert!(ready_address(&message(0), &ip).unwrap().is_some());
+        // Tentative addresses are not usable yet
+        assert!(ready_address(&message(IFA_F_TENTATIVE), &ip)
+            .unwrap()
+            .is_none());
+        match ready_address(&message(IFA_F_TENTATIVE | IFA_F_DADFAILED), &ip) {
+            Err(NetlinkIpError::DadFailed) => {}
+            result => panic!("unexpected result: {:?}", result),
+        }
+        // Other addresses are ignored, even when their duplicate address detection fails
+        let other = [0xfe, 0x80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2];
+        assert!(ready_address(&message(IFA_F_DADFAILED), &other)
+            .unwrap()
+            .is_none());
+    }
+}
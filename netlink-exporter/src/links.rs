@@ -0,0 +1,108 @@
+use std::collections::HashMap;
+
+use iproute2::Link;
+use rtnetlink::{LinkInfo, LinkNla, LinkState};
+
+/// What we know about a link, from the initial dump and the `RTM_NEWLINK` notifications
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LinkEntry {
+    pub name: String,
+    pub kind: String,
+    pub master: Option<u32>,
+    pub state: Option<LinkState>,
+    pub carrier_changes: Option<u32>,
+}
+
+impl LinkEntry {
+    pub fn from_link(link: &Link) -> Self {
+        let mut entry = LinkEntry {
+            name: link.name().unwrap_or("").to_string(),
+            kind: String::new(),
+            master: link.master_index(),
+            state: link.operational_state(),
+            carrier_changes: None,
+        };
+        for nla in link.attributes() {
+            match *nla {
+                LinkNla::CarrierChanges(value) => entry.carrier_changes = Some(value),
+                LinkNla::LinkInfo(ref infos) => {
+                    for info in infos {
+                        if let LinkInfo::Kind(ref kind) = *info {
+                            entry.kind = kind.as_str().to_string();
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+        entry
+    }
+}
+
+/// Labels attached to the metrics of a link
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LinkLabels {
+    pub index: u32,
+    pub name: String,
+    pub kind: String,
+    pub master: String,
+}
+
+/// The links of the system, kept up to date by the link notifications
+#[derive(Clone, Debug, Default)]
+pub struct LinkCache {
+    links: HashMap<u32, LinkEntry>,
+}
+
+impl LinkCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add or replace a link
+    pub fn update(&mut self, link: &Link) {
+        let mut entry = LinkEntry::from_link(link);
+        // Some notifications (for instance when a link is enslaved) don't carry the kind of the
+        // link, which never changes anyway.
+        if entry.kind.is_empty() {
+            if let Some(previous) = self.links.get(&link.index()) {
+                entry.kind = previous.kind.clone();
+            }
+        }
+        self.links.insert(link.index(), entry);
+    }
+
+    pub fn remove(&mut self, index: u32) {
+        self.links.remove(&index);
+    }
+
+    pub fn get(&self, index: u32) -> Option<&LinkEntry> {
+        self.links.get(&index)
+    }
+
+    /// Indices of the links, in ascending order
+    pub fn indices(&self) -> Vec<u32> {
+        let mut indices: Vec<u32> = self.links.keys().cloned().collect();
+        indices.sort();
+        indices
+    }
+
+    /// Labels of the link with index `index`. Links we don't know about (for instance because
+    /// they were created after a notification got lost) only get an `ifindex` label.
+    pub fn labels(&self, index: u32) -> LinkLabels {
+        let mut labels = LinkLabels {
+            index,
+            name: String::new(),
+            kind: String::new(),
+            master: String::new(),
+        };
+        if let Some(entry) = self.links.get(&index) {
+            labels.name = entry.name.clone();
+            labels.kind = entry.kind.clone();
+            if let Some(master) = entry.master.and_then(|master| self.links.get(&master)) {
+                labels.master = master.name.clone();
+            }
+        }
+        labels
+    }
+}
@@ -0,0 +1,155 @@
+//! Export the counters of the links, their operational state, and the number of addresses and
+//! routes in the prometheus text exposition format.
+//!
+//! ```text
+//! netlink-exporter [--listen ADDRESS:PORT]
+//! ```
+//!
+//! The metrics are served on `http://127.0.0.1:9417/metrics` by default. The counters, addresses
+//! and routes are dumped at each scrape, while the links (and thus the `ifname`, `kind` and
+//! `master` labels) are dumped once at startup and then kept up to date with the link
+//! notifications. They are dumped again when notifications are lost. To try it locally:
+//!
+//! ```text
+//! cargo run -p netlink-exporter &
+//! ip link add br0 type bridge
+//! ip link add veth0 type veth peer name veth1
+//! ip link set veth0 master br0 up
+//! curl -s 127.0.0.1:9417/metrics | grep 'veth0'
+//! ```
+
+extern crate futures;
+extern crate iproute2;
+extern crate rtnetlink;
+extern crate tokio_core;
+extern crate tokio_io;
+
+mod links;
+mod metrics;
+mod server;
+
+use std::cell::RefCell;
+use std::env;
+use std::net::SocketAddr;
+use std::process;
+use std::rc::Rc;
+
+use futures::future::{self, Either};
+use futures::{Future, Stream};
+use tokio_core::reactor::Core;
+
+use iproute2::{new_connection, new_multicast_connection, Link, NetlinkIpError};
+use rtnetlink::constants::RTNLGRP_LINK;
+use rtnetlink::RtnlMessage;
+
+use links::LinkCache;
+
+const DEFAULT_LISTEN_ADDRESS: &str = "127.0.0.1:9417";
+
+fn usage() -> ! {
+    eprintln!("usage: netlink-exporter [--listen ADDRESS:PORT]");
+    process::exit(1);
+}
+
+fn parse_args() -> SocketAddr {
+    let mut listen = DEFAULT_LISTEN_ADDRESS.to_string();
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--listen" | "-l" => listen = args.next().unwrap_or_else(|| usage()),
+            _ => usage(),
+        }
+    }
+    listen.parse().unwrap_or_else(|_| {
+        eprintln!("invalid listen address: {}", listen);
+        process::exit(1);
+    })
+}
+
+fn main() {
+    let addr = parse_args();
+
+    let mut core = Core::new().unwrap();
+    let reactor = core.handle();
+
+    // Subscribe to the link notifications before dumping the links, so that no change is missed
+    // between the dump and the subscription.
+    let (connection, handle, notifications) = new_multicast_connection(&[RTNLGRP_LINK]).unwrap();
+    reactor.spawn(connection.map_err(|e| eprintln!("netlink connection failed: {}", e)));
+
+    let cache = Rc::new(RefCell::new(LinkCache::new()));
+    let links = core.run(handle.link().get().execute()).unwrap();
+    for link in &links {
+        cache.borrow_mut().update(link);
+    }
+
+    let events_cache = Rc::clone(&cache);
+    reactor.spawn(notifications.for_each(move |msg| {
+        // Notifications were lost because the socket receive buffer overflowed: dump the links
+        // again. The next notifications are only handled once the dump completes.
+        if msg.is_overrun() {
+            eprintln!("link notifications were lost, dumping the links again");
+            let cache = Rc::clone(&events_cache);
+            return Either::A(handle.link().get().execute().then(move |result| {
+                match result {
+                    Ok(links) => {
+                        let mut fresh = LinkCache::new();
+                        for link in &links {
+                            fresh.update(link);
+                        }
+                        *cache.borrow_mut() = fresh;
+                    }
+                    Err(e) => eprintln!("failed to dump the links: {}", e),
+                }
+                Ok(())
+            }));
+        }
+        let mut cache = events_cache.borrow_mut();
+        match msg.into_parts().1 {
+            RtnlMessage::NewLink(message) => match Link::from_link_message(message) {
+                Ok(link) => cache.update(&link),
+                Err(e) => eprintln!("invalid link notification: {}", e),
+            },
+            RtnlMessage::DelLink(message) => cache.remove(message.header().index()),
+            _ => {}
+        }
+        Either::B(future::ok(()))
+    }));
+
+    // The kernel only runs one dump at a time per netlink socket, so each scrape uses its own
+    // connection and runs the dumps one after the other. The connection completes once the scrape
+    // is done and its handle is dropped.
+    let scrape_reactor = reactor.clone();
+    let scrape = move || -> server::ScrapeFuture {
+        let (connection, handle) = match new_connection() {
+            Ok(connection) => connection,
+            Err(e) => return Box::new(future::err(NetlinkIpError::Io(e))),
+        };
+        scrape_reactor.spawn(connection.map_err(|e| eprintln!("netlink connection failed: {}", e)));
+        let cache = Rc::clone(&cache);
+        // Requests are sent as soon as they are created, so only create a request once the
+        // previous dump is done.
+        let stats = handle.stats().get().execute();
+        let addresses_handle = handle.clone();
+        Box::new(
+            stats
+                .and_then(move |stats| {
+                    let addresses = addresses_handle.address().get().execute();
+                    addresses.map(move |addresses| (stats, addresses))
+                })
+                .and_then(move |(stats, addresses)| {
+                    let routes = handle.route().get().execute();
+                    routes.map(move |routes| (stats, addresses, routes))
+                })
+                .map(move |(stats, addresses, routes)| {
+                    metrics::render(&cache.borrow(), &stats, &addresses, &routes)
+                }),
+        )
+    };
+    let server = server::serve(&addr, &reactor, scrape).unwrap_or_else(|e| {
+        eprintln!("failed to listen on {}: {}", addr, e);
+        process::exit(1);
+    });
+    eprintln!("serving metrics on http://{}/metrics", addr);
+    core.run(server).unwrap();
+}
@@ -0,0 +1,262 @@
+use std::collections::BTreeMap;
+use std::fmt::{Display, Write};
+
+use iproute2::{route_table, LinkStatistics};
+use rtnetlink::constants::{AF_INET, AF_INET6};
+use rtnetlink::{AddressMessage, LinkState, RouteMessage};
+
+use links::{LinkCache, LinkLabels};
+
+/// Escape a label value, as required by the text exposition format
+fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '"' => escaped.push_str("\\\""),
+            '\n' => escaped.push_str("\\n"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn family_name(family: u8) -> String {
+    match u16::from(family) {
+        AF_INET => "inet".to_string(),
+        AF_INET6 => "inet6".to_string(),
+        other => other.to_string(),
+    }
+}
+
+/// Writes metrics in the prometheus text exposition format (version 0.0.4)
+#[derive(Debug, Default)]
+pub struct MetricsWriter {
+    output: String,
+}
+
+impl MetricsWriter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start a new metric family. All the samples of a family must be written right after it.
+    pub fn family(&mut self, name: &str, kind: &str, help: &str) {
+        let _ = writeln!(self.output, "# HELP {} {}", name, help);
+        let _ = writeln!(self.output, "# TYPE {} {}", name, kind);
+    }
+
+    pub fn sample<V: Display>(&mut self, name: &str, labels: &[(&str, &str)], value: V) {
+        self.output.push_str(name);
+        if !labels.is_empty() {
+            self.output.push('{');
+            for (i, &(label, label_value)) in labels.iter().enumerate() {
+                if i != 0 {
+                    self.output.push(',');
+                }
+                let _ = write!(self.output, "{}=\"{}\"", label, escape(label_value));
+            }
+            self.output.push('}');
+        }
+        let _ = writeln!(self.output, " {}", value);
+    }
+
+    fn link_sample<V: Display>(&mut self, name: &str, labels: &LinkLabels, value: V) {
+        let index = labels.index.to_string();
+        self.sample(
+            name,
+            &[
+                ("ifindex", &index),
+                ("ifname", &labels.name),
+                ("kind", &labels.kind),
+                ("master", &labels.master),
+            ],
+            value,
+        );
+    }
+
+    pub fn into_string(self) -> String {
+        self.output
+    }
+}
+
+/// Render the metrics of a scrape
+pub fn render(
+    links: &LinkCache,
+    stats: &[LinkStatistics],
+    addresses: &[AddressMessage],
+    routes: &[RouteMessage],
+) -> String {
+    let mut writer = MetricsWriter::new();
+    let indices = links.indices();
+
+    writer.family(
+        "netlink_link_info",
+        "gauge",
+        "Links of the system. The value is always 1.",
+    );
+    for index in &indices {
+        writer.link_sample("netlink_link_info", &links.labels(*index), 1);
+    }
+
+    writer.family(
+        "netlink_link_oper_up",
+        "gauge",
+        "Whether the operational state of the link is up.",
+    );
+    for index in &indices {
+        let entry = links.get(*index).unwrap();
+        let up = u8::from(entry.state == Some(LinkState::Up));
+        writer.link_sample("netlink_link_oper_up", &links.labels(*index), up);
+    }
+
+    writer.family(
+        "netlink_link_carrier_changes_total",
+        "counter",
+        "Number of times the carrier of the link changed.",
+    );
+    for index in &indices {
+        if let Some(changes) = links.get(*index).unwrap().carrier_changes {
+            let labels = links.labels(*index);
+            writer.link_sample("netlink_link_carrier_changes_total", &labels, changes);
+        }
+    }
+
+    macro_rules! counters {
+        ($(($name:expr, $help:expr, $field:ident)),*) => {
+            $(
+                writer.family($name, "counter", $help);
+                for link_stats in stats {
+                    if let Some(counters) = link_stats.counters() {
+                        let labels = links.labels(link_stats.index());
+                        writer.link_sample($name, &labels, counters.$field);
+                    }
+                }
+            )*
+        };
+    }
+    counters!(
+        (
+            "netlink_link_receive_bytes_total",
+            "Number of bytes received by the link.",
+            rx_bytes
+        ),
+        (
+            "netlink_link_transmit_bytes_total",
+            "Number of bytes transmitted by the link.",
+            tx_bytes
+        ),
+        (
+            "netlink_link_receive_packets_total",
+            "Number of packets received by the link.",
+            rx_packets
+        ),
+        (
+            "netlink_link_transmit_packets_total",
+            "Number of packets transmitted by the link.",
+            tx_packets
+        ),
+        (
+            "netlink_link_receive_errors_total",
+            "Number of receive errors of the link.",
+            rx_errors
+        ),
+        (
+            "netlink_link_transmit_errors_total",
+            "Number of transmit errors of the link.",
+            tx_errors
+        ),
+        (
+            "netlink_link_receive_dropped_total",
+            "Number of received packets dropped by the link.",
+            rx_dropped
+        ),
+        (
+            "netlink_link_transmit_dropped_total",
+            "Number of transmitted packets dropped by the link.",
+            tx_dropped
+        )
+    );
+
+    let mut address_counts = BTreeMap::new();
+    for address in addresses {
+        let key = (address.header.index, address.header.family);
+        *address_counts.entry(key).or_insert(0u64) += 1;
+    }
+    writer.family(
+        "netlink_link_addresses",
+        "gauge",
+        "Number of addresses of the link, per address family.",
+    );
+    for (&(index, family), count) in &address_counts {
+        let labels = links.labels(index);
+        let index = index.to_string();
+        let family = family_name(family);
+        writer.sample(
+            "netlink_link_addresses",
+            &[
+                ("ifindex", &index),
+                ("ifname", &labels.name),
+                ("kind", &labels.kind),
+                ("master", &labels.master),
+                ("family", &family),
+            ],
+            count,
+        );
+    }
+
+    let mut route_counts = BTreeMap::new();
+    for route in routes {
+        let key = (route.header.address_family, route_table(route));
+        *route_counts.entry(key).or_insert(0u64) += 1;
+    }
+    writer.family(
+        "netlink_routes",
+        "gauge",
+        "Number of routes, per address family and routing table.",
+    );
+    for (&(family, table), count) in &route_counts {
+        let family = family_name(family);
+        let table = table.to_string();
+        writer.sample(
+            "netlink_routes",
+            &[("family", &family), ("table", &table)],
+            count,
+        );
+    }
+
+    writer.into_string()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn escape_label_values() {
+        assert_eq!(escape("eth0"), "eth0");
+        assert_eq!(escape("a\"b\\c\nd"), "a\\\"b\\\\c\\nd");
+    }
+
+    #[test]
+    fn write_samples() {
+        let mut writer = MetricsWriter::new();
+        writer.family("netlink_routes", "gauge", "Number of routes.");
+        writer.sample("netlink_routes", &[("family", "inet"), ("table", "254")], 3);
+        writer.sample("netlink_up", &[], 1);
+        assert_eq!(
+            writer.into_string(),
+            "# HELP netlink_routes Number of routes.\n\
+             # TYPE netlink_routes gauge\n\
+             netlink_routes{family=\"inet\",table=\"254\"} 3\n\
+             netlink_up 1\n"
+        );
+    }
+
+    #[test]
+    fn family_names() {
+        assert_eq!(family_name(AF_INET as u8), "inet");
+        assert_eq!(family_name(AF_INET6 as u8), "inet6");
+        assert_eq!(family_name(7), "7");
+    }
+}
@@ -0,0 +1,116 @@
+use std::io;
+use std::net::SocketAddr;
+use std::rc::Rc;
+
+use futures::{future, Future, Stream};
+use tokio_core::net::{TcpListener, TcpStream};
+use tokio_core::reactor::Handle;
+use tokio_io::io::{read, write_all};
+
+use iproute2::NetlinkIpError;
+
+pub type ScrapeFuture = Box<dyn Future<Item = String, Error = NetlinkIpError>>;
+
+const INDEX: &str = "<html>\n\
+<head><title>netlink exporter</title></head>\n\
+<body><h1>netlink exporter</h1><p><a href=\"/metrics\">Metrics</a></p></body>\n\
+</html>\n";
+
+/// A minimal HTTP/1.0 server: it reads the request line, answers with a single response and
+/// closes the connection, which is all prometheus needs.
+pub fn serve<F>(
+    addr: &SocketAddr,
+    handle: &Handle,
+    scrape: F,
+) -> io::Result<impl Future<Item = (), Error = io::Error>>
+where
+    F: Fn() -> ScrapeFuture + 'static,
+{
+    let listener = TcpListener::bind(addr, handle)?;
+    let handle = handle.clone();
+    let scrape = Rc::new(scrape);
+    Ok(listener.incoming().for_each(move |(stream, _)| {
+        let scrape = Rc::clone(&scrape);
+        handle.spawn(respond(stream, scrape).map_err(|e| eprintln!("http error: {}", e)));
+        Ok(())
+    }))
+}
+
+fn respond<F>(stream: TcpStream, scrape: Rc<F>) -> impl Future<Item = (), Error = io::Error>
+where
+    F: Fn() -> ScrapeFuture + 'static,
+{
+    read(stream, vec![0; 4096])
+        .and_then(move |(stream, buf, len)| {
+            let response: Box<dyn Future<Item = Vec<u8>, Error = io::Error>> =
+                match request_path(&buf[..len]) {
+                    Some("/metrics") => Box::new(scrape().then(|result| {
+                        Ok(match result {
+                            Ok(body) => response(
+                                "200 OK",
+                                "text/plain; version=0.0.4; charset=utf-8",
+                                &body,
+                            ),
+                            Err(e) => response(
+                                "500 Internal Server Error",
+                                "text/plain; charset=utf-8",
+                                &format!("scrape failed: {}\n", e),
+                            ),
+                        })
+                    })),
+                    Some("/") => Box::new(future::ok(response("200 OK", "text/html", INDEX))),
+                    Some(_) => Box::new(future::ok(response(
+                        "404 Not Found",
+                        "text/plain",
+                        "not found\n",
+                    ))),
+                    None => Box::new(future::ok(response(
+                        "400 Bad Request",
+                        "text/plain",
+                        "bad request\n",
+                    ))),
+                };
+            response.and_then(move |response| write_all(stream, response))
+        })
+        .map(|_| ())
+}
+
+/// Return the path of a `GET` request, without the query string
+fn request_path(request: &[u8]) -> Option<&str> {
+    let line = ::std::str::from_utf8(request).ok()?.lines().next()?;
+    let mut words = line.split_whitespace();
+    if words.next()? != "GET" {
+        return None;
+    }
+    words.next()?.split('?').next()
+}
+
+fn response(status: &str, content_type: &str, body: &str) -> Vec<u8> {
+    format!(
+        "HTTP/1.0 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        content_type,
+        body.len(),
+        body
+    )
+    .into_bytes()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_request_path() {
+        assert_eq!(
+            request_path(b"GET /metrics HTTP/1.1\r\nHost: localhost\r\n\r\n"),
+            Some("/metrics")
+        );
+        assert_eq!(
+            request_path(b"GET /metrics?x=1 HTTP/1.1\r\n"),
+            Some("/metrics")
+        );
+        assert_eq!(request_path(b"POST /metrics HTTP/1.1\r\n"), None);
+        assert_eq!(request_path(b"\xff\xfe"), None);
+    }
+}
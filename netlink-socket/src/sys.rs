@@ -235,6 +235,55 @@ impl Socket {
         Ok(res as usize)
     }
 
+    /// Send each buffer of `bufs` as a separate datagram, with a single `sendmmsg` system call.
+    /// Return the number of datagrams that were sent, which can be less than `bufs.len()`.
+    pub fn send_mmsg_to(
+        &self,
+        bufs: &[&[u8]],
+        addr: &SocketAddr,
+        flags: libc::c_int,
+    ) -> Result<usize> {
+        let (addr_ptr, addr_len) = addr.as_raw();
+        let mut iovecs: Vec<libc::iovec> = bufs
+            .iter()
+            .map(|buf| libc::iovec {
+                iov_base: buf.as_ptr() as *mut libc::c_void,
+                iov_len: buf.len() as libc::size_t,
+            })
+            .collect();
+        let mut headers: Vec<libc::mmsghdr> = iovecs
+            .iter_mut()
+            .map(|iovec| {
+                let mut header: libc::mmsghdr = unsafe { mem::zeroed() };
+                header.msg_hdr.msg_name = addr_ptr as *mut libc::c_void;
+                header.msg_hdr.msg_namelen = addr_len;
+                header.msg_hdr.msg_iov = iovec as *mut libc::iovec;
+                header.msg_hdr.msg_iovlen = 1;
+                header
+            })
+            .collect();
+
+        let res = unsafe {
+            libc::sendmmsg(
+                self.0,
+                headers.as_mut_ptr(),
+                headers.len() as libc::c_uint,
+                flags,
+            )
+        };
+        if res < 0 {
+            return Err(Error::last_os_error());
+        }
+        Ok(res as usize)
+    }
+
+    /// Return the size of the socket send buffer (`SO_SNDBUF`). The kernel rejects the datagrams
+    /// that are larger than this.
+    pub fn get_send_buffer_size(&self) -> Result<usize> {
+        let size: libc::c_int = getsockopt(self.0, libc::SOL_SOCKET, libc::SO_SNDBUF)?;
+        Ok(size as usize)
+    }
+
     pub fn set_pktinfo(&mut self, set: bool) -> Result<()> {
         setsockopt(self.0, libc::SOL_NETLINK, libc::NETLINK_PKTINFO, set)
     }
@@ -309,8 +358,28 @@ impl Socket {
     pub fn get_cap_ack(&self) -> Result<bool> {
         getsockopt(self.0, libc::SOL_NETLINK, libc::NETLINK_CAP_ACK)
     }
+
+    /// Enable or disable strict checking of the requests (`NETLINK_GET_STRICT_CHK`). When strict
+    /// checking is enabled, the kernel rejects malformed requests instead of ignoring the invalid
+    /// fields, and honors the filters of the dump requests. It requires Linux 4.20 or later.
+    pub fn set_strict_checking(&mut self, set: bool) -> Result<()> {
+        setsockopt(
+            self.0,
+            libc::SOL_NETLINK,
+            NETLINK_GET_STRICT_CHK,
+            libc::c_int::from(set),
+        )
+    }
+
+    pub fn get_strict_checking(&self) -> Result<bool> {
+        let value: libc::c_int = getsockopt(self.0, libc::SOL_NETLINK, NETLINK_GET_STRICT_CHK)?;
+        Ok(value != 0)
+    }
 }
 
+// Not exported by all the versions of libc we support
+const NETLINK_GET_STRICT_CHK: libc::c_int = 12;
+
 // FIXME: setsockopt and getsockopt don't work... We get always get EINVAL, which the manpage
 // describes as:
 //
@@ -397,6 +466,35 @@ mod test {
         sock.set_non_blocking(false).unwrap();
     }
 
+    #[test]
+    fn send_buffer_size() {
+        let sock = Socket::new(Protocol::Route).unwrap();
+        assert!(sock.get_send_buffer_size().unwrap() > 0);
+    }
+
+    #[test]
+    fn send_mmsg_to() {
+        let sock = Socket::new(Protocol::Route).unwrap();
+        // Two NLMSG_NOOP headers, without NLM_F_ACK, so the kernel does not answer
+        let noop = [16, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+        let kernel = SocketAddr::new(0, 0);
+        let sent = sock
+            .send_mmsg_to(&[&noop[..], &noop[..]], &kernel, 0)
+            .unwrap();
+        assert_eq!(sent, 2);
+    }
+
+    #[test]
+    fn strict_checking() {
+        let mut sock = Socket::new(Protocol::Route).unwrap();
+        // Older kernels don't support this option
+        if sock.set_strict_checking(true).is_ok() {
+            assert!(sock.get_strict_checking().unwrap());
+            sock.set_strict_checking(false).unwrap();
+            assert!(!sock.get_strict_checking().unwrap());
+        }
+    }
+
     // FIXME!
     // #[test]
     // fn options() {
@@ -31,6 +31,27 @@ impl TokioSocket {
         self.0.get_ref().connect(addr)
     }
 
+    /// Join the multicast group `group`, to receive the notifications sent to that group
+    pub fn add_membership(&mut self, group: u32) -> io::Result<()> {
+        self.0.get_mut().add_membership(group)
+    }
+
+    /// Leave the multicast group `group`
+    pub fn drop_membership(&mut self, group: u32) -> io::Result<()> {
+        self.0.get_mut().drop_membership(group)
+    }
+
+    /// Enable or disable strict checking of the requests. See
+    /// [`Socket::set_strict_checking()`](struct.Socket.html#method.set_strict_checking).
+    pub fn set_strict_checking(&mut self, set: bool) -> io::Result<()> {
+        self.0.get_mut().set_strict_checking(set)
+    }
+
+    /// Return the size of the socket send buffer
+    pub fn get_send_buffer_size(&self) -> io::Result<usize> {
+        self.0.get_ref().get_send_buffer_size()
+    }
+
     pub fn poll_send(&mut self, buf: &[u8]) -> Poll<usize, io::Error> {
         // Check if the socket it writable. If PollEvented::poll_write_ready return NotReady, it
         // will already have arranged for the current task to be notified when the socket becomes
@@ -83,6 +104,25 @@ impl TokioSocket {
         }
     }
 
+    /// Send each buffer of `bufs` as a separate datagram with a single system call. See
+    /// [`Socket::send_mmsg_to()`](struct.Socket.html#method.send_mmsg_to).
+    pub fn poll_send_mmsg_to(
+        &mut self,
+        bufs: &[&[u8]],
+        target: &sys::SocketAddr,
+    ) -> Poll<usize, io::Error> {
+        try_ready!(self.0.poll_write_ready());
+
+        match self.0.get_ref().send_mmsg_to(bufs, target, 0) {
+            Ok(n) => Ok(Async::Ready(n)),
+            Err(ref e) if e.kind() == io::ErrorKind::WouldBlock => {
+                self.0.clear_write_ready()?;
+                Ok(Async::NotReady)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
     pub fn poll_recv_from(&mut self, buf: &mut [u8]) -> Poll<(usize, sys::SocketAddr), io::Error> {
         try_ready!(self.0.poll_read_ready(mio::Ready::readable()));
 
@@ -0,0 +1,307 @@
+use std::collections::HashMap;
+
+use libc;
+
+use rtnetlink::constants::{
+    IFA_F_DADFAILED, IFA_F_DEPRECATED, IFA_F_HOMEADDRESS, IFA_F_MANAGETEMPADDR, IFA_F_MCAUTOJOIN,
+    IFA_F_NODAD, IFA_F_NOPREFIXROUTE, IFA_F_OPTIMISTIC, IFA_F_PERMANENT, IFA_F_SECONDARY,
+    IFA_F_STABLE_PRIVACY, IFA_F_TENTATIVE,
+};
+use rtnetlink::{AddressMessage, AddressNla};
+
+use args::{matches, unknown, Args};
+use json::Json;
+use link::{format_link, link_json};
+use names::{family_name, format_ip, parse_prefix, scope_name};
+use {Context, Error};
+
+const USAGE: &str = "Usage: rip address show [ dev DEV ]
+       rip address { add | del } PREFIX dev DEV";
+
+// Address flags, in the order ip prints them. `IFA_F_PERMANENT` is printed as `dynamic` when it is
+// not set.
+const FLAGS: &[(u32, &str)] = &[
+    (IFA_F_SECONDARY, "secondary"),
+    (IFA_F_NODAD, "nodad"),
+    (IFA_F_OPTIMISTIC, "optimistic"),
+    (IFA_F_DADFAILED, "dadfailed"),
+    (IFA_F_HOMEADDRESS, "home"),
+    (IFA_F_DEPRECATED, "deprecated"),
+    (IFA_F_TENTATIVE, "tentative"),
+    (IFA_F_PERMANENT, "dynamic"),
+    (IFA_F_MANAGETEMPADDR, "mngtmpaddr"),
+    (IFA_F_NOPREFIXROUTE, "noprefixroute"),
+    (IFA_F_MCAUTOJOIN, "autojoin"),
+    (IFA_F_STABLE_PRIVACY, "stable-privacy"),
+];
+
+// Lifetime of the addresses that don't expire
+const INFINITY_LIFE_TIME: u32 = 0xffff_ffff;
+
+/// The attributes of an address message that ip prints
+#[derive(Default)]
+struct AddressInfo<'a> {
+    local: Option<&'a [u8]>,
+    peer: Option<&'a [u8]>,
+    broadcast: Option<&'a [u8]>,
+    label: Option<&'a str>,
+    flags: u32,
+    valid_life_time: Option<u32>,
+    preferred_life_time: Option<u32>,
+}
+
+impl<'a> AddressInfo<'a> {
+    fn new(address: &'a AddressMessage) -> Self {
+        let mut info = AddressInfo {
+            flags: u32::from(address.header.flags),
+            ..Default::default()
+        };
+        let mut ifa_address = None;
+        for nla in &address.nlas {
+            match *nla {
+                AddressNla::Local(ref bytes) => info.local = Some(&bytes[..]),
+                AddressNla::Address(ref bytes) => ifa_address = Some(&bytes[..]),
+                AddressNla::Broadcast(ref bytes) => info.broadcast = Some(&bytes[..]),
+                AddressNla::Label(ref label) => info.label = Some(label),
+                AddressNla::Flags(flags) => info.flags = flags,
+                AddressNla::CacheInfo(ref cache_info) => {
+                    info.valid_life_time = Some(cache_info.ifa_valid as u32);
+                    info.preferred_life_time = Some(cache_info.ifa_preferred as u32);
+                }
+                _ => {}
+            }
+        }
+        // The address attribute is the address of the peer on point-to-point links, and the local
+        // address otherwise
+        match (info.local, ifa_address) {
+            (None, _) => info.local = ifa_address,
+            (Some(local), Some(address)) if local != address => info.peer = Some(address),
+            _ => {}
+        }
+        info
+    }
+
+    fn flag_names(&self) -> Vec<&'static str> {
+        FLAGS
+            .iter()
+            .filter(|&&(flag, _)| {
+                if flag == IFA_F_PERMANENT {
+                    self.flags & flag == 0
+                } else {
+                    self.flags & flag != 0
+                }
+            })
+            .map(|&(flag, name)| {
+                if flag == IFA_F_SECONDARY && !self.is_ipv4() {
+                    "temporary"
+                } else {
+                    name
+                }
+            })
+            .collect()
+    }
+
+    fn is_ipv4(&self) -> bool {
+        self.local.map(<[u8]>::len) == Some(4)
+    }
+}
+
+fn format_life_time(life_time: u32) -> String {
+    if life_time == INFINITY_LIFE_TIME {
+        "forever".to_string()
+    } else {
+        format!("{}sec", life_time)
+    }
+}
+
+/// Format an address like `ip address show`, on two lines
+pub fn format_address(address: &AddressMessage) -> String {
+    let info = AddressInfo::new(address);
+    let mut line = format!("    {}", family_name(address.header.family));
+    if let Some(local) = info.local {
+        line += &format!(" {}", format_ip(local));
+    }
+    if let Some(peer) = info.peer {
+        line += &format!(" peer {}", format_ip(peer));
+    }
+    line += &format!("/{}", address.header.prefix_len);
+    if let Some(broadcast) = info.broadcast {
+        line += &format!(" brd {}", format_ip(broadcast));
+    }
+    line += &format!(" scope {}", scope_name(address.header.scope));
+    for flag in info.flag_names() {
+        line += &format!(" {}", flag);
+    }
+    if let Some(label) = info.label {
+        line += &format!(" {}", label);
+    }
+    if let (Some(valid), Some(preferred)) = (info.valid_life_time, info.preferred_life_time) {
+        line += &format!(
+            "\n       valid_lft {} preferred_lft {}",
+            format_life_time(valid),
+            format_life_time(preferred)
+        );
+    }
+    line
+}
+
+/// Return an address as a JSON object, with the members of the `addr_info` array of
+/// `ip -j address show`
+pub fn address_json(address: &AddressMessage) -> Json {
+    let info = AddressInfo::new(address);
+    let mut json = Json::object();
+    json.insert("family", family_name(address.header.family));
+    if let Some(local) = info.local {
+        json.insert("local", format_ip(local));
+    }
+    if let Some(peer) = info.peer {
+        json.insert("address", format_ip(peer));
+    }
+    json.insert("prefixlen", address.header.prefix_len);
+    if let Some(broadcast) = info.broadcast {
+        json.insert("broadcast", format_ip(broadcast));
+    }
+    json.insert("scope", scope_name(address.header.scope));
+    for flag in info.flag_names() {
+        json.insert(flag, true);
+    }
+    if let Some(label) = info.label {
+        json.insert("label", label);
+    }
+    if let Some(valid) = info.valid_life_time {
+        json.insert("valid_life_time", valid);
+    }
+    if let Some(preferred) = info.preferred_life_time {
+        json.insert("preferred_life_time", preferred);
+    }
+    json
+}
+
+fn show(ctx: &mut Context, mut args: Args) -> Result<(), Error> {
+    let mut device = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "dev" => device = Some(args.value("dev")?),
+            _ if device.is_none() => device = Some(arg),
+            _ => return Err(unknown(&arg)),
+        }
+    }
+
+    let links = ctx.run(ctx.handle.link().get().execute())?;
+    let mut request = ctx.handle.address().get();
+    if ctx.family != 0 {
+        request = request.family(ctx.family);
+    }
+    let addresses = ctx.run(request.execute())?;
+    let names: HashMap<u32, String> = links
+        .iter()
+        .filter_map(|link| link.name().map(|name| (link.index(), name.to_string())))
+        .collect();
+    if let Some(ref device) = device {
+        if !links
+            .iter()
+            .any(|link| link.name() == Some(device.as_str()))
+        {
+            return Err(Error::Message(format!(
+                "Device \"{}\" does not exist.",
+                device
+            )));
+        }
+    }
+
+    let mut json = vec![];
+    for link in &links {
+        if let Some(ref device) = device {
+            if link.name() != Some(device.as_str()) {
+                continue;
+            }
+        }
+        let link_addresses: Vec<&AddressMessage> = addresses
+            .iter()
+            .filter(|address| address.header.index == link.index())
+            .filter(|address| {
+                let family = i32::from(address.header.family);
+                family == libc::AF_INET || family == libc::AF_INET6
+            })
+            .collect();
+        // Like ip, only show the links that have addresses of the requested family
+        if ctx.family != 0 && link_addresses.is_empty() {
+            continue;
+        }
+        if ctx.json {
+            let mut link_json = link_json(link, &names);
+            let addr_info = link_addresses
+                .iter()
+                .map(|address| address_json(address))
+                .collect::<Vec<_>>();
+            link_json.insert("addr_info", addr_info);
+            json.push(link_json);
+        } else {
+            println!("{}", format_link(link, &names));
+            for address in link_addresses {
+                println!("{}", format_address(address));
+            }
+        }
+    }
+    if ctx.json {
+        println!("{}", Json::Array(json));
+    }
+    Ok(())
+}
+
+fn add_or_del(ctx: &mut Context, mut args: Args, add: bool) -> Result<(), Error> {
+    let mut prefix = None;
+    let mut device = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "dev" => device = Some(args.value("dev")?),
+            "local" => prefix = Some(parse_prefix(&args.value("local")?, "local")?),
+            _ if prefix.is_none() => prefix = Some(parse_prefix(&arg, "local")?),
+            _ => return Err(unknown(&arg)),
+        }
+    }
+    let device = device.ok_or_else(|| {
+        Error::Usage("Not enough information: \"dev\" argument is required.".to_string())
+    })?;
+    let (address, prefix_len) = prefix.ok_or_else(|| {
+        Error::Usage("Not enough information: the address is required.".to_string())
+    })?;
+    let index = ctx.link_index(&device)?;
+    if add {
+        ctx.run(
+            ctx.handle
+                .address()
+                .add(index, address, prefix_len)
+                .execute(),
+        )
+    } else {
+        ctx.run(
+            ctx.handle
+                .address()
+                .del(index, address, prefix_len)
+                .execute(),
+        )
+    }
+}
+
+pub fn run(ctx: &mut Context, mut args: Args) -> Result<(), Error> {
+    let command = match args.next() {
+        Some(command) => command,
+        None => return show(ctx, args),
+    };
+    if matches(&command, "show") || matches(&command, "list") || command == "lst" {
+        show(ctx, args)
+    } else if matches(&command, "add") {
+        add_or_del(ctx, args, true)
+    } else if matches(&command, "delete") {
+        add_or_del(ctx, args, false)
+    } else if matches(&command, "help") {
+        println!("{}", USAGE);
+        Ok(())
+    } else {
+        Err(Error::Usage(format!(
+            "Command \"{}\" is unknown, try \"rip address help\".",
+            command
+        )))
+    }
+}
@@ -0,0 +1,60 @@
+use std::collections::VecDeque;
+use std::str::FromStr;
+
+use Error;
+
+/// Return whether `arg` is an abbreviation of `keyword`, like `ip` accepts `a` for `address`
+pub fn matches(arg: &str, keyword: &str) -> bool {
+    !arg.is_empty() && keyword.starts_with(arg)
+}
+
+/// The remaining arguments of the command line
+pub struct Args(VecDeque<String>);
+
+impl Args {
+    pub fn new(args: Vec<String>) -> Self {
+        Args(args.into())
+    }
+
+    pub fn peek(&self) -> Option<&str> {
+        self.0.front().map(String::as_str)
+    }
+
+    pub fn next(&mut self) -> Option<String> {
+        self.0.pop_front()
+    }
+
+    /// Return the value of `keyword`, which is the next argument
+    pub fn value(&mut self, keyword: &str) -> Result<String, Error> {
+        self.next().ok_or_else(|| {
+            Error::Usage(format!(
+                "Command line is not complete: \"{}\" expects a value.",
+                keyword
+            ))
+        })
+    }
+
+    /// Parse the value of `keyword`, which is the next argument
+    pub fn parse<T: FromStr>(&mut self, keyword: &str) -> Result<T, Error> {
+        let value = self.value(keyword)?;
+        parse(&value, keyword)
+    }
+}
+
+/// Parse the value of `keyword`
+pub fn parse<T: FromStr>(value: &str, keyword: &str) -> Result<T, Error> {
+    value.parse().map_err(|_| invalid(value, keyword))
+}
+
+/// Return the error for an invalid value of `keyword`
+pub fn invalid(value: &str, keyword: &str) -> Error {
+    Error::Usage(format!(
+        "Error: argument \"{}\" is wrong: \"{}\" value is invalid",
+        value, keyword
+    ))
+}
+
+/// Return the error for an unexpected argument
+pub fn unknown(arg: &str) -> Error {
+    Error::Usage(format!("Error: unexpected argument \"{}\".", arg))
+}
@@ -0,0 +1,161 @@
+use std::fmt;
+
+/// A JSON value, printed in the compact form `ip -j` uses
+#[derive(Clone, Debug, PartialEq)]
+pub enum Json {
+    Null,
+    Bool(bool),
+    Number(i64),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+impl Json {
+    /// Create an empty object
+    pub fn object() -> Self {
+        Json::Object(vec![])
+    }
+
+    /// Add a member to an object. Members are printed in the order they are added.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is not an object.
+    pub fn insert<T: Into<Json>>(&mut self, key: &str, value: T) -> &mut Self {
+        match *self {
+            Json::Object(ref mut members) => members.push((key.to_string(), value.into())),
+            _ => panic!("cannot insert a member in a JSON value that is not an object"),
+        }
+        self
+    }
+}
+
+impl From<bool> for Json {
+    fn from(value: bool) -> Self {
+        Json::Bool(value)
+    }
+}
+
+impl From<u8> for Json {
+    fn from(value: u8) -> Self {
+        Json::Number(i64::from(value))
+    }
+}
+
+impl From<u16> for Json {
+    fn from(value: u16) -> Self {
+        Json::Number(i64::from(value))
+    }
+}
+
+impl From<u32> for Json {
+    fn from(value: u32) -> Self {
+        Json::Number(i64::from(value))
+    }
+}
+
+impl From<i32> for Json {
+    fn from(value: i32) -> Self {
+        Json::Number(i64::from(value))
+    }
+}
+
+impl<'a> From<&'a str> for Json {
+    fn from(value: &'a str) -> Self {
+        Json::String(value.to_string())
+    }
+}
+
+impl From<String> for Json {
+    fn from(value: String) -> Self {
+        Json::String(value)
+    }
+}
+
+impl From<Vec<Json>> for Json {
+    fn from(value: Vec<Json>) -> Self {
+        Json::Array(value)
+    }
+}
+
+fn write_string(f: &mut fmt::Formatter, value: &str) -> fmt::Result {
+    f.write_str("\"")?;
+    for c in value.chars() {
+        match c {
+            '"' => f.write_str("\\\"")?,
+            '\\' => f.write_str("\\\\")?,
+            '\n' => f.write_str("\\n")?,
+            '\r' => f.write_str("\\r")?,
+            '\t' => f.write_str("\\t")?,
+            c if (c as u32) < 0x20 => write!(f, "\\u{:04x}", c as u32)?,
+            c => write!(f, "{}", c)?,
+        }
+    }
+    f.write_str("\"")
+}
+
+impl fmt::Display for Json {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Json::Null => f.write_str("null"),
+            Json::Bool(value) => write!(f, "{}", value),
+            Json::Number(value) => write!(f, "{}", value),
+            Json::String(ref value) => write_string(f, value),
+            Json::Array(ref values) => {
+                f.write_str("[")?;
+                for (i, value) in values.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(",")?;
+                    }
+                    write!(f, "{}", value)?;
+                }
+                f.write_str("]")
+            }
+            Json::Object(ref members) => {
+                f.write_str("{")?;
+                for (i, (key, value)) in members.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(",")?;
+                    }
+                    write_string(f, key)?;
+                    write!(f, ":{}", value)?;
+                }
+                f.write_str("}")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn format_values() {
+        assert_eq!(Json::Null.to_string(), "null");
+        assert_eq!(Json::from(true).to_string(), "true");
+        assert_eq!(Json::from(42u32).to_string(), "42");
+        assert_eq!(Json::from(-1i32).to_string(), "-1");
+        assert_eq!(Json::from("eth0").to_string(), "\"eth0\"");
+    }
+
+    #[test]
+    fn escape_strings() {
+        let value = Json::from("a \"quoted\" \\ name\n\u{1}");
+        assert_eq!(value.to_string(), r#""a \"quoted\" \\ name\n\u0001""#);
+    }
+
+    #[test]
+    fn format_nested_values() {
+        let mut link = Json::object();
+        link.insert("ifindex", 1u32)
+            .insert("ifname", "lo")
+            .insert("flags", vec![Json::from("LOOPBACK"), Json::from("UP")]);
+        let value = Json::from(vec![link, Json::object()]);
+        assert_eq!(
+            value.to_string(),
+            r#"[{"ifindex":1,"ifname":"lo","flags":["LOOPBACK","UP"]},{}]"#
+        );
+    }
+}
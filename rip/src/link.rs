@@ -0,0 +1,430 @@
+use std::collections::HashMap;
+
+use eui48::MacAddress;
+use rtnetlink::constants::{
+    IFF_ALLMULTI, IFF_AUTOMEDIA, IFF_BROADCAST, IFF_DEBUG, IFF_DORMANT, IFF_DYNAMIC, IFF_ECHO,
+    IFF_LOOPBACK, IFF_LOWER_UP, IFF_MASTER, IFF_MULTICAST, IFF_NOARP, IFF_NOTRAILERS,
+    IFF_POINTOPOINT, IFF_PORTSEL, IFF_PROMISC, IFF_RUNNING, IFF_SLAVE, IFF_UP, MACVLAN_MODE_BRIDGE,
+    MACVLAN_MODE_PASSTHRU, MACVLAN_MODE_PRIVATE, MACVLAN_MODE_SOURCE, MACVLAN_MODE_VEPA,
+};
+
+use rtnetlink::{LinkFlags, LinkNla};
+
+use iproute2::{BridgeOptions, Link};
+
+use args::{invalid, matches, unknown, Args};
+use json::Json;
+use names::{format_ip, link_type_name, oper_state_name, parse_mac};
+use {Context, Error};
+
+const USAGE: &str = "Usage: rip link show [ [ dev ] DEV ]
+       rip link add [ link DEV ] [ name ] NAME [ mtu MTU ] [ address LLADDR ] type TYPE [ ARGS ]
+       rip link set [ dev ] DEV { up | down | mtu MTU | name NAME | address LLADDR |
+                                  master DEV | nomaster } ...
+       rip link del [ dev ] DEV
+TYPE := { dummy | bridge | veth peer [ name ] NAME | vlan id ID |
+          macvlan mode { private | vepa | bridge | passthru | source } | vrf table TABLE }";
+
+// Link flags, in the order ip prints them
+const FLAGS: &[(u32, &str)] = &[
+    (IFF_LOOPBACK, "LOOPBACK"),
+    (IFF_BROADCAST, "BROADCAST"),
+    (IFF_POINTOPOINT, "POINTOPOINT"),
+    (IFF_MULTICAST, "MULTICAST"),
+    (IFF_NOARP, "NOARP"),
+    (IFF_ALLMULTI, "ALLMULTI"),
+    (IFF_PROMISC, "PROMISC"),
+    (IFF_MASTER, "MASTER"),
+    (IFF_SLAVE, "SLAVE"),
+    (IFF_DEBUG, "DEBUG"),
+    (IFF_DYNAMIC, "DYNAMIC"),
+    (IFF_AUTOMEDIA, "AUTOMEDIA"),
+    (IFF_PORTSEL, "PORTSEL"),
+    (IFF_NOTRAILERS, "NOTRAILERS"),
+    (IFF_UP, "UP"),
+    (IFF_LOWER_UP, "LOWER_UP"),
+    (IFF_DORMANT, "DORMANT"),
+    (IFF_ECHO, "ECHO"),
+];
+
+fn flag_names(flags: LinkFlags) -> Vec<&'static str> {
+    let mut names = vec![];
+    if flags.0 & IFF_UP != 0 && flags.0 & IFF_RUNNING == 0 {
+        names.push("NO-CARRIER");
+    }
+    names.extend(
+        FLAGS
+            .iter()
+            .filter(|&&(flag, _)| flags.0 & flag != 0)
+            .map(|&(_, name)| name),
+    );
+    names
+}
+
+/// Return the name of a link, or `ifINDEX` if the link is unknown, like ip does
+pub fn link_name(names: &HashMap<u32, String>, index: u32) -> String {
+    names
+        .get(&index)
+        .cloned()
+        .unwrap_or_else(|| format!("if{}", index))
+}
+
+fn addresses(link: &Link) -> (Option<&[u8]>, Option<&[u8]>) {
+    let broadcast = link.attributes().iter().find_map(|nla| match *nla {
+        LinkNla::Broadcast(ref bytes) => Some(&bytes[..]),
+        _ => None,
+    });
+    (link.address().map(MacAddress::as_bytes), broadcast)
+}
+
+fn qdisc(link: &Link) -> Option<&str> {
+    link.attributes().iter().find_map(|nla| match *nla {
+        LinkNla::Qdisc(ref qdisc) => Some(qdisc.as_str()),
+        _ => None,
+    })
+}
+
+/// Return the name of the parent of a link, like the peer of a veth link. Like ip, links whose
+/// parent is in another namespace are shown with the index of the parent.
+fn parent(link: &Link, names: &HashMap<u32, String>) -> Option<String> {
+    let index = link.parent_index().or_else(|| {
+        link.attributes().iter().find_map(|nla| match *nla {
+            LinkNla::Link(index) => Some(index),
+            _ => None,
+        })
+    })?;
+    if index == 0 {
+        Some("NONE".to_string())
+    } else if link.attributes().iter().any(|nla| match *nla {
+        LinkNla::LinkNetnsId(_) => true,
+        _ => false,
+    }) {
+        Some(format!("if{}", index))
+    } else {
+        Some(link_name(names, index))
+    }
+}
+
+/// Format a link like `ip link show`, on two lines
+pub fn format_link(link: &Link, names: &HashMap<u32, String>) -> String {
+    let mut line = format!("{}: {}", link.index(), link.name().unwrap_or(""));
+    if let Some(parent) = parent(link, names) {
+        line += &format!("@{}", parent);
+    }
+    line += &format!(": <{}>", flag_names(link.flags()).join(","));
+    if let Some(mtu) = link.mtu() {
+        line += &format!(" mtu {}", mtu);
+    }
+    if let Some(qdisc) = qdisc(link) {
+        line += &format!(" qdisc {}", qdisc);
+    }
+    if let Some(master) = link.master_index() {
+        line += &format!(" master {}", link_name(names, master));
+    }
+    if let Some(state) = link.operational_state() {
+        line += &format!(" state {}", oper_state_name(state));
+    }
+    if let Some(qlen) = link.tx_queue_length() {
+        line += &format!(" qlen {}", qlen);
+    }
+    line += &format!("\n    link/{}", link_type_name(link.link_layer_type()));
+    let (address, broadcast) = addresses(link);
+    if let Some(address) = address {
+        line += &format!(" {}", format_ip(address));
+        if let Some(broadcast) = broadcast {
+            line += &format!(" brd {}", format_ip(broadcast));
+        }
+    }
+    if let Some(alias) = link.alias() {
+        line += &format!("\n    alias {}", alias);
+    }
+    line
+}
+
+/// Return a link as a JSON object, with the members of `ip -j link show`
+pub fn link_json(link: &Link, names: &HashMap<u32, String>) -> Json {
+    let mut json = Json::object();
+    json.insert("ifindex", link.index());
+    if let Some(parent) = parent(link, names) {
+        json.insert("link", parent);
+    }
+    json.insert("ifname", link.name().unwrap_or(""));
+    let flags = flag_names(link.flags())
+        .into_iter()
+        .map(Json::from)
+        .collect::<Vec<_>>();
+    json.insert("flags", flags);
+    if let Some(mtu) = link.mtu() {
+        json.insert("mtu", mtu);
+    }
+    if let Some(qdisc) = qdisc(link) {
+        json.insert("qdisc", qdisc);
+    }
+    if let Some(master) = link.master_index() {
+        json.insert("master", link_name(names, master));
+    }
+    if let Some(state) = link.operational_state() {
+        json.insert("operstate", oper_state_name(state));
+    }
+    if let Some(qlen) = link.tx_queue_length() {
+        json.insert("txqlen", qlen);
+    }
+    json.insert("link_type", link_type_name(link.link_layer_type()));
+    let (address, broadcast) = addresses(link);
+    if let Some(address) = address {
+        json.insert("address", format_ip(address));
+    }
+    if let Some(broadcast) = broadcast {
+        json.insert("broadcast", format_ip(broadcast));
+    }
+    if let Some(alias) = link.alias() {
+        json.insert("ifalias", alias);
+    }
+    json
+}
+
+/// Parse `[ dev ] DEV`
+fn device(args: &mut Args) -> Result<String, Error> {
+    let device = args.value("dev")?;
+    if device == "dev" {
+        args.value("dev")
+    } else {
+        Ok(device)
+    }
+}
+
+fn show(ctx: &mut Context, mut args: Args) -> Result<(), Error> {
+    let name = match args.peek() {
+        Some(_) => Some(device(&mut args)?),
+        None => None,
+    };
+    if let Some(arg) = args.next() {
+        return Err(unknown(&arg));
+    }
+
+    let links = ctx.run(ctx.handle.link().get().execute())?;
+    let names = links
+        .iter()
+        .filter_map(|link| link.name().map(|name| (link.index(), name.to_string())))
+        .collect();
+    let links: Vec<Link> = match name {
+        Some(name) => {
+            let links: Vec<Link> = links
+                .into_iter()
+                .filter(|link| link.name() == Some(name.as_str()))
+                .collect();
+            if links.is_empty() {
+                return Err(Error::Message(format!(
+                    "Device \"{}\" does not exist.",
+                    name
+                )));
+            }
+            links
+        }
+        None => links,
+    };
+
+    if ctx.json {
+        let links = links.iter().map(|link| link_json(link, &names)).collect();
+        println!("{}", Json::Array(links));
+    } else {
+        for link in &links {
+            println!("{}", format_link(link, &names));
+        }
+    }
+    Ok(())
+}
+
+fn macvlan_mode(value: &str) -> Result<u32, Error> {
+    match value {
+        "private" => Ok(MACVLAN_MODE_PRIVATE),
+        "vepa" => Ok(MACVLAN_MODE_VEPA),
+        "bridge" => Ok(MACVLAN_MODE_BRIDGE),
+        "passthru" => Ok(MACVLAN_MODE_PASSTHRU),
+        "source" => Ok(MACVLAN_MODE_SOURCE),
+        _ => Err(invalid(value, "mode")),
+    }
+}
+
+fn add(ctx: &mut Context, mut args: Args) -> Result<(), Error> {
+    let mut parent = None;
+    let mut name = None;
+    let mut mtu = None;
+    let mut address = None;
+    let mut kind = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "link" => parent = Some(args.value("link")?),
+            "name" | "dev" => name = Some(args.value("name")?),
+            "mtu" => mtu = Some(args.parse::<u32>("mtu")?),
+            "address" => address = Some(parse_mac(&args.value("address")?, "address")?),
+            "type" => {
+                kind = Some(args.value("type")?);
+                // The remaining arguments are specific to the type
+                break;
+            }
+            _ if name.is_none() => name = Some(arg),
+            _ => return Err(unknown(&arg)),
+        }
+    }
+    let name = name.ok_or_else(|| {
+        Error::Usage("Not enough information: \"dev\" argument is required.".to_string())
+    })?;
+    let kind = kind.ok_or_else(|| {
+        Error::Usage("Not enough information: \"type\" argument is required.".to_string())
+    })?;
+    let parent = match parent {
+        Some(parent) => Some(ctx.link_index(&parent)?),
+        None => None,
+    };
+    let require_parent = || {
+        parent.ok_or_else(|| {
+            Error::Usage(format!(
+                "Not enough information: \"link\" argument is required for {} links.",
+                kind
+            ))
+        })
+    };
+
+    let request = ctx.handle.link().add();
+    let mut request = match kind.as_str() {
+        "dummy" => request.dummy(name),
+        "bridge" => request.bridge(name, BridgeOptions::new()),
+        "veth" => {
+            match args.next() {
+                Some(ref arg) if arg == "peer" => {}
+                Some(arg) => return Err(unknown(&arg)),
+                None => {
+                    return Err(Error::Usage(
+                        "Not enough information: \"peer\" argument is required.".to_string(),
+                    ))
+                }
+            }
+            let peer = match args.value("peer")? {
+                ref arg if arg == "name" => args.value("name")?,
+                arg => arg,
+            };
+            request.veth(name, peer)
+        }
+        "vlan" => {
+            let parent = require_parent()?;
+            match args.next() {
+                Some(ref arg) if arg == "id" => {}
+                Some(arg) => return Err(unknown(&arg)),
+                None => {
+                    return Err(Error::Usage(
+                        "Not enough information: \"id\" argument is required.".to_string(),
+                    ))
+                }
+            }
+            let id = args.parse::<u16>("id")?;
+            request.vlan(name, parent, id)
+        }
+        "macvlan" => {
+            let parent = require_parent()?;
+            let mode = match args.next() {
+                Some(ref arg) if arg == "mode" => macvlan_mode(&args.value("mode")?)?,
+                Some(arg) => return Err(unknown(&arg)),
+                None => MACVLAN_MODE_VEPA,
+            };
+            request.macvlan(name, parent, mode)
+        }
+        "vrf" => {
+            match args.next() {
+                Some(ref arg) if arg == "table" => {}
+                Some(arg) => return Err(unknown(&arg)),
+                None => {
+                    return Err(Error::Usage(
+                        "Not enough information: \"table\" argument is required.".to_string(),
+                    ))
+                }
+            }
+            let table = args.parse::<u32>("table")?;
+            request.vrf(name, table)
+        }
+        _ => {
+            return Err(Error::Message(format!(
+                "Link type \"{}\" is not supported, try \"rip link help\".",
+                kind
+            )))
+        }
+    };
+    if let Some(arg) = args.next() {
+        return Err(unknown(&arg));
+    }
+
+    {
+        let message = request.message_mut();
+        // Like ip, create the link down
+        message.header_mut().set_flags(LinkFlags::from(0));
+        if let Some(mtu) = mtu {
+            message.append_nla(LinkNla::Mtu(mtu));
+        }
+        if let Some(address) = address {
+            message.append_nla(LinkNla::Address(address.as_bytes().to_vec()));
+        }
+    }
+    ctx.run(request.execute())
+}
+
+fn set(ctx: &mut Context, mut args: Args) -> Result<(), Error> {
+    let name = device(&mut args)?;
+    let index = ctx.link_index(&name)?;
+    let mut request = ctx.handle.link().set(index);
+    let mut changes = 0;
+    while let Some(arg) = args.next() {
+        request = match arg.as_str() {
+            "up" => request.up(),
+            "down" => request.down(),
+            "mtu" => request.mtu(args.parse("mtu")?),
+            "name" => request.name(args.value("name")?),
+            "address" => request.address(parse_mac(&args.value("address")?, "address")?),
+            "master" => {
+                let master = args.value("master")?;
+                request.master(ctx.link_index(&master)?)
+            }
+            "nomaster" => request.nomaster(),
+            _ => return Err(unknown(&arg)),
+        };
+        changes += 1;
+    }
+    if changes == 0 {
+        return Err(Error::Usage(
+            "Not enough information: nothing to set.".to_string(),
+        ));
+    }
+    ctx.run(request.execute())
+}
+
+fn del(ctx: &mut Context, mut args: Args) -> Result<(), Error> {
+    let name = device(&mut args)?;
+    if let Some(arg) = args.next() {
+        return Err(unknown(&arg));
+    }
+    let index = ctx.link_index(&name)?;
+    ctx.run(ctx.handle.link().del(index).execute())
+}
+
+pub fn run(ctx: &mut Context, mut args: Args) -> Result<(), Error> {
+    let command = match args.next() {
+        Some(command) => command,
+        None => return show(ctx, args),
+    };
+    if matches(&command, "show") || matches(&command, "list") || command == "lst" {
+        show(ctx, args)
+    } else if matches(&command, "add") {
+        add(ctx, args)
+    } else if matches(&command, "set") {
+        set(ctx, args)
+    } else if matches(&command, "delete") {
+        del(ctx, args)
+    } else if matches(&command, "help") {
+        println!("{}", USAGE);
+        Ok(())
+    } else {
+        Err(Error::Usage(format!(
+            "Command \"{}\" is unknown, try \"rip link help\".",
+            command
+        )))
+    }
+}
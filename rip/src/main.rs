@@ -0,0 +1,252 @@
+//! A command line tool similar to `ip(8)`, built on the `iproute2` crate.
+//!
+//! ```text
+//! rip [ -j ] [ -4 | -6 ] OBJECT { COMMAND | help }
+//! OBJECT := { link | address | route | neigh | rule | monitor }
+//! ```
+//!
+//! The commands and their output follow `ip`'s, for the most common options:
+//!
+//! ```text
+//! rip link show [ dev ] [ DEV ]
+//! rip link add [ link DEV ] [ name ] NAME type TYPE [ ARGS ]
+//! rip link set [ dev ] DEV { up | down | mtu MTU | name NAME | address LLADDR | master DEV | nomaster }
+//! rip link del [ dev ] DEV
+//! rip address show [ dev DEV ]
+//! rip address { add | del } PREFIX dev DEV
+//! rip route show [ table TABLE ] [ dev DEV ]
+//! rip route { add | del } [ TYPE ] PREFIX [ via ADDR ] [ dev DEV ] [ table TABLE ] [ metric METRIC ]
+//! rip route get ADDR
+//! rip neigh show [ dev DEV ]
+//! rip neigh { add | del } ADDR [ lladdr LLADDR ] dev DEV [ nud STATE ]
+//! rip rule show [ table TABLE ]
+//! rip rule { add | del } [ not ] [ from PREFIX ] [ to PREFIX ] [ iif DEV ] [ oif DEV ] [ fwmark MARK ] [ priority PRIORITY ] [ table TABLE | l3mdev ] [ ACTION ]
+//! rip monitor [ all | link | address | route | neigh ]
+//! ```
+//!
+//! Objects and commands can be abbreviated like with `ip`: `rip a` shows the addresses, and
+//! `rip r g 1.1.1.1` looks up a route. With `-j`, the output is printed in JSON.
+
+extern crate eui48;
+extern crate futures;
+extern crate iproute2;
+extern crate libc;
+extern crate rtnetlink;
+extern crate tokio_core;
+
+mod address;
+mod args;
+mod json;
+mod link;
+mod monitor;
+mod names;
+mod neigh;
+mod route;
+mod rule;
+
+use std::collections::HashMap;
+use std::env;
+use std::fmt;
+use std::io;
+use std::process;
+
+use futures::{Future, Stream};
+use tokio_core::reactor::Core;
+
+use iproute2::{new_connection, ConnectionHandle, NetlinkIpError};
+use rtnetlink::{NetlinkMessage, RtnlMessage};
+
+use args::{matches, Args};
+
+const USAGE: &str = "Usage: rip [ -j ] [ -4 | -6 ] OBJECT { COMMAND | help }
+where  OBJECT := { link | address | route | neigh | rule | monitor }";
+
+/// Error of a command, printed before exiting
+pub enum Error {
+    /// The command line is invalid
+    Usage(String),
+    /// The kernel rejected a request, or the connection failed
+    Netlink(NetlinkIpError),
+    /// The command cannot be run, for example because a link does not exist
+    Message(String),
+}
+
+impl Error {
+    fn exit_code(&self) -> i32 {
+        match *self {
+            Error::Netlink(_) => 2,
+            Error::Usage(_) | Error::Message(_) => 1,
+        }
+    }
+}
+
+impl From<NetlinkIpError> for Error {
+    fn from(error: NetlinkIpError) -> Self {
+        Error::Netlink(error)
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Self {
+        Error::Netlink(NetlinkIpError::Io(error))
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::Usage(ref message) | Error::Message(ref message) => write!(f, "{}", message),
+            Error::Netlink(NetlinkIpError::NetlinkError(ref message)) => {
+                if let RtnlMessage::Error(ref error) = *message.message() {
+                    // Like ip, only print the description of the error
+                    let description = io::Error::from_raw_os_error(-error.code).to_string();
+                    let description = match description.find(" (os error") {
+                        Some(end) => &description[..end],
+                        None => &description[..],
+                    };
+                    write!(f, "RTNETLINK answers: {}", description)
+                } else {
+                    write!(f, "Unexpected answer: {:?}", message)
+                }
+            }
+            Error::Netlink(ref error) => write!(f, "{}", error),
+        }
+    }
+}
+
+/// State shared by the commands: the event loop that runs the netlink connection, a handle to
+/// send requests, and the global options
+pub struct Context {
+    core: Core,
+    pub handle: ConnectionHandle,
+    /// Whether to print JSON (`-j`)
+    pub json: bool,
+    /// Address family selected with `-4` or `-6`, or 0 for all families
+    pub family: u8,
+}
+
+impl Context {
+    fn new(json: bool, family: u8) -> io::Result<Self> {
+        let core = Core::new()?;
+        let (connection, handle) = new_connection()?;
+        core.handle().spawn(connection.map_err(|_| ()));
+        Ok(Context {
+            core,
+            handle,
+            json,
+            family,
+        })
+    }
+
+    /// Run a future on the event loop, until it completes
+    pub fn run<F>(&mut self, future: F) -> Result<F::Item, Error>
+    where
+        F: Future,
+        Error: From<F::Error>,
+    {
+        self.core.run(future).map_err(Error::from)
+    }
+
+    /// Run a future in the background, while the event loop runs
+    pub fn spawn<F: Future<Item = (), Error = ()> + 'static>(&self, future: F) {
+        self.core.handle().spawn(future)
+    }
+
+    /// Send a request, and return the messages of the response. An error message in the response
+    /// is returned as an error.
+    pub fn request(&mut self, request: NetlinkMessage) -> Result<Vec<NetlinkMessage>, Error> {
+        let response = self.handle.request(request).collect();
+        let messages = self.run(response)?;
+        if let Some(error) = messages.iter().find(|message| message.is_error()) {
+            return Err(NetlinkIpError::NetlinkError(error.clone()).into());
+        }
+        Ok(messages)
+    }
+
+    /// Return the index of the link named `name`
+    pub fn link_index(&mut self, name: &str) -> Result<u32, Error> {
+        let resolve = self.handle.link().resolver().resolve(name);
+        match self.core.run(resolve) {
+            Ok(index) => Ok(index),
+            Err(NetlinkIpError::LinkNotFound) => {
+                Err(Error::Message(format!("Cannot find device \"{}\"", name)))
+            }
+            Err(e) => Err(Error::Netlink(e)),
+        }
+    }
+
+    /// Return the names of the links, by index
+    pub fn link_names(&mut self) -> Result<HashMap<u32, String>, Error> {
+        let links = self.run(self.handle.link().get().execute())?;
+        Ok(links
+            .into_iter()
+            .filter_map(|link| {
+                let index = link.index();
+                link.name().map(|name| (index, name.to_string()))
+            })
+            .collect())
+    }
+}
+
+fn run(mut args: Args) -> Result<(), Error> {
+    let mut json = false;
+    let mut family = 0;
+    while let Some(option) = args.peek().map(String::from) {
+        if !option.starts_with('-') {
+            break;
+        }
+        args.next();
+        match option.as_str() {
+            "-j" | "-json" => json = true,
+            "-4" => family = libc::AF_INET as u8,
+            "-6" => family = libc::AF_INET6 as u8,
+            "-h" | "-help" => {
+                println!("{}", USAGE);
+                return Ok(());
+            }
+            _ => {
+                return Err(Error::Usage(format!(
+                    "Option \"{}\" is unknown, try \"rip -help\".",
+                    option
+                )))
+            }
+        }
+    }
+
+    let object = match args.next() {
+        Some(object) => object,
+        None => return Err(Error::Usage(USAGE.to_string())),
+    };
+    if matches(&object, "help") {
+        println!("{}", USAGE);
+        return Ok(());
+    }
+
+    let mut ctx = Context::new(json, family)?;
+    if matches(&object, "link") {
+        link::run(&mut ctx, args)
+    } else if matches(&object, "address") {
+        address::run(&mut ctx, args)
+    } else if matches(&object, "route") {
+        route::run(&mut ctx, args)
+    } else if matches(&object, "rule") {
+        rule::run(&mut ctx, args)
+    } else if matches(&object, "neighbour") || matches(&object, "neighbor") {
+        neigh::run(&mut ctx, args)
+    } else if matches(&object, "monitor") {
+        monitor::run(&mut ctx, args)
+    } else {
+        Err(Error::Usage(format!(
+            "Object \"{}\" is unknown, try \"rip help\".",
+            object
+        )))
+    }
+}
+
+fn main() {
+    let args = Args::new(env::args().skip(1).collect());
+    if let Err(e) = run(args) {
+        eprintln!("{}", e);
+        process::exit(e.exit_code());
+    }
+}
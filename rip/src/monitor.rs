@@ -0,0 +1,151 @@
+use std::collections::HashMap;
+
+use futures::{Future, Stream};
+use libc;
+
+use rtnetlink::constants::{
+    RTNLGRP_IPV4_IFADDR, RTNLGRP_IPV4_ROUTE, RTNLGRP_IPV6_IFADDR, RTNLGRP_IPV6_ROUTE, RTNLGRP_LINK,
+    RTNLGRP_NEIGH,
+};
+use rtnetlink::{NetlinkMessage, RtnlMessage};
+
+use iproute2::{new_multicast_connection, Link};
+
+use address::{address_json, format_address};
+use args::{matches, unknown, Args};
+use link::{format_link, link_json, link_name};
+use neigh::{format_neighbour, neighbour_json};
+use route::{format_route, route_json};
+use {Context, Error};
+
+/// Return the multicast groups of an object
+fn groups(object: &str, family: u8) -> Result<Vec<u32>, Error> {
+    let ipv4 = family == 0 || i32::from(family) == libc::AF_INET;
+    let ipv6 = family == 0 || i32::from(family) == libc::AF_INET6;
+    let mut groups = vec![];
+    if object == "all" || matches(object, "link") {
+        groups.push(RTNLGRP_LINK);
+    }
+    if object == "all" || matches(object, "address") {
+        if ipv4 {
+            groups.push(RTNLGRP_IPV4_IFADDR);
+        }
+        if ipv6 {
+            groups.push(RTNLGRP_IPV6_IFADDR);
+        }
+    }
+    if object == "all" || matches(object, "route") {
+        if ipv4 {
+            groups.push(RTNLGRP_IPV4_ROUTE);
+        }
+        if ipv6 {
+            groups.push(RTNLGRP_IPV6_ROUTE);
+        }
+    }
+    if object == "all" || matches(object, "neighbour") || matches(object, "neighbor") {
+        groups.push(RTNLGRP_NEIGH);
+    }
+    if groups.is_empty() {
+        return Err(unknown(object));
+    }
+    Ok(groups)
+}
+
+/// Print a notification like `ip monitor`, and keep the names of the links up to date
+fn print_notification(message: &NetlinkMessage, names: &mut HashMap<u32, String>, json: bool) {
+    let (deleted, mut text, mut object) = match *message.message() {
+        RtnlMessage::NewLink(ref link) | RtnlMessage::DelLink(ref link) => {
+            // Bridge ports are also notified with AF_BRIDGE messages
+            if link.header().address_family() != 0 {
+                return;
+            }
+            let link = match Link::from_link_message(link.clone()) {
+                Ok(link) => link,
+                Err(e) => {
+                    eprintln!("invalid link notification: {}", e);
+                    return;
+                }
+            };
+            let deleted = message.is_del_link();
+            if let Some(name) = link.name() {
+                names.insert(link.index(), name.to_string());
+            }
+            let output = (deleted, format_link(&link, names), link_json(&link, names));
+            if deleted {
+                names.remove(&link.index());
+            }
+            output
+        }
+        RtnlMessage::NewAddress(ref address) | RtnlMessage::DelAddress(ref address) => {
+            let index = address.header.index;
+            let text = format!(
+                "{}: {}{}",
+                index,
+                link_name(names, index),
+                format_address(address)
+            );
+            let mut json = address_json(address);
+            json.insert("ifindex", index)
+                .insert("ifname", link_name(names, index));
+            (message.is_del_address(), text, json)
+        }
+        RtnlMessage::NewRoute(ref route) | RtnlMessage::DelRoute(ref route) => (
+            message.is_del_route(),
+            format_route(route, names, true),
+            route_json(route, names, true),
+        ),
+        RtnlMessage::NewNeighbour(ref neighbour) | RtnlMessage::DelNeighbour(ref neighbour) => {
+            let family = i32::from(neighbour.header.family);
+            if family != libc::AF_INET && family != libc::AF_INET6 {
+                return;
+            }
+            (
+                message.is_del_neighbour(),
+                format_neighbour(neighbour, names),
+                neighbour_json(neighbour, names),
+            )
+        }
+        _ => return,
+    };
+    if json {
+        if deleted {
+            object.insert("deleted", true);
+        }
+        println!("{}", object);
+    } else {
+        if deleted {
+            text = format!("Deleted {}", text);
+        }
+        println!("{}", text);
+    }
+}
+
+pub fn run(ctx: &mut Context, mut args: Args) -> Result<(), Error> {
+    let mut groups_to_join = vec![];
+    while let Some(arg) = args.next() {
+        if matches(&arg, "help") {
+            println!("Usage: rip monitor [ all | link | address | route | neigh ]...");
+            return Ok(());
+        }
+        groups_to_join.extend(groups(&arg, ctx.family)?);
+    }
+    if groups_to_join.is_empty() {
+        groups_to_join = groups("all", ctx.family)?;
+    }
+
+    // Join the groups before dumping the links, so that no name change is missed
+    let (connection, _handle, notifications) = new_multicast_connection(&groups_to_join)?;
+    ctx.spawn(connection.map_err(|e| eprintln!("netlink connection failed: {}", e)));
+    let mut names = ctx.link_names()?;
+
+    let json = ctx.json;
+    let monitor = notifications.for_each(move |message| {
+        if message.is_overrun() {
+            eprintln!("warning: the socket receive buffer overflowed, notifications were lost");
+        } else {
+            print_notification(&message, &mut names, json);
+        }
+        Ok(())
+    });
+    ctx.run(monitor.map_err(|()| Error::Message("the notification stream failed".to_string())))
+}
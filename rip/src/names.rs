@@ -0,0 +1,237 @@
+//! Names of the netlink constants, as `ip` prints and parses them
+
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use eui48::MacAddress;
+use libc;
+
+use rtnetlink::constants::{
+    NUD_DELAY, NUD_FAILED, NUD_INCOMPLETE, NUD_NOARP, NUD_NONE, NUD_PERMANENT, NUD_PROBE,
+    NUD_REACHABLE, NUD_STALE,
+};
+use rtnetlink::{LinkLayerType, LinkState, RouteKind, RouteProtocol};
+
+use args::invalid;
+use Error;
+
+pub fn family_name(family: u8) -> &'static str {
+    match i32::from(family) {
+        libc::AF_INET => "inet",
+        libc::AF_INET6 => "inet6",
+        libc::AF_BRIDGE => "bridge",
+        _ => "unknown",
+    }
+}
+
+/// Format an IPv4 or IPv6 address from its bytes
+pub fn format_ip(bytes: &[u8]) -> String {
+    match bytes.len() {
+        4 => Ipv4Addr::new(bytes[0], bytes[1], bytes[2], bytes[3]).to_string(),
+        16 => {
+            let mut octets = [0; 16];
+            octets.copy_from_slice(bytes);
+            Ipv6Addr::from(octets).to_string()
+        }
+        _ => format_mac(bytes),
+    }
+}
+
+/// Format a link layer address
+pub fn format_mac(bytes: &[u8]) -> String {
+    bytes
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect::<Vec<_>>()
+        .join(":")
+}
+
+pub fn parse_mac(value: &str, keyword: &str) -> Result<MacAddress, Error> {
+    MacAddress::parse_str(value).map_err(|_| invalid(value, keyword))
+}
+
+/// Parse `ADDRESS[/PREFIX_LEN]`. Without prefix length, the prefix is a single address.
+pub fn parse_prefix(value: &str, keyword: &str) -> Result<(IpAddr, u8), Error> {
+    let mut parts = value.splitn(2, '/');
+    let address: IpAddr = parts
+        .next()
+        .unwrap()
+        .parse()
+        .map_err(|_| invalid(value, keyword))?;
+    let max_len = if address.is_ipv4() { 32 } else { 128 };
+    let prefix_len = match parts.next() {
+        Some(len) => len.parse().map_err(|_| invalid(value, keyword))?,
+        None => max_len,
+    };
+    if prefix_len > max_len {
+        return Err(invalid(value, keyword));
+    }
+    Ok((address, prefix_len))
+}
+
+pub fn scope_name(scope: u8) -> String {
+    match scope {
+        0 => "global".to_string(),
+        200 => "site".to_string(),
+        253 => "link".to_string(),
+        254 => "host".to_string(),
+        255 => "nowhere".to_string(),
+        _ => scope.to_string(),
+    }
+}
+
+pub fn table_name(table: u32) -> String {
+    match table {
+        253 => "default".to_string(),
+        254 => "main".to_string(),
+        255 => "local".to_string(),
+        _ => table.to_string(),
+    }
+}
+
+pub fn parse_table(value: &str) -> Result<u32, Error> {
+    match value {
+        "default" => Ok(253),
+        "main" => Ok(254),
+        "local" => Ok(255),
+        _ => value.parse().map_err(|_| invalid(value, "table")),
+    }
+}
+
+const ROUTE_KINDS: &[(RouteKind, &str)] = &[
+    (RouteKind::Unspec, "none"),
+    (RouteKind::Unicast, "unicast"),
+    (RouteKind::Local, "local"),
+    (RouteKind::Broadcast, "broadcast"),
+    (RouteKind::Anycast, "anycast"),
+    (RouteKind::Multicast, "multicast"),
+    (RouteKind::Blackhole, "blackhole"),
+    (RouteKind::Unreachable, "unreachable"),
+    (RouteKind::Prohibit, "prohibit"),
+    (RouteKind::Throw, "throw"),
+    (RouteKind::Nat, "nat"),
+    (RouteKind::Xresolve, "xresolve"),
+];
+
+pub fn route_kind_name(kind: RouteKind) -> String {
+    ROUTE_KINDS
+        .iter()
+        .find(|&&(k, _)| k == kind)
+        .map(|&(_, name)| name.to_string())
+        .unwrap_or_else(|| u8::from(kind).to_string())
+}
+
+/// Parse a route type. Unlike the other names, route types are never numbers.
+pub fn parse_route_kind(value: &str) -> Option<RouteKind> {
+    ROUTE_KINDS
+        .iter()
+        .find(|&&(_, name)| name == value)
+        .map(|&(kind, _)| kind)
+}
+
+const ROUTE_PROTOCOLS: &[(RouteProtocol, &str)] = &[
+    (RouteProtocol::Unspec, "unspec"),
+    (RouteProtocol::Redirect, "redirect"),
+    (RouteProtocol::Kernel, "kernel"),
+    (RouteProtocol::Boot, "boot"),
+    (RouteProtocol::Static, "static"),
+    (RouteProtocol::Gated, "gated"),
+    (RouteProtocol::Ra, "ra"),
+    (RouteProtocol::Mrt, "mrt"),
+    (RouteProtocol::Zebra, "zebra"),
+    (RouteProtocol::Bird, "bird"),
+    (RouteProtocol::Dnrouted, "dnrouted"),
+    (RouteProtocol::Xorp, "xorp"),
+    (RouteProtocol::Ntk, "ntk"),
+    (RouteProtocol::Dhcp, "dhcp"),
+    (RouteProtocol::Mrouted, "mrouted"),
+    (RouteProtocol::Babel, "babel"),
+];
+
+pub fn protocol_name(protocol: RouteProtocol) -> String {
+    ROUTE_PROTOCOLS
+        .iter()
+        .find(|&&(p, _)| p == protocol)
+        .map(|&(_, name)| name.to_string())
+        .unwrap_or_else(|| u8::from(protocol).to_string())
+}
+
+pub fn parse_protocol(value: &str) -> Result<RouteProtocol, Error> {
+    if let Some(&(protocol, _)) = ROUTE_PROTOCOLS.iter().find(|&&(_, name)| name == value) {
+        return Ok(protocol);
+    }
+    value
+        .parse::<u8>()
+        .map(RouteProtocol::from)
+        .map_err(|_| invalid(value, "protocol"))
+}
+
+pub fn oper_state_name(state: LinkState) -> String {
+    match state {
+        LinkState::Unknown => "UNKNOWN".to_string(),
+        LinkState::NotPresent => "NOTPRESENT".to_string(),
+        LinkState::Down => "DOWN".to_string(),
+        LinkState::LowerLayerDown => "LOWERLAYERDOWN".to_string(),
+        LinkState::Testing => "TESTING".to_string(),
+        LinkState::Dormant => "DORMANT".to_string(),
+        LinkState::Up => "UP".to_string(),
+        LinkState::Other(state) => state.to_string(),
+    }
+}
+
+/// Name of a link layer type, as printed after `link/`
+pub fn link_type_name(link_type: LinkLayerType) -> String {
+    let name = match link_type {
+        LinkLayerType::Ether => "ether",
+        LinkLayerType::Loopback => "loopback",
+        LinkLayerType::Tunnel => "ipip",
+        LinkLayerType::Tunnel6 => "tunnel6",
+        LinkLayerType::Sit => "sit",
+        LinkLayerType::IpGre => "gre",
+        LinkLayerType::Ip6Gre => "gre6",
+        LinkLayerType::Ppp => "ppp",
+        LinkLayerType::Infiniband => "infiniband",
+        LinkLayerType::Ieee80211 => "ieee802.11",
+        LinkLayerType::Rawip => "rawip",
+        LinkLayerType::Can => "can",
+        LinkLayerType::Netlink => "netlink",
+        LinkLayerType::Void => "void",
+        LinkLayerType::None => "none",
+        _ => return format!("[{}]", u16::from(link_type)),
+    };
+    name.to_string()
+}
+
+const NEIGHBOUR_STATES: &[(u16, &str)] = &[
+    (NUD_INCOMPLETE, "INCOMPLETE"),
+    (NUD_REACHABLE, "REACHABLE"),
+    (NUD_STALE, "STALE"),
+    (NUD_DELAY, "DELAY"),
+    (NUD_PROBE, "PROBE"),
+    (NUD_FAILED, "FAILED"),
+    (NUD_NOARP, "NOARP"),
+    (NUD_PERMANENT, "PERMANENT"),
+];
+
+/// Names of the states of a neighbour
+pub fn neighbour_state_names(state: u16) -> Vec<&'static str> {
+    if state == NUD_NONE {
+        return vec!["NONE"];
+    }
+    NEIGHBOUR_STATES
+        .iter()
+        .filter(|&&(flag, _)| state & flag != 0)
+        .map(|&(_, name)| name)
+        .collect()
+}
+
+pub fn parse_neighbour_state(value: &str) -> Result<u16, Error> {
+    let upper = value.to_uppercase();
+    if upper == "NONE" {
+        return Ok(NUD_NONE);
+    }
+    NEIGHBOUR_STATES
+        .iter()
+        .find(|&&(_, name)| name == upper)
+        .map(|&(state, _)| state)
+        .ok_or_else(|| invalid(value, "nud"))
+}
@@ -0,0 +1,211 @@
+use std::collections::HashMap;
+use std::net::IpAddr;
+
+use libc;
+
+use rtnetlink::constants::{
+    NLM_F_ACK, NLM_F_CREATE, NLM_F_DUMP, NLM_F_EXCL, NLM_F_REQUEST, NTF_PROXY, NTF_ROUTER,
+    NUD_NOARP, NUD_PERMANENT,
+};
+use rtnetlink::{NeighbourMessage, NeighbourNla, NetlinkFlags, NetlinkMessage, RtnlMessage};
+
+use iproute2::NetlinkIpError;
+
+use args::{invalid, matches, unknown, Args};
+use json::Json;
+use link::link_name;
+use names::{format_ip, format_mac, neighbour_state_names, parse_mac, parse_neighbour_state};
+use {Context, Error};
+
+const USAGE: &str = "Usage: rip neigh show [ dev DEV ]
+       rip neigh { add | del } ADDR [ lladdr LLADDR ] dev DEV [ nud STATE ] [ router ]
+STATE := { permanent | noarp | stale | reachable | none | incomplete | delay | probe | failed }";
+
+fn is_ip_neighbour(neighbour: &NeighbourMessage) -> bool {
+    let family = i32::from(neighbour.header.family);
+    family == libc::AF_INET || family == libc::AF_INET6
+}
+
+fn addresses(neighbour: &NeighbourMessage) -> (Option<&[u8]>, Option<&[u8]>) {
+    let mut destination = None;
+    let mut lladdr = None;
+    for nla in &neighbour.nlas {
+        match *nla {
+            NeighbourNla::Destination(ref bytes) => destination = Some(&bytes[..]),
+            NeighbourNla::LinkLayerAddress(ref bytes) => lladdr = Some(&bytes[..]),
+            _ => {}
+        }
+    }
+    (destination, lladdr)
+}
+
+/// Format a neighbour like `ip neigh show`
+pub fn format_neighbour(neighbour: &NeighbourMessage, names: &HashMap<u32, String>) -> String {
+    let (destination, lladdr) = addresses(neighbour);
+    let mut line = destination.map(format_ip).unwrap_or_default();
+    line += &format!(" dev {}", link_name(names, neighbour.header.ifindex));
+    if let Some(lladdr) = lladdr {
+        line += &format!(" lladdr {}", format_mac(lladdr));
+    }
+    if neighbour.header.flags & NTF_ROUTER != 0 {
+        line += " router";
+    }
+    if neighbour.header.flags & NTF_PROXY != 0 {
+        line += " proxy";
+    }
+    for state in neighbour_state_names(neighbour.header.state) {
+        line += &format!(" {}", state);
+    }
+    line
+}
+
+/// Return a neighbour as a JSON object, with the members of `ip -j neigh show`
+pub fn neighbour_json(neighbour: &NeighbourMessage, names: &HashMap<u32, String>) -> Json {
+    let (destination, lladdr) = addresses(neighbour);
+    let mut json = Json::object();
+    if let Some(destination) = destination {
+        json.insert("dst", format_ip(destination));
+    }
+    json.insert("dev", link_name(names, neighbour.header.ifindex));
+    if let Some(lladdr) = lladdr {
+        json.insert("lladdr", format_mac(lladdr));
+    }
+    if neighbour.header.flags & NTF_ROUTER != 0 {
+        json.insert("router", Json::Null);
+    }
+    if neighbour.header.flags & NTF_PROXY != 0 {
+        json.insert("proxy", Json::Null);
+    }
+    let states = neighbour_state_names(neighbour.header.state)
+        .into_iter()
+        .map(Json::from)
+        .collect::<Vec<_>>();
+    json.insert("state", states);
+    json
+}
+
+fn show(ctx: &mut Context, mut args: Args) -> Result<(), Error> {
+    let mut device = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "dev" => device = Some(args.value("dev")?),
+            _ => return Err(unknown(&arg)),
+        }
+    }
+    let device = match device {
+        Some(device) => Some(ctx.link_index(&device)?),
+        None => None,
+    };
+
+    let mut message = NeighbourMessage::default();
+    message.header.family = ctx.family;
+    let mut request = NetlinkMessage::from(RtnlMessage::GetNeighbour(message));
+    request
+        .header_mut()
+        .set_flags(NetlinkFlags::from(NLM_F_REQUEST | NLM_F_DUMP));
+    let mut neighbours = vec![];
+    for message in ctx.request(request)? {
+        if let RtnlMessage::NewNeighbour(ref neighbour) = *message.message() {
+            // The dump also has the bridge forwarding database entries. Like ip, don't show the
+            // neighbours that don't use ARP or NDP, like the multicast addresses.
+            if is_ip_neighbour(neighbour)
+                && neighbour.header.state & NUD_NOARP == 0
+                && (device.is_none() || Some(neighbour.header.ifindex) == device)
+            {
+                neighbours.push(neighbour.clone());
+            }
+        } else {
+            return Err(NetlinkIpError::UnexpectedMessage(message.clone()).into());
+        }
+    }
+
+    let names = ctx.link_names()?;
+    if ctx.json {
+        let neighbours = neighbours
+            .iter()
+            .map(|neighbour| neighbour_json(neighbour, &names))
+            .collect();
+        println!("{}", Json::Array(neighbours));
+    } else {
+        for neighbour in &neighbours {
+            println!("{}", format_neighbour(neighbour, &names));
+        }
+    }
+    Ok(())
+}
+
+fn add_or_del(ctx: &mut Context, mut args: Args, add: bool) -> Result<(), Error> {
+    let mut message = NeighbourMessage::default();
+    message.header.state = NUD_PERMANENT;
+    let mut destination = None;
+    let mut device = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "lladdr" => {
+                let lladdr = parse_mac(&args.value("lladdr")?, "lladdr")?;
+                message
+                    .nlas
+                    .push(NeighbourNla::LinkLayerAddress(lladdr.as_bytes().to_vec()));
+            }
+            "dev" => device = Some(args.value("dev")?),
+            "nud" => message.header.state = parse_neighbour_state(&args.value("nud")?)?,
+            "router" => message.header.flags |= NTF_ROUTER,
+            "to" if destination.is_none() => destination = Some(args.value("to")?),
+            _ if destination.is_none() => destination = Some(arg),
+            _ => return Err(unknown(&arg)),
+        }
+    }
+    let device = device.ok_or_else(|| {
+        Error::Usage("Not enough information: \"dev\" argument is required.".to_string())
+    })?;
+    let destination = destination.ok_or_else(|| {
+        Error::Usage("Not enough information: the address is required.".to_string())
+    })?;
+    let address: IpAddr = destination
+        .parse()
+        .map_err(|_| invalid(&destination, "to"))?;
+    let (family, bytes) = match address {
+        IpAddr::V4(address) => (libc::AF_INET, address.octets().to_vec()),
+        IpAddr::V6(address) => (libc::AF_INET6, address.octets().to_vec()),
+    };
+    message.header.family = family as u8;
+    message.header.ifindex = ctx.link_index(&device)?;
+    message.nlas.push(NeighbourNla::Destination(bytes));
+
+    let (message, flags) = if add {
+        (
+            RtnlMessage::NewNeighbour(message),
+            NLM_F_REQUEST | NLM_F_ACK | NLM_F_CREATE | NLM_F_EXCL,
+        )
+    } else {
+        (
+            RtnlMessage::DelNeighbour(message),
+            NLM_F_REQUEST | NLM_F_ACK,
+        )
+    };
+    let mut request = NetlinkMessage::from(message);
+    request.header_mut().set_flags(NetlinkFlags::from(flags));
+    ctx.request(request).map(|_| ())
+}
+
+pub fn run(ctx: &mut Context, mut args: Args) -> Result<(), Error> {
+    let command = match args.next() {
+        Some(command) => command,
+        None => return show(ctx, args),
+    };
+    if matches(&command, "show") || matches(&command, "list") || command == "lst" {
+        show(ctx, args)
+    } else if matches(&command, "add") {
+        add_or_del(ctx, args, true)
+    } else if matches(&command, "delete") {
+        add_or_del(ctx, args, false)
+    } else if matches(&command, "help") {
+        println!("{}", USAGE);
+        Ok(())
+    } else {
+        Err(Error::Usage(format!(
+            "Command \"{}\" is unknown, try \"rip neigh help\".",
+            command
+        )))
+    }
+}
@@ -0,0 +1,423 @@
+use std::collections::HashMap;
+use std::net::IpAddr;
+
+use libc;
+
+use rtnetlink::constants::NLM_F_REQUEST;
+use rtnetlink::{
+    NetlinkFlags, NetlinkMessage, RouteKind, RouteMessage, RouteNla, RouteProtocol, RouteScope,
+    RtnlMessage,
+};
+
+use iproute2::{route_table, NetlinkIpError};
+
+use args::{invalid, matches, unknown, Args};
+use json::Json;
+use link::link_name;
+use names::{
+    format_ip, parse_prefix, parse_protocol, parse_route_kind, parse_table, protocol_name,
+    route_kind_name, scope_name, table_name,
+};
+use {Context, Error};
+
+const USAGE: &str = "Usage: rip route show [ table { TABLE | all } ] [ dev DEV ]
+       rip route { add | del } [ TYPE ] { PREFIX | default } [ via ADDR ] [ dev DEV ]
+                               [ table TABLE ] [ metric METRIC ] [ proto PROTOCOL ]
+       rip route get ADDR
+TYPE := { unicast | local | broadcast | multicast | throw | unreachable | prohibit | blackhole }";
+
+// Table of the routes shown by default
+const MAIN_TABLE: u32 = 254;
+
+/// The attributes of a route message that ip prints
+#[derive(Default)]
+struct RouteInfo<'a> {
+    destination: Option<&'a [u8]>,
+    source: Option<&'a [u8]>,
+    gateway: Option<&'a [u8]>,
+    preferred_source: Option<&'a [u8]>,
+    output_interface: Option<u32>,
+    metric: Option<u32>,
+    preference: Option<u8>,
+}
+
+impl<'a> RouteInfo<'a> {
+    fn new(route: &'a RouteMessage) -> Self {
+        let mut info = RouteInfo::default();
+        for nla in &route.nlas {
+            match *nla {
+                RouteNla::Destination(ref bytes) => info.destination = Some(&bytes[..]),
+                RouteNla::Source(ref bytes) => info.source = Some(&bytes[..]),
+                RouteNla::Gateway(ref bytes) => info.gateway = Some(&bytes[..]),
+                RouteNla::PrefSource(ref bytes) => info.preferred_source = Some(&bytes[..]),
+                RouteNla::Oif(index) => info.output_interface = Some(index),
+                RouteNla::Priority(metric) => info.metric = Some(metric),
+                RouteNla::Pref(ref bytes) if !bytes.is_empty() => info.preference = Some(bytes[0]),
+                _ => {}
+            }
+        }
+        info
+    }
+}
+
+fn max_prefix_len(family: u8) -> u8 {
+    if i32::from(family) == libc::AF_INET6 {
+        128
+    } else {
+        32
+    }
+}
+
+pub fn format_prefix(address: Option<&[u8]>, prefix_len: u8, family: u8) -> String {
+    match address {
+        Some(address) if prefix_len == max_prefix_len(family) => format_ip(address),
+        Some(address) => format!("{}/{}", format_ip(address), prefix_len),
+        None if prefix_len == 0 => "default".to_string(),
+        None => format!("0/{}", prefix_len),
+    }
+}
+
+fn preference_name(preference: u8) -> String {
+    match preference {
+        0 => "medium".to_string(),
+        1 => "high".to_string(),
+        3 => "low".to_string(),
+        _ => preference.to_string(),
+    }
+}
+
+// Like ip, don't print the protocol of the routes added by an administrator
+fn show_protocol(protocol: RouteProtocol) -> bool {
+    protocol != RouteProtocol::Boot && protocol != RouteProtocol::Unspec
+}
+
+/// Format a route like `ip route show`. Like ip, the table is only printed if the routes are not
+/// filtered by table, and the table is not the main table.
+pub fn format_route(
+    route: &RouteMessage,
+    names: &HashMap<u32, String>,
+    show_table: bool,
+) -> String {
+    let header = &route.header;
+    let info = RouteInfo::new(route);
+    let mut line = String::new();
+    if header.kind != RouteKind::Unicast {
+        line += &format!("{} ", route_kind_name(header.kind));
+    }
+    line += &format_prefix(
+        info.destination,
+        header.destination_length,
+        header.address_family,
+    );
+    if header.source_length != 0 {
+        line += &format!(
+            " from {}",
+            format_prefix(info.source, header.source_length, header.address_family)
+        );
+    }
+    if let Some(gateway) = info.gateway {
+        line += &format!(" via {}", format_ip(gateway));
+    }
+    if let Some(index) = info.output_interface {
+        line += &format!(" dev {}", link_name(names, index));
+    }
+    let table = route_table(route);
+    if show_table && table != MAIN_TABLE {
+        line += &format!(" table {}", table_name(table));
+    }
+    if show_protocol(header.protocol) {
+        line += &format!(" proto {}", protocol_name(header.protocol));
+    }
+    if header.scope != RouteScope::Universe {
+        line += &format!(" scope {}", scope_name(u8::from(header.scope)));
+    }
+    if let Some(source) = info.preferred_source {
+        line += &format!(" src {}", format_ip(source));
+    }
+    if let Some(metric) = info.metric {
+        line += &format!(" metric {}", metric);
+    }
+    if let Some(preference) = info.preference {
+        line += &format!(" pref {}", preference_name(preference));
+    }
+    line
+}
+
+/// Return a route as a JSON object, with the members of `ip -j route show`
+pub fn route_json(route: &RouteMessage, names: &HashMap<u32, String>, show_table: bool) -> Json {
+    let header = &route.header;
+    let info = RouteInfo::new(route);
+    let mut json = Json::object();
+    if header.kind != RouteKind::Unicast {
+        json.insert("type", route_kind_name(header.kind));
+    }
+    json.insert(
+        "dst",
+        format_prefix(
+            info.destination,
+            header.destination_length,
+            header.address_family,
+        ),
+    );
+    if header.source_length != 0 {
+        json.insert(
+            "src",
+            format_prefix(info.source, header.source_length, header.address_family),
+        );
+    }
+    if let Some(gateway) = info.gateway {
+        json.insert("gateway", format_ip(gateway));
+    }
+    if let Some(index) = info.output_interface {
+        json.insert("dev", link_name(names, index));
+    }
+    let table = route_table(route);
+    if show_table && table != MAIN_TABLE {
+        json.insert("table", table_name(table));
+    }
+    if show_protocol(header.protocol) {
+        json.insert("protocol", protocol_name(header.protocol));
+    }
+    if header.scope != RouteScope::Universe {
+        json.insert("scope", scope_name(u8::from(header.scope)));
+    }
+    if let Some(source) = info.preferred_source {
+        json.insert("prefsrc", format_ip(source));
+    }
+    if let Some(metric) = info.metric {
+        json.insert("metric", metric);
+    }
+    if let Some(preference) = info.preference {
+        json.insert("pref", preference_name(preference));
+    }
+    json
+}
+
+// Like ip, only show the IPv4 routes by default, unless the routes of all the tables are shown
+fn family(ctx: &Context, all_tables: bool) -> u8 {
+    if ctx.family == 0 && !all_tables {
+        libc::AF_INET as u8
+    } else {
+        ctx.family
+    }
+}
+
+fn print_routes(
+    ctx: &Context,
+    routes: &[RouteMessage],
+    names: &HashMap<u32, String>,
+    show_table: bool,
+) {
+    if ctx.json {
+        let routes = routes
+            .iter()
+            .map(|route| route_json(route, names, show_table))
+            .collect();
+        println!("{}", Json::Array(routes));
+    } else {
+        for route in routes {
+            println!("{}", format_route(route, names, show_table));
+        }
+    }
+}
+
+fn show(ctx: &mut Context, mut args: Args) -> Result<(), Error> {
+    let mut table = Some(MAIN_TABLE);
+    let mut device = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "table" => {
+                let value = args.value("table")?;
+                table = if value == "all" {
+                    None
+                } else {
+                    Some(parse_table(&value)?)
+                }
+            }
+            "dev" | "oif" => device = Some(args.value("dev")?),
+            _ => return Err(unknown(&arg)),
+        }
+    }
+    let device = match device {
+        Some(device) => Some(ctx.link_index(&device)?),
+        None => None,
+    };
+
+    let names = ctx.link_names()?;
+    let mut request = ctx
+        .handle
+        .route()
+        .get()
+        .family(family(ctx, table.is_none()));
+    if let Some(table) = table {
+        request = request.table(table);
+    }
+    let routes: Vec<RouteMessage> = ctx
+        .run(request.execute())?
+        .into_iter()
+        // Like ip, don't show the routes cached by the kernel
+        .filter(|route| !route.header.flags.has_cloned())
+        .filter(|route| device.is_none() || RouteInfo::new(route).output_interface == device)
+        .collect();
+    print_routes(ctx, &routes, &names, table.is_none());
+    Ok(())
+}
+
+fn add_or_del(ctx: &mut Context, mut args: Args, add: bool) -> Result<(), Error> {
+    let mut kind = None;
+    let mut destination = None;
+    let mut request = ctx.handle.route().add();
+    let mut protocol = None;
+    while let Some(arg) = args.next() {
+        request = match arg.as_str() {
+            "via" => {
+                let gateway = args.value("via")?;
+                let gateway: IpAddr = gateway.parse().map_err(|_| invalid(&gateway, "via"))?;
+                request.gateway(gateway)
+            }
+            "dev" | "oif" => {
+                let device = args.value("dev")?;
+                request.output_interface(ctx.link_index(&device)?)
+            }
+            "table" => request.table(parse_table(&args.value("table")?)?),
+            "metric" | "priority" | "preference" => request.priority(args.parse("metric")?),
+            "proto" | "protocol" => {
+                protocol = Some(parse_protocol(&args.value("protocol")?)?);
+                request
+            }
+            "to" if destination.is_none() => {
+                destination = Some(args.value("to")?);
+                request
+            }
+            _ if kind.is_none() && destination.is_none() && parse_route_kind(&arg).is_some() => {
+                kind = parse_route_kind(&arg);
+                request
+            }
+            _ if destination.is_none() => {
+                destination = Some(arg);
+                request
+            }
+            _ => return Err(unknown(&arg)),
+        };
+    }
+
+    let destination = destination.ok_or_else(|| {
+        Error::Usage("Not enough information: the destination is required.".to_string())
+    })?;
+    if destination == "default" || destination == "all" || destination == "any" {
+        let family = if ctx.family != 0 {
+            ctx.family
+        } else if request.message_mut().header.address_family != 0 {
+            // The family of the gateway
+            request.message_mut().header.address_family
+        } else {
+            libc::AF_INET as u8
+        };
+        request.message_mut().header.address_family = family;
+    } else {
+        let (address, prefix_len) = parse_prefix(&destination, "to")?;
+        request = request.destination(address, prefix_len);
+    }
+
+    if add {
+        if let Some(kind) = kind {
+            request = request.kind(kind);
+            // Like ip, set the scope of the routes that are not unicast routes
+            request.message_mut().header.scope = match kind {
+                RouteKind::Local | RouteKind::Nat => RouteScope::Host,
+                RouteKind::Broadcast | RouteKind::Multicast | RouteKind::Anycast => {
+                    RouteScope::Link
+                }
+                _ => RouteScope::Universe,
+            };
+        }
+        if let Some(protocol) = protocol {
+            request = request.protocol(protocol);
+        }
+        ctx.run(request.execute())
+    } else {
+        // Like ip, only match the type and the protocol of the route if they are given
+        let mut message = request.message_mut().clone();
+        message.header.kind = kind.unwrap_or(RouteKind::Unspec);
+        message.header.protocol = protocol.unwrap_or(RouteProtocol::Unspec);
+        ctx.run(ctx.handle.route().del(message).execute())
+    }
+}
+
+fn get(ctx: &mut Context, mut args: Args) -> Result<(), Error> {
+    let destination = args.value("to")?;
+    let destination = if destination == "to" {
+        args.value("to")?
+    } else {
+        destination
+    };
+    if let Some(arg) = args.next() {
+        return Err(unknown(&arg));
+    }
+    let address: IpAddr = destination
+        .parse()
+        .map_err(|_| invalid(&destination, "to"))?;
+
+    let mut message = RouteMessage::default();
+    let bytes = match address {
+        IpAddr::V4(address) => {
+            message.header.address_family = libc::AF_INET as u8;
+            address.octets().to_vec()
+        }
+        IpAddr::V6(address) => {
+            message.header.address_family = libc::AF_INET6 as u8;
+            address.octets().to_vec()
+        }
+    };
+    message.header.destination_length = max_prefix_len(message.header.address_family);
+    message.nlas.push(RouteNla::Destination(bytes));
+    let mut request = NetlinkMessage::from(RtnlMessage::GetRoute(message));
+    request
+        .header_mut()
+        .set_flags(NetlinkFlags::from(NLM_F_REQUEST));
+
+    let mut routes = vec![];
+    for message in ctx.request(request)? {
+        match *message.message() {
+            RtnlMessage::NewRoute(ref route) => routes.push(route.clone()),
+            _ => return Err(NetlinkIpError::UnexpectedMessage(message.clone()).into()),
+        }
+    }
+
+    let names = ctx.link_names()?;
+    if ctx.json {
+        print_routes(ctx, &routes, &names, true);
+    } else {
+        for route in &routes {
+            println!("{}", format_route(route, &names, true));
+            if route.header.flags.has_cloned() {
+                println!("    cache");
+            }
+        }
+    }
+    Ok(())
+}
+
+pub fn run(ctx: &mut Context, mut args: Args) -> Result<(), Error> {
+    let command = match args.next() {
+        Some(command) => command,
+        None => return show(ctx, args),
+    };
+    if matches(&command, "show") || matches(&command, "list") || command == "lst" {
+        show(ctx, args)
+    } else if matches(&command, "get") {
+        get(ctx, args)
+    } else if matches(&command, "add") {
+        add_or_del(ctx, args, true)
+    } else if matches(&command, "delete") {
+        add_or_del(ctx, args, false)
+    } else if matches(&command, "help") {
+        println!("{}", USAGE);
+        Ok(())
+    } else {
+        Err(Error::Usage(format!(
+            "Command \"{}\" is unknown, try \"rip route help\".",
+            command
+        )))
+    }
+}
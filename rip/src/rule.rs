@@ -0,0 +1,399 @@
+use libc;
+
+use rtnetlink::constants::{
+    FIB_RULE_IIF_DETACHED, FIB_RULE_INVERT, FIB_RULE_OIF_DETACHED, FR_ACT_BLACKHOLE, FR_ACT_GOTO,
+    FR_ACT_NOP, FR_ACT_PROHIBIT, FR_ACT_TO_TBL, FR_ACT_UNREACHABLE, FR_ACT_UNSPEC, RTPROT_KERNEL,
+};
+use rtnetlink::{RouteProtocol, RuleMessage, RuleNla};
+
+use iproute2::rule_table;
+
+use args::{invalid, matches, unknown, Args};
+use json::Json;
+use names::{format_ip, parse_prefix, parse_table, protocol_name, table_name};
+use route::format_prefix;
+use {Context, Error};
+
+const USAGE: &str = "Usage: rip rule show [ table TABLE ]
+       rip rule { add | del } [ not ] [ from PREFIX ] [ to PREFIX ] [ iif DEV ] [ oif DEV ]
+                              [ fwmark MARK[/MASK] ] [ priority PRIORITY ]
+                              [ table TABLE | l3mdev ] [ ACTION ]
+ACTION := { blackhole | unreachable | prohibit | nop }";
+
+const ACTIONS: &[(u8, &str)] = &[
+    (FR_ACT_BLACKHOLE, "blackhole"),
+    (FR_ACT_UNREACHABLE, "unreachable"),
+    (FR_ACT_PROHIBIT, "prohibit"),
+    (FR_ACT_NOP, "nop"),
+];
+
+fn action_name(action: u8) -> String {
+    ACTIONS
+        .iter()
+        .find(|&&(value, _)| value == action)
+        .map(|&(_, name)| name.to_string())
+        .unwrap_or_else(|| action.to_string())
+}
+
+fn parse_action(value: &str) -> Option<u8> {
+    ACTIONS
+        .iter()
+        .find(|&&(_, name)| name == value)
+        .map(|&(action, _)| action)
+}
+
+fn max_prefix_len(family: u8) -> u8 {
+    if i32::from(family) == libc::AF_INET6 {
+        128
+    } else {
+        32
+    }
+}
+
+/// The attributes of a rule message that ip prints
+#[derive(Default)]
+struct RuleInfo<'a> {
+    priority: u32,
+    source: Option<&'a [u8]>,
+    destination: Option<&'a [u8]>,
+    input_interface: Option<&'a str>,
+    output_interface: Option<&'a str>,
+    fwmark: Option<u32>,
+    fwmask: Option<u32>,
+    l3mdev: bool,
+    goto: Option<u32>,
+    protocol: Option<u8>,
+}
+
+impl<'a> RuleInfo<'a> {
+    fn new(rule: &'a RuleMessage) -> Self {
+        let mut info = RuleInfo::default();
+        for nla in &rule.nlas {
+            match *nla {
+                RuleNla::Priority(priority) => info.priority = priority,
+                RuleNla::Source(ref bytes) => info.source = Some(&bytes[..]),
+                RuleNla::Destination(ref bytes) => info.destination = Some(&bytes[..]),
+                RuleNla::IifName(ref name) => info.input_interface = Some(name),
+                RuleNla::OifName(ref name) => info.output_interface = Some(name),
+                RuleNla::FwMark(mark) => info.fwmark = Some(mark),
+                RuleNla::FwMask(mask) => info.fwmask = Some(mask),
+                RuleNla::L3MDev(l3mdev) => info.l3mdev = l3mdev != 0,
+                RuleNla::Goto(priority) => info.goto = Some(priority),
+                RuleNla::Protocol(protocol) => info.protocol = Some(protocol),
+                _ => {}
+            }
+        }
+        info
+    }
+
+    /// Return the firewall mark and mask, formatted like ip
+    fn fwmark(&self) -> Option<(String, Option<String>)> {
+        if self.fwmark.is_none() && self.fwmask.is_none() {
+            return None;
+        }
+        let mark = format!("{:#x}", self.fwmark.unwrap_or(0));
+        let mask = match self.fwmask {
+            Some(mask) if mask != 0xffff_ffff => Some(format!("{:#x}", mask)),
+            _ => None,
+        };
+        Some((mark, mask))
+    }
+
+    // Like ip, only print the protocol of the rules that were not added by the kernel
+    fn protocol(&self) -> Option<String> {
+        match self.protocol {
+            Some(protocol) if protocol != 0 && protocol != RTPROT_KERNEL => {
+                Some(protocol_name(RouteProtocol::from(protocol)))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Format a rule like `ip rule show`
+pub fn format_rule(rule: &RuleMessage) -> String {
+    let header = &rule.header;
+    let info = RuleInfo::new(rule);
+    let mut line = format!("{}:\t", info.priority);
+    if header.flags & FIB_RULE_INVERT != 0 {
+        line += "not ";
+    }
+    if info.source.is_some() || header.src_len != 0 {
+        line += &format!(
+            "from {}",
+            format_prefix(info.source, header.src_len, header.family)
+        );
+    } else {
+        line += "from all";
+    }
+    if info.destination.is_some() || header.dst_len != 0 {
+        line += &format!(
+            " to {}",
+            format_prefix(info.destination, header.dst_len, header.family)
+        );
+    }
+    if header.tos != 0 {
+        line += &format!(" tos {:#x}", header.tos);
+    }
+    if let Some((mark, mask)) = info.fwmark() {
+        line += &format!(" fwmark {}", mark);
+        if let Some(mask) = mask {
+            line += &format!("/{}", mask);
+        }
+    }
+    if let Some(name) = info.input_interface {
+        line += &format!(" iif {}", name);
+        if header.flags & FIB_RULE_IIF_DETACHED != 0 {
+            line += " [detached]";
+        }
+    }
+    if let Some(name) = info.output_interface {
+        line += &format!(" oif {}", name);
+        if header.flags & FIB_RULE_OIF_DETACHED != 0 {
+            line += " [detached]";
+        }
+    }
+    let table = rule_table(rule);
+    if info.l3mdev {
+        line += " lookup [l3mdev-table]";
+    } else if table != 0 {
+        line += &format!(" lookup {}", table_name(table));
+    }
+    if let Some(protocol) = info.protocol() {
+        line += &format!(" proto {}", protocol);
+    }
+    if header.action == FR_ACT_GOTO {
+        match info.goto {
+            Some(priority) => line += &format!(" goto {}", priority),
+            None => line += " goto none",
+        }
+    } else if header.action != FR_ACT_TO_TBL {
+        line += &format!(" {}", action_name(header.action));
+    }
+    line
+}
+
+/// Return a rule as a JSON object, with the members of `ip -j rule show`
+pub fn rule_json(rule: &RuleMessage) -> Json {
+    let header = &rule.header;
+    let info = RuleInfo::new(rule);
+    let mut json = Json::object();
+    json.insert("priority", info.priority);
+    if header.flags & FIB_RULE_INVERT != 0 {
+        json.insert("not", Json::Null);
+    }
+    match info.source {
+        Some(source) => {
+            json.insert("src", format_ip(source));
+            if header.src_len != max_prefix_len(header.family) {
+                json.insert("srclen", header.src_len);
+            }
+        }
+        None if header.src_len != 0 => {
+            json.insert("src", "0");
+            json.insert("srclen", header.src_len);
+        }
+        None => {
+            json.insert("src", "all");
+        }
+    }
+    match info.destination {
+        Some(destination) => {
+            json.insert("dst", format_ip(destination));
+            if header.dst_len != max_prefix_len(header.family) {
+                json.insert("dstlen", header.dst_len);
+            }
+        }
+        None if header.dst_len != 0 => {
+            json.insert("dst", "0");
+            json.insert("dstlen", header.dst_len);
+        }
+        None => {}
+    }
+    if header.tos != 0 {
+        json.insert("tos", format!("{:#x}", header.tos));
+    }
+    if let Some((mark, mask)) = info.fwmark() {
+        json.insert("fwmark", mark);
+        if let Some(mask) = mask {
+            json.insert("fwmask", mask);
+        }
+    }
+    if let Some(name) = info.input_interface {
+        json.insert("iif", name);
+        if header.flags & FIB_RULE_IIF_DETACHED != 0 {
+            json.insert("iif_detached", Json::Null);
+        }
+    }
+    if let Some(name) = info.output_interface {
+        json.insert("oif", name);
+        if header.flags & FIB_RULE_OIF_DETACHED != 0 {
+            json.insert("oif_detached", Json::Null);
+        }
+    }
+    let table = rule_table(rule);
+    if info.l3mdev {
+        json.insert("l3mdev", Json::Null);
+    } else if table != 0 {
+        json.insert("table", table_name(table));
+    }
+    if let Some(protocol) = info.protocol() {
+        json.insert("protocol", protocol);
+    }
+    if header.action == FR_ACT_GOTO {
+        match info.goto {
+            Some(priority) => json.insert("goto", priority),
+            None => json.insert("goto", "none"),
+        };
+    } else if header.action != FR_ACT_TO_TBL {
+        json.insert("action", action_name(header.action));
+    }
+    json
+}
+
+// Like ip, only show the IPv4 rules by default
+fn family(ctx: &Context) -> u8 {
+    if ctx.family == 0 {
+        libc::AF_INET as u8
+    } else {
+        ctx.family
+    }
+}
+
+fn show(ctx: &mut Context, mut args: Args) -> Result<(), Error> {
+    let mut table = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "table" | "lookup" => table = Some(parse_table(&args.value("table")?)?),
+            _ => return Err(unknown(&arg)),
+        }
+    }
+
+    let mut request = ctx.handle.rule().get().family(family(ctx));
+    if let Some(table) = table {
+        request = request.table(table);
+    }
+    let rules = ctx.run(request.execute())?;
+    if ctx.json {
+        println!("{}", Json::Array(rules.iter().map(rule_json).collect()));
+    } else {
+        for rule in &rules {
+            println!("{}", format_rule(rule));
+        }
+    }
+    Ok(())
+}
+
+/// Parse a firewall mark and an optional mask, in decimal or hexadecimal
+fn parse_fwmark(value: &str) -> Result<(u32, Option<u32>), Error> {
+    fn parse_u32(value: &str) -> Option<u32> {
+        match value.strip_prefix("0x") {
+            Some(hex) => u32::from_str_radix(hex, 16).ok(),
+            None => value.parse().ok(),
+        }
+    }
+    let mut parts = value.splitn(2, '/');
+    let mark = parts
+        .next()
+        .and_then(parse_u32)
+        .ok_or_else(|| invalid(value, "fwmark"))?;
+    let mask = match parts.next() {
+        Some(mask) => Some(parse_u32(mask).ok_or_else(|| invalid(value, "fwmark"))?),
+        None => None,
+    };
+    Ok((mark, mask))
+}
+
+fn add_or_del(ctx: &mut Context, mut args: Args, add: bool) -> Result<(), Error> {
+    let mut request = ctx.handle.rule().add();
+    if ctx.family != 0 {
+        request = request.family(ctx.family);
+    }
+    let mut table = false;
+    let mut action = None;
+    while let Some(arg) = args.next() {
+        request = match arg.as_str() {
+            "not" => {
+                request.message_mut().header.flags |= FIB_RULE_INVERT;
+                request
+            }
+            "from" | "to" => {
+                let value = args.value(&arg)?;
+                if value == "all" {
+                    request
+                } else {
+                    let (address, prefix_len) = parse_prefix(&value, &arg)?;
+                    if arg == "from" {
+                        request.source(address, prefix_len)
+                    } else {
+                        request.destination(address, prefix_len)
+                    }
+                }
+            }
+            "iif" | "dev" => request.input_interface(args.value("iif")?),
+            "oif" => request.output_interface(args.value("oif")?),
+            "fwmark" => {
+                let (mark, mask) = parse_fwmark(&args.value("fwmark")?)?;
+                if let Some(mask) = mask {
+                    request.message_mut().nlas.push(RuleNla::FwMask(mask));
+                }
+                request.fwmark(mark)
+            }
+            "priority" | "preference" | "pref" | "order" => {
+                request.priority(args.parse("priority")?)
+            }
+            "table" | "lookup" => {
+                table = true;
+                request.table(parse_table(&args.value("table")?)?)
+            }
+            "l3mdev" => {
+                table = true;
+                request.l3mdev()
+            }
+            _ => match parse_action(&arg) {
+                Some(value) => {
+                    action = Some(value);
+                    request
+                }
+                None => return Err(unknown(&arg)),
+            },
+        };
+    }
+
+    if add {
+        if let Some(action) = action {
+            request = request.action(action);
+        }
+        ctx.run(request.execute())
+    } else {
+        // Like ip, only match the table and the action of the rule if they are given
+        let mut message = request.message_mut().clone();
+        if !table {
+            message.header.table = 0;
+        }
+        message.header.action = action.unwrap_or(FR_ACT_UNSPEC);
+        ctx.run(ctx.handle.rule().del(message).execute())
+    }
+}
+
+pub fn run(ctx: &mut Context, mut args: Args) -> Result<(), Error> {
+    let command = match args.next() {
+        Some(command) => command,
+        None => return show(ctx, args),
+    };
+    if matches(&command, "show") || matches(&command, "list") || command == "lst" {
+        show(ctx, args)
+    } else if matches(&command, "add") {
+        add_or_del(ctx, args, true)
+    } else if matches(&command, "delete") {
+        add_or_del(ctx, args, false)
+    } else if matches(&command, "help") {
+        println!("{}", USAGE);
+        Ok(())
+    } else {
+        Err(Error::Usage(format!(
+            "Command \"{}\" is unknown, try \"rip rule help\".",
+            command
+        )))
+    }
+}
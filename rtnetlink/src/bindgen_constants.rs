@@ -132,43 +132,108 @@ pub const IFLA_BR_VLAN_STATS_ENABLED: int = 41;
 pub const IFLA_BR_MCAST_STATS_ENABLED: int = 42;
 pub const IFLA_BR_MCAST_IGMP_VERSION: int = 43;
 pub const IFLA_BR_MCAST_MLD_VERSION: int = 44;
-//
-// pub const BRIDGE_MODE_UNSPEC: int = 0;
-// pub const BRIDGE_MODE_HAIRPIN: int = 1;
-//
-// pub const IFLA_BRPORT_UNSPEC: int = 0;
-// pub const IFLA_BRPORT_STATE: int = 1;
-// pub const IFLA_BRPORT_PRIORITY: int = 2;
-// pub const IFLA_BRPORT_COST: int = 3;
-// pub const IFLA_BRPORT_MODE: int = 4;
-// pub const IFLA_BRPORT_GUARD: int = 5;
-// pub const IFLA_BRPORT_PROTECT: int = 6;
-// pub const IFLA_BRPORT_FAST_LEAVE: int = 7;
-// pub const IFLA_BRPORT_LEARNING: int = 8;
-// pub const IFLA_BRPORT_UNICAST_FLOOD: int = 9;
-// pub const IFLA_BRPORT_PROXYARP: int = 10;
-// pub const IFLA_BRPORT_LEARNING_SYNC: int = 11;
-// pub const IFLA_BRPORT_PROXYARP_WIFI: int = 12;
-// pub const IFLA_BRPORT_ROOT_ID: int = 13;
-// pub const IFLA_BRPORT_BRIDGE_ID: int = 14;
-// pub const IFLA_BRPORT_DESIGNATED_PORT: int = 15;
-// pub const IFLA_BRPORT_DESIGNATED_COST: int = 16;
-// pub const IFLA_BRPORT_ID: int = 17;
-// pub const IFLA_BRPORT_NO: int = 18;
-// pub const IFLA_BRPORT_TOPOLOGY_CHANGE_ACK: int = 19;
-// pub const IFLA_BRPORT_CONFIG_PENDING: int = 20;
-// pub const IFLA_BRPORT_MESSAGE_AGE_TIMER: int = 21;
-// pub const IFLA_BRPORT_FORWARD_DELAY_TIMER: int = 22;
-// pub const IFLA_BRPORT_HOLD_TIMER: int = 23;
-// pub const IFLA_BRPORT_FLUSH: int = 24;
-// pub const IFLA_BRPORT_MULTICAST_ROUTER: int = 25;
-// pub const IFLA_BRPORT_PAD: int = 26;
-// pub const IFLA_BRPORT_MCAST_FLOOD: int = 27;
-// pub const IFLA_BRPORT_MCAST_TO_UCAST: int = 28;
-// pub const IFLA_BRPORT_VLAN_TUNNEL: int = 29;
-// pub const IFLA_BRPORT_BCAST_FLOOD: int = 30;
-// pub const IFLA_BRPORT_GROUP_FWD_MASK: int = 31;
-// pub const IFLA_BRPORT_NEIGH_SUPPRESS: int = 32;
+pub const IFLA_BR_VLAN_STATS_PER_PORT: int = 45;
+pub const IFLA_BR_MULTI_BOOLOPT: int = 46;
+pub const IFLA_BR_MCAST_QUERIER_STATE: int = 47;
+pub const IFLA_BR_FDB_N_LEARNED: int = 48;
+pub const IFLA_BR_FDB_MAX_LEARNED: int = 49;
+
+pub const BR_BOOLOPT_NO_LL_LEARN: int = 0;
+pub const BR_BOOLOPT_MCAST_VLAN_SNOOPING: int = 1;
+pub const BR_BOOLOPT_MST_ENABLE: int = 2;
+
+pub const IFLA_BRIDGE_FLAGS: int = 0;
+pub const IFLA_BRIDGE_MODE: int = 1;
+pub const IFLA_BRIDGE_VLAN_INFO: int = 2;
+pub const IFLA_BRIDGE_VLAN_TUNNEL_INFO: int = 3;
+pub const BRIDGE_FLAGS_MASTER: int = 1;
+pub const BRIDGE_FLAGS_SELF: int = 2;
+pub const BRIDGE_MODE_VEB: int = 0;
+pub const BRIDGE_MODE_VEPA: int = 1;
+// pub const BRIDGE_MODE_UNDEF: int = 65535;
+pub const BRIDGE_VLAN_INFO_MASTER: int = 1;
+pub const BRIDGE_VLAN_INFO_PVID: int = 2;
+pub const BRIDGE_VLAN_INFO_UNTAGGED: int = 4;
+pub const BRIDGE_VLAN_INFO_RANGE_BEGIN: int = 8;
+pub const BRIDGE_VLAN_INFO_RANGE_END: int = 16;
+pub const BRIDGE_VLAN_INFO_BRENTRY: int = 32;
+pub const BRIDGE_VLAN_INFO_ONLY_OPTS: int = 64;
+pub const IFLA_BRIDGE_VLAN_TUNNEL_UNSPEC: int = 0;
+pub const IFLA_BRIDGE_VLAN_TUNNEL_ID: int = 1;
+pub const IFLA_BRIDGE_VLAN_TUNNEL_VID: int = 2;
+pub const IFLA_BRIDGE_VLAN_TUNNEL_FLAGS: int = 3;
+
+pub const MDBA_UNSPEC: int = 0;
+pub const MDBA_MDB: int = 1;
+pub const MDBA_ROUTER: int = 2;
+pub const MDBA_MDB_UNSPEC: int = 0;
+pub const MDBA_MDB_ENTRY: int = 1;
+pub const MDBA_MDB_ENTRY_UNSPEC: int = 0;
+pub const MDBA_MDB_ENTRY_INFO: int = 1;
+pub const MDBA_MDB_EATTR_UNSPEC: int = 0;
+pub const MDBA_MDB_EATTR_TIMER: int = 1;
+pub const MDBA_MDB_EATTR_SRC_LIST: int = 2;
+pub const MDBA_MDB_EATTR_GROUP_MODE: int = 3;
+pub const MDBA_MDB_EATTR_SOURCE: int = 4;
+pub const MDBA_MDB_EATTR_RTPROT: int = 5;
+pub const MDBA_ROUTER_UNSPEC: int = 0;
+pub const MDBA_ROUTER_PORT: int = 1;
+pub const MDBA_ROUTER_PATTR_UNSPEC: int = 0;
+pub const MDBA_ROUTER_PATTR_TIMER: int = 1;
+pub const MDBA_ROUTER_PATTR_TYPE: int = 2;
+pub const MDBA_SET_ENTRY_UNSPEC: int = 0;
+pub const MDBA_SET_ENTRY: int = 1;
+pub const MDBA_SET_ENTRY_ATTRS: int = 2;
+pub const MDB_TEMPORARY: int = 0;
+pub const MDB_PERMANENT: int = 1;
+pub const MDB_FLAGS_OFFLOAD: int = 1;
+pub const MDB_FLAGS_FAST_LEAVE: int = 2;
+pub const MDB_FLAGS_STAR_EXCL: int = 4;
+pub const MDB_FLAGS_BLOCKED: int = 8;
+pub const ETH_P_IP: int = 2048;
+pub const ETH_P_IPV6: int = 34525;
+
+pub const IFLA_BRPORT_UNSPEC: int = 0;
+pub const IFLA_BRPORT_STATE: int = 1;
+pub const IFLA_BRPORT_PRIORITY: int = 2;
+pub const IFLA_BRPORT_COST: int = 3;
+pub const IFLA_BRPORT_MODE: int = 4;
+pub const IFLA_BRPORT_GUARD: int = 5;
+pub const IFLA_BRPORT_PROTECT: int = 6;
+pub const IFLA_BRPORT_FAST_LEAVE: int = 7;
+pub const IFLA_BRPORT_LEARNING: int = 8;
+pub const IFLA_BRPORT_UNICAST_FLOOD: int = 9;
+pub const IFLA_BRPORT_PROXYARP: int = 10;
+pub const IFLA_BRPORT_LEARNING_SYNC: int = 11;
+pub const IFLA_BRPORT_PROXYARP_WIFI: int = 12;
+pub const IFLA_BRPORT_ROOT_ID: int = 13;
+pub const IFLA_BRPORT_BRIDGE_ID: int = 14;
+pub const IFLA_BRPORT_DESIGNATED_PORT: int = 15;
+pub const IFLA_BRPORT_DESIGNATED_COST: int = 16;
+pub const IFLA_BRPORT_ID: int = 17;
+pub const IFLA_BRPORT_NO: int = 18;
+pub const IFLA_BRPORT_TOPOLOGY_CHANGE_ACK: int = 19;
+pub const IFLA_BRPORT_CONFIG_PENDING: int = 20;
+pub const IFLA_BRPORT_MESSAGE_AGE_TIMER: int = 21;
+pub const IFLA_BRPORT_FORWARD_DELAY_TIMER: int = 22;
+pub const IFLA_BRPORT_HOLD_TIMER: int = 23;
+pub const IFLA_BRPORT_FLUSH: int = 24;
+pub const IFLA_BRPORT_MULTICAST_ROUTER: int = 25;
+pub const IFLA_BRPORT_PAD: int = 26;
+pub const IFLA_BRPORT_MCAST_FLOOD: int = 27;
+pub const IFLA_BRPORT_MCAST_TO_UCAST: int = 28;
+pub const IFLA_BRPORT_VLAN_TUNNEL: int = 29;
+pub const IFLA_BRPORT_BCAST_FLOOD: int = 30;
+pub const IFLA_BRPORT_GROUP_FWD_MASK: int = 31;
+pub const IFLA_BRPORT_NEIGH_SUPPRESS: int = 32;
+pub const IFLA_BRPORT_ISOLATED: int = 33;
+pub const IFLA_BRPORT_BACKUP_PORT: int = 34;
+
+pub const BR_STATE_DISABLED: int = 0;
+pub const BR_STATE_LISTENING: int = 1;
+pub const BR_STATE_LEARNING: int = 2;
+pub const BR_STATE_FORWARDING: int = 3;
+pub const BR_STATE_BLOCKING: int = 4;
 
 pub const IFLA_INFO_UNSPEC: int = 0;
 pub const IFLA_INFO_KIND: int = 1;
@@ -186,32 +251,34 @@ pub const IFLA_VLAN_PROTOCOL: int = 5;
 
 // pub const IFLA_VLAN_QOS_UNSPEC: int = 0;
 // pub const IFLA_VLAN_QOS_MAPPING: int = 1;
-//
-// pub const IFLA_MACVLAN_UNSPEC: int = 0;
-// pub const IFLA_MACVLAN_MODE: int = 1;
-// pub const IFLA_MACVLAN_FLAGS: int = 2;
-// pub const IFLA_MACVLAN_MACADDR_MODE: int = 3;
-// pub const IFLA_MACVLAN_MACADDR: int = 4;
-// pub const IFLA_MACVLAN_MACADDR_DATA: int = 5;
-// pub const IFLA_MACVLAN_MACADDR_COUNT: int = 6;
-//
-// pub const MACVLAN_MODE_PRIVATE: int = 1;
-// pub const MACVLAN_MODE_VEPA: int = 2;
-// pub const MACVLAN_MODE_BRIDGE: int = 4;
-// pub const MACVLAN_MODE_PASSTHRU: int = 8;
-// pub const MACVLAN_MODE_SOURCE: int = 16;
-//
-// pub const MACVLAN_MACADDR_ADD: int = 0;
-// pub const MACVLAN_MACADDR_DEL: int = 1;
-// pub const MACVLAN_MACADDR_FLUSH: int = 2;
-// pub const MACVLAN_MACADDR_SET: int = 3;
-//
-// pub const IFLA_VRF_UNSPEC: int = 0;
-// pub const IFLA_VRF_TABLE: int = 1;
-//
-// pub const IFLA_VRF_PORT_UNSPEC: int = 0;
-// pub const IFLA_VRF_PORT_TABLE: int = 1;
-//
+
+pub const IFLA_MACVLAN_UNSPEC: int = 0;
+pub const IFLA_MACVLAN_MODE: int = 1;
+pub const IFLA_MACVLAN_FLAGS: int = 2;
+pub const IFLA_MACVLAN_MACADDR_MODE: int = 3;
+pub const IFLA_MACVLAN_MACADDR: int = 4;
+pub const IFLA_MACVLAN_MACADDR_DATA: int = 5;
+pub const IFLA_MACVLAN_MACADDR_COUNT: int = 6;
+pub const IFLA_MACVLAN_BC_QUEUE_LEN: int = 7;
+pub const IFLA_MACVLAN_BC_QUEUE_LEN_USED: int = 8;
+
+pub const MACVLAN_MODE_PRIVATE: int = 1;
+pub const MACVLAN_MODE_VEPA: int = 2;
+pub const MACVLAN_MODE_BRIDGE: int = 4;
+pub const MACVLAN_MODE_PASSTHRU: int = 8;
+pub const MACVLAN_MODE_SOURCE: int = 16;
+
+pub const MACVLAN_MACADDR_ADD: int = 0;
+pub const MACVLAN_MACADDR_DEL: int = 1;
+pub const MACVLAN_MACADDR_FLUSH: int = 2;
+pub const MACVLAN_MACADDR_SET: int = 3;
+
+pub const IFLA_VRF_UNSPEC: int = 0;
+pub const IFLA_VRF_TABLE: int = 1;
+
+pub const IFLA_VRF_PORT_UNSPEC: int = 0;
+pub const IFLA_VRF_PORT_TABLE: int = 1;
+
 // pub const IFLA_MACSEC_UNSPEC: int = 0;
 // pub const IFLA_MACSEC_SCI: int = 1;
 // pub const IFLA_MACSEC_PORT: int = 2;
@@ -232,16 +299,85 @@ pub const IFLA_VLAN_PROTOCOL: int = 5;
 // pub const MACSEC_VALIDATE_CHECK: int = 1;
 // pub const MACSEC_VALIDATE_STRICT: int = 2;
 // pub const MACSEC_VALIDATE_MAX: int = 2;
-//
-// pub const IFLA_IPVLAN_UNSPEC: int = 0;
-// pub const IFLA_IPVLAN_MODE: int = 1;
-// pub const IFLA_IPVLAN_FLAGS: int = 2;
-//
-// pub const IPVLAN_MODE_L2: int = 0;
-// pub const IPVLAN_MODE_L3: int = 1;
-// pub const IPVLAN_MODE_L3S: int = 2;
+
+pub const IFLA_IPVLAN_UNSPEC: int = 0;
+pub const IFLA_IPVLAN_MODE: int = 1;
+pub const IFLA_IPVLAN_FLAGS: int = 2;
+
+pub const IPVLAN_MODE_L2: int = 0;
+pub const IPVLAN_MODE_L3: int = 1;
+pub const IPVLAN_MODE_L3S: int = 2;
 // pub const IPVLAN_MODE_MAX: int = 3;
-//
+
+pub const IFLA_GRE_UNSPEC: int = 0;
+pub const IFLA_GRE_LINK: int = 1;
+pub const IFLA_GRE_IFLAGS: int = 2;
+pub const IFLA_GRE_OFLAGS: int = 3;
+pub const IFLA_GRE_IKEY: int = 4;
+pub const IFLA_GRE_OKEY: int = 5;
+pub const IFLA_GRE_LOCAL: int = 6;
+pub const IFLA_GRE_REMOTE: int = 7;
+pub const IFLA_GRE_TTL: int = 8;
+pub const IFLA_GRE_TOS: int = 9;
+pub const IFLA_GRE_PMTUDISC: int = 10;
+pub const IFLA_GRE_ENCAP_LIMIT: int = 11;
+pub const IFLA_GRE_FLOWINFO: int = 12;
+pub const IFLA_GRE_FLAGS: int = 13;
+pub const IFLA_GRE_ENCAP_TYPE: int = 14;
+pub const IFLA_GRE_ENCAP_FLAGS: int = 15;
+pub const IFLA_GRE_ENCAP_SPORT: int = 16;
+pub const IFLA_GRE_ENCAP_DPORT: int = 17;
+pub const IFLA_GRE_COLLECT_METADATA: int = 18;
+pub const IFLA_GRE_IGNORE_DF: int = 19;
+pub const IFLA_GRE_FWMARK: int = 20;
+pub const IFLA_GRE_ERSPAN_INDEX: int = 21;
+pub const IFLA_GRE_ERSPAN_VER: int = 22;
+pub const IFLA_GRE_ERSPAN_DIR: int = 23;
+pub const IFLA_GRE_ERSPAN_HWID: int = 24;
+
+pub const GRE_CSUM: int = 32768;
+pub const GRE_ROUTING: int = 16384;
+pub const GRE_KEY: int = 8192;
+pub const GRE_SEQ: int = 4096;
+
+pub const IFLA_IPTUN_UNSPEC: int = 0;
+pub const IFLA_IPTUN_LINK: int = 1;
+pub const IFLA_IPTUN_LOCAL: int = 2;
+pub const IFLA_IPTUN_REMOTE: int = 3;
+pub const IFLA_IPTUN_TTL: int = 4;
+pub const IFLA_IPTUN_TOS: int = 5;
+pub const IFLA_IPTUN_ENCAP_LIMIT: int = 6;
+pub const IFLA_IPTUN_FLOWINFO: int = 7;
+pub const IFLA_IPTUN_FLAGS: int = 8;
+pub const IFLA_IPTUN_PROTO: int = 9;
+pub const IFLA_IPTUN_PMTUDISC: int = 10;
+pub const IFLA_IPTUN_6RD_PREFIX: int = 11;
+pub const IFLA_IPTUN_6RD_RELAY_PREFIX: int = 12;
+pub const IFLA_IPTUN_6RD_PREFIXLEN: int = 13;
+pub const IFLA_IPTUN_6RD_RELAY_PREFIXLEN: int = 14;
+pub const IFLA_IPTUN_ENCAP_TYPE: int = 15;
+pub const IFLA_IPTUN_ENCAP_FLAGS: int = 16;
+pub const IFLA_IPTUN_ENCAP_SPORT: int = 17;
+pub const IFLA_IPTUN_ENCAP_DPORT: int = 18;
+pub const IFLA_IPTUN_COLLECT_METADATA: int = 19;
+pub const IFLA_IPTUN_FWMARK: int = 20;
+
+pub const TUNNEL_ENCAP_NONE: int = 0;
+pub const TUNNEL_ENCAP_FOU: int = 1;
+pub const TUNNEL_ENCAP_GUE: int = 2;
+
+pub const TUNNEL_ENCAP_FLAG_CSUM: int = 1;
+pub const TUNNEL_ENCAP_FLAG_CSUM6: int = 2;
+pub const TUNNEL_ENCAP_FLAG_REMCSUM: int = 4;
+
+pub const IFLA_VTI_UNSPEC: int = 0;
+pub const IFLA_VTI_LINK: int = 1;
+pub const IFLA_VTI_IKEY: int = 2;
+pub const IFLA_VTI_OKEY: int = 3;
+pub const IFLA_VTI_LOCAL: int = 4;
+pub const IFLA_VTI_REMOTE: int = 5;
+pub const IFLA_VTI_FWMARK: int = 6;
+
 // pub const IFLA_VXLAN_UNSPEC: int = 0;
 // pub const IFLA_VXLAN_ID: int = 1;
 // pub const IFLA_VXLAN_GROUP: int = 2;
@@ -342,25 +478,25 @@ pub const IFLA_VLAN_PROTOCOL: int = 5;
 // pub const IFLA_BOND_SLAVE_AD_ACTOR_OPER_PORT_STATE: int = 7;
 // pub const IFLA_BOND_SLAVE_AD_PARTNER_OPER_PORT_STATE: int = 8;
 //
-// pub const IFLA_VF_INFO_UNSPEC: int = 0;
-// pub const IFLA_VF_INFO: int = 1;
+pub const IFLA_VF_INFO_UNSPEC: int = 0;
+pub const IFLA_VF_INFO: int = 1;
 //
-// pub const IFLA_VF_UNSPEC: int = 0;
-// pub const IFLA_VF_MAC: int = 1;
-// pub const IFLA_VF_VLAN: int = 2;
-// pub const IFLA_VF_TX_RATE: int = 3;
-// pub const IFLA_VF_SPOOFCHK: int = 4;
-// pub const IFLA_VF_LINK_STATE: int = 5;
-// pub const IFLA_VF_RATE: int = 6;
-// pub const IFLA_VF_RSS_QUERY_EN: int = 7;
-// pub const IFLA_VF_STATS: int = 8;
-// pub const IFLA_VF_TRUST: int = 9;
-// pub const IFLA_VF_IB_NODE_GUID: int = 10;
-// pub const IFLA_VF_IB_PORT_GUID: int = 11;
-// pub const IFLA_VF_VLAN_LIST: int = 12;
+pub const IFLA_VF_UNSPEC: int = 0;
+pub const IFLA_VF_MAC: int = 1;
+pub const IFLA_VF_VLAN: int = 2;
+pub const IFLA_VF_TX_RATE: int = 3;
+pub const IFLA_VF_SPOOFCHK: int = 4;
+pub const IFLA_VF_LINK_STATE: int = 5;
+pub const IFLA_VF_RATE: int = 6;
+pub const IFLA_VF_RSS_QUERY_EN: int = 7;
+pub const IFLA_VF_STATS: int = 8;
+pub const IFLA_VF_TRUST: int = 9;
+pub const IFLA_VF_IB_NODE_GUID: int = 10;
+pub const IFLA_VF_IB_PORT_GUID: int = 11;
+pub const IFLA_VF_VLAN_LIST: int = 12;
 //
-// pub const IFLA_VF_VLAN_INFO_UNSPEC: int = 0;
-// pub const IFLA_VF_VLAN_INFO: int = 1;
+pub const IFLA_VF_VLAN_INFO_UNSPEC: int = 0;
+pub const IFLA_VF_VLAN_INFO: int = 1;
 //
 // pub const TCA_ROOT_UNSPEC: int = 0;
 // pub const TCA_ROOT_TAB: int = 1;
@@ -370,65 +506,65 @@ pub const IFLA_VLAN_PROTOCOL: int = 5;
 //
 // pub const NDUSEROPT_UNSPEC: int = 0;
 // pub const NDUSEROPT_SRCADDR: int = 1;
-//
-// pub const RTNLGRP_NONE: int = 0;
-// pub const RTNLGRP_LINK: int = 1;
-// pub const RTNLGRP_NOTIFY: int = 2;
-// pub const RTNLGRP_NEIGH: int = 3;
-// pub const RTNLGRP_TC: int = 4;
-// pub const RTNLGRP_IPV4_IFADDR: int = 5;
-// pub const RTNLGRP_IPV4_MROUTE: int = 6;
-// pub const RTNLGRP_IPV4_ROUTE: int = 7;
-// pub const RTNLGRP_IPV4_RULE: int = 8;
-// pub const RTNLGRP_IPV6_IFADDR: int = 9;
-// pub const RTNLGRP_IPV6_MROUTE: int = 10;
-// pub const RTNLGRP_IPV6_ROUTE: int = 11;
-// pub const RTNLGRP_IPV6_IFINFO: int = 12;
-// pub const RTNLGRP_DECNET_IFADDR: int = 13;
+
+pub const RTNLGRP_NONE: int = 0;
+pub const RTNLGRP_LINK: int = 1;
+pub const RTNLGRP_NOTIFY: int = 2;
+pub const RTNLGRP_NEIGH: int = 3;
+pub const RTNLGRP_TC: int = 4;
+pub const RTNLGRP_IPV4_IFADDR: int = 5;
+pub const RTNLGRP_IPV4_MROUTE: int = 6;
+pub const RTNLGRP_IPV4_ROUTE: int = 7;
+pub const RTNLGRP_IPV4_RULE: int = 8;
+pub const RTNLGRP_IPV6_IFADDR: int = 9;
+pub const RTNLGRP_IPV6_MROUTE: int = 10;
+pub const RTNLGRP_IPV6_ROUTE: int = 11;
+pub const RTNLGRP_IPV6_IFINFO: int = 12;
+pub const RTNLGRP_DECNET_IFADDR: int = 13;
 // pub const RTNLGRP_NOP2: int = 14;
-// pub const RTNLGRP_DECNET_ROUTE: int = 15;
-// pub const RTNLGRP_DECNET_RULE: int = 16;
+pub const RTNLGRP_DECNET_ROUTE: int = 15;
+pub const RTNLGRP_DECNET_RULE: int = 16;
 // pub const RTNLGRP_NOP4: int = 17;
-// pub const RTNLGRP_IPV6_PREFIX: int = 18;
-// pub const RTNLGRP_IPV6_RULE: int = 19;
-// pub const RTNLGRP_ND_USEROPT: int = 20;
-// pub const RTNLGRP_PHONET_IFADDR: int = 21;
-// pub const RTNLGRP_PHONET_ROUTE: int = 22;
-// pub const RTNLGRP_DCB: int = 23;
-// pub const RTNLGRP_IPV4_NETCONF: int = 24;
-// pub const RTNLGRP_IPV6_NETCONF: int = 25;
-// pub const RTNLGRP_MDB: int = 26;
-// pub const RTNLGRP_MPLS_ROUTE: int = 27;
-// pub const RTNLGRP_NSID: int = 28;
-// pub const RTNLGRP_MPLS_NETCONF: int = 29;
-// pub const RTNLGRP_IPV4_MROUTE_R: int = 30;
-// pub const RTNLGRP_IPV6_MROUTE_R: int = 31;
-//
-// pub const IFLA_VF_LINK_STATE_AUTO: int = 0;
-// pub const IFLA_VF_LINK_STATE_ENABLE: int = 1;
-// pub const IFLA_VF_LINK_STATE_DISABLE: int = 2;
+pub const RTNLGRP_IPV6_PREFIX: int = 18;
+pub const RTNLGRP_IPV6_RULE: int = 19;
+pub const RTNLGRP_ND_USEROPT: int = 20;
+pub const RTNLGRP_PHONET_IFADDR: int = 21;
+pub const RTNLGRP_PHONET_ROUTE: int = 22;
+pub const RTNLGRP_DCB: int = 23;
+pub const RTNLGRP_IPV4_NETCONF: int = 24;
+pub const RTNLGRP_IPV6_NETCONF: int = 25;
+pub const RTNLGRP_MDB: int = 26;
+pub const RTNLGRP_MPLS_ROUTE: int = 27;
+pub const RTNLGRP_NSID: int = 28;
+pub const RTNLGRP_MPLS_NETCONF: int = 29;
+pub const RTNLGRP_IPV4_MROUTE_R: int = 30;
+pub const RTNLGRP_IPV6_MROUTE_R: int = 31;
+
+pub const IFLA_VF_LINK_STATE_AUTO: int = 0;
+pub const IFLA_VF_LINK_STATE_ENABLE: int = 1;
+pub const IFLA_VF_LINK_STATE_DISABLE: int = 2;
 //
-// pub const IFLA_VF_STATS_RX_PACKETS: int = 0;
-// pub const IFLA_VF_STATS_TX_PACKETS: int = 1;
-// pub const IFLA_VF_STATS_RX_BYTES: int = 2;
-// pub const IFLA_VF_STATS_TX_BYTES: int = 3;
-// pub const IFLA_VF_STATS_BROADCAST: int = 4;
-// pub const IFLA_VF_STATS_MULTICAST: int = 5;
-// pub const IFLA_VF_STATS_PAD: int = 6;
-// pub const IFLA_VF_STATS_RX_DROPPED: int = 7;
-// pub const IFLA_VF_STATS_TX_DROPPED: int = 8;
+pub const IFLA_VF_STATS_RX_PACKETS: int = 0;
+pub const IFLA_VF_STATS_TX_PACKETS: int = 1;
+pub const IFLA_VF_STATS_RX_BYTES: int = 2;
+pub const IFLA_VF_STATS_TX_BYTES: int = 3;
+pub const IFLA_VF_STATS_BROADCAST: int = 4;
+pub const IFLA_VF_STATS_MULTICAST: int = 5;
+pub const IFLA_VF_STATS_PAD: int = 6;
+pub const IFLA_VF_STATS_RX_DROPPED: int = 7;
+pub const IFLA_VF_STATS_TX_DROPPED: int = 8;
 //
-// pub const IFLA_VF_PORT_UNSPEC: int = 0;
-// pub const IFLA_VF_PORT: int = 1;
+pub const IFLA_VF_PORT_UNSPEC: int = 0;
+pub const IFLA_VF_PORT: int = 1;
 //
-// pub const IFLA_PORT_UNSPEC: int = 0;
-// pub const IFLA_PORT_VF: int = 1;
-// pub const IFLA_PORT_PROFILE: int = 2;
-// pub const IFLA_PORT_VSI_TYPE: int = 3;
-// pub const IFLA_PORT_INSTANCE_UUID: int = 4;
-// pub const IFLA_PORT_HOST_UUID: int = 5;
-// pub const IFLA_PORT_REQUEST: int = 6;
-// pub const IFLA_PORT_RESPONSE: int = 7;
+pub const IFLA_PORT_UNSPEC: int = 0;
+pub const IFLA_PORT_VF: int = 1;
+pub const IFLA_PORT_PROFILE: int = 2;
+pub const IFLA_PORT_VSI_TYPE: int = 3;
+pub const IFLA_PORT_INSTANCE_UUID: int = 4;
+pub const IFLA_PORT_HOST_UUID: int = 5;
+pub const IFLA_PORT_REQUEST: int = 6;
+pub const IFLA_PORT_RESPONSE: int = 7;
 //
 // pub const PORT_REQUEST_PREASSOCIATE: int = 0;
 // pub const PORT_REQUEST_PREASSOCIATE_RR: int = 1;
@@ -464,31 +600,36 @@ pub const IFLA_VLAN_PROTOCOL: int = 5;
 // pub const IFLA_HSR_SUPERVISION_ADDR: int = 4;
 // pub const IFLA_HSR_SEQ_NR: int = 5;
 // pub const IFLA_HSR_VERSION: int = 6;
-//
-// pub const IFLA_STATS_UNSPEC: int = 0;
-// pub const IFLA_STATS_LINK_64: int = 1;
-// pub const IFLA_STATS_LINK_XSTATS: int = 2;
-// pub const IFLA_STATS_LINK_XSTATS_SLAVE: int = 3;
-// pub const IFLA_STATS_LINK_OFFLOAD_XSTATS: int = 4;
-// pub const IFLA_STATS_AF_SPEC: int = 5;
-//
-// pub const LINK_XSTATS_TYPE_UNSPEC: int = 0;
-// pub const LINK_XSTATS_TYPE_BRIDGE: int = 1;
-//
-// pub const IFLA_OFFLOAD_XSTATS_UNSPEC: int = 0;
-// pub const IFLA_OFFLOAD_XSTATS_CPU_HIT: int = 1;
-//
-// pub const XDP_ATTACHED_NONE: int = 0;
-// pub const XDP_ATTACHED_DRV: int = 1;
-// pub const XDP_ATTACHED_SKB: int = 2;
-// pub const XDP_ATTACHED_HW: int = 3;
-//
-// pub const IFLA_XDP_UNSPEC: int = 0;
-// pub const IFLA_XDP_FD: int = 1;
-// pub const IFLA_XDP_ATTACHED: int = 2;
-// pub const IFLA_XDP_FLAGS: int = 3;
-// pub const IFLA_XDP_PROG_ID: int = 4;
-//
+
+pub const IFLA_STATS_UNSPEC: int = 0;
+pub const IFLA_STATS_LINK_64: int = 1;
+pub const IFLA_STATS_LINK_XSTATS: int = 2;
+pub const IFLA_STATS_LINK_XSTATS_SLAVE: int = 3;
+pub const IFLA_STATS_LINK_OFFLOAD_XSTATS: int = 4;
+pub const IFLA_STATS_AF_SPEC: int = 5;
+
+pub const LINK_XSTATS_TYPE_UNSPEC: int = 0;
+pub const LINK_XSTATS_TYPE_BRIDGE: int = 1;
+
+pub const IFLA_OFFLOAD_XSTATS_UNSPEC: int = 0;
+pub const IFLA_OFFLOAD_XSTATS_CPU_HIT: int = 1;
+
+pub const XDP_ATTACHED_NONE: int = 0;
+pub const XDP_ATTACHED_DRV: int = 1;
+pub const XDP_ATTACHED_SKB: int = 2;
+pub const XDP_ATTACHED_HW: int = 3;
+pub const XDP_ATTACHED_MULTI: int = 4;
+
+pub const IFLA_XDP_UNSPEC: int = 0;
+pub const IFLA_XDP_FD: int = 1;
+pub const IFLA_XDP_ATTACHED: int = 2;
+pub const IFLA_XDP_FLAGS: int = 3;
+pub const IFLA_XDP_PROG_ID: int = 4;
+pub const IFLA_XDP_DRV_PROG_ID: int = 5;
+pub const IFLA_XDP_SKB_PROG_ID: int = 6;
+pub const IFLA_XDP_HW_PROG_ID: int = 7;
+pub const IFLA_XDP_EXPECTED_FD: int = 8;
+
 // pub const IFLA_EVENT_NONE: int = 0;
 // pub const IFLA_EVENT_REBOOT: int = 1;
 // pub const IFLA_EVENT_FEATURES: int = 2;
@@ -506,19 +647,20 @@ pub const IFA_ANYCAST: int = 5;
 pub const IFA_CACHEINFO: int = 6;
 pub const IFA_MULTICAST: int = 7;
 pub const IFA_FLAGS: int = 8;
-//
-// pub const NDA_UNSPEC: int = 0;
-// pub const NDA_DST: int = 1;
-// pub const NDA_LLADDR: int = 2;
-// pub const NDA_CACHEINFO: int = 3;
-// pub const NDA_PROBES: int = 4;
-// pub const NDA_VLAN: int = 5;
-// pub const NDA_PORT: int = 6;
-// pub const NDA_VNI: int = 7;
-// pub const NDA_IFINDEX: int = 8;
-// pub const NDA_MASTER: int = 9;
-// pub const NDA_LINK_NETNSID: int = 10;
-// pub const NDA_SRC_VNI: int = 11;
+
+pub const NDA_UNSPEC: int = 0;
+pub const NDA_DST: int = 1;
+pub const NDA_LLADDR: int = 2;
+pub const NDA_CACHEINFO: int = 3;
+pub const NDA_PROBES: int = 4;
+pub const NDA_VLAN: int = 5;
+pub const NDA_PORT: int = 6;
+pub const NDA_VNI: int = 7;
+pub const NDA_IFINDEX: int = 8;
+pub const NDA_MASTER: int = 9;
+pub const NDA_LINK_NETNSID: int = 10;
+pub const NDA_SRC_VNI: int = 11;
+pub const NDA_PROTOCOL: int = 12;
 //
 // pub const NDTPA_UNSPEC: int = 0;
 // pub const NDTPA_IFINDEX: int = 1;
@@ -768,18 +910,19 @@ pub const NLA_F_NESTED: int = 32768;
 pub const NLA_F_NET_BYTEORDER: int = 16384;
 pub const NLA_TYPE_MASK: int = -49153;
 // pub const NLA_ALIGNTO: int = 4;
-// pub const MACVLAN_FLAG_NOPROMISC: int = 1;
-// pub const IPVLAN_F_PRIVATE: int = 1;
-// pub const IPVLAN_F_VEPA: int = 2;
+pub const MACVLAN_FLAG_NOPROMISC: int = 1;
+pub const IPVLAN_F_PRIVATE: int = 1;
+pub const IPVLAN_F_VEPA: int = 2;
 // pub const MAX_VLAN_LIST_LEN: int = 1;
 // pub const PORT_PROFILE_MAX: int = 40;
 // pub const PORT_UUID_MAX: int = 16;
 // pub const PORT_SELF_VF: int = -1;
-// pub const XDP_FLAGS_UPDATE_IF_NOEXIST: int = 1;
-// pub const XDP_FLAGS_SKB_MODE: int = 2;
-// pub const XDP_FLAGS_DRV_MODE: int = 4;
-// pub const XDP_FLAGS_HW_MODE: int = 8;
-// pub const XDP_FLAGS_MODES: int = 14;
+pub const XDP_FLAGS_UPDATE_IF_NOEXIST: int = 1;
+pub const XDP_FLAGS_SKB_MODE: int = 2;
+pub const XDP_FLAGS_DRV_MODE: int = 4;
+pub const XDP_FLAGS_HW_MODE: int = 8;
+pub const XDP_FLAGS_REPLACE: int = 16;
+pub const XDP_FLAGS_MODES: int = 14;
 // pub const XDP_FLAGS_MASK: int = 15;
 // pub const IFA_F_SECONDARY: int = 1;
 // pub const IFA_F_TEMPORARY: int = 1;
@@ -794,22 +937,23 @@ pub const NLA_TYPE_MASK: int = -49153;
 // pub const IFA_F_NOPREFIXROUTE: int = 512;
 // pub const IFA_F_MCAUTOJOIN: int = 1024;
 // pub const IFA_F_STABLE_PRIVACY: int = 2048;
-// pub const NTF_USE: int = 1;
-// pub const NTF_SELF: int = 2;
-// pub const NTF_MASTER: int = 4;
-// pub const NTF_PROXY: int = 8;
-// pub const NTF_EXT_LEARNED: int = 16;
-// pub const NTF_OFFLOADED: int = 32;
-// pub const NTF_ROUTER: int = 128;
-// pub const NUD_INCOMPLETE: int = 1;
-// pub const NUD_REACHABLE: int = 2;
-// pub const NUD_STALE: int = 4;
-// pub const NUD_DELAY: int = 8;
-// pub const NUD_PROBE: int = 16;
-// pub const NUD_FAILED: int = 32;
-// pub const NUD_NOARP: int = 64;
-// pub const NUD_PERMANENT: int = 128;
-// pub const NUD_NONE: int = 0;
+pub const NTF_USE: int = 1;
+pub const NTF_SELF: int = 2;
+pub const NTF_MASTER: int = 4;
+pub const NTF_PROXY: int = 8;
+pub const NTF_EXT_LEARNED: int = 16;
+pub const NTF_OFFLOADED: int = 32;
+pub const NTF_STICKY: int = 64;
+pub const NTF_ROUTER: int = 128;
+pub const NUD_INCOMPLETE: int = 1;
+pub const NUD_REACHABLE: int = 2;
+pub const NUD_STALE: int = 4;
+pub const NUD_DELAY: int = 8;
+pub const NUD_PROBE: int = 16;
+pub const NUD_FAILED: int = 32;
+pub const NUD_NOARP: int = 64;
+pub const NUD_PERMANENT: int = 128;
+pub const NUD_NONE: int = 0;
 // pub const RTNL_FAMILY_IPMR: int = 128;
 // pub const RTNL_FAMILY_IP6MR: int = 129;
 // pub const RTNL_FAMILY_MAX: int = 129;
@@ -868,10 +1012,10 @@ pub const RTM_F_FIB_MATCH: int = 8192;
 // pub const RTMGRP_DECNET_ROUTE: int = 16384;
 // pub const RTMGRP_IPV6_PREFIX: int = 131072;
 // pub const TCA_FLAG_LARGE_DUMP_ON: int = 1;
-// pub const RTEXT_FILTER_VF: int = 1;
-// pub const RTEXT_FILTER_BRVLAN: int = 2;
-// pub const RTEXT_FILTER_BRVLAN_COMPRESSED: int = 4;
-// pub const RTEXT_FILTER_SKIP_STATS: int = 8;
+pub const RTEXT_FILTER_VF: int = 1;
+pub const RTEXT_FILTER_BRVLAN: int = 2;
+pub const RTEXT_FILTER_BRVLAN_COMPRESSED: int = 4;
+pub const RTEXT_FILTER_SKIP_STATS: int = 8;
 
 pub const ARPHRD_NETROM: int = 0;
 pub const ARPHRD_ETHER: int = 1;
@@ -942,3 +1086,37 @@ pub const ARPHRD_VOID: int = 65535;
 pub const ARPHRD_NONE: int = 65534;
 // pub const ARPOP_REQUEST: int = 1;
 // pub const ARPOP_REPLY: int = 2;
+
+pub const FRA_UNSPEC: int = 0;
+pub const FRA_DST: int = 1;
+pub const FRA_SRC: int = 2;
+pub const FRA_IIFNAME: int = 3;
+pub const FRA_GOTO: int = 4;
+pub const FRA_PRIORITY: int = 6;
+pub const FRA_FWMARK: int = 10;
+pub const FRA_FLOW: int = 11;
+pub const FRA_TUN_ID: int = 12;
+pub const FRA_SUPPRESS_IFGROUP: int = 13;
+pub const FRA_SUPPRESS_PREFIXLEN: int = 14;
+pub const FRA_TABLE: int = 15;
+pub const FRA_FWMASK: int = 16;
+pub const FRA_OIFNAME: int = 17;
+pub const FRA_PAD: int = 18;
+pub const FRA_L3MDEV: int = 19;
+pub const FRA_UID_RANGE: int = 20;
+pub const FRA_PROTOCOL: int = 21;
+pub const FRA_IP_PROTO: int = 22;
+pub const FRA_SPORT_RANGE: int = 23;
+pub const FRA_DPORT_RANGE: int = 24;
+pub const FR_ACT_UNSPEC: int = 0;
+pub const FR_ACT_TO_TBL: int = 1;
+pub const FR_ACT_GOTO: int = 2;
+pub const FR_ACT_NOP: int = 3;
+pub const FR_ACT_BLACKHOLE: int = 6;
+pub const FR_ACT_UNREACHABLE: int = 7;
+pub const FR_ACT_PROHIBIT: int = 8;
+pub const FIB_RULE_PERMANENT: int = 1;
+pub const FIB_RULE_INVERT: int = 2;
+pub const FIB_RULE_UNRESOLVED: int = 4;
+pub const FIB_RULE_IIF_DETACHED: int = 8;
+pub const FIB_RULE_OIF_DETACHED: int = 16;
@@ -1,12 +1,10 @@
 use std::marker::PhantomData;
 
-// FIXME: for some reason, the compiler says BufMut and Emitable are unused, but they _are_ used.
-// These traits need to be in scope for some methods to be called.
-
-use bytes::{BufMut, BytesMut};
+use bytes::BytesMut;
 use packets::NetlinkMessage;
 use tokio_io::codec::{Decoder, Encoder};
 
+// Emitable must be in scope to call buffer_len() on the messages being encoded
 use {Emitable, Error, NetlinkBuffer};
 
 pub struct NetlinkCodec<T> {
@@ -79,14 +77,36 @@ impl Encoder for NetlinkCodec<NetlinkMessage> {
     fn encode(&mut self, msg: Self::Item, buf: &mut BytesMut) -> Result<(), Self::Error> {
         let msg_len = msg.buffer_len();
         // FIXME: we should have a max length for the buffer
-        while buf.remaining_mut() < msg_len {
-            let new_len = buf.len() + 2048;
-            buf.resize(new_len, 0);
-        }
-        unsafe {
-            let size = msg.to_bytes(&mut buf.bytes_mut()[..])?;
-            buf.advance_mut(size);
-        }
+        // The new bytes are zeroed, since emitting a message does not write its padding
+        let start = buf.len();
+        buf.resize(start + msg_len, 0);
+        let size = msg.to_bytes(&mut buf[start..])?;
+        buf.truncate(start + size);
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use {LinkMessage, RtnlMessage};
+
+    #[test]
+    fn encode_zeroes_padding() {
+        // A buffer whose spare capacity is not zeroed
+        let mut buf = BytesMut::from(vec![0xff; 128]);
+        buf.clear();
+
+        let mut message = LinkMessage::new();
+        message.header_mut().set_index(1);
+        let mut message = NetlinkMessage::from(RtnlMessage::GetLink(message));
+        message.finalize();
+        NetlinkCodec::<NetlinkMessage>::new()
+            .encode(message, &mut buf)
+            .unwrap();
+
+        assert_eq!(buf.len(), 32);
+        // The reserved byte after the address family of the link header
+        assert_eq!(buf[17], 0);
+    }
+}
@@ -274,6 +274,69 @@ mod rtnl_address_nlas {
 }
 pub use self::rtnl_address_nlas::*;
 
+/// Flags of the addresses. Only the first 8 flags fit in the header of the "address" messages: the
+/// `IFA_FLAGS` attribute carries all of them.
+mod rtnl_address_flags {
+    pub const IFA_F_SECONDARY: u32 = 0x01;
+    pub const IFA_F_TEMPORARY: u32 = IFA_F_SECONDARY;
+    pub const IFA_F_NODAD: u32 = 0x02;
+    pub const IFA_F_OPTIMISTIC: u32 = 0x04;
+    pub const IFA_F_DADFAILED: u32 = 0x08;
+    pub const IFA_F_HOMEADDRESS: u32 = 0x10;
+    pub const IFA_F_DEPRECATED: u32 = 0x20;
+    pub const IFA_F_TENTATIVE: u32 = 0x40;
+    pub const IFA_F_PERMANENT: u32 = 0x80;
+    pub const IFA_F_MANAGETEMPADDR: u32 = 0x100;
+    pub const IFA_F_NOPREFIXROUTE: u32 = 0x200;
+    pub const IFA_F_MCAUTOJOIN: u32 = 0x400;
+    pub const IFA_F_STABLE_PRIVACY: u32 = 0x800;
+}
+pub use self::rtnl_address_flags::*;
+
+/// Constants used to identify the various attributes used for "neighbour" messages of the
+/// `NETLINK_ROUTE` family: `RTM_NEWNEIGH`, `RTM_DELNEIGH`, and `RTM_GETNEIGH`
+mod rtnl_neighbour_nlas {
+    use bindgen_constants as constants;
+    pub const NDA_UNSPEC: u16 = constants::NDA_UNSPEC as u16;
+    pub const NDA_DST: u16 = constants::NDA_DST as u16;
+    pub const NDA_LLADDR: u16 = constants::NDA_LLADDR as u16;
+    pub const NDA_CACHEINFO: u16 = constants::NDA_CACHEINFO as u16;
+    pub const NDA_PROBES: u16 = constants::NDA_PROBES as u16;
+    pub const NDA_VLAN: u16 = constants::NDA_VLAN as u16;
+    pub const NDA_PORT: u16 = constants::NDA_PORT as u16;
+    pub const NDA_VNI: u16 = constants::NDA_VNI as u16;
+    pub const NDA_IFINDEX: u16 = constants::NDA_IFINDEX as u16;
+    pub const NDA_MASTER: u16 = constants::NDA_MASTER as u16;
+    pub const NDA_LINK_NETNSID: u16 = constants::NDA_LINK_NETNSID as u16;
+    pub const NDA_SRC_VNI: u16 = constants::NDA_SRC_VNI as u16;
+    pub const NDA_PROTOCOL: u16 = constants::NDA_PROTOCOL as u16;
+}
+pub use self::rtnl_neighbour_nlas::*;
+
+/// Constants used for the state (`NUD_*`) and flags (`NTF_*`) fields of neighbour messages
+mod rtnl_neighbour_states_flags {
+    use bindgen_constants as constants;
+    pub const NUD_NONE: u16 = constants::NUD_NONE as u16;
+    pub const NUD_INCOMPLETE: u16 = constants::NUD_INCOMPLETE as u16;
+    pub const NUD_REACHABLE: u16 = constants::NUD_REACHABLE as u16;
+    pub const NUD_STALE: u16 = constants::NUD_STALE as u16;
+    pub const NUD_DELAY: u16 = constants::NUD_DELAY as u16;
+    pub const NUD_PROBE: u16 = constants::NUD_PROBE as u16;
+    pub const NUD_FAILED: u16 = constants::NUD_FAILED as u16;
+    pub const NUD_NOARP: u16 = constants::NUD_NOARP as u16;
+    pub const NUD_PERMANENT: u16 = constants::NUD_PERMANENT as u16;
+
+    pub const NTF_USE: u8 = constants::NTF_USE as u8;
+    pub const NTF_SELF: u8 = constants::NTF_SELF as u8;
+    pub const NTF_MASTER: u8 = constants::NTF_MASTER as u8;
+    pub const NTF_PROXY: u8 = constants::NTF_PROXY as u8;
+    pub const NTF_EXT_LEARNED: u8 = constants::NTF_EXT_LEARNED as u8;
+    pub const NTF_OFFLOADED: u8 = constants::NTF_OFFLOADED as u8;
+    pub const NTF_STICKY: u8 = constants::NTF_STICKY as u8;
+    pub const NTF_ROUTER: u8 = constants::NTF_ROUTER as u8;
+}
+pub use self::rtnl_neighbour_states_flags::*;
+
 /// Constants used to identify the various attributes used for "link" messages of the
 /// `NETLINK_ROUTE` family: `RTM_NEWLINK`, `RTM_DELLINK`, `RTM_GETLINK` and `RTM_SETLINK`
 mod rtnl_link_nlas {
@@ -391,9 +454,414 @@ mod rtnl_link_nlas {
     pub const IFLA_BR_MCAST_STATS_ENABLED: u16 = constants::IFLA_BR_MCAST_STATS_ENABLED as u16;
     pub const IFLA_BR_MCAST_IGMP_VERSION: u16 = constants::IFLA_BR_MCAST_IGMP_VERSION as u16;
     pub const IFLA_BR_MCAST_MLD_VERSION: u16 = constants::IFLA_BR_MCAST_MLD_VERSION as u16;
+    pub const IFLA_BR_VLAN_STATS_PER_PORT: u16 = constants::IFLA_BR_VLAN_STATS_PER_PORT as u16;
+    pub const IFLA_BR_MULTI_BOOLOPT: u16 = constants::IFLA_BR_MULTI_BOOLOPT as u16;
+    pub const IFLA_BR_MCAST_QUERIER_STATE: u16 = constants::IFLA_BR_MCAST_QUERIER_STATE as u16;
+    pub const IFLA_BR_FDB_N_LEARNED: u16 = constants::IFLA_BR_FDB_N_LEARNED as u16;
+    pub const IFLA_BR_FDB_MAX_LEARNED: u16 = constants::IFLA_BR_FDB_MAX_LEARNED as u16;
+
+    pub const IFLA_BRPORT_UNSPEC: u16 = constants::IFLA_BRPORT_UNSPEC as u16;
+    pub const IFLA_BRPORT_STATE: u16 = constants::IFLA_BRPORT_STATE as u16;
+    pub const IFLA_BRPORT_PRIORITY: u16 = constants::IFLA_BRPORT_PRIORITY as u16;
+    pub const IFLA_BRPORT_COST: u16 = constants::IFLA_BRPORT_COST as u16;
+    pub const IFLA_BRPORT_MODE: u16 = constants::IFLA_BRPORT_MODE as u16;
+    pub const IFLA_BRPORT_GUARD: u16 = constants::IFLA_BRPORT_GUARD as u16;
+    pub const IFLA_BRPORT_PROTECT: u16 = constants::IFLA_BRPORT_PROTECT as u16;
+    pub const IFLA_BRPORT_FAST_LEAVE: u16 = constants::IFLA_BRPORT_FAST_LEAVE as u16;
+    pub const IFLA_BRPORT_LEARNING: u16 = constants::IFLA_BRPORT_LEARNING as u16;
+    pub const IFLA_BRPORT_UNICAST_FLOOD: u16 = constants::IFLA_BRPORT_UNICAST_FLOOD as u16;
+    pub const IFLA_BRPORT_PROXYARP: u16 = constants::IFLA_BRPORT_PROXYARP as u16;
+    pub const IFLA_BRPORT_LEARNING_SYNC: u16 = constants::IFLA_BRPORT_LEARNING_SYNC as u16;
+    pub const IFLA_BRPORT_PROXYARP_WIFI: u16 = constants::IFLA_BRPORT_PROXYARP_WIFI as u16;
+    pub const IFLA_BRPORT_ROOT_ID: u16 = constants::IFLA_BRPORT_ROOT_ID as u16;
+    pub const IFLA_BRPORT_BRIDGE_ID: u16 = constants::IFLA_BRPORT_BRIDGE_ID as u16;
+    pub const IFLA_BRPORT_DESIGNATED_PORT: u16 = constants::IFLA_BRPORT_DESIGNATED_PORT as u16;
+    pub const IFLA_BRPORT_DESIGNATED_COST: u16 = constants::IFLA_BRPORT_DESIGNATED_COST as u16;
+    pub const IFLA_BRPORT_ID: u16 = constants::IFLA_BRPORT_ID as u16;
+    pub const IFLA_BRPORT_NO: u16 = constants::IFLA_BRPORT_NO as u16;
+    pub const IFLA_BRPORT_TOPOLOGY_CHANGE_ACK: u16 = constants::IFLA_BRPORT_TOPOLOGY_CHANGE_ACK as u16;
+    pub const IFLA_BRPORT_CONFIG_PENDING: u16 = constants::IFLA_BRPORT_CONFIG_PENDING as u16;
+    pub const IFLA_BRPORT_MESSAGE_AGE_TIMER: u16 = constants::IFLA_BRPORT_MESSAGE_AGE_TIMER as u16;
+    pub const IFLA_BRPORT_FORWARD_DELAY_TIMER: u16 = constants::IFLA_BRPORT_FORWARD_DELAY_TIMER as u16;
+    pub const IFLA_BRPORT_HOLD_TIMER: u16 = constants::IFLA_BRPORT_HOLD_TIMER as u16;
+    pub const IFLA_BRPORT_FLUSH: u16 = constants::IFLA_BRPORT_FLUSH as u16;
+    pub const IFLA_BRPORT_MULTICAST_ROUTER: u16 = constants::IFLA_BRPORT_MULTICAST_ROUTER as u16;
+    pub const IFLA_BRPORT_PAD: u16 = constants::IFLA_BRPORT_PAD as u16;
+    pub const IFLA_BRPORT_MCAST_FLOOD: u16 = constants::IFLA_BRPORT_MCAST_FLOOD as u16;
+    pub const IFLA_BRPORT_MCAST_TO_UCAST: u16 = constants::IFLA_BRPORT_MCAST_TO_UCAST as u16;
+    pub const IFLA_BRPORT_VLAN_TUNNEL: u16 = constants::IFLA_BRPORT_VLAN_TUNNEL as u16;
+    pub const IFLA_BRPORT_BCAST_FLOOD: u16 = constants::IFLA_BRPORT_BCAST_FLOOD as u16;
+    pub const IFLA_BRPORT_GROUP_FWD_MASK: u16 = constants::IFLA_BRPORT_GROUP_FWD_MASK as u16;
+    pub const IFLA_BRPORT_NEIGH_SUPPRESS: u16 = constants::IFLA_BRPORT_NEIGH_SUPPRESS as u16;
+    pub const IFLA_BRPORT_ISOLATED: u16 = constants::IFLA_BRPORT_ISOLATED as u16;
+    pub const IFLA_BRPORT_BACKUP_PORT: u16 = constants::IFLA_BRPORT_BACKUP_PORT as u16;
+
+    pub const IFLA_MACVLAN_UNSPEC: u16 = constants::IFLA_MACVLAN_UNSPEC as u16;
+    pub const IFLA_MACVLAN_MODE: u16 = constants::IFLA_MACVLAN_MODE as u16;
+    pub const IFLA_MACVLAN_FLAGS: u16 = constants::IFLA_MACVLAN_FLAGS as u16;
+    pub const IFLA_MACVLAN_MACADDR_MODE: u16 = constants::IFLA_MACVLAN_MACADDR_MODE as u16;
+    pub const IFLA_MACVLAN_MACADDR: u16 = constants::IFLA_MACVLAN_MACADDR as u16;
+    pub const IFLA_MACVLAN_MACADDR_DATA: u16 = constants::IFLA_MACVLAN_MACADDR_DATA as u16;
+    pub const IFLA_MACVLAN_MACADDR_COUNT: u16 = constants::IFLA_MACVLAN_MACADDR_COUNT as u16;
+    pub const IFLA_MACVLAN_BC_QUEUE_LEN: u16 = constants::IFLA_MACVLAN_BC_QUEUE_LEN as u16;
+    pub const IFLA_MACVLAN_BC_QUEUE_LEN_USED: u16 = constants::IFLA_MACVLAN_BC_QUEUE_LEN_USED as u16;
+
+    pub const IFLA_IPVLAN_UNSPEC: u16 = constants::IFLA_IPVLAN_UNSPEC as u16;
+    pub const IFLA_IPVLAN_MODE: u16 = constants::IFLA_IPVLAN_MODE as u16;
+    pub const IFLA_IPVLAN_FLAGS: u16 = constants::IFLA_IPVLAN_FLAGS as u16;
+
+    pub const IFLA_GRE_UNSPEC: u16 = constants::IFLA_GRE_UNSPEC as u16;
+    pub const IFLA_GRE_LINK: u16 = constants::IFLA_GRE_LINK as u16;
+    pub const IFLA_GRE_IFLAGS: u16 = constants::IFLA_GRE_IFLAGS as u16;
+    pub const IFLA_GRE_OFLAGS: u16 = constants::IFLA_GRE_OFLAGS as u16;
+    pub const IFLA_GRE_IKEY: u16 = constants::IFLA_GRE_IKEY as u16;
+    pub const IFLA_GRE_OKEY: u16 = constants::IFLA_GRE_OKEY as u16;
+    pub const IFLA_GRE_LOCAL: u16 = constants::IFLA_GRE_LOCAL as u16;
+    pub const IFLA_GRE_REMOTE: u16 = constants::IFLA_GRE_REMOTE as u16;
+    pub const IFLA_GRE_TTL: u16 = constants::IFLA_GRE_TTL as u16;
+    pub const IFLA_GRE_TOS: u16 = constants::IFLA_GRE_TOS as u16;
+    pub const IFLA_GRE_PMTUDISC: u16 = constants::IFLA_GRE_PMTUDISC as u16;
+    pub const IFLA_GRE_ENCAP_LIMIT: u16 = constants::IFLA_GRE_ENCAP_LIMIT as u16;
+    pub const IFLA_GRE_FLOWINFO: u16 = constants::IFLA_GRE_FLOWINFO as u16;
+    pub const IFLA_GRE_FLAGS: u16 = constants::IFLA_GRE_FLAGS as u16;
+    pub const IFLA_GRE_ENCAP_TYPE: u16 = constants::IFLA_GRE_ENCAP_TYPE as u16;
+    pub const IFLA_GRE_ENCAP_FLAGS: u16 = constants::IFLA_GRE_ENCAP_FLAGS as u16;
+    pub const IFLA_GRE_ENCAP_SPORT: u16 = constants::IFLA_GRE_ENCAP_SPORT as u16;
+    pub const IFLA_GRE_ENCAP_DPORT: u16 = constants::IFLA_GRE_ENCAP_DPORT as u16;
+    pub const IFLA_GRE_COLLECT_METADATA: u16 = constants::IFLA_GRE_COLLECT_METADATA as u16;
+    pub const IFLA_GRE_IGNORE_DF: u16 = constants::IFLA_GRE_IGNORE_DF as u16;
+    pub const IFLA_GRE_FWMARK: u16 = constants::IFLA_GRE_FWMARK as u16;
+    pub const IFLA_GRE_ERSPAN_INDEX: u16 = constants::IFLA_GRE_ERSPAN_INDEX as u16;
+    pub const IFLA_GRE_ERSPAN_VER: u16 = constants::IFLA_GRE_ERSPAN_VER as u16;
+    pub const IFLA_GRE_ERSPAN_DIR: u16 = constants::IFLA_GRE_ERSPAN_DIR as u16;
+    pub const IFLA_GRE_ERSPAN_HWID: u16 = constants::IFLA_GRE_ERSPAN_HWID as u16;
+
+    pub const IFLA_IPTUN_UNSPEC: u16 = constants::IFLA_IPTUN_UNSPEC as u16;
+    pub const IFLA_IPTUN_LINK: u16 = constants::IFLA_IPTUN_LINK as u16;
+    pub const IFLA_IPTUN_LOCAL: u16 = constants::IFLA_IPTUN_LOCAL as u16;
+    pub const IFLA_IPTUN_REMOTE: u16 = constants::IFLA_IPTUN_REMOTE as u16;
+    pub const IFLA_IPTUN_TTL: u16 = constants::IFLA_IPTUN_TTL as u16;
+    pub const IFLA_IPTUN_TOS: u16 = constants::IFLA_IPTUN_TOS as u16;
+    pub const IFLA_IPTUN_ENCAP_LIMIT: u16 = constants::IFLA_IPTUN_ENCAP_LIMIT as u16;
+    pub const IFLA_IPTUN_FLOWINFO: u16 = constants::IFLA_IPTUN_FLOWINFO as u16;
+    pub const IFLA_IPTUN_FLAGS: u16 = constants::IFLA_IPTUN_FLAGS as u16;
+    pub const IFLA_IPTUN_PROTO: u16 = constants::IFLA_IPTUN_PROTO as u16;
+    pub const IFLA_IPTUN_PMTUDISC: u16 = constants::IFLA_IPTUN_PMTUDISC as u16;
+    pub const IFLA_IPTUN_6RD_PREFIX: u16 = constants::IFLA_IPTUN_6RD_PREFIX as u16;
+    pub const IFLA_IPTUN_6RD_RELAY_PREFIX: u16 = constants::IFLA_IPTUN_6RD_RELAY_PREFIX as u16;
+    pub const IFLA_IPTUN_6RD_PREFIXLEN: u16 = constants::IFLA_IPTUN_6RD_PREFIXLEN as u16;
+    pub const IFLA_IPTUN_6RD_RELAY_PREFIXLEN: u16 = constants::IFLA_IPTUN_6RD_RELAY_PREFIXLEN as u16;
+    pub const IFLA_IPTUN_ENCAP_TYPE: u16 = constants::IFLA_IPTUN_ENCAP_TYPE as u16;
+    pub const IFLA_IPTUN_ENCAP_FLAGS: u16 = constants::IFLA_IPTUN_ENCAP_FLAGS as u16;
+    pub const IFLA_IPTUN_ENCAP_SPORT: u16 = constants::IFLA_IPTUN_ENCAP_SPORT as u16;
+    pub const IFLA_IPTUN_ENCAP_DPORT: u16 = constants::IFLA_IPTUN_ENCAP_DPORT as u16;
+    pub const IFLA_IPTUN_COLLECT_METADATA: u16 = constants::IFLA_IPTUN_COLLECT_METADATA as u16;
+    pub const IFLA_IPTUN_FWMARK: u16 = constants::IFLA_IPTUN_FWMARK as u16;
+
+    pub const IFLA_VTI_UNSPEC: u16 = constants::IFLA_VTI_UNSPEC as u16;
+    pub const IFLA_VTI_LINK: u16 = constants::IFLA_VTI_LINK as u16;
+    pub const IFLA_VTI_IKEY: u16 = constants::IFLA_VTI_IKEY as u16;
+    pub const IFLA_VTI_OKEY: u16 = constants::IFLA_VTI_OKEY as u16;
+    pub const IFLA_VTI_LOCAL: u16 = constants::IFLA_VTI_LOCAL as u16;
+    pub const IFLA_VTI_REMOTE: u16 = constants::IFLA_VTI_REMOTE as u16;
+    pub const IFLA_VTI_FWMARK: u16 = constants::IFLA_VTI_FWMARK as u16;
+
+    pub const IFLA_VRF_UNSPEC: u16 = constants::IFLA_VRF_UNSPEC as u16;
+    pub const IFLA_VRF_TABLE: u16 = constants::IFLA_VRF_TABLE as u16;
+    pub const IFLA_VRF_PORT_UNSPEC: u16 = constants::IFLA_VRF_PORT_UNSPEC as u16;
+    pub const IFLA_VRF_PORT_TABLE: u16 = constants::IFLA_VRF_PORT_TABLE as u16;
+
+    pub const VETH_INFO_UNSPEC: u16 = 0;
+    pub const VETH_INFO_PEER: u16 = 1;
 }
 pub use self::rtnl_link_nlas::*;
 
+/// Values of the `IFLA_MACVLAN_*`, `IFLA_IPVLAN_*`, `IFLA_BR_*` and `IFLA_BRPORT_*` attributes,
+/// used by macvlan, macvtap, ipvlan and bridge links, and by bridge ports
+mod rtnl_link_info_modes {
+    use bindgen_constants as constants;
+    pub const MACVLAN_MODE_PRIVATE: u32 = constants::MACVLAN_MODE_PRIVATE as u32;
+    pub const MACVLAN_MODE_VEPA: u32 = constants::MACVLAN_MODE_VEPA as u32;
+    pub const MACVLAN_MODE_BRIDGE: u32 = constants::MACVLAN_MODE_BRIDGE as u32;
+    pub const MACVLAN_MODE_PASSTHRU: u32 = constants::MACVLAN_MODE_PASSTHRU as u32;
+    pub const MACVLAN_MODE_SOURCE: u32 = constants::MACVLAN_MODE_SOURCE as u32;
+
+    pub const MACVLAN_MACADDR_ADD: u32 = constants::MACVLAN_MACADDR_ADD as u32;
+    pub const MACVLAN_MACADDR_DEL: u32 = constants::MACVLAN_MACADDR_DEL as u32;
+    pub const MACVLAN_MACADDR_FLUSH: u32 = constants::MACVLAN_MACADDR_FLUSH as u32;
+    pub const MACVLAN_MACADDR_SET: u32 = constants::MACVLAN_MACADDR_SET as u32;
+
+    pub const MACVLAN_FLAG_NOPROMISC: u16 = constants::MACVLAN_FLAG_NOPROMISC as u16;
+
+    pub const IPVLAN_MODE_L2: u16 = constants::IPVLAN_MODE_L2 as u16;
+    pub const IPVLAN_MODE_L3: u16 = constants::IPVLAN_MODE_L3 as u16;
+    pub const IPVLAN_MODE_L3S: u16 = constants::IPVLAN_MODE_L3S as u16;
+
+    pub const IPVLAN_F_PRIVATE: u16 = constants::IPVLAN_F_PRIVATE as u16;
+    pub const IPVLAN_F_VEPA: u16 = constants::IPVLAN_F_VEPA as u16;
+
+    pub const BR_BOOLOPT_NO_LL_LEARN: u32 = constants::BR_BOOLOPT_NO_LL_LEARN as u32;
+    pub const BR_BOOLOPT_MCAST_VLAN_SNOOPING: u32 = constants::BR_BOOLOPT_MCAST_VLAN_SNOOPING as u32;
+    pub const BR_BOOLOPT_MST_ENABLE: u32 = constants::BR_BOOLOPT_MST_ENABLE as u32;
+
+    pub const BR_STATE_DISABLED: u8 = constants::BR_STATE_DISABLED as u8;
+    pub const BR_STATE_LISTENING: u8 = constants::BR_STATE_LISTENING as u8;
+    pub const BR_STATE_LEARNING: u8 = constants::BR_STATE_LEARNING as u8;
+    pub const BR_STATE_FORWARDING: u8 = constants::BR_STATE_FORWARDING as u8;
+    pub const BR_STATE_BLOCKING: u8 = constants::BR_STATE_BLOCKING as u8;
+}
+pub use self::rtnl_link_info_modes::*;
+
+/// Values of the `IFLA_GRE_*`, `IFLA_IPTUN_*` and `IFLA_VTI_*` attributes, found in the
+/// `IFLA_INFO_DATA` of ip tunnels
+mod rtnl_link_info_tunnels {
+    use bindgen_constants as constants;
+    pub const GRE_CSUM: u16 = constants::GRE_CSUM as u16;
+    pub const GRE_ROUTING: u16 = constants::GRE_ROUTING as u16;
+    pub const GRE_KEY: u16 = constants::GRE_KEY as u16;
+    pub const GRE_SEQ: u16 = constants::GRE_SEQ as u16;
+
+    pub const TUNNEL_ENCAP_NONE: u16 = constants::TUNNEL_ENCAP_NONE as u16;
+    pub const TUNNEL_ENCAP_FOU: u16 = constants::TUNNEL_ENCAP_FOU as u16;
+    pub const TUNNEL_ENCAP_GUE: u16 = constants::TUNNEL_ENCAP_GUE as u16;
+
+    pub const TUNNEL_ENCAP_FLAG_CSUM: u16 = constants::TUNNEL_ENCAP_FLAG_CSUM as u16;
+    pub const TUNNEL_ENCAP_FLAG_CSUM6: u16 = constants::TUNNEL_ENCAP_FLAG_CSUM6 as u16;
+    pub const TUNNEL_ENCAP_FLAG_REMCSUM: u16 = constants::TUNNEL_ENCAP_FLAG_REMCSUM as u16;
+}
+pub use self::rtnl_link_info_tunnels::*;
+
+/// Constants used in the `IFLA_VFINFO_LIST`, `IFLA_VF_PORTS` and `IFLA_PORT_SELF` attributes, that
+/// describe the SR-IOV virtual functions of a link
+mod rtnl_link_vf {
+    use bindgen_constants as constants;
+    pub const IFLA_VF_INFO_UNSPEC: u16 = constants::IFLA_VF_INFO_UNSPEC as u16;
+    pub const IFLA_VF_INFO: u16 = constants::IFLA_VF_INFO as u16;
+
+    pub const IFLA_VF_UNSPEC: u16 = constants::IFLA_VF_UNSPEC as u16;
+    pub const IFLA_VF_MAC: u16 = constants::IFLA_VF_MAC as u16;
+    pub const IFLA_VF_VLAN: u16 = constants::IFLA_VF_VLAN as u16;
+    pub const IFLA_VF_TX_RATE: u16 = constants::IFLA_VF_TX_RATE as u16;
+    pub const IFLA_VF_SPOOFCHK: u16 = constants::IFLA_VF_SPOOFCHK as u16;
+    pub const IFLA_VF_LINK_STATE: u16 = constants::IFLA_VF_LINK_STATE as u16;
+    pub const IFLA_VF_RATE: u16 = constants::IFLA_VF_RATE as u16;
+    pub const IFLA_VF_RSS_QUERY_EN: u16 = constants::IFLA_VF_RSS_QUERY_EN as u16;
+    pub const IFLA_VF_STATS: u16 = constants::IFLA_VF_STATS as u16;
+    pub const IFLA_VF_TRUST: u16 = constants::IFLA_VF_TRUST as u16;
+    pub const IFLA_VF_IB_NODE_GUID: u16 = constants::IFLA_VF_IB_NODE_GUID as u16;
+    pub const IFLA_VF_IB_PORT_GUID: u16 = constants::IFLA_VF_IB_PORT_GUID as u16;
+    pub const IFLA_VF_VLAN_LIST: u16 = constants::IFLA_VF_VLAN_LIST as u16;
+
+    pub const IFLA_VF_VLAN_INFO_UNSPEC: u16 = constants::IFLA_VF_VLAN_INFO_UNSPEC as u16;
+    pub const IFLA_VF_VLAN_INFO: u16 = constants::IFLA_VF_VLAN_INFO as u16;
+
+    pub const IFLA_VF_STATS_RX_PACKETS: u16 = constants::IFLA_VF_STATS_RX_PACKETS as u16;
+    pub const IFLA_VF_STATS_TX_PACKETS: u16 = constants::IFLA_VF_STATS_TX_PACKETS as u16;
+    pub const IFLA_VF_STATS_RX_BYTES: u16 = constants::IFLA_VF_STATS_RX_BYTES as u16;
+    pub const IFLA_VF_STATS_TX_BYTES: u16 = constants::IFLA_VF_STATS_TX_BYTES as u16;
+    pub const IFLA_VF_STATS_BROADCAST: u16 = constants::IFLA_VF_STATS_BROADCAST as u16;
+    pub const IFLA_VF_STATS_MULTICAST: u16 = constants::IFLA_VF_STATS_MULTICAST as u16;
+    pub const IFLA_VF_STATS_PAD: u16 = constants::IFLA_VF_STATS_PAD as u16;
+    pub const IFLA_VF_STATS_RX_DROPPED: u16 = constants::IFLA_VF_STATS_RX_DROPPED as u16;
+    pub const IFLA_VF_STATS_TX_DROPPED: u16 = constants::IFLA_VF_STATS_TX_DROPPED as u16;
+
+    pub const IFLA_VF_PORT_UNSPEC: u16 = constants::IFLA_VF_PORT_UNSPEC as u16;
+    pub const IFLA_VF_PORT: u16 = constants::IFLA_VF_PORT as u16;
+
+    pub const IFLA_PORT_UNSPEC: u16 = constants::IFLA_PORT_UNSPEC as u16;
+    pub const IFLA_PORT_VF: u16 = constants::IFLA_PORT_VF as u16;
+    pub const IFLA_PORT_PROFILE: u16 = constants::IFLA_PORT_PROFILE as u16;
+    pub const IFLA_PORT_VSI_TYPE: u16 = constants::IFLA_PORT_VSI_TYPE as u16;
+    pub const IFLA_PORT_INSTANCE_UUID: u16 = constants::IFLA_PORT_INSTANCE_UUID as u16;
+    pub const IFLA_PORT_HOST_UUID: u16 = constants::IFLA_PORT_HOST_UUID as u16;
+    pub const IFLA_PORT_REQUEST: u16 = constants::IFLA_PORT_REQUEST as u16;
+    pub const IFLA_PORT_RESPONSE: u16 = constants::IFLA_PORT_RESPONSE as u16;
+
+    pub const IFLA_VF_LINK_STATE_AUTO: u32 = constants::IFLA_VF_LINK_STATE_AUTO as u32;
+    pub const IFLA_VF_LINK_STATE_ENABLE: u32 = constants::IFLA_VF_LINK_STATE_ENABLE as u32;
+    pub const IFLA_VF_LINK_STATE_DISABLE: u32 = constants::IFLA_VF_LINK_STATE_DISABLE as u32;
+}
+pub use self::rtnl_link_vf::*;
+
+/// Constants used in the `IFLA_XDP` attribute of link messages, to attach eXpress Data Path
+/// programs to links
+mod rtnl_link_xdp {
+    use bindgen_constants as constants;
+    pub const IFLA_XDP_UNSPEC: u16 = constants::IFLA_XDP_UNSPEC as u16;
+    pub const IFLA_XDP_FD: u16 = constants::IFLA_XDP_FD as u16;
+    pub const IFLA_XDP_ATTACHED: u16 = constants::IFLA_XDP_ATTACHED as u16;
+    pub const IFLA_XDP_FLAGS: u16 = constants::IFLA_XDP_FLAGS as u16;
+    pub const IFLA_XDP_PROG_ID: u16 = constants::IFLA_XDP_PROG_ID as u16;
+    pub const IFLA_XDP_DRV_PROG_ID: u16 = constants::IFLA_XDP_DRV_PROG_ID as u16;
+    pub const IFLA_XDP_SKB_PROG_ID: u16 = constants::IFLA_XDP_SKB_PROG_ID as u16;
+    pub const IFLA_XDP_HW_PROG_ID: u16 = constants::IFLA_XDP_HW_PROG_ID as u16;
+    pub const IFLA_XDP_EXPECTED_FD: u16 = constants::IFLA_XDP_EXPECTED_FD as u16;
+
+    pub const XDP_ATTACHED_NONE: u8 = constants::XDP_ATTACHED_NONE as u8;
+    pub const XDP_ATTACHED_DRV: u8 = constants::XDP_ATTACHED_DRV as u8;
+    pub const XDP_ATTACHED_SKB: u8 = constants::XDP_ATTACHED_SKB as u8;
+    pub const XDP_ATTACHED_HW: u8 = constants::XDP_ATTACHED_HW as u8;
+    pub const XDP_ATTACHED_MULTI: u8 = constants::XDP_ATTACHED_MULTI as u8;
+
+    pub const XDP_FLAGS_UPDATE_IF_NOEXIST: u32 = constants::XDP_FLAGS_UPDATE_IF_NOEXIST as u32;
+    pub const XDP_FLAGS_SKB_MODE: u32 = constants::XDP_FLAGS_SKB_MODE as u32;
+    pub const XDP_FLAGS_DRV_MODE: u32 = constants::XDP_FLAGS_DRV_MODE as u32;
+    pub const XDP_FLAGS_HW_MODE: u32 = constants::XDP_FLAGS_HW_MODE as u32;
+    pub const XDP_FLAGS_REPLACE: u32 = constants::XDP_FLAGS_REPLACE as u32;
+    pub const XDP_FLAGS_MODES: u32 = constants::XDP_FLAGS_MODES as u32;
+}
+pub use self::rtnl_link_xdp::*;
+
+/// Multicast groups of the `NETLINK_ROUTE` protocol family. Sockets that join a group receive
+/// notifications when the corresponding objects change.
+mod rtnl_groups {
+    use bindgen_constants as constants;
+    pub const RTNLGRP_NONE: u32 = constants::RTNLGRP_NONE as u32;
+    pub const RTNLGRP_LINK: u32 = constants::RTNLGRP_LINK as u32;
+    pub const RTNLGRP_NOTIFY: u32 = constants::RTNLGRP_NOTIFY as u32;
+    pub const RTNLGRP_NEIGH: u32 = constants::RTNLGRP_NEIGH as u32;
+    pub const RTNLGRP_TC: u32 = constants::RTNLGRP_TC as u32;
+    pub const RTNLGRP_IPV4_IFADDR: u32 = constants::RTNLGRP_IPV4_IFADDR as u32;
+    pub const RTNLGRP_IPV4_MROUTE: u32 = constants::RTNLGRP_IPV4_MROUTE as u32;
+    pub const RTNLGRP_IPV4_ROUTE: u32 = constants::RTNLGRP_IPV4_ROUTE as u32;
+    pub const RTNLGRP_IPV4_RULE: u32 = constants::RTNLGRP_IPV4_RULE as u32;
+    pub const RTNLGRP_IPV6_IFADDR: u32 = constants::RTNLGRP_IPV6_IFADDR as u32;
+    pub const RTNLGRP_IPV6_MROUTE: u32 = constants::RTNLGRP_IPV6_MROUTE as u32;
+    pub const RTNLGRP_IPV6_ROUTE: u32 = constants::RTNLGRP_IPV6_ROUTE as u32;
+    pub const RTNLGRP_IPV6_IFINFO: u32 = constants::RTNLGRP_IPV6_IFINFO as u32;
+    pub const RTNLGRP_DECNET_IFADDR: u32 = constants::RTNLGRP_DECNET_IFADDR as u32;
+    pub const RTNLGRP_DECNET_ROUTE: u32 = constants::RTNLGRP_DECNET_ROUTE as u32;
+    pub const RTNLGRP_DECNET_RULE: u32 = constants::RTNLGRP_DECNET_RULE as u32;
+    pub const RTNLGRP_IPV6_PREFIX: u32 = constants::RTNLGRP_IPV6_PREFIX as u32;
+    pub const RTNLGRP_IPV6_RULE: u32 = constants::RTNLGRP_IPV6_RULE as u32;
+    pub const RTNLGRP_ND_USEROPT: u32 = constants::RTNLGRP_ND_USEROPT as u32;
+    pub const RTNLGRP_PHONET_IFADDR: u32 = constants::RTNLGRP_PHONET_IFADDR as u32;
+    pub const RTNLGRP_PHONET_ROUTE: u32 = constants::RTNLGRP_PHONET_ROUTE as u32;
+    pub const RTNLGRP_DCB: u32 = constants::RTNLGRP_DCB as u32;
+    pub const RTNLGRP_IPV4_NETCONF: u32 = constants::RTNLGRP_IPV4_NETCONF as u32;
+    pub const RTNLGRP_IPV6_NETCONF: u32 = constants::RTNLGRP_IPV6_NETCONF as u32;
+    pub const RTNLGRP_MDB: u32 = constants::RTNLGRP_MDB as u32;
+    pub const RTNLGRP_MPLS_ROUTE: u32 = constants::RTNLGRP_MPLS_ROUTE as u32;
+    pub const RTNLGRP_NSID: u32 = constants::RTNLGRP_NSID as u32;
+    pub const RTNLGRP_MPLS_NETCONF: u32 = constants::RTNLGRP_MPLS_NETCONF as u32;
+    pub const RTNLGRP_IPV4_MROUTE_R: u32 = constants::RTNLGRP_IPV4_MROUTE_R as u32;
+    pub const RTNLGRP_IPV6_MROUTE_R: u32 = constants::RTNLGRP_IPV6_MROUTE_R as u32;
+}
+pub use self::rtnl_groups::*;
+
+/// Constants used in the `RTM_NEWSTATS` and `RTM_GETSTATS` messages, to retrieve link statistics
+mod rtnl_stats {
+    use bindgen_constants as constants;
+    pub const IFLA_STATS_UNSPEC: u16 = constants::IFLA_STATS_UNSPEC as u16;
+    pub const IFLA_STATS_LINK_64: u16 = constants::IFLA_STATS_LINK_64 as u16;
+    pub const IFLA_STATS_LINK_XSTATS: u16 = constants::IFLA_STATS_LINK_XSTATS as u16;
+    pub const IFLA_STATS_LINK_XSTATS_SLAVE: u16 = constants::IFLA_STATS_LINK_XSTATS_SLAVE as u16;
+    pub const IFLA_STATS_LINK_OFFLOAD_XSTATS: u16 = constants::IFLA_STATS_LINK_OFFLOAD_XSTATS as u16;
+    pub const IFLA_STATS_AF_SPEC: u16 = constants::IFLA_STATS_AF_SPEC as u16;
+
+    // Bits of the filter mask of the stats messages (`IFLA_STATS_FILTER_BIT(attribute)` in the
+    // kernel headers)
+    pub const IFLA_STATS_FILTER_LINK_64: u32 = 1 << (IFLA_STATS_LINK_64 - 1);
+    pub const IFLA_STATS_FILTER_LINK_XSTATS: u32 = 1 << (IFLA_STATS_LINK_XSTATS - 1);
+    pub const IFLA_STATS_FILTER_LINK_XSTATS_SLAVE: u32 = 1 << (IFLA_STATS_LINK_XSTATS_SLAVE - 1);
+    pub const IFLA_STATS_FILTER_LINK_OFFLOAD_XSTATS: u32 =
+        1 << (IFLA_STATS_LINK_OFFLOAD_XSTATS - 1);
+    pub const IFLA_STATS_FILTER_AF_SPEC: u32 = 1 << (IFLA_STATS_AF_SPEC - 1);
+
+    pub const LINK_XSTATS_TYPE_UNSPEC: u16 = constants::LINK_XSTATS_TYPE_UNSPEC as u16;
+    pub const LINK_XSTATS_TYPE_BRIDGE: u16 = constants::LINK_XSTATS_TYPE_BRIDGE as u16;
+
+    pub const IFLA_OFFLOAD_XSTATS_UNSPEC: u16 = constants::IFLA_OFFLOAD_XSTATS_UNSPEC as u16;
+    pub const IFLA_OFFLOAD_XSTATS_CPU_HIT: u16 = constants::IFLA_OFFLOAD_XSTATS_CPU_HIT as u16;
+}
+pub use self::rtnl_stats::*;
+
+/// Constants used in the `IFLA_AF_SPEC` attribute of `AF_BRIDGE` link messages, for instance to
+/// manage the VLANs of bridge ports.
+mod rtnl_link_af_bridge {
+    use bindgen_constants as constants;
+    pub const IFLA_BRIDGE_FLAGS: u16 = constants::IFLA_BRIDGE_FLAGS as u16;
+    pub const IFLA_BRIDGE_MODE: u16 = constants::IFLA_BRIDGE_MODE as u16;
+    pub const IFLA_BRIDGE_VLAN_INFO: u16 = constants::IFLA_BRIDGE_VLAN_INFO as u16;
+    pub const IFLA_BRIDGE_VLAN_TUNNEL_INFO: u16 = constants::IFLA_BRIDGE_VLAN_TUNNEL_INFO as u16;
+
+    pub const BRIDGE_FLAGS_MASTER: u16 = constants::BRIDGE_FLAGS_MASTER as u16;
+    pub const BRIDGE_FLAGS_SELF: u16 = constants::BRIDGE_FLAGS_SELF as u16;
+
+    pub const BRIDGE_MODE_VEB: u16 = constants::BRIDGE_MODE_VEB as u16;
+    pub const BRIDGE_MODE_VEPA: u16 = constants::BRIDGE_MODE_VEPA as u16;
+
+    pub const BRIDGE_VLAN_INFO_MASTER: u16 = constants::BRIDGE_VLAN_INFO_MASTER as u16;
+    pub const BRIDGE_VLAN_INFO_PVID: u16 = constants::BRIDGE_VLAN_INFO_PVID as u16;
+    pub const BRIDGE_VLAN_INFO_UNTAGGED: u16 = constants::BRIDGE_VLAN_INFO_UNTAGGED as u16;
+    pub const BRIDGE_VLAN_INFO_RANGE_BEGIN: u16 = constants::BRIDGE_VLAN_INFO_RANGE_BEGIN as u16;
+    pub const BRIDGE_VLAN_INFO_RANGE_END: u16 = constants::BRIDGE_VLAN_INFO_RANGE_END as u16;
+    pub const BRIDGE_VLAN_INFO_BRENTRY: u16 = constants::BRIDGE_VLAN_INFO_BRENTRY as u16;
+    pub const BRIDGE_VLAN_INFO_ONLY_OPTS: u16 = constants::BRIDGE_VLAN_INFO_ONLY_OPTS as u16;
+
+    pub const IFLA_BRIDGE_VLAN_TUNNEL_UNSPEC: u16 = constants::IFLA_BRIDGE_VLAN_TUNNEL_UNSPEC as u16;
+    pub const IFLA_BRIDGE_VLAN_TUNNEL_ID: u16 = constants::IFLA_BRIDGE_VLAN_TUNNEL_ID as u16;
+    pub const IFLA_BRIDGE_VLAN_TUNNEL_VID: u16 = constants::IFLA_BRIDGE_VLAN_TUNNEL_VID as u16;
+    pub const IFLA_BRIDGE_VLAN_TUNNEL_FLAGS: u16 = constants::IFLA_BRIDGE_VLAN_TUNNEL_FLAGS as u16;
+}
+pub use self::rtnl_link_af_bridge::*;
+
+/// Constants used by the bridge multicast database messages: `RTM_NEWMDB`, `RTM_DELMDB` and
+/// `RTM_GETMDB`
+mod rtnl_mdb {
+    use bindgen_constants as constants;
+    pub const MDBA_UNSPEC: u16 = constants::MDBA_UNSPEC as u16;
+    pub const MDBA_MDB: u16 = constants::MDBA_MDB as u16;
+    pub const MDBA_ROUTER: u16 = constants::MDBA_ROUTER as u16;
+
+    pub const MDBA_MDB_UNSPEC: u16 = constants::MDBA_MDB_UNSPEC as u16;
+    pub const MDBA_MDB_ENTRY: u16 = constants::MDBA_MDB_ENTRY as u16;
+
+    pub const MDBA_MDB_ENTRY_UNSPEC: u16 = constants::MDBA_MDB_ENTRY_UNSPEC as u16;
+    pub const MDBA_MDB_ENTRY_INFO: u16 = constants::MDBA_MDB_ENTRY_INFO as u16;
+
+    pub const MDBA_MDB_EATTR_UNSPEC: u16 = constants::MDBA_MDB_EATTR_UNSPEC as u16;
+    pub const MDBA_MDB_EATTR_TIMER: u16 = constants::MDBA_MDB_EATTR_TIMER as u16;
+    pub const MDBA_MDB_EATTR_SRC_LIST: u16 = constants::MDBA_MDB_EATTR_SRC_LIST as u16;
+    pub const MDBA_MDB_EATTR_GROUP_MODE: u16 = constants::MDBA_MDB_EATTR_GROUP_MODE as u16;
+    pub const MDBA_MDB_EATTR_SOURCE: u16 = constants::MDBA_MDB_EATTR_SOURCE as u16;
+    pub const MDBA_MDB_EATTR_RTPROT: u16 = constants::MDBA_MDB_EATTR_RTPROT as u16;
+
+    pub const MDBA_ROUTER_UNSPEC: u16 = constants::MDBA_ROUTER_UNSPEC as u16;
+    pub const MDBA_ROUTER_PORT: u16 = constants::MDBA_ROUTER_PORT as u16;
+
+    pub const MDBA_ROUTER_PATTR_UNSPEC: u16 = constants::MDBA_ROUTER_PATTR_UNSPEC as u16;
+    pub const MDBA_ROUTER_PATTR_TIMER: u16 = constants::MDBA_ROUTER_PATTR_TIMER as u16;
+    pub const MDBA_ROUTER_PATTR_TYPE: u16 = constants::MDBA_ROUTER_PATTR_TYPE as u16;
+
+    pub const MDBA_SET_ENTRY_UNSPEC: u16 = constants::MDBA_SET_ENTRY_UNSPEC as u16;
+    pub const MDBA_SET_ENTRY: u16 = constants::MDBA_SET_ENTRY as u16;
+    pub const MDBA_SET_ENTRY_ATTRS: u16 = constants::MDBA_SET_ENTRY_ATTRS as u16;
+
+    pub const MDB_TEMPORARY: u8 = constants::MDB_TEMPORARY as u8;
+    pub const MDB_PERMANENT: u8 = constants::MDB_PERMANENT as u8;
+
+    pub const MDB_FLAGS_OFFLOAD: u8 = constants::MDB_FLAGS_OFFLOAD as u8;
+    pub const MDB_FLAGS_FAST_LEAVE: u8 = constants::MDB_FLAGS_FAST_LEAVE as u8;
+    pub const MDB_FLAGS_STAR_EXCL: u8 = constants::MDB_FLAGS_STAR_EXCL as u8;
+    pub const MDB_FLAGS_BLOCKED: u8 = constants::MDB_FLAGS_BLOCKED as u8;
+
+    pub const ETH_P_IP: u16 = constants::ETH_P_IP as u16;
+    pub const ETH_P_IPV6: u16 = constants::ETH_P_IPV6 as u16;
+}
+pub use self::rtnl_mdb::*;
+
+/// Flags of the `IFLA_EXT_MASK` attribute, used in `RTM_GETLINK` requests to ask the kernel for
+/// extended information.
+mod rtext_filters {
+    use bindgen_constants as constants;
+    pub const RTEXT_FILTER_VF: u32 = constants::RTEXT_FILTER_VF as u32;
+    pub const RTEXT_FILTER_BRVLAN: u32 = constants::RTEXT_FILTER_BRVLAN as u32;
+    pub const RTEXT_FILTER_BRVLAN_COMPRESSED: u32 = constants::RTEXT_FILTER_BRVLAN_COMPRESSED as u32;
+    pub const RTEXT_FILTER_SKIP_STATS: u32 = constants::RTEXT_FILTER_SKIP_STATS as u32;
+}
+pub use self::rtext_filters::*;
+
 /// Constants that identify the link layer type in a `NETLINK_ROUTE` packet of type `RTM_NEWLINK`,
 /// `RTM_DELLINK`, `RTM_GETLINK` and `RTM_SETLINK`
 mod rtnl_link_layer_type {
@@ -667,3 +1135,44 @@ mod if_states {
 	pub const IF_OPER_UP: u8 = 6;
 }
 pub use self::if_states::*;
+
+/// Constants used by the routing rule messages: `RTM_NEWRULE`, `RTM_DELRULE` and `RTM_GETRULE`
+mod rtnl_rule {
+    use bindgen_constants as constants;
+    pub const FRA_UNSPEC: u16 = constants::FRA_UNSPEC as u16;
+    pub const FRA_DST: u16 = constants::FRA_DST as u16;
+    pub const FRA_SRC: u16 = constants::FRA_SRC as u16;
+    pub const FRA_IIFNAME: u16 = constants::FRA_IIFNAME as u16;
+    pub const FRA_GOTO: u16 = constants::FRA_GOTO as u16;
+    pub const FRA_PRIORITY: u16 = constants::FRA_PRIORITY as u16;
+    pub const FRA_FWMARK: u16 = constants::FRA_FWMARK as u16;
+    pub const FRA_FLOW: u16 = constants::FRA_FLOW as u16;
+    pub const FRA_TUN_ID: u16 = constants::FRA_TUN_ID as u16;
+    pub const FRA_SUPPRESS_IFGROUP: u16 = constants::FRA_SUPPRESS_IFGROUP as u16;
+    pub const FRA_SUPPRESS_PREFIXLEN: u16 = constants::FRA_SUPPRESS_PREFIXLEN as u16;
+    pub const FRA_TABLE: u16 = constants::FRA_TABLE as u16;
+    pub const FRA_FWMASK: u16 = constants::FRA_FWMASK as u16;
+    pub const FRA_OIFNAME: u16 = constants::FRA_OIFNAME as u16;
+    pub const FRA_PAD: u16 = constants::FRA_PAD as u16;
+    pub const FRA_L3MDEV: u16 = constants::FRA_L3MDEV as u16;
+    pub const FRA_UID_RANGE: u16 = constants::FRA_UID_RANGE as u16;
+    pub const FRA_PROTOCOL: u16 = constants::FRA_PROTOCOL as u16;
+    pub const FRA_IP_PROTO: u16 = constants::FRA_IP_PROTO as u16;
+    pub const FRA_SPORT_RANGE: u16 = constants::FRA_SPORT_RANGE as u16;
+    pub const FRA_DPORT_RANGE: u16 = constants::FRA_DPORT_RANGE as u16;
+
+    pub const FR_ACT_UNSPEC: u8 = constants::FR_ACT_UNSPEC as u8;
+    pub const FR_ACT_TO_TBL: u8 = constants::FR_ACT_TO_TBL as u8;
+    pub const FR_ACT_GOTO: u8 = constants::FR_ACT_GOTO as u8;
+    pub const FR_ACT_NOP: u8 = constants::FR_ACT_NOP as u8;
+    pub const FR_ACT_BLACKHOLE: u8 = constants::FR_ACT_BLACKHOLE as u8;
+    pub const FR_ACT_UNREACHABLE: u8 = constants::FR_ACT_UNREACHABLE as u8;
+    pub const FR_ACT_PROHIBIT: u8 = constants::FR_ACT_PROHIBIT as u8;
+
+    pub const FIB_RULE_PERMANENT: u32 = constants::FIB_RULE_PERMANENT as u32;
+    pub const FIB_RULE_INVERT: u32 = constants::FIB_RULE_INVERT as u32;
+    pub const FIB_RULE_UNRESOLVED: u32 = constants::FIB_RULE_UNRESOLVED as u32;
+    pub const FIB_RULE_IIF_DETACHED: u32 = constants::FIB_RULE_IIF_DETACHED as u32;
+    pub const FIB_RULE_OIF_DETACHED: u32 = constants::FIB_RULE_OIF_DETACHED as u32;
+}
+pub use self::rtnl_rule::*;
@@ -1,3 +1,4 @@
+use std::collections::VecDeque;
 use std::io;
 use tokio_io::codec::{Decoder, Encoder};
 
@@ -5,14 +6,24 @@ use bytes::{BufMut, BytesMut};
 use futures::{Async, AsyncSink, Poll, Sink, StartSend, Stream};
 use netlink_socket::{SocketAddr, TokioSocket};
 
+/// A `Stream` and `Sink` of netlink messages, on top of a netlink socket.
+///
+/// The messages that are sent are packed into datagrams of at most
+/// [`max_datagram_size()`](#method.max_datagram_size) bytes, so that a single system call sends
+/// several messages. Up to [`max_datagrams()`](#method.max_datagrams) datagrams can be queued
+/// before the sink needs to be flushed. When several datagrams are queued, they are sent with a
+/// single `sendmmsg` system call.
 pub struct NetlinkFramed<C> {
     socket: TokioSocket,
     codec: C,
     reader: BytesMut,
-    writer: BytesMut,
+    // Datagrams waiting to be sent. It may temporarily contain one more datagram than
+    // `max_datagrams`.
+    writer: VecDeque<BytesMut>,
+    max_datagram_size: usize,
+    max_datagrams: usize,
     out_addr: SocketAddr,
     in_addr: SocketAddr,
-    flushed: bool,
 }
 
 impl<C: Decoder> Stream for NetlinkFramed<C> {
@@ -64,47 +75,62 @@ impl<C: Encoder> Sink for NetlinkFramed<C> {
     fn start_send(&mut self, item: Self::SinkItem) -> StartSend<Self::SinkItem, Self::SinkError> {
         trace!("sending frame");
 
-        if !self.flushed {
+        // All the queued datagrams are sent to the same address
+        let (frame, out_addr) = item;
+        let same_addr = out_addr.port_number() == self.out_addr.port_number()
+            && out_addr.multicast_groups() == self.out_addr.multicast_groups();
+        if self.writer.len() > self.max_datagrams || (!self.writer.is_empty() && !same_addr) {
             trace!("flushing the sink, before sending the frame");
             match self.poll_complete()? {
                 Async::Ready(()) => trace!("sink flushed"),
                 Async::NotReady => {
                     trace!("could not flush the sink entirely");
-                    return Ok(AsyncSink::NotReady(item));
+                    return Ok(AsyncSink::NotReady((frame, out_addr)));
                 }
             }
         }
 
-        let (frame, out_addr) = item;
-        self.codec.encode(frame, &mut self.writer)?;
+        let mut buf = BytesMut::new();
+        self.codec.encode(frame, &mut buf)?;
         self.out_addr = out_addr;
-        self.flushed = false;
-        trace!("frame encoded; length={}", self.writer.len());
+        trace!("frame encoded; length={}", buf.len());
+
+        // Pack the frame into the last datagram if it fits, otherwise start a new datagram
+        match self.writer.back_mut() {
+            Some(datagram) if datagram.len() + buf.len() <= self.max_datagram_size => {
+                datagram.extend_from_slice(&buf);
+            }
+            _ => self.writer.push_back(buf),
+        }
 
         Ok(AsyncSink::Ready)
     }
 
     fn poll_complete(&mut self) -> Poll<(), C::Error> {
-        if self.flushed {
-            return Ok(Async::Ready(()));
-        }
-
-        trace!("flushing frame; length={}", self.writer.len());
-        let n = try_ready!(self.socket.poll_send_to(&self.writer, &self.out_addr));
-        trace!("written {}", n);
-
-        let wrote_all = n == self.writer.len();
-        self.writer.clear();
-        self.flushed = true;
-
-        if wrote_all {
-            Ok(Async::Ready(()))
-        } else {
-            Err(io::Error::new(
-                io::ErrorKind::Other,
-                "failed to write entire datagram to socket",
-            ).into())
+        while !self.writer.is_empty() {
+            if self.max_datagrams > 1 && self.writer.len() > 1 {
+                trace!("flushing {} datagrams", self.writer.len());
+                let n = {
+                    let bufs: Vec<&[u8]> = self.writer.iter().map(|buf| &buf[..]).collect();
+                    try_ready!(self.socket.poll_send_mmsg_to(&bufs, &self.out_addr))
+                };
+                trace!("written {} datagrams", n);
+                self.writer.drain(..n);
+            } else {
+                trace!("flushing datagram; length={}", self.writer[0].len());
+                let n = try_ready!(self.socket.poll_send_to(&self.writer[0], &self.out_addr));
+                trace!("written {}", n);
+                let datagram = self.writer.pop_front().unwrap();
+                if n != datagram.len() {
+                    return Err(io::Error::new(
+                        io::ErrorKind::Other,
+                        "failed to write entire datagram to socket",
+                    )
+                    .into());
+                }
+            }
         }
+        Ok(Async::Ready(()))
     }
 
     fn close(&mut self) -> Poll<(), C::Error> {
@@ -114,24 +140,57 @@ impl<C: Encoder> Sink for NetlinkFramed<C> {
 }
 
 const INITIAL_READER_CAPACITY: usize = 64 * 1024;
-const INITIAL_WRITER_CAPACITY: usize = 8 * 1024;
+
+/// Size of the datagrams when the size of the socket send buffer cannot be retrieved
+const DEFAULT_MAX_DATAGRAM_SIZE: usize = 32 * 1024;
+// The kernel rejects the datagrams larger than the send buffer minus this overhead
+const SEND_BUFFER_OVERHEAD: usize = 32;
 
 impl<C> NetlinkFramed<C> {
     /// Create a new `NetlinkFramed` backed by the given socket and codec.
     ///
     /// See struct level documentation for more details.
     pub fn new(socket: TokioSocket, codec: C) -> NetlinkFramed<C> {
+        let max_datagram_size = socket
+            .get_send_buffer_size()
+            .map(|size| size.saturating_sub(SEND_BUFFER_OVERHEAD))
+            .unwrap_or(DEFAULT_MAX_DATAGRAM_SIZE);
         NetlinkFramed {
             socket,
             codec,
             out_addr: SocketAddr::new(0, 0),
             in_addr: SocketAddr::new(0, 0),
             reader: BytesMut::with_capacity(INITIAL_READER_CAPACITY),
-            writer: BytesMut::with_capacity(INITIAL_WRITER_CAPACITY),
-            flushed: true,
+            writer: VecDeque::new(),
+            max_datagram_size,
+            max_datagrams: 1,
         }
     }
 
+    /// Maximum size of the datagrams the messages are packed into. It defaults to the largest
+    /// datagram the socket send buffer accepts.
+    pub fn max_datagram_size(&self) -> usize {
+        self.max_datagram_size
+    }
+
+    /// Set the maximum size of the datagrams the messages are packed into. A message larger than
+    /// this is sent in its own datagram. With a size of 0, each message is sent separately.
+    pub fn set_max_datagram_size(&mut self, size: usize) {
+        self.max_datagram_size = size;
+    }
+
+    /// Maximum number of datagrams queued before the sink needs to be flushed
+    pub fn max_datagrams(&self) -> usize {
+        self.max_datagrams
+    }
+
+    /// Set the maximum number of datagrams queued before the sink needs to be flushed. The queued
+    /// datagrams are sent with a single `sendmmsg` system call. It defaults to 1, which means
+    /// that `sendmmsg` is not used.
+    pub fn set_max_datagrams(&mut self, count: usize) {
+        self.max_datagrams = count.max(1);
+    }
+
     /// Returns a reference to the underlying I/O stream wrapped by `Framed`.
     ///
     /// # Note
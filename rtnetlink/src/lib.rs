@@ -141,5 +141,9 @@ pub use self::errors::*;
 #[cfg(feature = "tokio_support")] pub use codecs::*;
 #[cfg(feature = "tokio_support")] pub use self::framed::*;
 
+// Serde
+#[cfg(feature = "serde_support")] extern crate serde;
+#[cfg(feature = "serde_support")] #[macro_use] extern crate serde_derive;
+
 // Tests
 #[cfg(test)] #[macro_use] extern crate lazy_static;
@@ -160,6 +160,90 @@ impl NetlinkMessage {
         self.message().is_get_address()
     }
 
+    /// Check if the payload is a `RTM_NEWROUTE` message
+    /// ([`Rtnl::NewRoute`](enum.RtnlMessage.html#variant.NewRoute))
+    pub fn is_new_route(&self) -> bool {
+        self.message().is_new_route()
+    }
+
+    /// Check if the payload is a `RTM_DELROUTE` message
+    /// ([`Rtnl::DelRoute`](enum.RtnlMessage.html#variant.DelRoute))
+    pub fn is_del_route(&self) -> bool {
+        self.message().is_del_route()
+    }
+
+    /// Check if the payload is a `RTM_GETROUTE` message
+    /// ([`Rtnl::GetRoute`](enum.RtnlMessage.html#variant.GetRoute))
+    pub fn is_get_route(&self) -> bool {
+        self.message().is_get_route()
+    }
+
+    /// Check if the payload is a `RTM_NEWNEIGH` message
+    /// ([`Rtnl::NewNeighbour`](enum.RtnlMessage.html#variant.NewNeighbour))
+    pub fn is_new_neighbour(&self) -> bool {
+        self.message().is_new_neighbour()
+    }
+
+    /// Check if the payload is a `RTM_DELNEIGH` message
+    /// ([`Rtnl::DelNeighbour`](enum.RtnlMessage.html#variant.DelNeighbour))
+    pub fn is_del_neighbour(&self) -> bool {
+        self.message().is_del_neighbour()
+    }
+
+    /// Check if the payload is a `RTM_GETNEIGH` message
+    /// ([`Rtnl::GetNeighbour`](enum.RtnlMessage.html#variant.GetNeighbour))
+    pub fn is_get_neighbour(&self) -> bool {
+        self.message().is_get_neighbour()
+    }
+
+    /// Check if the payload is a `RTM_NEWRULE` message
+    /// ([`Rtnl::NewRule`](enum.RtnlMessage.html#variant.NewRule))
+    pub fn is_new_rule(&self) -> bool {
+        self.message().is_new_rule()
+    }
+
+    /// Check if the payload is a `RTM_DELRULE` message
+    /// ([`Rtnl::DelRule`](enum.RtnlMessage.html#variant.DelRule))
+    pub fn is_del_rule(&self) -> bool {
+        self.message().is_del_rule()
+    }
+
+    /// Check if the payload is a `RTM_GETRULE` message
+    /// ([`Rtnl::GetRule`](enum.RtnlMessage.html#variant.GetRule))
+    pub fn is_get_rule(&self) -> bool {
+        self.message().is_get_rule()
+    }
+
+    /// Check if the payload is a `RTM_NEWMDB` message
+    /// ([`Rtnl::NewMdb`](enum.RtnlMessage.html#variant.NewMdb))
+    pub fn is_new_mdb(&self) -> bool {
+        self.message().is_new_mdb()
+    }
+
+    /// Check if the payload is a `RTM_DELMDB` message
+    /// ([`Rtnl::DelMdb`](enum.RtnlMessage.html#variant.DelMdb))
+    pub fn is_del_mdb(&self) -> bool {
+        self.message().is_del_mdb()
+    }
+
+    /// Check if the payload is a `RTM_GETMDB` message
+    /// ([`Rtnl::GetMdb`](enum.RtnlMessage.html#variant.GetMdb))
+    pub fn is_get_mdb(&self) -> bool {
+        self.message().is_get_mdb()
+    }
+
+    /// Check if the payload is a `RTM_NEWSTATS` message
+    /// ([`Rtnl::NewStats`](enum.RtnlMessage.html#variant.NewStats))
+    pub fn is_new_stats(&self) -> bool {
+        self.message().is_new_stats()
+    }
+
+    /// Check if the payload is a `RTM_GETSTATS` message
+    /// ([`Rtnl::GetStats`](enum.RtnlMessage.html#variant.GetStats))
+    pub fn is_get_stats(&self) -> bool {
+        self.message().is_get_stats()
+    }
+
     /// Ensure the header (`NetlinkHeader`) is consistent with the payload (`RtnlMessage`):
     ///
     /// - compute the payload length and set the header's length field
@@ -184,15 +268,15 @@ impl NetlinkMessage {
             NewAddress(_) => RTM_NEWADDR,
             DelAddress(_) => RTM_DELADDR,
             GetAddress(_) => RTM_GETADDR,
-            // NewRoute(_) => RTM_NEWROUTE,
-            // DelRoute(_) => RTM_DELROUTE,
-            // GetRoute(_) => RTM_GETROUTE,
-            // NewNeighbour(_) => RTM_NEWNEIGH,
-            // DelNeighbour(_) => RTM_DELNEIGH,
-            // GetNeighbour(_) => RTM_GETNEIGH,
-            // NewRule(_) => RTM_NEWRULE,
-            // DelRule(_) => RTM_DELRULE,
-            // GetRule(_) => RTM_GETRULE,
+            NewRoute(_) => RTM_NEWROUTE,
+            DelRoute(_) => RTM_DELROUTE,
+            GetRoute(_) => RTM_GETROUTE,
+            NewNeighbour(_) => RTM_NEWNEIGH,
+            DelNeighbour(_) => RTM_DELNEIGH,
+            GetNeighbour(_) => RTM_GETNEIGH,
+            NewRule(_) => RTM_NEWRULE,
+            DelRule(_) => RTM_DELRULE,
+            GetRule(_) => RTM_GETRULE,
             // NewQueueDiscipline(_) => RTM_NEWQDISC,
             // DelQueueDiscipline(_) => RTM_DELQDISC,
             // GetQueueDiscipline(_) => RTM_GETQDISC,
@@ -220,14 +304,14 @@ impl NetlinkMessage {
             // NewNetconf(_) => RTM_NEWNETCONF,
             // DelNetconf(_) => RTM_DELNETCONF,
             // GetNetconf(_) => RTM_GETNETCONF,
-            // NewMdb(_) => RTM_NEWMDB,
-            // DelMdb(_) => RTM_DELMDB,
-            // GetMdb(_) => RTM_GETMDB,
+            NewMdb(_) => RTM_NEWMDB,
+            DelMdb(_) => RTM_DELMDB,
+            GetMdb(_) => RTM_GETMDB,
             // NewNsId(_) => RTM_NEWNSID,
             // DelNsId(_) => RTM_DELNSID,
             // GetNsId(_) => RTM_GETNSID,
-            // NewStats(_) => RTM_NEWSTATS,
-            // GetStats(_) => RTM_GETSTATS,
+            NewStats(_) => RTM_NEWSTATS,
+            GetStats(_) => RTM_GETSTATS,
             // NewCacheReport(_) => RTM_NEWCACHEREPORT,
             Other(_) => unimplemented!(),
         };
@@ -264,6 +348,60 @@ impl<'buffer, T: AsRef<[u8]> + 'buffer> Parseable<NetlinkMessage> for NetlinkBuf
                 }
             }
 
+            // Route messages
+            RTM_NEWROUTE | RTM_GETROUTE | RTM_DELROUTE => {
+                let msg: RouteMessage = RouteBuffer::new(&self.payload()).parse()?;
+                match header.message_type() {
+                    RTM_NEWROUTE => NewRoute(msg),
+                    RTM_GETROUTE => GetRoute(msg),
+                    RTM_DELROUTE => DelRoute(msg),
+                    _ => unreachable!(),
+                }
+            }
+
+            // Neighbour messages
+            RTM_NEWNEIGH | RTM_GETNEIGH | RTM_DELNEIGH => {
+                let msg: NeighbourMessage = NeighbourBuffer::new(&self.payload()).parse()?;
+                match header.message_type() {
+                    RTM_NEWNEIGH => NewNeighbour(msg),
+                    RTM_GETNEIGH => GetNeighbour(msg),
+                    RTM_DELNEIGH => DelNeighbour(msg),
+                    _ => unreachable!(),
+                }
+            }
+
+            // Routing rule messages
+            RTM_NEWRULE | RTM_GETRULE | RTM_DELRULE => {
+                let msg: RuleMessage = RuleBuffer::new(&self.payload()).parse()?;
+                match header.message_type() {
+                    RTM_NEWRULE => NewRule(msg),
+                    RTM_GETRULE => GetRule(msg),
+                    RTM_DELRULE => DelRule(msg),
+                    _ => unreachable!(),
+                }
+            }
+
+            // Bridge multicast database messages
+            RTM_NEWMDB | RTM_GETMDB | RTM_DELMDB => {
+                let msg: MdbMessage = MdbBuffer::new(&self.payload()).parse()?;
+                match header.message_type() {
+                    RTM_NEWMDB => NewMdb(msg),
+                    RTM_GETMDB => GetMdb(msg),
+                    RTM_DELMDB => DelMdb(msg),
+                    _ => unreachable!(),
+                }
+            }
+
+            // Link statistics messages
+            RTM_NEWSTATS | RTM_GETSTATS => {
+                let msg: StatsMessage = StatsBuffer::new(&self.payload()).parse()?;
+                match header.message_type() {
+                    RTM_NEWSTATS => NewStats(msg),
+                    RTM_GETSTATS => GetStats(msg),
+                    _ => unreachable!(),
+                }
+            }
+
             NLMSG_ERROR => {
                 let msg: ErrorMessage = ErrorBuffer::new(&self.payload()).parse()?;
                 if msg.code >= 0 {
@@ -307,6 +445,30 @@ impl Emitable for NetlinkMessage {
             | NewAddress(ref msg)
             | DelAddress(ref msg)
             | GetAddress(ref msg)
+            => msg.buffer_len(),
+
+            | NewRoute(ref msg)
+            | DelRoute(ref msg)
+            | GetRoute(ref msg)
+            => msg.buffer_len(),
+
+            | NewNeighbour(ref msg)
+            | DelNeighbour(ref msg)
+            | GetNeighbour(ref msg)
+            => msg.buffer_len(),
+
+            | NewRule(ref msg)
+            | DelRule(ref msg)
+            | GetRule(ref msg)
+            => msg.buffer_len(),
+
+            | NewMdb(ref msg)
+            | DelMdb(ref msg)
+            | GetMdb(ref msg)
+            => msg.buffer_len(),
+
+            | NewStats(ref msg)
+            | GetStats(ref msg)
             => msg.buffer_len()
         };
         self.header.buffer_len() + payload_len
@@ -336,6 +498,30 @@ impl Emitable for NetlinkMessage {
             | NewAddress(ref msg)
             | DelAddress(ref msg)
             | GetAddress(ref msg)
+            => msg.emit(buffer),
+
+            | NewRoute(ref msg)
+            | DelRoute(ref msg)
+            | GetRoute(ref msg)
+            => msg.emit(buffer),
+
+            | NewNeighbour(ref msg)
+            | DelNeighbour(ref msg)
+            | GetNeighbour(ref msg)
+            => msg.emit(buffer),
+
+            | NewRule(ref msg)
+            | DelRule(ref msg)
+            | GetRule(ref msg)
+            => msg.emit(buffer),
+
+            | NewMdb(ref msg)
+            | DelMdb(ref msg)
+            | GetMdb(ref msg)
+            => msg.emit(buffer),
+
+            | NewStats(ref msg)
+            | GetStats(ref msg)
             => msg.emit(buffer)
         }
     }
@@ -1,4 +1,5 @@
 use byteorder::{ByteOrder, NativeEndian};
+use std::cmp;
 use std::mem::size_of;
 use std::ptr;
 
@@ -127,6 +128,7 @@ impl<'buffer, T: AsRef<[u8]> + AsMut<[u8]> + ?Sized> NlaBuffer<&'buffer mut T> {
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde_support", derive(Serialize, Deserialize))]
 pub struct DefaultNla {
     kind: u16,
     value: Vec<u8>,
@@ -159,6 +161,13 @@ pub trait Nla {
     fn kind(&self) -> u16;
 
     fn emit_value(&self, buffer: &mut [u8]);
+
+    /// Whether the `NLA_F_NESTED` flag must be set when emitting this attribute. A few attributes
+    /// (such as the multicast querier state of bridges) are lists of attributes that the kernel
+    /// flags as nested.
+    fn is_nested(&self) -> bool {
+        false
+    }
 }
 
 impl<T: Nla> Emitable for T {
@@ -170,6 +179,9 @@ impl<T: Nla> Emitable for T {
     fn emit(&self, buffer: &mut [u8]) {
         let mut buffer = NlaBuffer::new(buffer);
         buffer.set_kind(self.kind());
+        if self.is_nested() {
+            buffer.set_nested_flag();
+        }
         // do not include the padding here, but do include the header
         buffer.set_length(self.value_len() as u16 + 4);
         self.emit_value(buffer.value_mut());
@@ -260,6 +272,20 @@ where
         Ok(unsafe { ptr::read(buf.as_ptr() as *const Self) })
     }
 
+    /// Parse a structure made of `C` counters (or configuration values). The kernel appends new
+    /// fields to these structures over time, so unlike `from_bytes()`, the buffer may be longer or
+    /// shorter than `Self`: unknown trailing fields are ignored, and missing ones are set to 0.
+    fn from_counters<C>(buf: &[u8]) -> Result<Self> {
+        let counters = buf.len() / size_of::<C>();
+        if counters * size_of::<C>() != buf.len() {
+            return Err(Error::MalformedNlaValue);
+        }
+        let mut bytes = vec![0; size_of::<Self>()];
+        let len = cmp::min(buf.len(), bytes.len());
+        bytes[..len].copy_from_slice(&buf[..len]);
+        Self::from_bytes(&bytes[..])
+    }
+
     fn to_bytes(&self, buf: &mut [u8]) {
         unsafe { ptr::write(buf.as_mut_ptr() as *mut Self, *self) }
     }
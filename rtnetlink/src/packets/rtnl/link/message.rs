@@ -1,6 +1,7 @@
-use {Emitable, Parseable, Result};
+use constants::{AF_BRIDGE, IFLA_AF_SPEC, IFLA_PROTINFO};
+use {Emitable, NlasIterator, Parseable, Result};
 
-use super::{LinkBuffer, LinkHeader, LinkNla};
+use super::{BridgePortNla, LinkAfBridgeNla, LinkBuffer, LinkHeader, LinkNla};
 
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct LinkMessage {
@@ -71,7 +72,31 @@ impl<'buffer, T: AsRef<[u8]> + 'buffer> Parseable<Vec<LinkNla>> for LinkBuffer<&
     fn parse(&self) -> Result<Vec<LinkNla>> {
         let mut nlas = vec![];
         for nla_buf in self.nlas() {
-            nlas.push(nla_buf?.parse()?);
+            let nla_buf = nla_buf?;
+            if self.address_family() != AF_BRIDGE as u8 {
+                nlas.push(nla_buf.parse()?);
+                continue;
+            }
+            // The content of IFLA_PROTINFO and IFLA_AF_SPEC depends on the address family. For
+            // AF_BRIDGE, they contain the bridge port attributes and the bridge attributes (VLANs
+            // for instance).
+            match nla_buf.kind() {
+                IFLA_PROTINFO => {
+                    let mut port_nlas = vec![];
+                    for nla in NlasIterator::new(nla_buf.value()) {
+                        port_nlas.push(<_ as Parseable<BridgePortNla>>::parse(&nla?)?);
+                    }
+                    nlas.push(LinkNla::ProtoInfoBridge(port_nlas));
+                }
+                IFLA_AF_SPEC => {
+                    let mut bridge_nlas = vec![];
+                    for nla in NlasIterator::new(nla_buf.value()) {
+                        bridge_nlas.push(<_ as Parseable<LinkAfBridgeNla>>::parse(&nla?)?);
+                    }
+                    nlas.push(LinkNla::AfSpecBridge(bridge_nlas));
+                }
+                _ => nlas.push(nla_buf.parse()?),
+            }
         }
         Ok(nlas)
     }
@@ -110,6 +135,112 @@ mod test {
         0x08, 0x00, 0x29, 0x00, 0x00, 0x00, 0x01, 0x00, // Maximum GSO size L=8,T=41,V=65536
     ];
 
+    #[cfg_attr(nightly, rustfmt::skip)]
+    static BRIDGE_PORT: [u8; 40] = [
+        0x07, // address family = AF_BRIDGE
+        0x00, // reserved
+        0x01, 0x00, // link layer type 1 = ether
+        0x05, 0x00, 0x00, 0x00, // interface index = 5
+        0x00, 0x00, 0x00, 0x00, // device flags
+        0x00, 0x00, 0x00, 0x00, // reserved 2 (aka device change flag)
+
+        // nlas
+        0x18, 0x00, // L = 24
+        0x0c, 0x80, // T = 12 (IFLA_PROTINFO) | NLA_F_NESTED
+            0x08, 0x00, // L = 8
+            0x03, 0x00, // T = 3 (IFLA_BRPORT_COST)
+            0x64, 0x00, 0x00, 0x00, // V = 100
+            0x05, 0x00, // L = 5
+            0x01, 0x00, // T = 1 (IFLA_BRPORT_STATE)
+            0x03, // V = 3 (BR_STATE_FORWARDING)
+            0x00, 0x00, 0x00, // padding
+            0x04, 0x00, // L = 4
+            0x18, 0x00, // T = 24 (IFLA_BRPORT_FLUSH)
+    ];
+
+    #[test]
+    fn parse_bridge_port() {
+        let buffer = LinkBuffer::new(&BRIDGE_PORT);
+        let msg = <LinkBuffer<_> as Parseable<LinkMessage>>::parse(&buffer).unwrap();
+        assert_eq!(msg.header().address_family(), AF_BRIDGE as u8);
+        assert_eq!(
+            msg.nlas(),
+            &[LinkNla::ProtoInfoBridge(vec![
+                BridgePortNla::Cost(100),
+                BridgePortNla::State(BR_STATE_FORWARDING),
+                BridgePortNla::Flush,
+            ])][..]
+        );
+    }
+
+    #[test]
+    fn emit_bridge_port() {
+        let mut header = LinkHeader::new();
+        header.set_address_family(AF_BRIDGE as u8).set_index(5);
+        let nlas = vec![LinkNla::ProtoInfoBridge(vec![
+            BridgePortNla::Cost(100),
+            BridgePortNla::State(BR_STATE_FORWARDING),
+            BridgePortNla::Flush,
+        ])];
+        let msg = LinkMessage::from_parts(header, nlas);
+
+        let mut buf = vec![0; 40];
+        assert_eq!(msg.buffer_len(), 40);
+        msg.emit(&mut buf[..]);
+        assert_eq!(&buf[..], &BRIDGE_PORT[..]);
+    }
+
+    #[cfg_attr(nightly, rustfmt::skip)]
+    static BRIDGE_VLANS: [u8; 36] = [
+        0x07, // address family = AF_BRIDGE
+        0x00, // reserved
+        0x01, 0x00, // link layer type 1 = ether
+        0x05, 0x00, 0x00, 0x00, // interface index = 5
+        0x00, 0x00, 0x00, 0x00, // device flags
+        0x00, 0x00, 0x00, 0x00, // reserved 2 (aka device change flag)
+
+        // nlas
+        0x14, 0x00, // L = 20
+        0x1a, 0x00, // T = 26 (IFLA_AF_SPEC)
+            0x08, 0x00, // L = 8
+            0x02, 0x00, // T = 2 (IFLA_BRIDGE_VLAN_INFO)
+            0x06, 0x00, // flags = BRIDGE_VLAN_INFO_PVID | BRIDGE_VLAN_INFO_UNTAGGED
+            0x01, 0x00, // vid = 1
+            0x08, 0x00, // L = 8
+            0x02, 0x00, // T = 2 (IFLA_BRIDGE_VLAN_INFO)
+            0x00, 0x00, // flags = 0
+            0x0a, 0x00, // vid = 10
+    ];
+
+    lazy_static! {
+        static ref BRIDGE_VLANS_NLAS: Vec<LinkNla> = vec![LinkNla::AfSpecBridge(vec![
+            LinkAfBridgeNla::VlanInfo(BridgeVlanInfo::new(
+                1,
+                BRIDGE_VLAN_INFO_PVID | BRIDGE_VLAN_INFO_UNTAGGED,
+            )),
+            LinkAfBridgeNla::VlanInfo(BridgeVlanInfo::new(10, 0)),
+        ])];
+    }
+
+    #[test]
+    fn parse_bridge_vlans() {
+        let buffer = LinkBuffer::new(&BRIDGE_VLANS);
+        let msg = <LinkBuffer<_> as Parseable<LinkMessage>>::parse(&buffer).unwrap();
+        assert_eq!(msg.nlas(), BRIDGE_VLANS_NLAS.as_slice());
+    }
+
+    #[test]
+    fn emit_bridge_vlans() {
+        let mut header = LinkHeader::new();
+        header.set_address_family(AF_BRIDGE as u8).set_index(5);
+        let msg = LinkMessage::from_parts(header, BRIDGE_VLANS_NLAS.clone());
+
+        let mut buf = vec![0; 36];
+        assert_eq!(msg.buffer_len(), 36);
+        msg.emit(&mut buf[..]);
+        assert_eq!(&buf[..], &BRIDGE_VLANS[..]);
+    }
+
     #[test]
     fn packet_header_read() {
         let packet = LinkBuffer::new(&HEADER[0..16]);
@@ -16,6 +16,7 @@
 //     ...
 // }
 
+pub use super::bridge::LinkAfBridgeNla;
 pub use super::inet::LinkAfInetNla;
 pub use super::inet6::LinkAfInet6Nla;
 
@@ -31,7 +32,7 @@ pub enum LinkAfSpecNla {
     Ipx(Vec<u8>),
     AppleTalk(Vec<u8>),
     Netrom(Vec<u8>),
-    Bridge(Vec<u8>),
+    Bridge(Vec<LinkAfBridgeNla>),
     AtmPvc(Vec<u8>),
     X25(Vec<u8>),
     Inet(Vec<LinkAfInetNla>),
@@ -76,7 +77,6 @@ impl Nla for LinkAfSpecNla {
                 | Ipx(ref bytes)
                 | AppleTalk(ref bytes)
                 | Netrom(ref bytes)
-                | Bridge(ref bytes)
                 | AtmPvc(ref bytes)
                 | X25(ref bytes)
                 | Rose(ref bytes)
@@ -108,6 +108,7 @@ impl Nla for LinkAfSpecNla {
                 => bytes.len(),
             Inet6(ref af_inet6) => af_inet6.as_slice().buffer_len(),
             Inet(ref af_inet) =>  af_inet.as_slice().buffer_len(),
+            Bridge(ref af_bridge) => af_bridge.as_slice().buffer_len(),
             Other(ref nla) => nla.value_len(),
         }
     }
@@ -122,7 +123,6 @@ impl Nla for LinkAfSpecNla {
                 | Ipx(ref bytes)
                 | AppleTalk(ref bytes)
                 | Netrom(ref bytes)
-                | Bridge(ref bytes)
                 | AtmPvc(ref bytes)
                 | X25(ref bytes)
                 | Rose(ref bytes)
@@ -154,6 +154,7 @@ impl Nla for LinkAfSpecNla {
                 => buffer.copy_from_slice(bytes.as_slice()),
             LinkAfSpecNla::Inet6(ref attrs) => attrs.as_slice().emit(buffer),
             LinkAfSpecNla::Inet(ref attrs) => attrs.as_slice().emit(buffer),
+            LinkAfSpecNla::Bridge(ref attrs) => attrs.as_slice().emit(buffer),
             LinkAfSpecNla::Other(ref nla)  => nla.emit_value(buffer),
         }
     }
@@ -229,7 +230,13 @@ impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<LinkAfSpecNla> for NlaBuffer<&'
             AF_IPX => Ipx(payload.to_vec()),
             AF_APPLETALK => AppleTalk(payload.to_vec()),
             AF_NETROM => Netrom(payload.to_vec()),
-            AF_BRIDGE => Bridge(payload.to_vec()),
+            AF_BRIDGE => {
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    nlas.push(<NlaBuffer<_> as Parseable<LinkAfBridgeNla>>::parse(&nla?)?);
+                }
+                Bridge(nlas)
+            }
             AF_ATMPVC => AtmPvc(payload.to_vec()),
             AF_X25 => X25(payload.to_vec()),
             AF_ROSE => Rose(payload.to_vec()),
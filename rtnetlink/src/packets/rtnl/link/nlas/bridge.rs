@@ -0,0 +1,179 @@
+use std::mem::size_of;
+
+use byteorder::{ByteOrder, NativeEndian};
+
+use constants::*;
+use utils::{parse_u16, parse_u32};
+use {DefaultNla, Emitable, NativeNla, Nla, NlaBuffer, NlasIterator, Parseable, Result};
+
+/// VLAN of a bridge port (`struct bridge_vlan_info`)
+#[repr(C)]
+#[derive(Clone, Copy, Eq, PartialEq, Debug, Default)]
+pub struct BridgeVlanInfo {
+    /// A combination of the `BRIDGE_VLAN_INFO_*` flags
+    pub flags: u16,
+    /// VLAN identifier
+    pub vid: u16,
+}
+
+impl NativeNla for BridgeVlanInfo {}
+
+impl BridgeVlanInfo {
+    pub fn new(vid: u16, flags: u16) -> Self {
+        BridgeVlanInfo { flags, vid }
+    }
+
+    /// Whether this VLAN is the port VLAN identifier, ie the VLAN assigned to untagged ingress
+    /// traffic
+    pub fn is_pvid(&self) -> bool {
+        self.flags & BRIDGE_VLAN_INFO_PVID != 0
+    }
+
+    /// Whether the egress traffic of this VLAN is untagged
+    pub fn is_untagged(&self) -> bool {
+        self.flags & BRIDGE_VLAN_INFO_UNTAGGED != 0
+    }
+
+    /// Whether this is the first VLAN of a range
+    pub fn is_range_begin(&self) -> bool {
+        self.flags & BRIDGE_VLAN_INFO_RANGE_BEGIN != 0
+    }
+
+    /// Whether this is the last VLAN of a range
+    pub fn is_range_end(&self) -> bool {
+        self.flags & BRIDGE_VLAN_INFO_RANGE_END != 0
+    }
+}
+
+/// Attributes found in the `IFLA_AF_SPEC` attribute of `AF_BRIDGE` link messages.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum LinkAfBridgeNla {
+    /// A combination of `BRIDGE_FLAGS_MASTER` and `BRIDGE_FLAGS_SELF`, telling whether the
+    /// request is for the bridge (the default), or for the port device itself.
+    Flags(u16),
+    /// `BRIDGE_MODE_VEB` or `BRIDGE_MODE_VEPA`
+    Mode(u16),
+    VlanInfo(BridgeVlanInfo),
+    VlanTunnelInfo(Vec<BridgeVlanTunnelNla>),
+    Other(DefaultNla),
+}
+
+impl Nla for LinkAfBridgeNla {
+    #[cfg_attr(nightly, rustfmt::skip)]
+    fn value_len(&self) -> usize {
+        use self::LinkAfBridgeNla::*;
+        match *self {
+            Flags(_) | Mode(_) => size_of::<u16>(),
+            VlanInfo(_) => size_of::<BridgeVlanInfo>(),
+            VlanTunnelInfo(ref nlas) => nlas.as_slice().buffer_len(),
+            Other(ref nla) => nla.value_len(),
+        }
+    }
+
+    #[cfg_attr(nightly, rustfmt::skip)]
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::LinkAfBridgeNla::*;
+        match *self {
+            Flags(ref value)
+                | Mode(ref value)
+                => NativeEndian::write_u16(buffer, *value),
+            VlanInfo(ref info) => info.to_bytes(buffer),
+            VlanTunnelInfo(ref nlas) => nlas.as_slice().emit(buffer),
+            Other(ref nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::LinkAfBridgeNla::*;
+        match *self {
+            Flags(_) => IFLA_BRIDGE_FLAGS,
+            Mode(_) => IFLA_BRIDGE_MODE,
+            VlanInfo(_) => IFLA_BRIDGE_VLAN_INFO,
+            VlanTunnelInfo(_) => IFLA_BRIDGE_VLAN_TUNNEL_INFO,
+            Other(ref nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<LinkAfBridgeNla> for NlaBuffer<&'buffer T> {
+    fn parse(&self) -> Result<LinkAfBridgeNla> {
+        use self::LinkAfBridgeNla::*;
+        let payload = self.value();
+        Ok(match self.kind() {
+            IFLA_BRIDGE_FLAGS => Flags(parse_u16(payload)?),
+            IFLA_BRIDGE_MODE => Mode(parse_u16(payload)?),
+            IFLA_BRIDGE_VLAN_INFO => VlanInfo(BridgeVlanInfo::from_bytes(payload)?),
+            IFLA_BRIDGE_VLAN_TUNNEL_INFO => {
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    nlas.push(<NlaBuffer<_> as Parseable<BridgeVlanTunnelNla>>::parse(
+                        &nla?,
+                    )?);
+                }
+                VlanTunnelInfo(nlas)
+            }
+            _ => Other(<Self as Parseable<DefaultNla>>::parse(self)?),
+        })
+    }
+}
+
+/// Attributes of the `IFLA_BRIDGE_VLAN_TUNNEL_INFO` attribute, that maps a VLAN to a tunnel id
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum BridgeVlanTunnelNla {
+    Unspec(Vec<u8>),
+    Id(u32),
+    Vid(u16),
+    Flags(u16),
+    Other(DefaultNla),
+}
+
+impl Nla for BridgeVlanTunnelNla {
+    #[cfg_attr(nightly, rustfmt::skip)]
+    fn value_len(&self) -> usize {
+        use self::BridgeVlanTunnelNla::*;
+        match *self {
+            Unspec(ref bytes) => bytes.len(),
+            Id(_) => size_of::<u32>(),
+            Vid(_) | Flags(_) => size_of::<u16>(),
+            Other(ref nla) => nla.value_len(),
+        }
+    }
+
+    #[cfg_attr(nightly, rustfmt::skip)]
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::BridgeVlanTunnelNla::*;
+        match *self {
+            Unspec(ref bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            Id(ref value) => NativeEndian::write_u32(buffer, *value),
+            Vid(ref value)
+                | Flags(ref value)
+                => NativeEndian::write_u16(buffer, *value),
+            Other(ref nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::BridgeVlanTunnelNla::*;
+        match *self {
+            Unspec(_) => IFLA_BRIDGE_VLAN_TUNNEL_UNSPEC,
+            Id(_) => IFLA_BRIDGE_VLAN_TUNNEL_ID,
+            Vid(_) => IFLA_BRIDGE_VLAN_TUNNEL_VID,
+            Flags(_) => IFLA_BRIDGE_VLAN_TUNNEL_FLAGS,
+            Other(ref nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<BridgeVlanTunnelNla> for NlaBuffer<&'buffer T> {
+    fn parse(&self) -> Result<BridgeVlanTunnelNla> {
+        use self::BridgeVlanTunnelNla::*;
+        let payload = self.value();
+        Ok(match self.kind() {
+            IFLA_BRIDGE_VLAN_TUNNEL_UNSPEC => Unspec(payload.to_vec()),
+            IFLA_BRIDGE_VLAN_TUNNEL_ID => Id(parse_u32(payload)?),
+            IFLA_BRIDGE_VLAN_TUNNEL_VID => Vid(parse_u16(payload)?),
+            IFLA_BRIDGE_VLAN_TUNNEL_FLAGS => Flags(parse_u16(payload)?),
+            _ => Other(<Self as Parseable<DefaultNla>>::parse(self)?),
+        })
+    }
+}
@@ -0,0 +1,257 @@
+use byteorder::{ByteOrder, NativeEndian};
+use constants::*;
+use std::mem::size_of;
+use utils::{parse_mac, parse_u16, parse_u32, parse_u64, parse_u8};
+use {DefaultNla, Error, Nla, NlaBuffer, Parseable, Result};
+
+/// Attributes of a bridge port. They are found in the `IFLA_PROTINFO` attribute of `AF_BRIDGE`
+/// link messages, and in the `IFLA_INFO_SLAVE_DATA` attribute of links enslaved to a bridge.
+// https://elixir.bootlin.com/linux/latest/source/net/bridge/br_netlink.c#L192
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum BridgePortNla {
+    Unspec(Vec<u8>),
+    /// STP state of the port, one of the `BR_STATE_*` constants
+    State(u8),
+    Priority(u16),
+    Cost(u32),
+    /// Hairpin mode
+    Mode(u8),
+    Guard(u8),
+    /// Root block
+    Protect(u8),
+    FastLeave(u8),
+    Learning(u8),
+    UnicastFlood(u8),
+    ProxyArp(u8),
+    LearningSync(u8),
+    ProxyArpWifi(u8),
+    RootId((u16, [u8; 6])),
+    BridgeId((u16, [u8; 6])),
+    DesignatedPort(u16),
+    DesignatedCost(u16),
+    Id(u16),
+    No(u16),
+    TopologyChangeAck(u8),
+    ConfigPending(u8),
+    MessageAgeTimer(u64),
+    ForwardDelayTimer(u64),
+    HoldTimer(u64),
+    /// Flush the FDB entries learned on this port
+    Flush,
+    MulticastRouter(u8),
+    Pad(Vec<u8>),
+    MulticastFlood(u8),
+    MulticastToUnicast(u8),
+    VlanTunnel(u8),
+    BroadcastFlood(u8),
+    GroupFwdMask(u16),
+    NeighSuppress(u8),
+    Isolated(u8),
+    /// Index of the backup port, 0 to remove it
+    BackupPort(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for BridgePortNla {
+    #[cfg_attr(nightly, rustfmt::skip)]
+    fn value_len(&self) -> usize {
+        use self::BridgePortNla::*;
+        match self {
+            Unspec(bytes)
+                | Pad(bytes)
+                => bytes.len(),
+            Flush => 0,
+            MessageAgeTimer(_)
+                | ForwardDelayTimer(_)
+                | HoldTimer(_)
+                => size_of::<u64>(),
+            Cost(_)
+                | BackupPort(_)
+                => size_of::<u32>(),
+            Priority(_)
+                | DesignatedPort(_)
+                | DesignatedCost(_)
+                | Id(_)
+                | No(_)
+                | GroupFwdMask(_)
+                => size_of::<u16>(),
+            RootId(_)
+                | BridgeId(_)
+                => size_of::<u16>() + size_of::<[u8; 6]>(),
+            State(_)
+                | Mode(_)
+                | Guard(_)
+                | Protect(_)
+                | FastLeave(_)
+                | Learning(_)
+                | UnicastFlood(_)
+                | ProxyArp(_)
+                | LearningSync(_)
+                | ProxyArpWifi(_)
+                | TopologyChangeAck(_)
+                | ConfigPending(_)
+                | MulticastRouter(_)
+                | MulticastFlood(_)
+                | MulticastToUnicast(_)
+                | VlanTunnel(_)
+                | BroadcastFlood(_)
+                | NeighSuppress(_)
+                | Isolated(_)
+                => size_of::<u8>(),
+            Other(nla)
+                => nla.value_len(),
+        }
+    }
+
+    #[cfg_attr(nightly, rustfmt::skip)]
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::BridgePortNla::*;
+        match self {
+            Unspec(ref bytes)
+                | Pad(ref bytes)
+                => buffer.copy_from_slice(bytes),
+            Flush => {}
+            MessageAgeTimer(ref value)
+                | ForwardDelayTimer(ref value)
+                | HoldTimer(ref value)
+                => NativeEndian::write_u64(buffer, *value),
+            Cost(ref value)
+                | BackupPort(ref value)
+                => NativeEndian::write_u32(buffer, *value),
+            Priority(ref value)
+                | DesignatedPort(ref value)
+                | DesignatedCost(ref value)
+                | Id(ref value)
+                | No(ref value)
+                | GroupFwdMask(ref value)
+                => NativeEndian::write_u16(buffer, *value),
+            RootId((ref priority, ref address))
+                | BridgeId((ref priority, ref address))
+                => {
+                    NativeEndian::write_u16(buffer, *priority);
+                    buffer[2..].copy_from_slice(&address[..]);
+                }
+            State(ref value)
+                | Mode(ref value)
+                | Guard(ref value)
+                | Protect(ref value)
+                | FastLeave(ref value)
+                | Learning(ref value)
+                | UnicastFlood(ref value)
+                | ProxyArp(ref value)
+                | LearningSync(ref value)
+                | ProxyArpWifi(ref value)
+                | TopologyChangeAck(ref value)
+                | ConfigPending(ref value)
+                | MulticastRouter(ref value)
+                | MulticastFlood(ref value)
+                | MulticastToUnicast(ref value)
+                | VlanTunnel(ref value)
+                | BroadcastFlood(ref value)
+                | NeighSuppress(ref value)
+                | Isolated(ref value)
+                => buffer[0] = *value,
+            Other(nla)
+                => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::BridgePortNla::*;
+        match self {
+            Unspec(_) => IFLA_BRPORT_UNSPEC,
+            State(_) => IFLA_BRPORT_STATE,
+            Priority(_) => IFLA_BRPORT_PRIORITY,
+            Cost(_) => IFLA_BRPORT_COST,
+            Mode(_) => IFLA_BRPORT_MODE,
+            Guard(_) => IFLA_BRPORT_GUARD,
+            Protect(_) => IFLA_BRPORT_PROTECT,
+            FastLeave(_) => IFLA_BRPORT_FAST_LEAVE,
+            Learning(_) => IFLA_BRPORT_LEARNING,
+            UnicastFlood(_) => IFLA_BRPORT_UNICAST_FLOOD,
+            ProxyArp(_) => IFLA_BRPORT_PROXYARP,
+            LearningSync(_) => IFLA_BRPORT_LEARNING_SYNC,
+            ProxyArpWifi(_) => IFLA_BRPORT_PROXYARP_WIFI,
+            RootId(_) => IFLA_BRPORT_ROOT_ID,
+            BridgeId(_) => IFLA_BRPORT_BRIDGE_ID,
+            DesignatedPort(_) => IFLA_BRPORT_DESIGNATED_PORT,
+            DesignatedCost(_) => IFLA_BRPORT_DESIGNATED_COST,
+            Id(_) => IFLA_BRPORT_ID,
+            No(_) => IFLA_BRPORT_NO,
+            TopologyChangeAck(_) => IFLA_BRPORT_TOPOLOGY_CHANGE_ACK,
+            ConfigPending(_) => IFLA_BRPORT_CONFIG_PENDING,
+            MessageAgeTimer(_) => IFLA_BRPORT_MESSAGE_AGE_TIMER,
+            ForwardDelayTimer(_) => IFLA_BRPORT_FORWARD_DELAY_TIMER,
+            HoldTimer(_) => IFLA_BRPORT_HOLD_TIMER,
+            Flush => IFLA_BRPORT_FLUSH,
+            MulticastRouter(_) => IFLA_BRPORT_MULTICAST_ROUTER,
+            Pad(_) => IFLA_BRPORT_PAD,
+            MulticastFlood(_) => IFLA_BRPORT_MCAST_FLOOD,
+            MulticastToUnicast(_) => IFLA_BRPORT_MCAST_TO_UCAST,
+            VlanTunnel(_) => IFLA_BRPORT_VLAN_TUNNEL,
+            BroadcastFlood(_) => IFLA_BRPORT_BCAST_FLOOD,
+            GroupFwdMask(_) => IFLA_BRPORT_GROUP_FWD_MASK,
+            NeighSuppress(_) => IFLA_BRPORT_NEIGH_SUPPRESS,
+            Isolated(_) => IFLA_BRPORT_ISOLATED,
+            BackupPort(_) => IFLA_BRPORT_BACKUP_PORT,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<BridgePortNla> for NlaBuffer<&'buffer T> {
+    fn parse(&self) -> Result<BridgePortNla> {
+        use self::BridgePortNla::*;
+        let payload = self.value();
+        Ok(match self.kind() {
+            IFLA_BRPORT_UNSPEC => Unspec(payload.to_vec()),
+            IFLA_BRPORT_STATE => State(parse_u8(payload)?),
+            IFLA_BRPORT_PRIORITY => Priority(parse_u16(payload)?),
+            IFLA_BRPORT_COST => Cost(parse_u32(payload)?),
+            IFLA_BRPORT_MODE => Mode(parse_u8(payload)?),
+            IFLA_BRPORT_GUARD => Guard(parse_u8(payload)?),
+            IFLA_BRPORT_PROTECT => Protect(parse_u8(payload)?),
+            IFLA_BRPORT_FAST_LEAVE => FastLeave(parse_u8(payload)?),
+            IFLA_BRPORT_LEARNING => Learning(parse_u8(payload)?),
+            IFLA_BRPORT_UNICAST_FLOOD => UnicastFlood(parse_u8(payload)?),
+            IFLA_BRPORT_PROXYARP => ProxyArp(parse_u8(payload)?),
+            IFLA_BRPORT_LEARNING_SYNC => LearningSync(parse_u8(payload)?),
+            IFLA_BRPORT_PROXYARP_WIFI => ProxyArpWifi(parse_u8(payload)?),
+            IFLA_BRPORT_ROOT_ID | IFLA_BRPORT_BRIDGE_ID => {
+                if payload.len() != size_of::<u16>() + size_of::<[u8; 6]>() {
+                    return Err(Error::MalformedNlaValue);
+                }
+
+                let priority = NativeEndian::read_u16(&payload[..2]);
+                let address = parse_mac(&payload[2..])?;
+
+                match self.kind() {
+                    IFLA_BRPORT_ROOT_ID => RootId((priority, address)),
+                    IFLA_BRPORT_BRIDGE_ID => BridgeId((priority, address)),
+                    _ => unreachable!(),
+                }
+            }
+            IFLA_BRPORT_DESIGNATED_PORT => DesignatedPort(parse_u16(payload)?),
+            IFLA_BRPORT_DESIGNATED_COST => DesignatedCost(parse_u16(payload)?),
+            IFLA_BRPORT_ID => Id(parse_u16(payload)?),
+            IFLA_BRPORT_NO => No(parse_u16(payload)?),
+            IFLA_BRPORT_TOPOLOGY_CHANGE_ACK => TopologyChangeAck(parse_u8(payload)?),
+            IFLA_BRPORT_CONFIG_PENDING => ConfigPending(parse_u8(payload)?),
+            IFLA_BRPORT_MESSAGE_AGE_TIMER => MessageAgeTimer(parse_u64(payload)?),
+            IFLA_BRPORT_FORWARD_DELAY_TIMER => ForwardDelayTimer(parse_u64(payload)?),
+            IFLA_BRPORT_HOLD_TIMER => HoldTimer(parse_u64(payload)?),
+            IFLA_BRPORT_FLUSH => Flush,
+            IFLA_BRPORT_MULTICAST_ROUTER => MulticastRouter(parse_u8(payload)?),
+            IFLA_BRPORT_PAD => Pad(payload.to_vec()),
+            IFLA_BRPORT_MCAST_FLOOD => MulticastFlood(parse_u8(payload)?),
+            IFLA_BRPORT_MCAST_TO_UCAST => MulticastToUnicast(parse_u8(payload)?),
+            IFLA_BRPORT_VLAN_TUNNEL => VlanTunnel(parse_u8(payload)?),
+            IFLA_BRPORT_BCAST_FLOOD => BroadcastFlood(parse_u8(payload)?),
+            IFLA_BRPORT_GROUP_FWD_MASK => GroupFwdMask(parse_u16(payload)?),
+            IFLA_BRPORT_NEIGH_SUPPRESS => NeighSuppress(parse_u8(payload)?),
+            IFLA_BRPORT_ISOLATED => Isolated(parse_u8(payload)?),
+            IFLA_BRPORT_BACKUP_PORT => BackupPort(parse_u32(payload)?),
+            _ => Other(<Self as Parseable<DefaultNla>>::parse(self)?),
+        })
+    }
+}
@@ -85,7 +85,7 @@ impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<LinkAfInetNla> for NlaBuffer<&'
         let payload = self.value();
         Ok(match self.kind() {
             IFLA_INET_UNSPEC => Unspec(payload.to_vec()),
-            IFLA_INET_CONF => DevConf(LinkInetDevConf::from_bytes(payload)?),
+            IFLA_INET_CONF => DevConf(LinkInetDevConf::from_counters::<i32>(payload)?),
             _ => Other(<Self as Parseable<DefaultNla>>::parse(self)?),
         })
     }
@@ -203,9 +203,9 @@ impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<LinkAfInet6Nla> for NlaBuffer<&
             IFLA_INET6_UNSPEC => Unspec(payload.to_vec()),
             IFLA_INET6_FLAGS => Flags(parse_u32(payload)?),
             IFLA_INET6_CACHEINFO => CacheInfo(LinkInet6CacheInfo::from_bytes(payload)?),
-            IFLA_INET6_CONF => DevConf(Box::new(LinkInet6DevConf::from_bytes(payload)?)),
-            IFLA_INET6_STATS => Stats(Box::new(LinkInet6Stats::from_bytes(payload)?)),
-            IFLA_INET6_ICMP6STATS => IcmpStats(LinkIcmp6Stats::from_bytes(payload)?),
+            IFLA_INET6_CONF => DevConf(Box::new(LinkInet6DevConf::from_counters::<i32>(payload)?)),
+            IFLA_INET6_STATS => Stats(Box::new(LinkInet6Stats::from_counters::<u64>(payload)?)),
+            IFLA_INET6_ICMP6STATS => IcmpStats(LinkIcmp6Stats::from_counters::<u64>(payload)?),
             IFLA_INET6_TOKEN => Token(parse_ipv6(payload)?),
             IFLA_INET6_ADDR_GEN_MODE => AddrGenMode(parse_u8(payload)?),
             _ => Other(<Self as Parseable<DefaultNla>>::parse(self)?),
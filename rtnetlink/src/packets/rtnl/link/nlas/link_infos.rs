@@ -1,8 +1,10 @@
-use byteorder::{ByteOrder, NativeEndian};
+use byteorder::{BigEndian, ByteOrder, NativeEndian};
 use constants::*;
-use packets::rtnl::{LinkBuffer, LinkHeader, LinkMessage, LinkNla};
+use packets::rtnl::{BridgePortNla, LinkBuffer, LinkHeader, LinkMessage, LinkNla};
 use std::mem::size_of;
-use utils::{parse_mac, parse_string, parse_u16, parse_u32, parse_u64, parse_u8};
+use utils::{
+    parse_mac, parse_string, parse_u16, parse_u16_be, parse_u32, parse_u32_be, parse_u64, parse_u8,
+};
 use {DefaultNla, Emitable, Error, Nla, NlaBuffer, NlasIterator, Parseable, Result};
 
 const DUMMY: &str = "dummy";
@@ -24,6 +26,10 @@ const SIT: &str = "sit";
 const GRE: &str = "gre";
 const IP6GRE: &str = "ip6gre";
 const VTI: &str = "vti";
+const VTI6: &str = "vti6";
+const IP6TNL: &str = "ip6tnl";
+const ERSPAN: &str = "erspan";
+const IP6ERSPAN: &str = "ip6erspan";
 const VRF: &str = "vrf";
 const GTP: &str = "gtp";
 
@@ -33,8 +39,8 @@ pub enum LinkInfo {
     Xstats(Vec<u8>),
     Kind(LinkInfoKind),
     Data(LinkInfoData),
-    SlaveKind(Vec<u8>),
-    SlaveData(Vec<u8>),
+    SlaveKind(LinkInfoKind),
+    SlaveData(LinkInfoSlaveData),
 }
 
 impl Nla for LinkInfo {
@@ -44,11 +50,10 @@ impl Nla for LinkInfo {
         match self {
             Unspec(ref bytes)
                 | Xstats(ref bytes)
-                | SlaveKind(ref bytes)
-                | SlaveData(ref bytes)
                 => bytes.len(),
-            Kind(ref nla) => nla.value_len(),
+            Kind(ref nla) | SlaveKind(ref nla) => nla.value_len(),
             Data(ref nla) => nla.value_len(),
+            SlaveData(ref nla) => nla.value_len(),
         }
     }
 
@@ -58,11 +63,10 @@ impl Nla for LinkInfo {
         match self {
             Unspec(ref bytes)
                 | Xstats(ref bytes)
-                | SlaveKind(ref bytes)
-                | SlaveData(ref bytes)
                 => buffer.copy_from_slice(bytes),
-            Kind(ref nla) => nla.emit_value(buffer),
+            Kind(ref nla) | SlaveKind(ref nla) => nla.emit_value(buffer),
             Data(ref nla) => nla.emit_value(buffer),
+            SlaveData(ref nla) => nla.emit_value(buffer),
         }
     }
 
@@ -72,7 +76,7 @@ impl Nla for LinkInfo {
             Unspec(_) => IFLA_INFO_UNSPEC,
             Xstats(_) => IFLA_INFO_XSTATS,
             SlaveKind(_) => IFLA_INFO_SLAVE_KIND,
-            SlaveData(_) => IFLA_INFO_DATA,
+            SlaveData(_) => IFLA_INFO_SLAVE_DATA,
             Kind(_) => IFLA_INFO_KIND,
             Data(_) => IFLA_INFO_DATA,
         }
@@ -80,19 +84,54 @@ impl Nla for LinkInfo {
 }
 
 // XXX: we cannot impl Parseable<LinkInfo> because some attributes depend on each other. To parse
-// IFLA_INFO_DATA we first need to parse the preceding IFLA_INFO_KIND for example.
+// IFLA_INFO_DATA we first need to parse the preceding IFLA_INFO_KIND for example. Similarly,
+// IFLA_INFO_SLAVE_DATA depends on the preceding IFLA_INFO_SLAVE_KIND.
 impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<Vec<LinkInfo>> for NlaBuffer<&'buffer T> {
     fn parse(&self) -> Result<Vec<LinkInfo>> {
         let mut res = Vec::new();
         let nlas = NlasIterator::new(self.into_inner());
         let mut link_info_kind: Option<LinkInfoKind> = None;
+        let mut link_info_slave_kind: Option<LinkInfoKind> = None;
         for nla in nlas {
             let nla = nla?;
             match nla.kind() {
                 IFLA_INFO_UNSPEC => res.push(LinkInfo::Unspec(nla.value().to_vec())),
                 IFLA_INFO_XSTATS => res.push(LinkInfo::Xstats(nla.value().to_vec())),
-                IFLA_INFO_SLAVE_KIND => res.push(LinkInfo::SlaveKind(nla.value().to_vec())),
-                IFLA_INFO_SLAVE_DATA => res.push(LinkInfo::SlaveData(nla.value().to_vec())),
+                IFLA_INFO_SLAVE_KIND => {
+                    let parsed = <NlaBuffer<_> as Parseable<LinkInfoKind>>::parse(&nla)?;
+                    res.push(LinkInfo::SlaveKind(parsed.clone()));
+                    link_info_slave_kind = Some(parsed);
+                }
+                IFLA_INFO_SLAVE_DATA => {
+                    if let Some(link_info_slave_kind) = link_info_slave_kind {
+                        let payload = nla.value();
+                        let slave_data = match link_info_slave_kind {
+                            LinkInfoKind::Bridge => {
+                                let mut v = Vec::new();
+                                for nla in NlasIterator::new(payload) {
+                                    v.push(<NlaBuffer<_> as Parseable<BridgePortNla>>::parse(
+                                        &nla?,
+                                    )?);
+                                }
+                                LinkInfoSlaveData::Bridge(v)
+                            }
+                            LinkInfoKind::Vrf => {
+                                let mut v = Vec::new();
+                                for nla in NlasIterator::new(payload) {
+                                    v.push(<NlaBuffer<_> as Parseable<LinkInfoVrfPort>>::parse(
+                                        &nla?,
+                                    )?);
+                                }
+                                LinkInfoSlaveData::Vrf(v)
+                            }
+                            _ => LinkInfoSlaveData::Other(payload.to_vec()),
+                        };
+                        res.push(LinkInfo::SlaveData(slave_data));
+                    } else {
+                        return Err(Error::MalformedNlaValue);
+                    }
+                    link_info_slave_kind = None;
+                }
                 IFLA_INFO_KIND => {
                     let parsed = <NlaBuffer<_> as Parseable<LinkInfoKind>>::parse(&nla)?;
                     res.push(LinkInfo::Kind(parsed.clone()));
@@ -125,26 +164,148 @@ impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<Vec<LinkInfo>> for NlaBuffer<&'
                             LinkInfoKind::Tun => LinkInfoData::Tun(payload.to_vec()),
                             LinkInfoKind::Nlmon => LinkInfoData::Nlmon(payload.to_vec()),
                             LinkInfoKind::Veth => {
-                                let buffer = LinkBuffer::new(&payload);
-                                let header =
-                                    <LinkBuffer<_> as Parseable<LinkHeader>>::parse(&buffer)?;
-                                let nlas =
-                                    <LinkBuffer<_> as Parseable<Vec<LinkNla>>>::parse(&buffer)?;
-                                LinkInfoData::Veth(LinkMessage::from_parts(header, nlas))
+                                // The peer is nested in a VETH_INFO_PEER attribute
+                                let mut peer = None;
+                                for nla in NlasIterator::new(payload) {
+                                    let nla = nla?;
+                                    if nla.kind() == VETH_INFO_PEER {
+                                        let value = nla.value();
+                                        let buffer = LinkBuffer::new(&value);
+                                        let header =
+                                            <LinkBuffer<_> as Parseable<LinkHeader>>::parse(
+                                                &buffer,
+                                            )?;
+                                        let nlas =
+                                            <LinkBuffer<_> as Parseable<Vec<LinkNla>>>::parse(
+                                                &buffer,
+                                            )?;
+                                        peer = Some(LinkMessage::from_parts(header, nlas));
+                                    }
+                                }
+                                match peer {
+                                    Some(peer) => LinkInfoData::Veth(peer),
+                                    None => LinkInfoData::Other(payload.to_vec()),
+                                }
                             }
                             LinkInfoKind::Vxlan => LinkInfoData::Vxlan(payload.to_vec()),
                             LinkInfoKind::Bond => LinkInfoData::Bond(payload.to_vec()),
-                            LinkInfoKind::IpVlan => LinkInfoData::IpVlan(payload.to_vec()),
-                            LinkInfoKind::MacVlan => LinkInfoData::MacVlan(payload.to_vec()),
-                            LinkInfoKind::MacVtap => LinkInfoData::MacVtap(payload.to_vec()),
-                            LinkInfoKind::GreTap => LinkInfoData::GreTap(payload.to_vec()),
-                            LinkInfoKind::GreTap6 => LinkInfoData::GreTap6(payload.to_vec()),
-                            LinkInfoKind::IpTun => LinkInfoData::IpTun(payload.to_vec()),
-                            LinkInfoKind::SitTun => LinkInfoData::SitTun(payload.to_vec()),
-                            LinkInfoKind::GreTun => LinkInfoData::GreTun(payload.to_vec()),
-                            LinkInfoKind::GreTun6 => LinkInfoData::GreTun6(payload.to_vec()),
-                            LinkInfoKind::Vti => LinkInfoData::Vti(payload.to_vec()),
-                            LinkInfoKind::Vrf => LinkInfoData::Vrf(payload.to_vec()),
+                            LinkInfoKind::IpVlan => {
+                                let mut v = Vec::new();
+                                for nla in NlasIterator::new(payload) {
+                                    v.push(<NlaBuffer<_> as Parseable<LinkInfoIpVlan>>::parse(
+                                        &nla?,
+                                    )?);
+                                }
+                                LinkInfoData::IpVlan(v)
+                            }
+                            LinkInfoKind::MacVlan => {
+                                let mut v = Vec::new();
+                                for nla in NlasIterator::new(payload) {
+                                    v.push(<NlaBuffer<_> as Parseable<LinkInfoMacVlan>>::parse(
+                                        &nla?,
+                                    )?);
+                                }
+                                LinkInfoData::MacVlan(v)
+                            }
+                            LinkInfoKind::MacVtap => {
+                                let mut v = Vec::new();
+                                for nla in NlasIterator::new(payload) {
+                                    v.push(<NlaBuffer<_> as Parseable<LinkInfoMacVlan>>::parse(
+                                        &nla?,
+                                    )?);
+                                }
+                                LinkInfoData::MacVtap(v)
+                            }
+                            LinkInfoKind::GreTap => {
+                                let mut v = Vec::new();
+                                for nla in NlasIterator::new(payload) {
+                                    v.push(<NlaBuffer<_> as Parseable<LinkInfoGre>>::parse(&nla?)?);
+                                }
+                                LinkInfoData::GreTap(v)
+                            }
+                            LinkInfoKind::GreTap6 => {
+                                let mut v = Vec::new();
+                                for nla in NlasIterator::new(payload) {
+                                    v.push(<NlaBuffer<_> as Parseable<LinkInfoGre>>::parse(&nla?)?);
+                                }
+                                LinkInfoData::GreTap6(v)
+                            }
+                            LinkInfoKind::IpTun => {
+                                let mut v = Vec::new();
+                                for nla in NlasIterator::new(payload) {
+                                    v.push(<NlaBuffer<_> as Parseable<LinkInfoIpTun>>::parse(
+                                        &nla?,
+                                    )?);
+                                }
+                                LinkInfoData::IpTun(v)
+                            }
+                            LinkInfoKind::SitTun => {
+                                let mut v = Vec::new();
+                                for nla in NlasIterator::new(payload) {
+                                    v.push(<NlaBuffer<_> as Parseable<LinkInfoIpTun>>::parse(
+                                        &nla?,
+                                    )?);
+                                }
+                                LinkInfoData::SitTun(v)
+                            }
+                            LinkInfoKind::GreTun => {
+                                let mut v = Vec::new();
+                                for nla in NlasIterator::new(payload) {
+                                    v.push(<NlaBuffer<_> as Parseable<LinkInfoGre>>::parse(&nla?)?);
+                                }
+                                LinkInfoData::GreTun(v)
+                            }
+                            LinkInfoKind::GreTun6 => {
+                                let mut v = Vec::new();
+                                for nla in NlasIterator::new(payload) {
+                                    v.push(<NlaBuffer<_> as Parseable<LinkInfoGre>>::parse(&nla?)?);
+                                }
+                                LinkInfoData::GreTun6(v)
+                            }
+                            LinkInfoKind::Vti => {
+                                let mut v = Vec::new();
+                                for nla in NlasIterator::new(payload) {
+                                    v.push(<NlaBuffer<_> as Parseable<LinkInfoVti>>::parse(&nla?)?);
+                                }
+                                LinkInfoData::Vti(v)
+                            }
+                            LinkInfoKind::Vti6 => {
+                                let mut v = Vec::new();
+                                for nla in NlasIterator::new(payload) {
+                                    v.push(<NlaBuffer<_> as Parseable<LinkInfoVti>>::parse(&nla?)?);
+                                }
+                                LinkInfoData::Vti6(v)
+                            }
+                            LinkInfoKind::Ip6Tnl => {
+                                let mut v = Vec::new();
+                                for nla in NlasIterator::new(payload) {
+                                    v.push(<NlaBuffer<_> as Parseable<LinkInfoIpTun>>::parse(
+                                        &nla?,
+                                    )?);
+                                }
+                                LinkInfoData::Ip6Tnl(v)
+                            }
+                            LinkInfoKind::Erspan => {
+                                let mut v = Vec::new();
+                                for nla in NlasIterator::new(payload) {
+                                    v.push(<NlaBuffer<_> as Parseable<LinkInfoGre>>::parse(&nla?)?);
+                                }
+                                LinkInfoData::Erspan(v)
+                            }
+                            LinkInfoKind::Ip6Erspan => {
+                                let mut v = Vec::new();
+                                for nla in NlasIterator::new(payload) {
+                                    v.push(<NlaBuffer<_> as Parseable<LinkInfoGre>>::parse(&nla?)?);
+                                }
+                                LinkInfoData::Ip6Erspan(v)
+                            }
+                            LinkInfoKind::Vrf => {
+                                let mut v = Vec::new();
+                                for nla in NlasIterator::new(payload) {
+                                    v.push(<NlaBuffer<_> as Parseable<LinkInfoVrf>>::parse(&nla?)?);
+                                }
+                                LinkInfoData::Vrf(v)
+                            }
                             LinkInfoKind::Gtp => LinkInfoData::Gtp(payload.to_vec()),
                             LinkInfoKind::Other(_) => LinkInfoData::Other(payload.to_vec()),
                         };
@@ -162,6 +323,7 @@ impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<Vec<LinkInfo>> for NlaBuffer<&'
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde_support", derive(Serialize, Deserialize))]
 pub enum LinkInfoData {
     Bridge(Vec<LinkInfoBridge>),
     Tun(Vec<u8>),
@@ -169,24 +331,48 @@ pub enum LinkInfoData {
     Vlan(Vec<LinkInfoVlan>),
     Dummy(Vec<u8>),
     Ifb(Vec<u8>),
+    // The peer is a whole link message, which cannot be serialized
+    #[cfg_attr(feature = "serde_support", serde(skip))]
     Veth(LinkMessage),
     Vxlan(Vec<u8>),
     Bond(Vec<u8>),
-    IpVlan(Vec<u8>),
-    MacVlan(Vec<u8>),
-    MacVtap(Vec<u8>),
-    GreTap(Vec<u8>),
-    GreTap6(Vec<u8>),
-    IpTun(Vec<u8>),
-    SitTun(Vec<u8>),
-    GreTun(Vec<u8>),
-    GreTun6(Vec<u8>),
-    Vti(Vec<u8>),
-    Vrf(Vec<u8>),
+    IpVlan(Vec<LinkInfoIpVlan>),
+    MacVlan(Vec<LinkInfoMacVlan>),
+    // macvtap devices are configured with the exact same attributes as macvlan devices
+    MacVtap(Vec<LinkInfoMacVlan>),
+    GreTap(Vec<LinkInfoGre>),
+    GreTap6(Vec<LinkInfoGre>),
+    IpTun(Vec<LinkInfoIpTun>),
+    SitTun(Vec<LinkInfoIpTun>),
+    Ip6Tnl(Vec<LinkInfoIpTun>),
+    GreTun(Vec<LinkInfoGre>),
+    GreTun6(Vec<LinkInfoGre>),
+    Erspan(Vec<LinkInfoGre>),
+    Ip6Erspan(Vec<LinkInfoGre>),
+    Vti(Vec<LinkInfoVti>),
+    Vti6(Vec<LinkInfoVti>),
+    Vrf(Vec<LinkInfoVrf>),
     Gtp(Vec<u8>),
     Other(Vec<u8>),
 }
 
+/// The `VETH_INFO_PEER` attribute, that holds the link message of the peer of a veth link
+struct VethPeer<'a>(&'a LinkMessage);
+
+impl<'a> Nla for VethPeer<'a> {
+    fn value_len(&self) -> usize {
+        self.0.buffer_len()
+    }
+
+    fn kind(&self) -> u16 {
+        VETH_INFO_PEER
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        self.0.emit(buffer)
+    }
+}
+
 impl Nla for LinkInfoData {
     #[cfg_attr(nightly, rustfmt::skip)]
     fn value_len(&self) -> usize {
@@ -194,24 +380,25 @@ impl Nla for LinkInfoData {
         match self {
             Bridge(ref nlas) => nlas.as_slice().buffer_len(),
             Vlan(ref nlas) =>  nlas.as_slice().buffer_len(),
-            Veth(ref msg) => msg.buffer_len(),
+            IpVlan(ref nlas) => nlas.as_slice().buffer_len(),
+            MacVlan(ref nlas) | MacVtap(ref nlas) => nlas.as_slice().buffer_len(),
+            GreTap(ref nlas)
+                | GreTap6(ref nlas)
+                | GreTun(ref nlas)
+                | GreTun6(ref nlas)
+                | Erspan(ref nlas)
+                | Ip6Erspan(ref nlas)
+                => nlas.as_slice().buffer_len(),
+            IpTun(ref nlas) | SitTun(ref nlas) | Ip6Tnl(ref nlas) => nlas.as_slice().buffer_len(),
+            Vti(ref nlas) | Vti6(ref nlas) => nlas.as_slice().buffer_len(),
+            Vrf(ref nlas) => nlas.as_slice().buffer_len(),
+            Veth(ref msg) => VethPeer(msg).buffer_len(),
             Dummy(ref bytes)
                 | Tun(ref bytes)
                 | Nlmon(ref bytes)
                 | Ifb(ref bytes)
                 | Vxlan(ref bytes)
                 | Bond(ref bytes)
-                | IpVlan(ref bytes)
-                | MacVlan(ref bytes)
-                | MacVtap(ref bytes)
-                | GreTap(ref bytes)
-                | GreTap6(ref bytes)
-                | IpTun(ref bytes)
-                | SitTun(ref bytes)
-                | GreTun(ref bytes)
-                | GreTun6(ref bytes)
-                | Vti(ref bytes)
-                | Vrf(ref bytes)
                 | Gtp(ref bytes)
                 | Other(ref bytes)
                 => bytes.len(),
@@ -224,24 +411,25 @@ impl Nla for LinkInfoData {
         match self {
             Bridge(ref nlas) => nlas.as_slice().emit(buffer),
             Vlan(ref nlas) => nlas.as_slice().emit(buffer),
-            Veth(ref msg) => msg.emit(buffer),
+            IpVlan(ref nlas) => nlas.as_slice().emit(buffer),
+            MacVlan(ref nlas) | MacVtap(ref nlas) => nlas.as_slice().emit(buffer),
+            GreTap(ref nlas)
+                | GreTap6(ref nlas)
+                | GreTun(ref nlas)
+                | GreTun6(ref nlas)
+                | Erspan(ref nlas)
+                | Ip6Erspan(ref nlas)
+                => nlas.as_slice().emit(buffer),
+            IpTun(ref nlas) | SitTun(ref nlas) | Ip6Tnl(ref nlas) => nlas.as_slice().emit(buffer),
+            Vti(ref nlas) | Vti6(ref nlas) => nlas.as_slice().emit(buffer),
+            Vrf(ref nlas) => nlas.as_slice().emit(buffer),
+            Veth(ref msg) => VethPeer(msg).emit(buffer),
             Dummy(ref bytes)
                 | Tun(ref bytes)
                 | Nlmon(ref bytes)
                 | Ifb(ref bytes)
                 | Vxlan(ref bytes)
                 | Bond(ref bytes)
-                | IpVlan(ref bytes)
-                | MacVlan(ref bytes)
-                | MacVtap(ref bytes)
-                | GreTap(ref bytes)
-                | GreTap6(ref bytes)
-                | IpTun(ref bytes)
-                | SitTun(ref bytes)
-                | GreTun(ref bytes)
-                | GreTun6(ref bytes)
-                | Vti(ref bytes)
-                | Vrf(ref bytes)
                 | Gtp(ref bytes)
                 | Other(ref bytes)
                 => buffer.copy_from_slice(bytes),
@@ -253,7 +441,41 @@ impl Nla for LinkInfoData {
     }
 }
 
+/// Data describing a link enslaved to another link, like a port of a bridge or a VRF. The
+/// variant depends on the kind of the master link.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum LinkInfoSlaveData {
+    Bridge(Vec<BridgePortNla>),
+    Vrf(Vec<LinkInfoVrfPort>),
+    Other(Vec<u8>),
+}
+
+impl Nla for LinkInfoSlaveData {
+    fn value_len(&self) -> usize {
+        use self::LinkInfoSlaveData::*;
+        match self {
+            Bridge(ref nlas) => nlas.as_slice().buffer_len(),
+            Vrf(ref nlas) => nlas.as_slice().buffer_len(),
+            Other(ref bytes) => bytes.len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::LinkInfoSlaveData::*;
+        match self {
+            Bridge(ref nlas) => nlas.as_slice().emit(buffer),
+            Vrf(ref nlas) => nlas.as_slice().emit(buffer),
+            Other(ref bytes) => buffer.copy_from_slice(bytes),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        IFLA_INFO_SLAVE_DATA
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde_support", derive(Serialize, Deserialize))]
 pub enum LinkInfoKind {
     Dummy,
     Ifb,
@@ -273,45 +495,21 @@ pub enum LinkInfoKind {
     SitTun,
     GreTun,
     GreTun6,
+    Erspan,
+    Ip6Erspan,
+    Ip6Tnl,
     Vti,
+    Vti6,
     Vrf,
     Gtp,
     Other(String),
 }
 
-impl Nla for LinkInfoKind {
-    fn value_len(&self) -> usize {
-        use self::LinkInfoKind::*;
-        let len = match *self {
-            Dummy => DUMMY.len(),
-            Ifb => IFB.len(),
-            Bridge => BRIDGE.len(),
-            Tun => TUN.len(),
-            Nlmon => NLMON.len(),
-            Vlan => VLAN.len(),
-            Veth => VETH.len(),
-            Vxlan => VXLAN.len(),
-            Bond => BOND.len(),
-            IpVlan => IPVLAN.len(),
-            MacVlan => MACVLAN.len(),
-            MacVtap => MACVTAP.len(),
-            GreTap => GRETAP.len(),
-            GreTap6 => IP6GRETAP.len(),
-            IpTun => IPIP.len(),
-            SitTun => SIT.len(),
-            GreTun => GRE.len(),
-            GreTun6 => IP6GRE.len(),
-            Vti => VTI.len(),
-            Vrf => VRF.len(),
-            Gtp => GTP.len(),
-            Other(ref s) => s.len(),
-        };
-        len + 1
-    }
-
-    fn emit_value(&self, buffer: &mut [u8]) {
+impl LinkInfoKind {
+    /// Name of the link kind, as used by the kernel (for instance `"dummy"` or `"veth"`)
+    pub fn as_str(&self) -> &str {
         use self::LinkInfoKind::*;
-        let s = match *self {
+        match *self {
             Dummy => DUMMY,
             Ifb => IFB,
             Bridge => BRIDGE,
@@ -330,11 +528,25 @@ impl Nla for LinkInfoKind {
             SitTun => SIT,
             GreTun => GRE,
             GreTun6 => IP6GRE,
+            Erspan => ERSPAN,
+            Ip6Erspan => IP6ERSPAN,
+            Ip6Tnl => IP6TNL,
             Vti => VTI,
+            Vti6 => VTI6,
             Vrf => VRF,
             Gtp => GTP,
             Other(ref s) => s.as_str(),
-        };
+        }
+    }
+}
+
+impl Nla for LinkInfoKind {
+    fn value_len(&self) -> usize {
+        self.as_str().len() + 1
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        let s = self.as_str();
         buffer[..s.len()].copy_from_slice(s.as_bytes());
         buffer[s.len()] = 0;
     }
@@ -347,7 +559,7 @@ impl Nla for LinkInfoKind {
 impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<LinkInfoKind> for NlaBuffer<&'buffer T> {
     fn parse(&self) -> Result<LinkInfoKind> {
         use self::LinkInfoKind::*;
-        if self.kind() != IFLA_INFO_KIND {
+        if self.kind() != IFLA_INFO_KIND && self.kind() != IFLA_INFO_SLAVE_KIND {
             return Err(Error::MalformedNlaValue);
         }
         let s = parse_string(self.value())?;
@@ -370,7 +582,11 @@ impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<LinkInfoKind> for NlaBuffer<&'b
             SIT => SitTun,
             GRE => GreTun,
             IP6GRE => GreTun6,
+            ERSPAN => Erspan,
+            IP6ERSPAN => Ip6Erspan,
+            IP6TNL => Ip6Tnl,
             VTI => Vti,
+            VTI6 => Vti6,
             VRF => Vrf,
             GTP => Gtp,
             _ => Other(s),
@@ -378,89 +594,800 @@ impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<LinkInfoKind> for NlaBuffer<&'b
     }
 }
 
-// https://elixir.bootlin.com/linux/latest/source/net/8021q/vlan_netlink.c#L21
+// https://elixir.bootlin.com/linux/latest/source/net/8021q/vlan_netlink.c#L21
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde_support", derive(Serialize, Deserialize))]
+pub enum LinkInfoVlan {
+    Unspec(Vec<u8>),
+    Id(u16),
+    Flags((u32, u32)),
+    EgressQos(Vec<u8>),
+    IngressQos(Vec<u8>),
+    Protocol(u16),
+}
+
+impl Nla for LinkInfoVlan {
+    #[cfg_attr(nightly, rustfmt::skip)]
+    fn value_len(&self) -> usize {
+        use self::LinkInfoVlan::*;
+        match self {
+            Id(_) | Protocol(_) => size_of::<u16>(),
+            Flags(_) => size_of::<u32>() * 2,
+            Unspec(bytes)
+                | EgressQos(bytes)
+                | IngressQos(bytes)
+                => bytes.len(),
+        }
+    }
+
+    #[cfg_attr(nightly, rustfmt::skip)]
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::LinkInfoVlan::*;
+        match self {
+            Unspec(ref bytes)
+                | EgressQos(ref bytes)
+                | IngressQos(ref bytes)
+                => buffer.copy_from_slice(bytes),
+
+            Id(ref value)
+                | Protocol(ref value)
+                => NativeEndian::write_u16(buffer, *value),
+
+            Flags(ref flags) => {
+                NativeEndian::write_u32(buffer, flags.0);
+                NativeEndian::write_u32(buffer, flags.1)
+            }
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::LinkInfoVlan::*;
+        match self {
+            Unspec(_) => IFLA_VLAN_UNSPEC,
+            Id(_) => IFLA_VLAN_ID,
+            Flags(_) => IFLA_VLAN_FLAGS,
+            EgressQos(_) => IFLA_VLAN_EGRESS_QOS,
+            IngressQos(_) => IFLA_VLAN_INGRESS_QOS,
+            Protocol(_) => IFLA_VLAN_PROTOCOL,
+        }
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<LinkInfoVlan> for NlaBuffer<&'buffer T> {
+    fn parse(&self) -> Result<LinkInfoVlan> {
+        use self::LinkInfoVlan::*;
+        let payload = self.value();
+        Ok(match self.kind() {
+            IFLA_VLAN_UNSPEC => Unspec(payload.to_vec()),
+            IFLA_VLAN_ID => Id(parse_u16(payload)?),
+            IFLA_VLAN_FLAGS => {
+                let u32len = size_of::<u32>();
+                if payload.len() != (u32len * 2) {
+                    return Err(Error::MalformedNlaValue);
+                }
+                let flags = parse_u32(&payload[0..u32len])?;
+                let mask = parse_u32(&payload[u32len..])?;
+                Flags((flags, mask))
+            }
+            IFLA_VLAN_EGRESS_QOS => EgressQos(payload.to_vec()),
+            IFLA_VLAN_INGRESS_QOS => IngressQos(payload.to_vec()),
+            IFLA_VLAN_PROTOCOL => Protocol(parse_u16(payload)?),
+            _ => return Err(Error::MalformedNlaValue),
+        })
+    }
+}
+
+// https://elixir.bootlin.com/linux/latest/source/drivers/net/macvlan.c#L1306
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde_support", derive(Serialize, Deserialize))]
+pub enum LinkInfoMacVlan {
+    Unspec(Vec<u8>),
+    /// One of the `MACVLAN_MODE_*` constants
+    Mode(u32),
+    /// `MACVLAN_FLAG_NOPROMISC` or 0
+    Flags(u16),
+    /// One of the `MACVLAN_MACADDR_*` constants, describing how the kernel should interpret
+    /// the `MacAddr` and `MacAddrData` attributes. Only relevant in `MACVLAN_MODE_SOURCE` mode.
+    MacAddrMode(u32),
+    MacAddr([u8; 6]),
+    /// List of `MacAddr` attributes
+    MacAddrData(Vec<LinkInfoMacVlan>),
+    MacAddrCount(u32),
+    BcQueueLen(u32),
+    BcQueueLenUsed(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for LinkInfoMacVlan {
+    #[cfg_attr(nightly, rustfmt::skip)]
+    fn value_len(&self) -> usize {
+        use self::LinkInfoMacVlan::*;
+        match self {
+            Unspec(ref bytes) => bytes.len(),
+            Flags(_) => size_of::<u16>(),
+            Mode(_)
+                | MacAddrMode(_)
+                | MacAddrCount(_)
+                | BcQueueLen(_)
+                | BcQueueLenUsed(_)
+                => size_of::<u32>(),
+            MacAddr(_) => 6,
+            MacAddrData(ref nlas) => nlas.as_slice().buffer_len(),
+            Other(ref nla) => nla.value_len(),
+        }
+    }
+
+    #[cfg_attr(nightly, rustfmt::skip)]
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::LinkInfoMacVlan::*;
+        match self {
+            Unspec(ref bytes) => buffer.copy_from_slice(bytes),
+            Flags(ref value) => NativeEndian::write_u16(buffer, *value),
+            Mode(ref value)
+                | MacAddrMode(ref value)
+                | MacAddrCount(ref value)
+                | BcQueueLen(ref value)
+                | BcQueueLenUsed(ref value)
+                => NativeEndian::write_u32(buffer, *value),
+            MacAddr(ref bytes) => buffer.copy_from_slice(&bytes[..]),
+            MacAddrData(ref nlas) => nlas.as_slice().emit(buffer),
+            Other(ref nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::LinkInfoMacVlan::*;
+        match self {
+            Unspec(_) => IFLA_MACVLAN_UNSPEC,
+            Mode(_) => IFLA_MACVLAN_MODE,
+            Flags(_) => IFLA_MACVLAN_FLAGS,
+            MacAddrMode(_) => IFLA_MACVLAN_MACADDR_MODE,
+            MacAddr(_) => IFLA_MACVLAN_MACADDR,
+            MacAddrData(_) => IFLA_MACVLAN_MACADDR_DATA,
+            MacAddrCount(_) => IFLA_MACVLAN_MACADDR_COUNT,
+            BcQueueLen(_) => IFLA_MACVLAN_BC_QUEUE_LEN,
+            BcQueueLenUsed(_) => IFLA_MACVLAN_BC_QUEUE_LEN_USED,
+            Other(ref nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<LinkInfoMacVlan> for NlaBuffer<&'buffer T> {
+    fn parse(&self) -> Result<LinkInfoMacVlan> {
+        use self::LinkInfoMacVlan::*;
+        let payload = self.value();
+        Ok(match self.kind() {
+            IFLA_MACVLAN_UNSPEC => Unspec(payload.to_vec()),
+            IFLA_MACVLAN_MODE => Mode(parse_u32(payload)?),
+            IFLA_MACVLAN_FLAGS => Flags(parse_u16(payload)?),
+            IFLA_MACVLAN_MACADDR_MODE => MacAddrMode(parse_u32(payload)?),
+            IFLA_MACVLAN_MACADDR => MacAddr(parse_mac(payload)?),
+            IFLA_MACVLAN_MACADDR_DATA => {
+                let mut v = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    v.push(<NlaBuffer<_> as Parseable<LinkInfoMacVlan>>::parse(&nla?)?);
+                }
+                MacAddrData(v)
+            }
+            IFLA_MACVLAN_MACADDR_COUNT => MacAddrCount(parse_u32(payload)?),
+            IFLA_MACVLAN_BC_QUEUE_LEN => BcQueueLen(parse_u32(payload)?),
+            IFLA_MACVLAN_BC_QUEUE_LEN_USED => BcQueueLenUsed(parse_u32(payload)?),
+            _ => Other(<Self as Parseable<DefaultNla>>::parse(self)?),
+        })
+    }
+}
+
+// https://elixir.bootlin.com/linux/latest/source/drivers/net/ipvlan/ipvlan_main.c#L496
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde_support", derive(Serialize, Deserialize))]
+pub enum LinkInfoIpVlan {
+    Unspec(Vec<u8>),
+    /// One of the `IPVLAN_MODE_*` constants
+    Mode(u16),
+    /// A combination of the `IPVLAN_F_*` flags. If neither `IPVLAN_F_PRIVATE` nor
+    /// `IPVLAN_F_VEPA` is set, the device is in bridge mode.
+    Flags(u16),
+    Other(DefaultNla),
+}
+
+impl Nla for LinkInfoIpVlan {
+    fn value_len(&self) -> usize {
+        use self::LinkInfoIpVlan::*;
+        match self {
+            Unspec(ref bytes) => bytes.len(),
+            Mode(_) | Flags(_) => size_of::<u16>(),
+            Other(ref nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::LinkInfoIpVlan::*;
+        match self {
+            Unspec(ref bytes) => buffer.copy_from_slice(bytes),
+            Mode(ref value) | Flags(ref value) => NativeEndian::write_u16(buffer, *value),
+            Other(ref nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::LinkInfoIpVlan::*;
+        match self {
+            Unspec(_) => IFLA_IPVLAN_UNSPEC,
+            Mode(_) => IFLA_IPVLAN_MODE,
+            Flags(_) => IFLA_IPVLAN_FLAGS,
+            Other(ref nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<LinkInfoIpVlan> for NlaBuffer<&'buffer T> {
+    fn parse(&self) -> Result<LinkInfoIpVlan> {
+        use self::LinkInfoIpVlan::*;
+        let payload = self.value();
+        Ok(match self.kind() {
+            IFLA_IPVLAN_UNSPEC => Unspec(payload.to_vec()),
+            IFLA_IPVLAN_MODE => Mode(parse_u16(payload)?),
+            IFLA_IPVLAN_FLAGS => Flags(parse_u16(payload)?),
+            _ => Other(<Self as Parseable<DefaultNla>>::parse(self)?),
+        })
+    }
+}
+
+// https://elixir.bootlin.com/linux/latest/source/net/ipv4/ip_gre.c#L1390
+//
+// These attributes are used by gre, gretap and erspan links, as well as their IPv6 counterparts
+// (ip6gre, ip6gretap and ip6erspan).
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde_support", derive(Serialize, Deserialize))]
+pub enum LinkInfoGre {
+    Unspec(Vec<u8>),
+    Link(u32),
+    /// GRE flags (`GRE_CSUM`, `GRE_KEY`, `GRE_SEQ`...) set on incoming packets
+    IFlags(u16),
+    /// GRE flags (`GRE_CSUM`, `GRE_KEY`, `GRE_SEQ`...) set on outgoing packets
+    OFlags(u16),
+    IKey(u32),
+    OKey(u32),
+    /// Local address. This is an IPv4 address for gre/gretap/erspan links, and an IPv6 address
+    /// for ip6gre/ip6gretap/ip6erspan links.
+    Local(Vec<u8>),
+    /// Remote address. This is an IPv4 address for gre/gretap/erspan links, and an IPv6 address
+    /// for ip6gre/ip6gretap/ip6erspan links.
+    Remote(Vec<u8>),
+    Ttl(u8),
+    Tos(u8),
+    PathMtuDiscovery(u8),
+    EncapLimit(u8),
+    FlowInfo(u32),
+    Flags(u32),
+    /// One of the `TUNNEL_ENCAP_*` constants
+    EncapType(u16),
+    /// A combination of the `TUNNEL_ENCAP_FLAG_*` flags
+    EncapFlags(u16),
+    EncapSourcePort(u16),
+    EncapDestinationPort(u16),
+    CollectMetadata,
+    IgnoreDf(u8),
+    FwMark(u32),
+    ErspanIndex(u32),
+    ErspanVersion(u8),
+    ErspanDirection(u8),
+    ErspanHwId(u16),
+    Other(DefaultNla),
+}
+
+impl Nla for LinkInfoGre {
+    #[cfg_attr(nightly, rustfmt::skip)]
+    fn value_len(&self) -> usize {
+        use self::LinkInfoGre::*;
+        match self {
+            Unspec(ref bytes)
+                | Local(ref bytes)
+                | Remote(ref bytes)
+                => bytes.len(),
+            CollectMetadata => 0,
+            Ttl(_)
+                | Tos(_)
+                | PathMtuDiscovery(_)
+                | EncapLimit(_)
+                | IgnoreDf(_)
+                | ErspanVersion(_)
+                | ErspanDirection(_)
+                => size_of::<u8>(),
+            IFlags(_)
+                | OFlags(_)
+                | EncapType(_)
+                | EncapFlags(_)
+                | EncapSourcePort(_)
+                | EncapDestinationPort(_)
+                | ErspanHwId(_)
+                => size_of::<u16>(),
+            Link(_)
+                | IKey(_)
+                | OKey(_)
+                | FlowInfo(_)
+                | Flags(_)
+                | FwMark(_)
+                | ErspanIndex(_)
+                => size_of::<u32>(),
+            Other(ref nla) => nla.value_len(),
+        }
+    }
+
+    #[cfg_attr(nightly, rustfmt::skip)]
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::LinkInfoGre::*;
+        match self {
+            Unspec(ref bytes)
+                | Local(ref bytes)
+                | Remote(ref bytes)
+                => buffer.copy_from_slice(bytes),
+            CollectMetadata => {}
+            Ttl(ref value)
+                | Tos(ref value)
+                | PathMtuDiscovery(ref value)
+                | EncapLimit(ref value)
+                | IgnoreDf(ref value)
+                | ErspanVersion(ref value)
+                | ErspanDirection(ref value)
+                => buffer[0] = *value,
+            EncapType(ref value)
+                | EncapFlags(ref value)
+                | ErspanHwId(ref value)
+                => NativeEndian::write_u16(buffer, *value),
+            // these are in network byte order
+            IFlags(ref value)
+                | OFlags(ref value)
+                | EncapSourcePort(ref value)
+                | EncapDestinationPort(ref value)
+                => BigEndian::write_u16(buffer, *value),
+            Link(ref value)
+                | Flags(ref value)
+                | FwMark(ref value)
+                | ErspanIndex(ref value)
+                => NativeEndian::write_u32(buffer, *value),
+            // these are in network byte order
+            IKey(ref value)
+                | OKey(ref value)
+                | FlowInfo(ref value)
+                => BigEndian::write_u32(buffer, *value),
+            Other(ref nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::LinkInfoGre::*;
+        match self {
+            Unspec(_) => IFLA_GRE_UNSPEC,
+            Link(_) => IFLA_GRE_LINK,
+            IFlags(_) => IFLA_GRE_IFLAGS,
+            OFlags(_) => IFLA_GRE_OFLAGS,
+            IKey(_) => IFLA_GRE_IKEY,
+            OKey(_) => IFLA_GRE_OKEY,
+            Local(_) => IFLA_GRE_LOCAL,
+            Remote(_) => IFLA_GRE_REMOTE,
+            Ttl(_) => IFLA_GRE_TTL,
+            Tos(_) => IFLA_GRE_TOS,
+            PathMtuDiscovery(_) => IFLA_GRE_PMTUDISC,
+            EncapLimit(_) => IFLA_GRE_ENCAP_LIMIT,
+            FlowInfo(_) => IFLA_GRE_FLOWINFO,
+            Flags(_) => IFLA_GRE_FLAGS,
+            EncapType(_) => IFLA_GRE_ENCAP_TYPE,
+            EncapFlags(_) => IFLA_GRE_ENCAP_FLAGS,
+            EncapSourcePort(_) => IFLA_GRE_ENCAP_SPORT,
+            EncapDestinationPort(_) => IFLA_GRE_ENCAP_DPORT,
+            CollectMetadata => IFLA_GRE_COLLECT_METADATA,
+            IgnoreDf(_) => IFLA_GRE_IGNORE_DF,
+            FwMark(_) => IFLA_GRE_FWMARK,
+            ErspanIndex(_) => IFLA_GRE_ERSPAN_INDEX,
+            ErspanVersion(_) => IFLA_GRE_ERSPAN_VER,
+            ErspanDirection(_) => IFLA_GRE_ERSPAN_DIR,
+            ErspanHwId(_) => IFLA_GRE_ERSPAN_HWID,
+            Other(ref nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<LinkInfoGre> for NlaBuffer<&'buffer T> {
+    fn parse(&self) -> Result<LinkInfoGre> {
+        use self::LinkInfoGre::*;
+        let payload = self.value();
+        Ok(match self.kind() {
+            IFLA_GRE_UNSPEC => Unspec(payload.to_vec()),
+            IFLA_GRE_LINK => Link(parse_u32(payload)?),
+            IFLA_GRE_IFLAGS => IFlags(parse_u16_be(payload)?),
+            IFLA_GRE_OFLAGS => OFlags(parse_u16_be(payload)?),
+            IFLA_GRE_IKEY => IKey(parse_u32_be(payload)?),
+            IFLA_GRE_OKEY => OKey(parse_u32_be(payload)?),
+            IFLA_GRE_LOCAL => Local(payload.to_vec()),
+            IFLA_GRE_REMOTE => Remote(payload.to_vec()),
+            IFLA_GRE_TTL => Ttl(parse_u8(payload)?),
+            IFLA_GRE_TOS => Tos(parse_u8(payload)?),
+            IFLA_GRE_PMTUDISC => PathMtuDiscovery(parse_u8(payload)?),
+            IFLA_GRE_ENCAP_LIMIT => EncapLimit(parse_u8(payload)?),
+            IFLA_GRE_FLOWINFO => FlowInfo(parse_u32_be(payload)?),
+            IFLA_GRE_FLAGS => Flags(parse_u32(payload)?),
+            IFLA_GRE_ENCAP_TYPE => EncapType(parse_u16(payload)?),
+            IFLA_GRE_ENCAP_FLAGS => EncapFlags(parse_u16(payload)?),
+            IFLA_GRE_ENCAP_SPORT => EncapSourcePort(parse_u16_be(payload)?),
+            IFLA_GRE_ENCAP_DPORT => EncapDestinationPort(parse_u16_be(payload)?),
+            IFLA_GRE_COLLECT_METADATA => CollectMetadata,
+            IFLA_GRE_IGNORE_DF => IgnoreDf(parse_u8(payload)?),
+            IFLA_GRE_FWMARK => FwMark(parse_u32(payload)?),
+            IFLA_GRE_ERSPAN_INDEX => ErspanIndex(parse_u32(payload)?),
+            IFLA_GRE_ERSPAN_VER => ErspanVersion(parse_u8(payload)?),
+            IFLA_GRE_ERSPAN_DIR => ErspanDirection(parse_u8(payload)?),
+            IFLA_GRE_ERSPAN_HWID => ErspanHwId(parse_u16(payload)?),
+            _ => Other(<Self as Parseable<DefaultNla>>::parse(self)?),
+        })
+    }
+}
+
+// https://elixir.bootlin.com/linux/latest/source/net/ipv4/ipip.c#L571
+//
+// These attributes are used by ipip, sit and ip6tnl links.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde_support", derive(Serialize, Deserialize))]
+pub enum LinkInfoIpTun {
+    Unspec(Vec<u8>),
+    Link(u32),
+    /// Local address. This is an IPv4 address for ipip and sit links, and an IPv6 address for
+    /// ip6tnl links.
+    Local(Vec<u8>),
+    /// Remote address. This is an IPv4 address for ipip and sit links, and an IPv6 address for
+    /// ip6tnl links.
+    Remote(Vec<u8>),
+    Ttl(u8),
+    Tos(u8),
+    EncapLimit(u8),
+    FlowInfo(u32),
+    /// `IFLA_IPTUN_FLAGS` for sit links, which is a `u16`
+    SitFlags(u16),
+    /// `IFLA_IPTUN_FLAGS` for ip6tnl links, which is a `u32`
+    Ip6TnlFlags(u32),
+    Protocol(u8),
+    PathMtuDiscovery(u8),
+    SixRdPrefix(Vec<u8>),
+    SixRdRelayPrefix(Vec<u8>),
+    SixRdPrefixLen(u16),
+    SixRdRelayPrefixLen(u16),
+    /// One of the `TUNNEL_ENCAP_*` constants
+    EncapType(u16),
+    /// A combination of the `TUNNEL_ENCAP_FLAG_*` flags
+    EncapFlags(u16),
+    EncapSourcePort(u16),
+    EncapDestinationPort(u16),
+    CollectMetadata,
+    FwMark(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for LinkInfoIpTun {
+    #[cfg_attr(nightly, rustfmt::skip)]
+    fn value_len(&self) -> usize {
+        use self::LinkInfoIpTun::*;
+        match self {
+            Unspec(ref bytes)
+                | Local(ref bytes)
+                | Remote(ref bytes)
+                | SixRdPrefix(ref bytes)
+                | SixRdRelayPrefix(ref bytes)
+                => bytes.len(),
+            CollectMetadata => 0,
+            Ttl(_)
+                | Tos(_)
+                | EncapLimit(_)
+                | Protocol(_)
+                | PathMtuDiscovery(_)
+                => size_of::<u8>(),
+            SitFlags(_)
+                | SixRdPrefixLen(_)
+                | SixRdRelayPrefixLen(_)
+                | EncapType(_)
+                | EncapFlags(_)
+                | EncapSourcePort(_)
+                | EncapDestinationPort(_)
+                => size_of::<u16>(),
+            Link(_)
+                | FlowInfo(_)
+                | Ip6TnlFlags(_)
+                | FwMark(_)
+                => size_of::<u32>(),
+            Other(ref nla) => nla.value_len(),
+        }
+    }
+
+    #[cfg_attr(nightly, rustfmt::skip)]
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::LinkInfoIpTun::*;
+        match self {
+            Unspec(ref bytes)
+                | Local(ref bytes)
+                | Remote(ref bytes)
+                | SixRdPrefix(ref bytes)
+                | SixRdRelayPrefix(ref bytes)
+                => buffer.copy_from_slice(bytes),
+            CollectMetadata => {}
+            Ttl(ref value)
+                | Tos(ref value)
+                | EncapLimit(ref value)
+                | Protocol(ref value)
+                | PathMtuDiscovery(ref value)
+                => buffer[0] = *value,
+            SitFlags(ref value)
+                | SixRdPrefixLen(ref value)
+                | SixRdRelayPrefixLen(ref value)
+                | EncapType(ref value)
+                | EncapFlags(ref value)
+                => NativeEndian::write_u16(buffer, *value),
+            // these are in network byte order
+            EncapSourcePort(ref value)
+                | EncapDestinationPort(ref value)
+                => BigEndian::write_u16(buffer, *value),
+            Link(ref value)
+                | Ip6TnlFlags(ref value)
+                | FwMark(ref value)
+                => NativeEndian::write_u32(buffer, *value),
+            // this is in network byte order
+            FlowInfo(ref value) => BigEndian::write_u32(buffer, *value),
+            Other(ref nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::LinkInfoIpTun::*;
+        match self {
+            Unspec(_) => IFLA_IPTUN_UNSPEC,
+            Link(_) => IFLA_IPTUN_LINK,
+            Local(_) => IFLA_IPTUN_LOCAL,
+            Remote(_) => IFLA_IPTUN_REMOTE,
+            Ttl(_) => IFLA_IPTUN_TTL,
+            Tos(_) => IFLA_IPTUN_TOS,
+            EncapLimit(_) => IFLA_IPTUN_ENCAP_LIMIT,
+            FlowInfo(_) => IFLA_IPTUN_FLOWINFO,
+            SitFlags(_) | Ip6TnlFlags(_) => IFLA_IPTUN_FLAGS,
+            Protocol(_) => IFLA_IPTUN_PROTO,
+            PathMtuDiscovery(_) => IFLA_IPTUN_PMTUDISC,
+            SixRdPrefix(_) => IFLA_IPTUN_6RD_PREFIX,
+            SixRdRelayPrefix(_) => IFLA_IPTUN_6RD_RELAY_PREFIX,
+            SixRdPrefixLen(_) => IFLA_IPTUN_6RD_PREFIXLEN,
+            SixRdRelayPrefixLen(_) => IFLA_IPTUN_6RD_RELAY_PREFIXLEN,
+            EncapType(_) => IFLA_IPTUN_ENCAP_TYPE,
+            EncapFlags(_) => IFLA_IPTUN_ENCAP_FLAGS,
+            EncapSourcePort(_) => IFLA_IPTUN_ENCAP_SPORT,
+            EncapDestinationPort(_) => IFLA_IPTUN_ENCAP_DPORT,
+            CollectMetadata => IFLA_IPTUN_COLLECT_METADATA,
+            FwMark(_) => IFLA_IPTUN_FWMARK,
+            Other(ref nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<LinkInfoIpTun> for NlaBuffer<&'buffer T> {
+    fn parse(&self) -> Result<LinkInfoIpTun> {
+        use self::LinkInfoIpTun::*;
+        let payload = self.value();
+        Ok(match self.kind() {
+            IFLA_IPTUN_UNSPEC => Unspec(payload.to_vec()),
+            IFLA_IPTUN_LINK => Link(parse_u32(payload)?),
+            IFLA_IPTUN_LOCAL => Local(payload.to_vec()),
+            IFLA_IPTUN_REMOTE => Remote(payload.to_vec()),
+            IFLA_IPTUN_TTL => Ttl(parse_u8(payload)?),
+            IFLA_IPTUN_TOS => Tos(parse_u8(payload)?),
+            IFLA_IPTUN_ENCAP_LIMIT => EncapLimit(parse_u8(payload)?),
+            IFLA_IPTUN_FLOWINFO => FlowInfo(parse_u32_be(payload)?),
+            // sit and ip6tnl use different sizes for this attribute
+            IFLA_IPTUN_FLAGS => match payload.len() {
+                2 => SitFlags(parse_u16(payload)?),
+                _ => Ip6TnlFlags(parse_u32(payload)?),
+            },
+            IFLA_IPTUN_PROTO => Protocol(parse_u8(payload)?),
+            IFLA_IPTUN_PMTUDISC => PathMtuDiscovery(parse_u8(payload)?),
+            IFLA_IPTUN_6RD_PREFIX => SixRdPrefix(payload.to_vec()),
+            IFLA_IPTUN_6RD_RELAY_PREFIX => SixRdRelayPrefix(payload.to_vec()),
+            IFLA_IPTUN_6RD_PREFIXLEN => SixRdPrefixLen(parse_u16(payload)?),
+            IFLA_IPTUN_6RD_RELAY_PREFIXLEN => SixRdRelayPrefixLen(parse_u16(payload)?),
+            IFLA_IPTUN_ENCAP_TYPE => EncapType(parse_u16(payload)?),
+            IFLA_IPTUN_ENCAP_FLAGS => EncapFlags(parse_u16(payload)?),
+            IFLA_IPTUN_ENCAP_SPORT => EncapSourcePort(parse_u16_be(payload)?),
+            IFLA_IPTUN_ENCAP_DPORT => EncapDestinationPort(parse_u16_be(payload)?),
+            IFLA_IPTUN_COLLECT_METADATA => CollectMetadata,
+            IFLA_IPTUN_FWMARK => FwMark(parse_u32(payload)?),
+            _ => Other(<Self as Parseable<DefaultNla>>::parse(self)?),
+        })
+    }
+}
+
+// https://elixir.bootlin.com/linux/latest/source/net/ipv4/ip_vti.c#L570
+//
+// These attributes are used by vti and vti6 links.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde_support", derive(Serialize, Deserialize))]
+pub enum LinkInfoVti {
+    Unspec(Vec<u8>),
+    Link(u32),
+    IKey(u32),
+    OKey(u32),
+    /// Local address. This is an IPv4 address for vti links, and an IPv6 address for vti6 links.
+    Local(Vec<u8>),
+    /// Remote address. This is an IPv4 address for vti links, and an IPv6 address for vti6 links.
+    Remote(Vec<u8>),
+    FwMark(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for LinkInfoVti {
+    #[cfg_attr(nightly, rustfmt::skip)]
+    fn value_len(&self) -> usize {
+        use self::LinkInfoVti::*;
+        match self {
+            Unspec(ref bytes)
+                | Local(ref bytes)
+                | Remote(ref bytes)
+                => bytes.len(),
+            Link(_)
+                | IKey(_)
+                | OKey(_)
+                | FwMark(_)
+                => size_of::<u32>(),
+            Other(ref nla) => nla.value_len(),
+        }
+    }
+
+    #[cfg_attr(nightly, rustfmt::skip)]
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::LinkInfoVti::*;
+        match self {
+            Unspec(ref bytes)
+                | Local(ref bytes)
+                | Remote(ref bytes)
+                => buffer.copy_from_slice(bytes),
+            Link(ref value)
+                | FwMark(ref value)
+                => NativeEndian::write_u32(buffer, *value),
+            // these are in network byte order
+            IKey(ref value)
+                | OKey(ref value)
+                => BigEndian::write_u32(buffer, *value),
+            Other(ref nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::LinkInfoVti::*;
+        match self {
+            Unspec(_) => IFLA_VTI_UNSPEC,
+            Link(_) => IFLA_VTI_LINK,
+            IKey(_) => IFLA_VTI_IKEY,
+            OKey(_) => IFLA_VTI_OKEY,
+            Local(_) => IFLA_VTI_LOCAL,
+            Remote(_) => IFLA_VTI_REMOTE,
+            FwMark(_) => IFLA_VTI_FWMARK,
+            Other(ref nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<LinkInfoVti> for NlaBuffer<&'buffer T> {
+    fn parse(&self) -> Result<LinkInfoVti> {
+        use self::LinkInfoVti::*;
+        let payload = self.value();
+        Ok(match self.kind() {
+            IFLA_VTI_UNSPEC => Unspec(payload.to_vec()),
+            IFLA_VTI_LINK => Link(parse_u32(payload)?),
+            IFLA_VTI_IKEY => IKey(parse_u32_be(payload)?),
+            IFLA_VTI_OKEY => OKey(parse_u32_be(payload)?),
+            IFLA_VTI_LOCAL => Local(payload.to_vec()),
+            IFLA_VTI_REMOTE => Remote(payload.to_vec()),
+            IFLA_VTI_FWMARK => FwMark(parse_u32(payload)?),
+            _ => Other(<Self as Parseable<DefaultNla>>::parse(self)?),
+        })
+    }
+}
+
+// https://elixir.bootlin.com/linux/latest/source/drivers/net/vrf.c#L1426
 #[derive(Debug, PartialEq, Eq, Clone)]
-pub enum LinkInfoVlan {
+#[cfg_attr(feature = "serde_support", derive(Serialize, Deserialize))]
+pub enum LinkInfoVrf {
     Unspec(Vec<u8>),
-    Id(u16),
-    Flags((u32, u32)),
-    EgressQos(Vec<u8>),
-    IngressQos(Vec<u8>),
-    Protocol(u16),
+    /// Routing table associated with the VRF device
+    TableId(u32),
+    Other(DefaultNla),
 }
 
-impl Nla for LinkInfoVlan {
-    #[cfg_attr(nightly, rustfmt::skip)]
+impl Nla for LinkInfoVrf {
     fn value_len(&self) -> usize {
-        use self::LinkInfoVlan::*;
+        use self::LinkInfoVrf::*;
         match self {
-            Id(_) | Protocol(_) => size_of::<u16>(),
-            Flags(_) => size_of::<u32>() * 2,
-            Unspec(bytes)
-                | EgressQos(bytes)
-                | IngressQos(bytes)
-                => bytes.len(),
+            Unspec(ref bytes) => bytes.len(),
+            TableId(_) => size_of::<u32>(),
+            Other(ref nla) => nla.value_len(),
         }
     }
 
-    #[cfg_attr(nightly, rustfmt::skip)]
     fn emit_value(&self, buffer: &mut [u8]) {
-        use self::LinkInfoVlan::*;
+        use self::LinkInfoVrf::*;
         match self {
-            Unspec(ref bytes)
-                | EgressQos(ref bytes)
-                | IngressQos(ref bytes)
-                => buffer.copy_from_slice(bytes),
+            Unspec(ref bytes) => buffer.copy_from_slice(bytes),
+            TableId(ref value) => NativeEndian::write_u32(buffer, *value),
+            Other(ref nla) => nla.emit_value(buffer),
+        }
+    }
 
-            Id(ref value)
-                | Protocol(ref value)
-                => NativeEndian::write_u16(buffer, *value),
+    fn kind(&self) -> u16 {
+        use self::LinkInfoVrf::*;
+        match self {
+            Unspec(_) => IFLA_VRF_UNSPEC,
+            TableId(_) => IFLA_VRF_TABLE,
+            Other(ref nla) => nla.kind(),
+        }
+    }
+}
 
-            Flags(ref flags) => {
-                NativeEndian::write_u32(buffer, flags.0);
-                NativeEndian::write_u32(buffer, flags.1)
-            }
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<LinkInfoVrf> for NlaBuffer<&'buffer T> {
+    fn parse(&self) -> Result<LinkInfoVrf> {
+        use self::LinkInfoVrf::*;
+        let payload = self.value();
+        Ok(match self.kind() {
+            IFLA_VRF_UNSPEC => Unspec(payload.to_vec()),
+            IFLA_VRF_TABLE => TableId(parse_u32(payload)?),
+            _ => Other(<Self as Parseable<DefaultNla>>::parse(self)?),
+        })
+    }
+}
+
+// https://elixir.bootlin.com/linux/latest/source/drivers/net/vrf.c#L1455
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum LinkInfoVrfPort {
+    Unspec(Vec<u8>),
+    /// Routing table of the VRF device the link is enslaved to
+    TableId(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for LinkInfoVrfPort {
+    fn value_len(&self) -> usize {
+        use self::LinkInfoVrfPort::*;
+        match self {
+            Unspec(ref bytes) => bytes.len(),
+            TableId(_) => size_of::<u32>(),
+            Other(ref nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::LinkInfoVrfPort::*;
+        match self {
+            Unspec(ref bytes) => buffer.copy_from_slice(bytes),
+            TableId(ref value) => NativeEndian::write_u32(buffer, *value),
+            Other(ref nla) => nla.emit_value(buffer),
         }
     }
 
     fn kind(&self) -> u16 {
-        use self::LinkInfoVlan::*;
+        use self::LinkInfoVrfPort::*;
         match self {
-            Unspec(_) => IFLA_VLAN_UNSPEC,
-            Id(_) => IFLA_VLAN_ID,
-            Flags(_) => IFLA_VLAN_FLAGS,
-            EgressQos(_) => IFLA_VLAN_EGRESS_QOS,
-            IngressQos(_) => IFLA_VLAN_INGRESS_QOS,
-            Protocol(_) => IFLA_VLAN_PROTOCOL,
+            Unspec(_) => IFLA_VRF_PORT_UNSPEC,
+            TableId(_) => IFLA_VRF_PORT_TABLE,
+            Other(ref nla) => nla.kind(),
         }
     }
 }
 
-impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<LinkInfoVlan> for NlaBuffer<&'buffer T> {
-    fn parse(&self) -> Result<LinkInfoVlan> {
-        use self::LinkInfoVlan::*;
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<LinkInfoVrfPort> for NlaBuffer<&'buffer T> {
+    fn parse(&self) -> Result<LinkInfoVrfPort> {
+        use self::LinkInfoVrfPort::*;
         let payload = self.value();
         Ok(match self.kind() {
-            IFLA_VLAN_UNSPEC => Unspec(payload.to_vec()),
-            IFLA_VLAN_ID => Id(parse_u16(payload)?),
-            IFLA_VLAN_FLAGS => {
-                let u32len = size_of::<u32>();
-                if payload.len() != (u32len * 2) {
-                    return Err(Error::MalformedNlaValue);
-                }
-                let flags = parse_u32(&payload[0..u32len])?;
-                let mask = parse_u32(&payload[u32len..])?;
-                Flags((flags, mask))
-            }
-            IFLA_VLAN_EGRESS_QOS => EgressQos(payload.to_vec()),
-            IFLA_VLAN_INGRESS_QOS => IngressQos(payload.to_vec()),
-            IFLA_VLAN_PROTOCOL => Protocol(parse_u16(payload)?),
-            _ => return Err(Error::MalformedNlaValue),
+            IFLA_VRF_PORT_UNSPEC => Unspec(payload.to_vec()),
+            IFLA_VRF_PORT_TABLE => TableId(parse_u32(payload)?),
+            _ => Other(<Self as Parseable<DefaultNla>>::parse(self)?),
         })
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde_support", derive(Serialize, Deserialize))]
 pub enum LinkInfoBridge {
     Unspec(Vec<u8>),
     GroupAddr([u8; 6]),
@@ -508,6 +1435,13 @@ pub enum LinkInfoBridge {
     MulticastStatsEnabled(u8),
     MulticastIgmpVersion(u8),
     MulticastMldVersion(u8),
+    VlanStatsPerPort(u8),
+    /// Boolean options, as a `(value, mask)` tuple. Bits are indexed by the `BR_BOOLOPT_*`
+    /// constants.
+    MultiBoolOpt((u32, u32)),
+    MulticastQuerierState(Vec<u8>),
+    FdbNLearned(u32),
+    FdbMaxLearned(u32),
     Other(DefaultNla),
 }
 
@@ -519,6 +1453,7 @@ impl Nla for LinkInfoBridge {
             Unspec(bytes)
                 | FdbFlush(bytes)
                 | Pad(bytes)
+                | MulticastQuerierState(bytes)
                 => bytes.len(),
             HelloTimer(_)
                 | TcnTimer(_)
@@ -541,6 +1476,8 @@ impl Nla for LinkInfoBridge {
                 | MulticastLastMemberCount(_)
                 | MulticastStartupQueryCount(_)
                 | RootPathCost(_)
+                | FdbNLearned(_)
+                | FdbMaxLearned(_)
                 => size_of::<u32>(),
             Priority(_)
                 | VlanProtocol(_)
@@ -555,6 +1492,8 @@ impl Nla for LinkInfoBridge {
 
             GroupAddr(_) => size_of::<[u8; 6]>(),
 
+            MultiBoolOpt(_) => size_of::<u32>() * 2,
+
             VlanFiltering(_)
                 | TopologyChange(_)
                 | TopologyChangeDetected(_)
@@ -569,6 +1508,7 @@ impl Nla for LinkInfoBridge {
                 | MulticastStatsEnabled(_)
                 | MulticastIgmpVersion(_)
                 | MulticastMldVersion(_)
+                | VlanStatsPerPort(_)
                 => size_of::<u8>(),
             Other(nla)
                 => nla.value_len(),
@@ -582,6 +1522,7 @@ impl Nla for LinkInfoBridge {
             Unspec(ref bytes)
                 | FdbFlush(ref bytes)
                 | Pad(ref bytes)
+                | MulticastQuerierState(ref bytes)
                 => buffer.copy_from_slice(bytes),
 
             HelloTimer(ref value)
@@ -606,6 +1547,8 @@ impl Nla for LinkInfoBridge {
                 | MulticastLastMemberCount(ref value)
                 | MulticastStartupQueryCount(ref value)
                 | RootPathCost(ref value)
+                | FdbNLearned(ref value)
+                | FdbMaxLearned(ref value)
                 => NativeEndian::write_u32(buffer, *value),
 
             Priority(ref value)
@@ -624,6 +1567,11 @@ impl Nla for LinkInfoBridge {
 
             GroupAddr(ref value) => buffer.copy_from_slice(&value[..]),
 
+            MultiBoolOpt((ref value, ref mask)) => {
+                NativeEndian::write_u32(&mut buffer[..4], *value);
+                NativeEndian::write_u32(&mut buffer[4..], *mask);
+            }
+
             VlanFiltering(ref value)
                 | TopologyChange(ref value)
                 | TopologyChangeDetected(ref value)
@@ -638,6 +1586,7 @@ impl Nla for LinkInfoBridge {
                 | MulticastStatsEnabled(ref value)
                 | MulticastIgmpVersion(ref value)
                 | MulticastMldVersion(ref value)
+                | VlanStatsPerPort(ref value)
                 => buffer[0] = *value,
 
             Other(nla)
@@ -693,9 +1642,24 @@ impl Nla for LinkInfoBridge {
             MulticastStatsEnabled(_) => IFLA_BR_MCAST_STATS_ENABLED,
             MulticastIgmpVersion(_) => IFLA_BR_MCAST_IGMP_VERSION,
             MulticastMldVersion(_) => IFLA_BR_MCAST_MLD_VERSION,
+            VlanStatsPerPort(_) => IFLA_BR_VLAN_STATS_PER_PORT,
+            MultiBoolOpt(_) => IFLA_BR_MULTI_BOOLOPT,
+            MulticastQuerierState(_) => IFLA_BR_MCAST_QUERIER_STATE,
+            FdbNLearned(_) => IFLA_BR_FDB_N_LEARNED,
+            FdbMaxLearned(_) => IFLA_BR_FDB_MAX_LEARNED,
             Other(nla) => nla.kind(),
         }
     }
+
+    fn is_nested(&self) -> bool {
+        // the kernel sends the querier state as a list of BRIDGE_QUERIER_* attributes, flagged as
+        // nested
+        if let LinkInfoBridge::MulticastQuerierState(_) = *self {
+            true
+        } else {
+            false
+        }
+    }
 }
 
 impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<LinkInfoBridge> for NlaBuffer<&'buffer T> {
@@ -764,6 +1728,19 @@ impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<LinkInfoBridge> for NlaBuffer<&
             IFLA_BR_MCAST_STATS_ENABLED => MulticastStatsEnabled(parse_u8(payload)?),
             IFLA_BR_MCAST_IGMP_VERSION => MulticastIgmpVersion(parse_u8(payload)?),
             IFLA_BR_MCAST_MLD_VERSION => MulticastMldVersion(parse_u8(payload)?),
+            IFLA_BR_VLAN_STATS_PER_PORT => VlanStatsPerPort(parse_u8(payload)?),
+            IFLA_BR_MULTI_BOOLOPT => {
+                let u32len = size_of::<u32>();
+                if payload.len() != (u32len * 2) {
+                    return Err(Error::MalformedNlaValue);
+                }
+                let value = parse_u32(&payload[0..u32len])?;
+                let mask = parse_u32(&payload[u32len..])?;
+                MultiBoolOpt((value, mask))
+            }
+            IFLA_BR_MCAST_QUERIER_STATE => MulticastQuerierState(payload.to_vec()),
+            IFLA_BR_FDB_N_LEARNED => FdbNLearned(parse_u32(payload)?),
+            IFLA_BR_FDB_MAX_LEARNED => FdbMaxLearned(parse_u32(payload)?),
             _ => Other(<Self as Parseable<DefaultNla>>::parse(self)?),
         })
     }
@@ -1068,4 +2045,344 @@ mod tests {
         nlas.as_slice().emit(&mut vec);
         assert_eq!(&vec[..], &BRIDGE[..]);
     }
+
+    #[cfg_attr(nightly, rustfmt::skip)]
+    static BRIDGE_OPTIONS: [u8; 96] = [
+        0x0b, 0x00, // L = 11
+        0x01, 0x00, // T = 1 (IFLA_INFO_KIND)
+        0x62, 0x72, 0x69, 0x64, 0x67, 0x65, 0x00, // V = "bridge"
+        0x00, // padding
+
+        0x54, 0x00, // L = 84
+        0x02, 0x00, // T = 2 (IFLA_INFO_DATA)
+
+            0x08, 0x00, // L = 8
+            0x04, 0x00, // T = 4 (IFLA_BR_AGEING_TIME)
+            0x30, 0x75, 0x00, 0x00, // V = 30000
+
+            0x08, 0x00, // L = 8
+            0x05, 0x00, // T = 5 (IFLA_BR_STP_STATE)
+            0x01, 0x00, 0x00, 0x00, // V = 1
+
+            0x05, 0x00, // L = 5
+            0x07, 0x00, // T = 7 (IFLA_BR_VLAN_FILTERING)
+            0x01, // V = 1
+            0x00, 0x00, 0x00, // padding
+
+            0x06, 0x00, // L = 6
+            0x27, 0x00, // T = 39 (IFLA_BR_VLAN_DEFAULT_PVID)
+            0x01, 0x00, // V = 1
+            0x00, 0x00, // padding
+
+            0x05, 0x00, // L = 5
+            0x2d, 0x00, // T = 45 (IFLA_BR_VLAN_STATS_PER_PORT)
+            0x01, // V = 1
+            0x00, 0x00, 0x00, // padding
+
+            0x0c, 0x00, // L = 12
+            0x2e, 0x00, // T = 46 (IFLA_BR_MULTI_BOOLOPT)
+            0x01, 0x00, 0x00, 0x00, // V = 1 << BR_BOOLOPT_NO_LL_LEARN
+            0x01, 0x00, 0x00, 0x00, // mask = 1 << BR_BOOLOPT_NO_LL_LEARN
+
+            0x0c, 0x00, // L = 12
+            0x2f, 0x80, // T = 47 (IFLA_BR_MCAST_QUERIER_STATE) | NLA_F_NESTED
+                0x08, 0x00, // L = 8
+                0x01, 0x00, // T = 1 (BRIDGE_QUERIER_IP_ADDRESS)
+                0x0a, 0x00, 0x00, 0x01, // V = 10.0.0.1
+
+            0x08, 0x00, // L = 8
+            0x30, 0x00, // T = 48 (IFLA_BR_FDB_N_LEARNED)
+            0x03, 0x00, 0x00, 0x00, // V = 3
+
+            0x08, 0x00, // L = 8
+            0x31, 0x00, // T = 49 (IFLA_BR_FDB_MAX_LEARNED)
+            0x00, 0x01, 0x00, 0x00, // V = 256
+    ];
+
+    lazy_static! {
+        static ref BRIDGE_OPTIONS_INFO: Vec<LinkInfoBridge> = vec![
+            LinkInfoBridge::AgeingTime(30000),
+            LinkInfoBridge::StpState(1),
+            LinkInfoBridge::VlanFiltering(1),
+            LinkInfoBridge::VlanDefaultPvid(1),
+            LinkInfoBridge::VlanStatsPerPort(1),
+            LinkInfoBridge::MultiBoolOpt((1, 1)),
+            LinkInfoBridge::MulticastQuerierState(vec![
+                0x08, 0x00, 0x01, 0x00, 0x0a, 0x00, 0x00, 0x01,
+            ]),
+            LinkInfoBridge::FdbNLearned(3),
+            LinkInfoBridge::FdbMaxLearned(256),
+        ];
+    }
+
+    #[test]
+    fn parse_info_bridge_options() {
+        let nla = NlaBuffer::new_checked(&BRIDGE_OPTIONS[..]).unwrap();
+        let parsed = <NlaBuffer<_> as Parseable<Vec<LinkInfo>>>::parse(&nla).unwrap();
+        assert_eq!(
+            parsed,
+            vec![
+                LinkInfo::Kind(LinkInfoKind::Bridge),
+                LinkInfo::Data(LinkInfoData::Bridge(BRIDGE_OPTIONS_INFO.clone())),
+            ]
+        );
+    }
+
+    #[test]
+    fn emit_info_bridge_options() {
+        let nlas = vec![
+            LinkInfo::Kind(LinkInfoKind::Bridge),
+            LinkInfo::Data(LinkInfoData::Bridge(BRIDGE_OPTIONS_INFO.clone())),
+        ];
+
+        assert_eq!(nlas.as_slice().buffer_len(), 96);
+
+        let mut vec = vec![0xff; 96];
+        nlas.as_slice().emit(&mut vec);
+        assert_eq!(&vec[..], &BRIDGE_OPTIONS[..]);
+    }
+
+    #[test]
+    fn parse_malformed_multi_boolopt() {
+        let bytes = [0x08, 0x00, 0x2e, 0x00, 0x01, 0x00, 0x00, 0x00];
+        let nla = NlaBuffer::new_checked(&bytes[..]).unwrap();
+        assert!(<NlaBuffer<_> as Parseable<LinkInfoBridge>>::parse(&nla).is_err());
+    }
+
+    #[cfg_attr(nightly, rustfmt::skip)]
+    static MACVLAN: [u8; 56] = [
+        0x0c, 0x00, // L = 12
+        0x01, 0x00, // T = 1 (IFLA_INFO_KIND)
+        0x6d, 0x61, 0x63, 0x76, 0x6c, 0x61, 0x6e, 0x00, // V = "macvlan"
+
+        0x2c, 0x00, // L = 44
+        0x02, 0x00, // T = 2 (IFLA_INFO_DATA)
+
+            0x08, 0x00, // L = 8
+            0x01, 0x00, // T = 1 (IFLA_MACVLAN_MODE)
+            0x10, 0x00, 0x00, 0x00, // V = 16 (MACVLAN_MODE_SOURCE)
+
+            0x06, 0x00, // L = 6
+            0x02, 0x00, // T = 2 (IFLA_MACVLAN_FLAGS)
+            0x00, 0x00, // V = 0
+            0x00, 0x00, // padding
+
+            0x08, 0x00, // L = 8
+            0x06, 0x00, // T = 6 (IFLA_MACVLAN_MACADDR_COUNT)
+            0x01, 0x00, 0x00, 0x00, // V = 1
+
+            0x10, 0x00, // L = 16
+            0x05, 0x00, // T = 5 (IFLA_MACVLAN_MACADDR_DATA)
+                0x0a, 0x00, // L = 10
+                0x04, 0x00, // T = 4 (IFLA_MACVLAN_MACADDR)
+                0x52, 0x54, 0x00, 0x12, 0x34, 0x56, // V = 52:54:00:12:34:56
+                0x00, 0x00, // padding
+    ];
+
+    lazy_static! {
+        static ref MACVLAN_INFO: Vec<LinkInfoMacVlan> = vec![
+            LinkInfoMacVlan::Mode(MACVLAN_MODE_SOURCE),
+            LinkInfoMacVlan::Flags(0),
+            LinkInfoMacVlan::MacAddrCount(1),
+            LinkInfoMacVlan::MacAddrData(vec![LinkInfoMacVlan::MacAddr([
+                0x52, 0x54, 0x00, 0x12, 0x34, 0x56,
+            ])]),
+        ];
+    }
+
+    #[test]
+    fn parse_info_macvlan() {
+        let nla = NlaBuffer::new_checked(&MACVLAN[..]).unwrap();
+        let parsed = <NlaBuffer<_> as Parseable<Vec<LinkInfo>>>::parse(&nla).unwrap();
+        assert_eq!(
+            parsed,
+            vec![
+                LinkInfo::Kind(LinkInfoKind::MacVlan),
+                LinkInfo::Data(LinkInfoData::MacVlan(MACVLAN_INFO.clone())),
+            ]
+        );
+    }
+
+    #[test]
+    fn emit_info_macvlan() {
+        let nlas = vec![
+            LinkInfo::Kind(LinkInfoKind::MacVlan),
+            LinkInfo::Data(LinkInfoData::MacVlan(MACVLAN_INFO.clone())),
+        ];
+
+        assert_eq!(nlas.as_slice().buffer_len(), 56);
+
+        let mut vec = vec![0xff; 56];
+        nlas.as_slice().emit(&mut vec);
+        assert_eq!(&vec[..], &MACVLAN[..]);
+    }
+
+    #[cfg_attr(nightly, rustfmt::skip)]
+    static GRE: [u8; 52] = [
+        0x08, 0x00, // L = 8
+        0x01, 0x00, // T = 1 (IFLA_INFO_KIND)
+        0x67, 0x72, 0x65, 0x00, // V = "gre"
+
+        0x2c, 0x00, // L = 44
+        0x02, 0x00, // T = 2 (IFLA_INFO_DATA)
+
+            0x08, 0x00, // L = 8
+            0x06, 0x00, // T = 6 (IFLA_GRE_LOCAL)
+            0x0a, 0x00, 0x00, 0x01, // V = 10.0.0.1
+
+            0x08, 0x00, // L = 8
+            0x07, 0x00, // T = 7 (IFLA_GRE_REMOTE)
+            0x0a, 0x00, 0x00, 0x02, // V = 10.0.0.2
+
+            0x06, 0x00, // L = 6
+            0x02, 0x00, // T = 2 (IFLA_GRE_IFLAGS)
+            0x20, 0x00, // V = GRE_KEY (network byte order)
+            0x00, 0x00, // padding
+
+            0x08, 0x00, // L = 8
+            0x04, 0x00, // T = 4 (IFLA_GRE_IKEY)
+            0x00, 0x00, 0x00, 0x2a, // V = 42 (network byte order)
+
+            0x05, 0x00, // L = 5
+            0x08, 0x00, // T = 8 (IFLA_GRE_TTL)
+            0x40, // V = 64
+            0x00, 0x00, 0x00, // padding
+    ];
+
+    lazy_static! {
+        static ref GRE_INFO: Vec<LinkInfoGre> = vec![
+            LinkInfoGre::Local(vec![10, 0, 0, 1]),
+            LinkInfoGre::Remote(vec![10, 0, 0, 2]),
+            LinkInfoGre::IFlags(GRE_KEY),
+            LinkInfoGre::IKey(42),
+            LinkInfoGre::Ttl(64),
+        ];
+    }
+
+    #[test]
+    fn parse_info_gre() {
+        let nla = NlaBuffer::new_checked(&GRE[..]).unwrap();
+        let parsed = <NlaBuffer<_> as Parseable<Vec<LinkInfo>>>::parse(&nla).unwrap();
+        assert_eq!(
+            parsed,
+            vec![
+                LinkInfo::Kind(LinkInfoKind::GreTun),
+                LinkInfo::Data(LinkInfoData::GreTun(GRE_INFO.clone())),
+            ]
+        );
+    }
+
+    #[test]
+    fn emit_info_gre() {
+        let nlas = vec![
+            LinkInfo::Kind(LinkInfoKind::GreTun),
+            LinkInfo::Data(LinkInfoData::GreTun(GRE_INFO.clone())),
+        ];
+
+        assert_eq!(nlas.as_slice().buffer_len(), 52);
+
+        let mut vec = vec![0xff; 52];
+        nlas.as_slice().emit(&mut vec);
+        assert_eq!(&vec[..], &GRE[..]);
+    }
+
+    #[cfg_attr(nightly, rustfmt::skip)]
+    static VETH: [u8; 48] = [
+        0x09, 0x00, // L = 9
+        0x01, 0x00, // T = 1 (IFLA_INFO_KIND)
+        0x76, 0x65, 0x74, 0x68, 0x00, // V = "veth"
+        0x00, 0x00, 0x00, // padding
+
+        0x24, 0x00, // L = 36
+        0x02, 0x00, // T = 2 (IFLA_INFO_DATA)
+
+            0x20, 0x00, // L = 32
+            0x01, 0x00, // T = 1 (VETH_INFO_PEER)
+
+                // struct ifinfomsg of the peer
+                0x00, // address family
+                0x00, // padding
+                0x01, 0x00, // link layer type (ARPHRD_ETHER)
+                0x00, 0x00, 0x00, 0x00, // index
+                0x00, 0x00, 0x00, 0x00, // flags
+                0x00, 0x00, 0x00, 0x00, // change mask
+
+                0x09, 0x00, // L = 9
+                0x03, 0x00, // T = 3 (IFLA_IFNAME)
+                0x76, 0x65, 0x74, 0x68, 0x00, // V = "veth"
+                0x00, 0x00, 0x00, // padding
+    ];
+
+    lazy_static! {
+        static ref VETH_PEER: LinkMessage =
+            LinkMessage::from_parts(LinkHeader::new(), vec![LinkNla::IfName("veth".into())]);
+    }
+
+    #[test]
+    fn parse_info_veth() {
+        let nla = NlaBuffer::new_checked(&VETH[..]).unwrap();
+        let parsed = <NlaBuffer<_> as Parseable<Vec<LinkInfo>>>::parse(&nla).unwrap();
+        assert_eq!(
+            parsed,
+            vec![
+                LinkInfo::Kind(LinkInfoKind::Veth),
+                LinkInfo::Data(LinkInfoData::Veth(VETH_PEER.clone())),
+            ]
+        );
+    }
+
+    #[test]
+    fn emit_info_veth() {
+        let nlas = vec![
+            LinkInfo::Kind(LinkInfoKind::Veth),
+            LinkInfo::Data(LinkInfoData::Veth(VETH_PEER.clone())),
+        ];
+
+        assert_eq!(nlas.as_slice().buffer_len(), 48);
+
+        let mut vec = vec![0; 48];
+        nlas.as_slice().emit(&mut vec);
+        assert_eq!(&vec[..], &VETH[..]);
+    }
+
+    #[cfg_attr(nightly, rustfmt::skip)]
+    static VRF_SLAVE: [u8; 20] = [
+        0x08, 0x00, // L = 8
+        0x04, 0x00, // T = 4 (IFLA_INFO_SLAVE_KIND)
+        0x76, 0x72, 0x66, 0x00, // V = "vrf"
+
+        0x0c, 0x00, // L = 12
+        0x05, 0x00, // T = 5 (IFLA_INFO_SLAVE_DATA)
+
+            0x08, 0x00, // L = 8
+            0x01, 0x00, // T = 1 (IFLA_VRF_PORT_TABLE)
+            0x0a, 0x00, 0x00, 0x00, // V = 10
+    ];
+
+    #[test]
+    fn parse_info_vrf_slave() {
+        let nla = NlaBuffer::new_checked(&VRF_SLAVE[..]).unwrap();
+        let parsed = <NlaBuffer<_> as Parseable<Vec<LinkInfo>>>::parse(&nla).unwrap();
+        assert_eq!(
+            parsed,
+            vec![
+                LinkInfo::SlaveKind(LinkInfoKind::Vrf),
+                LinkInfo::SlaveData(LinkInfoSlaveData::Vrf(vec![LinkInfoVrfPort::TableId(10)])),
+            ]
+        );
+    }
+
+    #[test]
+    fn emit_info_vrf_slave() {
+        let nlas = vec![
+            LinkInfo::SlaveKind(LinkInfoKind::Vrf),
+            LinkInfo::SlaveData(LinkInfoSlaveData::Vrf(vec![LinkInfoVrfPort::TableId(10)])),
+        ];
+
+        assert_eq!(nlas.as_slice().buffer_len(), 20);
+
+        let mut vec = vec![0xff; 20];
+        nlas.as_slice().emit(&mut vec);
+        assert_eq!(&vec[..], &VRF_SLAVE[..]);
+    }
 }
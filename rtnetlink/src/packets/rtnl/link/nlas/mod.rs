@@ -10,6 +10,18 @@ pub use self::af_spec::*;
 mod link_infos;
 pub use self::link_infos::*;
 
+mod bridge;
+pub use self::bridge::*;
+
+mod bridge_port;
+pub use self::bridge_port::*;
+
+mod vf;
+pub use self::vf::*;
+
+mod xdp;
+pub use self::xdp::*;
+
 #[cfg(test)]
 mod tests;
 
@@ -19,7 +31,7 @@ use byteorder::{ByteOrder, NativeEndian};
 
 use constants::*;
 use utils::{parse_i32, parse_string, parse_u32, parse_u8};
-use {DefaultNla, Emitable, NativeNla, Nla, NlaBuffer, Parseable, Result};
+use {DefaultNla, Emitable, NativeNla, Nla, NlaBuffer, NlasIterator, Parseable, Result};
 
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub enum LinkNla {
@@ -28,13 +40,9 @@ pub enum LinkNla {
     Cost(Vec<u8>),
     Priority(Vec<u8>),
     Weight(Vec<u8>),
-    VfInfoList(Vec<u8>),
-    VfPorts(Vec<u8>),
-    PortSelf(Vec<u8>),
     PhysPortId(Vec<u8>),
     PhysSwitchId(Vec<u8>),
     Pad(Vec<u8>),
-    Xdp(Vec<u8>),
     Event(Vec<u8>),
     NewNetnsId(Vec<u8>),
     IfNetnsId(Vec<u8>),
@@ -44,6 +52,16 @@ pub enum LinkNla {
     LinkInfo(Vec<LinkInfo>),
     Wireless(Vec<u8>),
     ProtoInfo(Vec<u8>),
+    /// `IFLA_PROTINFO` attribute of `AF_BRIDGE` messages, describing a bridge port
+    ProtoInfoBridge(Vec<BridgePortNla>),
+    /// SR-IOV virtual functions of the link
+    VfInfoList(Vec<VfInfoNla>),
+    /// Port profiles of the SR-IOV virtual functions of the link
+    VfPorts(Vec<VfPortNla>),
+    /// Port profile of the link itself
+    PortSelf(Vec<PortNla>),
+    /// eXpress Data Path programs attached to the link
+    Xdp(Vec<LinkXdpNla>),
     // mac address (use to be [u8; 6] but it turns out MAC != HW address, for instance for IP over
     // GRE where it's an IPv4!)
     Address(Vec<u8>),
@@ -86,6 +104,9 @@ pub enum LinkNla {
     Map(LinkMap),
     // AF_SPEC
     AfSpec(LinkAfSpecNla),
+    /// `IFLA_AF_SPEC` attribute of `AF_BRIDGE` messages. Unlike for other messages, the bridge
+    /// attributes are not nested in an `AF_BRIDGE` attribute.
+    AfSpecBridge(Vec<LinkAfBridgeNla>),
     Other(DefaultNla),
 }
 
@@ -99,13 +120,9 @@ impl Nla for LinkNla {
                 | Cost(ref bytes)
                 | Priority(ref bytes)
                 | Weight(ref bytes)
-                | VfInfoList(ref bytes)
-                | VfPorts(ref bytes)
-                | PortSelf(ref bytes)
                 | PhysPortId(ref bytes)
                 | PhysSwitchId(ref bytes)
                 | Pad(ref bytes)
-                | Xdp(ref bytes)
                 | Event(ref bytes)
                 | NewNetnsId(ref bytes)
                 | IfNetnsId(ref bytes)
@@ -155,7 +172,13 @@ impl Nla for LinkNla {
             Stats(_) => size_of::<LinkStats32>(),
             Stats64(_) => size_of::<LinkStats64>(),
             LinkInfo(ref nlas) => nlas.as_slice().buffer_len(),
+            ProtoInfoBridge(ref nlas) => nlas.as_slice().buffer_len(),
+            VfInfoList(ref nlas) => nlas.as_slice().buffer_len(),
+            VfPorts(ref nlas) => nlas.as_slice().buffer_len(),
+            PortSelf(ref nlas) => nlas.as_slice().buffer_len(),
+            Xdp(ref nlas) => nlas.as_slice().buffer_len(),
             AfSpec(ref af_spec) => af_spec.buffer_len(),
+            AfSpecBridge(ref nlas) => nlas.as_slice().buffer_len(),
             Other(ref attr)  => attr.value_len(),
         }
     }
@@ -169,15 +192,11 @@ impl Nla for LinkNla {
                 | Cost(ref bytes)
                 | Priority(ref bytes)
                 | Weight(ref bytes)
-                | VfInfoList(ref bytes)
-                | VfPorts(ref bytes)
-                | PortSelf(ref bytes)
                 | PhysPortId(ref bytes)
                 | PhysSwitchId(ref bytes)
                 | Wireless(ref bytes)
                 | ProtoInfo(ref bytes)
                 | Pad(ref bytes)
-                | Xdp(ref bytes)
                 | Event(ref bytes)
                 | NewNetnsId(ref bytes)
                 | IfNetnsId(ref bytes)
@@ -231,10 +250,16 @@ impl Nla for LinkNla {
             Stats(ref stats) => stats.to_bytes(buffer),
             Stats64(ref stats) => stats.to_bytes(buffer),
             LinkInfo(ref nlas) => nlas.as_slice().emit(buffer),
+            ProtoInfoBridge(ref nlas) => nlas.as_slice().emit(buffer),
+            VfInfoList(ref nlas) => nlas.as_slice().emit(buffer),
+            VfPorts(ref nlas) => nlas.as_slice().emit(buffer),
+            PortSelf(ref nlas) => nlas.as_slice().emit(buffer),
+            Xdp(ref nlas) => nlas.as_slice().emit(buffer),
             // This is not supposed to fail, because the buffer length has normally been checked
             // before cally this method. If that fails, there's a bug in out code that needs to be
             // fixed.
             AfSpec(ref af_spec) => af_spec.emit(buffer),
+            AfSpecBridge(ref nlas) => nlas.as_slice().emit(buffer),
             // default nlas
             Other(ref attr) => attr.emit_value(buffer),
         }
@@ -255,7 +280,7 @@ impl Nla for LinkNla {
             PhysSwitchId(_) => IFLA_PHYS_SWITCH_ID,
             LinkInfo(_) => IFLA_LINKINFO,
             Wireless(_) => IFLA_WIRELESS,
-            ProtoInfo(_) => IFLA_PROTINFO,
+            ProtoInfo(_) | ProtoInfoBridge(_) => IFLA_PROTINFO,
             Pad(_) => IFLA_PAD,
             Xdp(_) => IFLA_XDP,
             Event(_) => IFLA_EVENT,
@@ -299,10 +324,20 @@ impl Nla for LinkNla {
             Map(_) => IFLA_MAP,
             Stats(_) => IFLA_STATS,
             Stats64(_) => IFLA_STATS64,
-            AfSpec(_) => IFLA_AF_SPEC,
+            AfSpec(_) | AfSpecBridge(_) => IFLA_AF_SPEC,
             Other(ref attr) => attr.kind(),
         }
     }
+
+    fn is_nested(&self) -> bool {
+        // the kernel only handles IFLA_PROTINFO as a list of bridge port attributes when it is
+        // flagged as nested. Otherwise, it is considered as a single IFLA_BRPORT_STATE.
+        if let LinkNla::ProtoInfoBridge(_) = *self {
+            true
+        } else {
+            false
+        }
+    }
 }
 
 impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<LinkNla> for NlaBuffer<&'buffer T> {
@@ -315,15 +350,11 @@ impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<LinkNla> for NlaBuffer<&'buffer
             IFLA_COST => Cost(payload.to_vec()),
             IFLA_PRIORITY => Priority(payload.to_vec()),
             IFLA_WEIGHT => Weight(payload.to_vec()),
-            IFLA_VFINFO_LIST => VfInfoList(payload.to_vec()),
-            IFLA_VF_PORTS => VfPorts(payload.to_vec()),
-            IFLA_PORT_SELF => PortSelf(payload.to_vec()),
             IFLA_PHYS_PORT_ID => PhysPortId(payload.to_vec()),
             IFLA_PHYS_SWITCH_ID => PhysSwitchId(payload.to_vec()),
             IFLA_WIRELESS => Wireless(payload.to_vec()),
             IFLA_PROTINFO => ProtoInfo(payload.to_vec()),
             IFLA_PAD => Pad(payload.to_vec()),
-            IFLA_XDP => Xdp(payload.to_vec()),
             IFLA_EVENT => Event(payload.to_vec()),
             IFLA_NEW_NETNSID => NewNetnsId(payload.to_vec()),
             IFLA_IF_NETNSID => IfNetnsId(payload.to_vec()),
@@ -367,11 +398,43 @@ impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<LinkNla> for NlaBuffer<&'buffer
 
             IFLA_OPERSTATE => OperState(parse_u8(payload)?.into()),
             IFLA_MAP => Map(LinkMap::from_bytes(payload)?),
-            IFLA_STATS => Stats(LinkStats32::from_bytes(payload)?),
-            IFLA_STATS64 => Stats64(LinkStats64::from_bytes(payload)?),
+            IFLA_STATS => Stats(LinkStats32::from_counters::<u32>(payload)?),
+            IFLA_STATS64 => Stats64(LinkStats64::from_counters::<u64>(payload)?),
+            // The kernel sends an empty IFLA_AF_SPEC attribute for links that are being deleted
+            IFLA_AF_SPEC if payload.is_empty() => {
+                Other(<Self as Parseable<DefaultNla>>::parse(self)?)
+            }
             IFLA_AF_SPEC => AfSpec(NlaBuffer::new_checked(payload)?.parse()?),
 
             IFLA_LINKINFO => LinkInfo(NlaBuffer::new_checked(payload)?.parse()?),
+            IFLA_VFINFO_LIST => {
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    nlas.push(<NlaBuffer<_> as Parseable<VfInfoNla>>::parse(&nla?)?);
+                }
+                VfInfoList(nlas)
+            }
+            IFLA_VF_PORTS => {
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    nlas.push(<NlaBuffer<_> as Parseable<VfPortNla>>::parse(&nla?)?);
+                }
+                VfPorts(nlas)
+            }
+            IFLA_PORT_SELF => {
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    nlas.push(<NlaBuffer<_> as Parseable<PortNla>>::parse(&nla?)?);
+                }
+                PortSelf(nlas)
+            }
+            IFLA_XDP => {
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    nlas.push(<NlaBuffer<_> as Parseable<LinkXdpNla>>::parse(&nla?)?);
+                }
+                Xdp(nlas)
+            }
             // default nlas
             _ => Other(<Self as Parseable<DefaultNla>>::parse(self)?),
         })
@@ -0,0 +1,719 @@
+use std::mem::size_of;
+
+use byteorder::{BigEndian, ByteOrder, NativeEndian};
+
+use constants::*;
+use utils::{parse_string, parse_u16, parse_u32, parse_u64, parse_u8};
+use {DefaultNla, Emitable, Error, NativeNla, Nla, NlaBuffer, NlasIterator, Parseable, Result};
+
+/// MAC address of a virtual function (`struct ifla_vf_mac`)
+#[repr(C)]
+#[derive(Clone, Copy, Eq, PartialEq, Debug, Default)]
+pub struct VfMac {
+    /// Index of the virtual function
+    pub vf: u32,
+    /// MAC address. Only the first six bytes are used for ethernet devices.
+    pub mac: [u8; 32],
+}
+
+impl NativeNla for VfMac {}
+
+impl VfMac {
+    pub fn new(vf: u32, address: [u8; 6]) -> Self {
+        let mut mac = [0; 32];
+        mac[..6].copy_from_slice(&address[..]);
+        VfMac { vf, mac }
+    }
+}
+
+/// VLAN of a virtual function (`struct ifla_vf_vlan`)
+#[repr(C)]
+#[derive(Clone, Copy, Eq, PartialEq, Debug, Default)]
+pub struct VfVlan {
+    pub vf: u32,
+    /// VLAN identifier, 0 to disable VLAN tagging
+    pub vlan: u32,
+    /// 802.1p priority of the VLAN tag
+    pub qos: u32,
+}
+
+impl NativeNla for VfVlan {}
+
+/// Maximum transmit rate of a virtual function, in Mbit/s (`struct ifla_vf_tx_rate`)
+#[repr(C)]
+#[derive(Clone, Copy, Eq, PartialEq, Debug, Default)]
+pub struct VfTxRate {
+    pub vf: u32,
+    pub rate: u32,
+}
+
+impl NativeNla for VfTxRate {}
+
+/// Transmit rate limits of a virtual function, in Mbit/s (`struct ifla_vf_rate`). 0 means no
+/// limit.
+#[repr(C)]
+#[derive(Clone, Copy, Eq, PartialEq, Debug, Default)]
+pub struct VfRate {
+    pub vf: u32,
+    pub min_tx_rate: u32,
+    pub max_tx_rate: u32,
+}
+
+impl NativeNla for VfRate {}
+
+/// A setting of a virtual function (`struct ifla_vf_spoofchk`, `struct ifla_vf_link_state`,
+/// `struct ifla_vf_rss_query_en` and `struct ifla_vf_trust`). For the link state, `setting` is
+/// one of the `IFLA_VF_LINK_STATE_*` constants, otherwise it is 1 for "on" and 0 for "off".
+#[repr(C)]
+#[derive(Clone, Copy, Eq, PartialEq, Debug, Default)]
+pub struct VfSetting {
+    pub vf: u32,
+    pub setting: u32,
+}
+
+impl NativeNla for VfSetting {}
+
+const VF_VLAN_INFO_LEN: usize = 16;
+
+/// VLAN of a virtual function, with its protocol (`struct ifla_vf_vlan_info`)
+#[derive(Clone, Copy, Eq, PartialEq, Debug, Default)]
+pub struct VfVlanInfo {
+    pub vf: u32,
+    pub vlan: u32,
+    pub qos: u32,
+    /// VLAN protocol: `ETH_P_8021Q` (0x8100) or `ETH_P_8021AD` (0x88a8)
+    pub protocol: u16,
+}
+
+impl VfVlanInfo {
+    fn from_bytes(buf: &[u8]) -> Result<Self> {
+        if buf.len() != VF_VLAN_INFO_LEN {
+            return Err(Error::MalformedNlaValue);
+        }
+        Ok(VfVlanInfo {
+            vf: NativeEndian::read_u32(&buf[0..4]),
+            vlan: NativeEndian::read_u32(&buf[4..8]),
+            qos: NativeEndian::read_u32(&buf[8..12]),
+            // the protocol is in network byte order
+            protocol: BigEndian::read_u16(&buf[12..14]),
+        })
+    }
+
+    fn emit(&self, buf: &mut [u8]) {
+        NativeEndian::write_u32(&mut buf[0..4], self.vf);
+        NativeEndian::write_u32(&mut buf[4..8], self.vlan);
+        NativeEndian::write_u32(&mut buf[8..12], self.qos);
+        BigEndian::write_u16(&mut buf[12..14], self.protocol);
+        // padding
+        buf[14] = 0;
+        buf[15] = 0;
+    }
+}
+
+/// Attributes of the `IFLA_VFINFO_LIST` attribute
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum VfInfoNla {
+    Unspec(Vec<u8>),
+    /// Attributes of a virtual function
+    Info(Vec<VfNla>),
+    Other(DefaultNla),
+}
+
+impl Nla for VfInfoNla {
+    #[cfg_attr(nightly, rustfmt::skip)]
+    fn value_len(&self) -> usize {
+        use self::VfInfoNla::*;
+        match *self {
+            Unspec(ref bytes) => bytes.len(),
+            Info(ref nlas) => nlas.as_slice().buffer_len(),
+            Other(ref nla) => nla.value_len(),
+        }
+    }
+
+    #[cfg_attr(nightly, rustfmt::skip)]
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::VfInfoNla::*;
+        match *self {
+            Unspec(ref bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            Info(ref nlas) => nlas.as_slice().emit(buffer),
+            Other(ref nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::VfInfoNla::*;
+        match *self {
+            Unspec(_) => IFLA_VF_INFO_UNSPEC,
+            Info(_) => IFLA_VF_INFO,
+            Other(ref nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<VfInfoNla> for NlaBuffer<&'buffer T> {
+    fn parse(&self) -> Result<VfInfoNla> {
+        use self::VfInfoNla::*;
+        let payload = self.value();
+        Ok(match self.kind() {
+            IFLA_VF_INFO_UNSPEC => Unspec(payload.to_vec()),
+            IFLA_VF_INFO => {
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    nlas.push(<NlaBuffer<_> as Parseable<VfNla>>::parse(&nla?)?);
+                }
+                Info(nlas)
+            }
+            _ => Other(<Self as Parseable<DefaultNla>>::parse(self)?),
+        })
+    }
+}
+
+/// Attributes of a virtual function (`IFLA_VF_*`)
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum VfNla {
+    Unspec(Vec<u8>),
+    Mac(VfMac),
+    Vlan(VfVlan),
+    /// Maximum transmit rate. Superseded by `Rate`.
+    TxRate(VfTxRate),
+    SpoofCheck(VfSetting),
+    LinkState(VfSetting),
+    Rate(VfRate),
+    RssQueryEnabled(VfSetting),
+    Stats(Vec<VfStatsNla>),
+    Trust(VfSetting),
+    IbNodeGuid(Vec<u8>),
+    IbPortGuid(Vec<u8>),
+    /// VLANs of the virtual function, with their protocol
+    VlanList(Vec<VfVlanNla>),
+    Other(DefaultNla),
+}
+
+impl VfNla {
+    /// Index of the virtual function the attribute applies to
+    pub fn vf(&self) -> Option<u32> {
+        use self::VfNla::*;
+        match *self {
+            Mac(ref mac) => Some(mac.vf),
+            Vlan(ref vlan) => Some(vlan.vf),
+            TxRate(ref rate) => Some(rate.vf),
+            Rate(ref rate) => Some(rate.vf),
+            SpoofCheck(ref setting)
+            | LinkState(ref setting)
+            | RssQueryEnabled(ref setting)
+            | Trust(ref setting) => Some(setting.vf),
+            _ => None,
+        }
+    }
+}
+
+impl Nla for VfNla {
+    #[cfg_attr(nightly, rustfmt::skip)]
+    fn value_len(&self) -> usize {
+        use self::VfNla::*;
+        match *self {
+            Unspec(ref bytes)
+                | IbNodeGuid(ref bytes)
+                | IbPortGuid(ref bytes)
+                => bytes.len(),
+            Mac(_) => size_of::<VfMac>(),
+            Vlan(_) => size_of::<VfVlan>(),
+            TxRate(_) => size_of::<VfTxRate>(),
+            Rate(_) => size_of::<VfRate>(),
+            SpoofCheck(_)
+                | LinkState(_)
+                | RssQueryEnabled(_)
+                | Trust(_)
+                => size_of::<VfSetting>(),
+            Stats(ref nlas) => nlas.as_slice().buffer_len(),
+            VlanList(ref nlas) => nlas.as_slice().buffer_len(),
+            Other(ref nla) => nla.value_len(),
+        }
+    }
+
+    #[cfg_attr(nightly, rustfmt::skip)]
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::VfNla::*;
+        match *self {
+            Unspec(ref bytes)
+                | IbNodeGuid(ref bytes)
+                | IbPortGuid(ref bytes)
+                => buffer.copy_from_slice(bytes.as_slice()),
+            Mac(ref mac) => mac.to_bytes(buffer),
+            Vlan(ref vlan) => vlan.to_bytes(buffer),
+            TxRate(ref rate) => rate.to_bytes(buffer),
+            Rate(ref rate) => rate.to_bytes(buffer),
+            SpoofCheck(ref setting)
+                | LinkState(ref setting)
+                | RssQueryEnabled(ref setting)
+                | Trust(ref setting)
+                => setting.to_bytes(buffer),
+            Stats(ref nlas) => nlas.as_slice().emit(buffer),
+            VlanList(ref nlas) => nlas.as_slice().emit(buffer),
+            Other(ref nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::VfNla::*;
+        match *self {
+            Unspec(_) => IFLA_VF_UNSPEC,
+            Mac(_) => IFLA_VF_MAC,
+            Vlan(_) => IFLA_VF_VLAN,
+            TxRate(_) => IFLA_VF_TX_RATE,
+            SpoofCheck(_) => IFLA_VF_SPOOFCHK,
+            LinkState(_) => IFLA_VF_LINK_STATE,
+            Rate(_) => IFLA_VF_RATE,
+            RssQueryEnabled(_) => IFLA_VF_RSS_QUERY_EN,
+            Stats(_) => IFLA_VF_STATS,
+            Trust(_) => IFLA_VF_TRUST,
+            IbNodeGuid(_) => IFLA_VF_IB_NODE_GUID,
+            IbPortGuid(_) => IFLA_VF_IB_PORT_GUID,
+            VlanList(_) => IFLA_VF_VLAN_LIST,
+            Other(ref nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<VfNla> for NlaBuffer<&'buffer T> {
+    fn parse(&self) -> Result<VfNla> {
+        use self::VfNla::*;
+        let payload = self.value();
+        Ok(match self.kind() {
+            IFLA_VF_UNSPEC => Unspec(payload.to_vec()),
+            IFLA_VF_MAC => Mac(VfMac::from_bytes(payload)?),
+            IFLA_VF_VLAN => Vlan(VfVlan::from_bytes(payload)?),
+            IFLA_VF_TX_RATE => TxRate(VfTxRate::from_bytes(payload)?),
+            IFLA_VF_SPOOFCHK => SpoofCheck(VfSetting::from_bytes(payload)?),
+            IFLA_VF_LINK_STATE => LinkState(VfSetting::from_bytes(payload)?),
+            IFLA_VF_RATE => Rate(VfRate::from_bytes(payload)?),
+            IFLA_VF_RSS_QUERY_EN => RssQueryEnabled(VfSetting::from_bytes(payload)?),
+            IFLA_VF_STATS => {
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    nlas.push(<NlaBuffer<_> as Parseable<VfStatsNla>>::parse(&nla?)?);
+                }
+                Stats(nlas)
+            }
+            IFLA_VF_TRUST => Trust(VfSetting::from_bytes(payload)?),
+            IFLA_VF_IB_NODE_GUID => IbNodeGuid(payload.to_vec()),
+            IFLA_VF_IB_PORT_GUID => IbPortGuid(payload.to_vec()),
+            IFLA_VF_VLAN_LIST => {
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    nlas.push(<NlaBuffer<_> as Parseable<VfVlanNla>>::parse(&nla?)?);
+                }
+                VlanList(nlas)
+            }
+            _ => Other(<Self as Parseable<DefaultNla>>::parse(self)?),
+        })
+    }
+}
+
+/// Attributes of the `IFLA_VF_VLAN_LIST` attribute
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum VfVlanNla {
+    Unspec(Vec<u8>),
+    Info(VfVlanInfo),
+    Other(DefaultNla),
+}
+
+impl Nla for VfVlanNla {
+    #[cfg_attr(nightly, rustfmt::skip)]
+    fn value_len(&self) -> usize {
+        use self::VfVlanNla::*;
+        match *self {
+            Unspec(ref bytes) => bytes.len(),
+            Info(_) => VF_VLAN_INFO_LEN,
+            Other(ref nla) => nla.value_len(),
+        }
+    }
+
+    #[cfg_attr(nightly, rustfmt::skip)]
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::VfVlanNla::*;
+        match *self {
+            Unspec(ref bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            Info(ref info) => info.emit(buffer),
+            Other(ref nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::VfVlanNla::*;
+        match *self {
+            Unspec(_) => IFLA_VF_VLAN_INFO_UNSPEC,
+            Info(_) => IFLA_VF_VLAN_INFO,
+            Other(ref nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<VfVlanNla> for NlaBuffer<&'buffer T> {
+    fn parse(&self) -> Result<VfVlanNla> {
+        use self::VfVlanNla::*;
+        let payload = self.value();
+        Ok(match self.kind() {
+            IFLA_VF_VLAN_INFO_UNSPEC => Unspec(payload.to_vec()),
+            IFLA_VF_VLAN_INFO => Info(VfVlanInfo::from_bytes(payload)?),
+            _ => Other(<Self as Parseable<DefaultNla>>::parse(self)?),
+        })
+    }
+}
+
+/// Statistics of a virtual function, found in the `IFLA_VF_STATS` attribute
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum VfStatsNla {
+    RxPackets(u64),
+    TxPackets(u64),
+    RxBytes(u64),
+    TxBytes(u64),
+    Broadcast(u64),
+    Multicast(u64),
+    Pad(Vec<u8>),
+    RxDropped(u64),
+    TxDropped(u64),
+    Other(DefaultNla),
+}
+
+impl Nla for VfStatsNla {
+    #[cfg_attr(nightly, rustfmt::skip)]
+    fn value_len(&self) -> usize {
+        use self::VfStatsNla::*;
+        match *self {
+            Pad(ref bytes) => bytes.len(),
+            RxPackets(_)
+                | TxPackets(_)
+                | RxBytes(_)
+                | TxBytes(_)
+                | Broadcast(_)
+                | Multicast(_)
+                | RxDropped(_)
+                | TxDropped(_)
+                => size_of::<u64>(),
+            Other(ref nla) => nla.value_len(),
+        }
+    }
+
+    #[cfg_attr(nightly, rustfmt::skip)]
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::VfStatsNla::*;
+        match *self {
+            Pad(ref bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            RxPackets(ref value)
+                | TxPackets(ref value)
+                | RxBytes(ref value)
+                | TxBytes(ref value)
+                | Broadcast(ref value)
+                | Multicast(ref value)
+                | RxDropped(ref value)
+                | TxDropped(ref value)
+                => NativeEndian::write_u64(buffer, *value),
+            Other(ref nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::VfStatsNla::*;
+        match *self {
+            RxPackets(_) => IFLA_VF_STATS_RX_PACKETS,
+            TxPackets(_) => IFLA_VF_STATS_TX_PACKETS,
+            RxBytes(_) => IFLA_VF_STATS_RX_BYTES,
+            TxBytes(_) => IFLA_VF_STATS_TX_BYTES,
+            Broadcast(_) => IFLA_VF_STATS_BROADCAST,
+            Multicast(_) => IFLA_VF_STATS_MULTICAST,
+            Pad(_) => IFLA_VF_STATS_PAD,
+            RxDropped(_) => IFLA_VF_STATS_RX_DROPPED,
+            TxDropped(_) => IFLA_VF_STATS_TX_DROPPED,
+            Other(ref nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<VfStatsNla> for NlaBuffer<&'buffer T> {
+    fn parse(&self) -> Result<VfStatsNla> {
+        use self::VfStatsNla::*;
+        let payload = self.value();
+        Ok(match self.kind() {
+            IFLA_VF_STATS_RX_PACKETS => RxPackets(parse_u64(payload)?),
+            IFLA_VF_STATS_TX_PACKETS => TxPackets(parse_u64(payload)?),
+            IFLA_VF_STATS_RX_BYTES => RxBytes(parse_u64(payload)?),
+            IFLA_VF_STATS_TX_BYTES => TxBytes(parse_u64(payload)?),
+            IFLA_VF_STATS_BROADCAST => Broadcast(parse_u64(payload)?),
+            IFLA_VF_STATS_MULTICAST => Multicast(parse_u64(payload)?),
+            IFLA_VF_STATS_PAD => Pad(payload.to_vec()),
+            IFLA_VF_STATS_RX_DROPPED => RxDropped(parse_u64(payload)?),
+            IFLA_VF_STATS_TX_DROPPED => TxDropped(parse_u64(payload)?),
+            _ => Other(<Self as Parseable<DefaultNla>>::parse(self)?),
+        })
+    }
+}
+
+/// Attributes of the `IFLA_VF_PORTS` attribute
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum VfPortNla {
+    Unspec(Vec<u8>),
+    /// Port profile of a virtual function
+    Port(Vec<PortNla>),
+    Other(DefaultNla),
+}
+
+impl Nla for VfPortNla {
+    #[cfg_attr(nightly, rustfmt::skip)]
+    fn value_len(&self) -> usize {
+        use self::VfPortNla::*;
+        match *self {
+            Unspec(ref bytes) => bytes.len(),
+            Port(ref nlas) => nlas.as_slice().buffer_len(),
+            Other(ref nla) => nla.value_len(),
+        }
+    }
+
+    #[cfg_attr(nightly, rustfmt::skip)]
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::VfPortNla::*;
+        match *self {
+            Unspec(ref bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            Port(ref nlas) => nlas.as_slice().emit(buffer),
+            Other(ref nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::VfPortNla::*;
+        match *self {
+            Unspec(_) => IFLA_VF_PORT_UNSPEC,
+            Port(_) => IFLA_VF_PORT,
+            Other(ref nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<VfPortNla> for NlaBuffer<&'buffer T> {
+    fn parse(&self) -> Result<VfPortNla> {
+        use self::VfPortNla::*;
+        let payload = self.value();
+        Ok(match self.kind() {
+            IFLA_VF_PORT_UNSPEC => Unspec(payload.to_vec()),
+            IFLA_VF_PORT => {
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    nlas.push(<NlaBuffer<_> as Parseable<PortNla>>::parse(&nla?)?);
+                }
+                Port(nlas)
+            }
+            _ => Other(<Self as Parseable<DefaultNla>>::parse(self)?),
+        })
+    }
+}
+
+/// Attributes of a port profile (802.1Qbg/802.1Qbh), found in the `IFLA_VF_PORT` and
+/// `IFLA_PORT_SELF` attributes
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum PortNla {
+    Unspec(Vec<u8>),
+    /// Index of the virtual function
+    Vf(u32),
+    Profile(String),
+    /// Raw `struct ifla_port_vsi`
+    VsiType(Vec<u8>),
+    InstanceUuid(Vec<u8>),
+    HostUuid(Vec<u8>),
+    /// One of the `PORT_REQUEST_*` values
+    Request(u8),
+    /// One of the `PORT_VDP_RESPONSE_*` or `PORT_PROFILE_RESPONSE_*` values
+    Response(u16),
+    Other(DefaultNla),
+}
+
+impl Nla for PortNla {
+    #[cfg_attr(nightly, rustfmt::skip)]
+    fn value_len(&self) -> usize {
+        use self::PortNla::*;
+        match *self {
+            Unspec(ref bytes)
+                | VsiType(ref bytes)
+                | InstanceUuid(ref bytes)
+                | HostUuid(ref bytes)
+                => bytes.len(),
+            Vf(_) => size_of::<u32>(),
+            // +1 for the nul byte
+            Profile(ref string) => string.len() + 1,
+            Request(_) => size_of::<u8>(),
+            Response(_) => size_of::<u16>(),
+            Other(ref nla) => nla.value_len(),
+        }
+    }
+
+    #[cfg_attr(nightly, rustfmt::skip)]
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::PortNla::*;
+        match *self {
+            Unspec(ref bytes)
+                | VsiType(ref bytes)
+                | InstanceUuid(ref bytes)
+                | HostUuid(ref bytes)
+                => buffer.copy_from_slice(bytes.as_slice()),
+            Vf(ref value) => NativeEndian::write_u32(buffer, *value),
+            Profile(ref string) => {
+                buffer[..string.len()].copy_from_slice(string.as_bytes());
+                buffer[string.len()] = 0;
+            }
+            Request(ref value) => buffer[0] = *value,
+            Response(ref value) => NativeEndian::write_u16(buffer, *value),
+            Other(ref nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::PortNla::*;
+        match *self {
+            Unspec(_) => IFLA_PORT_UNSPEC,
+            Vf(_) => IFLA_PORT_VF,
+            Profile(_) => IFLA_PORT_PROFILE,
+            VsiType(_) => IFLA_PORT_VSI_TYPE,
+            InstanceUuid(_) => IFLA_PORT_INSTANCE_UUID,
+            HostUuid(_) => IFLA_PORT_HOST_UUID,
+            Request(_) => IFLA_PORT_REQUEST,
+            Response(_) => IFLA_PORT_RESPONSE,
+            Other(ref nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<PortNla> for NlaBuffer<&'buffer T> {
+    fn parse(&self) -> Result<PortNla> {
+        use self::PortNla::*;
+        let payload = self.value();
+        Ok(match self.kind() {
+            IFLA_PORT_UNSPEC => Unspec(payload.to_vec()),
+            IFLA_PORT_VF => Vf(parse_u32(payload)?),
+            IFLA_PORT_PROFILE => Profile(parse_string(payload)?),
+            IFLA_PORT_VSI_TYPE => VsiType(payload.to_vec()),
+            IFLA_PORT_INSTANCE_UUID => InstanceUuid(payload.to_vec()),
+            IFLA_PORT_HOST_UUID => HostUuid(payload.to_vec()),
+            IFLA_PORT_REQUEST => Request(parse_u8(payload)?),
+            IFLA_PORT_RESPONSE => Response(parse_u16(payload)?),
+            _ => Other(<Self as Parseable<DefaultNla>>::parse(self)?),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use LinkNla;
+
+    #[cfg_attr(nightly, rustfmt::skip)]
+    static VF_INFO_LIST: [u8; 180] = [
+        0xb4, 0x00, // L = 180
+        0x16, 0x00, // T = 22 (IFLA_VFINFO_LIST)
+            0xb0, 0x00, // L = 176
+            0x01, 0x00, // T = 1 (IFLA_VF_INFO)
+
+                0x28, 0x00, // L = 40
+                0x01, 0x00, // T = 1 (IFLA_VF_MAC)
+                0x00, 0x00, 0x00, 0x00, // vf = 0
+                0x52, 0x54, 0x00, 0x12, 0x34, 0x56, // mac = 52:54:00:12:34:56
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+
+                0x10, 0x00, // L = 16
+                0x02, 0x00, // T = 2 (IFLA_VF_VLAN)
+                0x00, 0x00, 0x00, 0x00, // vf = 0
+                0x64, 0x00, 0x00, 0x00, // vlan = 100
+                0x03, 0x00, 0x00, 0x00, // qos = 3
+
+                0x0c, 0x00, // L = 12
+                0x03, 0x00, // T = 3 (IFLA_VF_TX_RATE)
+                0x00, 0x00, 0x00, 0x00, // vf = 0
+                0xe8, 0x03, 0x00, 0x00, // rate = 1000
+
+                0x0c, 0x00, // L = 12
+                0x04, 0x00, // T = 4 (IFLA_VF_SPOOFCHK)
+                0x00, 0x00, 0x00, 0x00, // vf = 0
+                0x01, 0x00, 0x00, 0x00, // setting = on
+
+                0x0c, 0x00, // L = 12
+                0x05, 0x00, // T = 5 (IFLA_VF_LINK_STATE)
+                0x00, 0x00, 0x00, 0x00, // vf = 0
+                0x00, 0x00, 0x00, 0x00, // link state = IFLA_VF_LINK_STATE_AUTO
+
+                0x10, 0x00, // L = 16
+                0x06, 0x00, // T = 6 (IFLA_VF_RATE)
+                0x00, 0x00, 0x00, 0x00, // vf = 0
+                0x00, 0x00, 0x00, 0x00, // min tx rate = 0
+                0xe8, 0x03, 0x00, 0x00, // max tx rate = 1000
+
+                0x0c, 0x00, // L = 12
+                0x09, 0x00, // T = 9 (IFLA_VF_TRUST)
+                0x00, 0x00, 0x00, 0x00, // vf = 0
+                0x00, 0x00, 0x00, 0x00, // setting = off
+
+                0x18, 0x00, // L = 24
+                0x0c, 0x00, // T = 12 (IFLA_VF_VLAN_LIST)
+                    0x14, 0x00, // L = 20
+                    0x01, 0x00, // T = 1 (IFLA_VF_VLAN_INFO)
+                    0x00, 0x00, 0x00, 0x00, // vf = 0
+                    0x64, 0x00, 0x00, 0x00, // vlan = 100
+                    0x03, 0x00, 0x00, 0x00, // qos = 3
+                    0x81, 0x00, // protocol = ETH_P_8021Q
+                    0x00, 0x00, // padding
+
+                0x1c, 0x00, // L = 28
+                0x08, 0x00, // T = 8 (IFLA_VF_STATS)
+                    0x0c, 0x00, // L = 12
+                    0x00, 0x00, // T = 0 (IFLA_VF_STATS_RX_PACKETS)
+                    0x2a, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // V = 42
+
+                    0x0c, 0x00, // L = 12
+                    0x01, 0x00, // T = 1 (IFLA_VF_STATS_TX_PACKETS)
+                    0x07, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // V = 7
+    ];
+
+    lazy_static! {
+        static ref VF_INFO_LIST_NLA: LinkNla = LinkNla::VfInfoList(vec![VfInfoNla::Info(vec![
+            VfNla::Mac(VfMac::new(0, [0x52, 0x54, 0x00, 0x12, 0x34, 0x56])),
+            VfNla::Vlan(VfVlan {
+                vf: 0,
+                vlan: 100,
+                qos: 3,
+            }),
+            VfNla::TxRate(VfTxRate { vf: 0, rate: 1000 }),
+            VfNla::SpoofCheck(VfSetting { vf: 0, setting: 1 }),
+            VfNla::LinkState(VfSetting {
+                vf: 0,
+                setting: IFLA_VF_LINK_STATE_AUTO,
+            }),
+            VfNla::Rate(VfRate {
+                vf: 0,
+                min_tx_rate: 0,
+                max_tx_rate: 1000,
+            }),
+            VfNla::Trust(VfSetting { vf: 0, setting: 0 }),
+            VfNla::VlanList(vec![VfVlanNla::Info(VfVlanInfo {
+                vf: 0,
+                vlan: 100,
+                qos: 3,
+                protocol: 0x8100,
+            })]),
+            VfNla::Stats(vec![VfStatsNla::RxPackets(42), VfStatsNla::TxPackets(7)]),
+        ])]);
+    }
+
+    #[test]
+    fn parse_vf_info_list() {
+        let buffer = NlaBuffer::new_checked(&VF_INFO_LIST[..]).unwrap();
+        let nla = <NlaBuffer<_> as Parseable<LinkNla>>::parse(&buffer).unwrap();
+        assert_eq!(nla, *VF_INFO_LIST_NLA);
+    }
+
+    #[test]
+    fn emit_vf_info_list() {
+        let mut buf = vec![0; 180];
+        assert_eq!(VF_INFO_LIST_NLA.buffer_len(), 180);
+        VF_INFO_LIST_NLA.emit(&mut buf[..]);
+        assert_eq!(&buf[..], &VF_INFO_LIST[..]);
+    }
+}
@@ -0,0 +1,157 @@
+use std::mem::size_of;
+
+use byteorder::{ByteOrder, NativeEndian};
+
+use constants::*;
+use utils::{parse_i32, parse_u32, parse_u8};
+use {DefaultNla, Nla, NlaBuffer, Parseable, Result};
+
+/// Attributes of the `IFLA_XDP` attribute, used to attach an eXpress Data Path program to a link
+/// and to query the programs attached to a link
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum LinkXdpNla {
+    Unspec(Vec<u8>),
+    /// File descriptor of the BPF program to attach, or -1 to detach the current program
+    Fd(i32),
+    /// How the programs are attached, one of the `XDP_ATTACHED_*` constants
+    Attached(u8),
+    /// A combination of the `XDP_FLAGS_*` flags
+    Flags(u32),
+    /// Identifier of the attached program, when a single program is attached
+    ProgId(u32),
+    /// Identifier of the program attached in native (driver) mode
+    DrvProgId(u32),
+    /// Identifier of the program attached in generic (skb) mode
+    SkbProgId(u32),
+    /// Identifier of the program offloaded to the hardware
+    HwProgId(u32),
+    /// File descriptor of the program expected to be attached, for `XDP_FLAGS_REPLACE`
+    ExpectedFd(i32),
+    Other(DefaultNla),
+}
+
+impl Nla for LinkXdpNla {
+    #[cfg_attr(nightly, rustfmt::skip)]
+    fn value_len(&self) -> usize {
+        use self::LinkXdpNla::*;
+        match *self {
+            Unspec(ref bytes) => bytes.len(),
+            Attached(_) => size_of::<u8>(),
+            Fd(_)
+                | Flags(_)
+                | ProgId(_)
+                | DrvProgId(_)
+                | SkbProgId(_)
+                | HwProgId(_)
+                | ExpectedFd(_)
+                => size_of::<u32>(),
+            Other(ref nla) => nla.value_len(),
+        }
+    }
+
+    #[cfg_attr(nightly, rustfmt::skip)]
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::LinkXdpNla::*;
+        match *self {
+            Unspec(ref bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            Attached(ref value) => buffer[0] = *value,
+            Fd(ref value)
+                | ExpectedFd(ref value)
+                => NativeEndian::write_i32(buffer, *value),
+            Flags(ref value)
+                | ProgId(ref value)
+                | DrvProgId(ref value)
+                | SkbProgId(ref value)
+                | HwProgId(ref value)
+                => NativeEndian::write_u32(buffer, *value),
+            Other(ref nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::LinkXdpNla::*;
+        match *self {
+            Unspec(_) => IFLA_XDP_UNSPEC,
+            Fd(_) => IFLA_XDP_FD,
+            Attached(_) => IFLA_XDP_ATTACHED,
+            Flags(_) => IFLA_XDP_FLAGS,
+            ProgId(_) => IFLA_XDP_PROG_ID,
+            DrvProgId(_) => IFLA_XDP_DRV_PROG_ID,
+            SkbProgId(_) => IFLA_XDP_SKB_PROG_ID,
+            HwProgId(_) => IFLA_XDP_HW_PROG_ID,
+            ExpectedFd(_) => IFLA_XDP_EXPECTED_FD,
+            Other(ref nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<LinkXdpNla> for NlaBuffer<&'buffer T> {
+    fn parse(&self) -> Result<LinkXdpNla> {
+        use self::LinkXdpNla::*;
+        let payload = self.value();
+        Ok(match self.kind() {
+            IFLA_XDP_UNSPEC => Unspec(payload.to_vec()),
+            IFLA_XDP_FD => Fd(parse_i32(payload)?),
+            IFLA_XDP_ATTACHED => Attached(parse_u8(payload)?),
+            IFLA_XDP_FLAGS => Flags(parse_u32(payload)?),
+            IFLA_XDP_PROG_ID => ProgId(parse_u32(payload)?),
+            IFLA_XDP_DRV_PROG_ID => DrvProgId(parse_u32(payload)?),
+            IFLA_XDP_SKB_PROG_ID => SkbProgId(parse_u32(payload)?),
+            IFLA_XDP_HW_PROG_ID => HwProgId(parse_u32(payload)?),
+            IFLA_XDP_EXPECTED_FD => ExpectedFd(parse_i32(payload)?),
+            _ => Other(<Self as Parseable<DefaultNla>>::parse(self)?),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use {Emitable, LinkNla};
+
+    #[cfg_attr(nightly, rustfmt::skip)]
+    static XDP_MULTI: [u8; 36] = [
+        0x24, 0x00, // L = 36
+        0x2b, 0x00, // T = 43 (IFLA_XDP)
+            0x05, 0x00, // L = 5
+            0x02, 0x00, // T = 2 (IFLA_XDP_ATTACHED)
+            0x04, // V = XDP_ATTACHED_MULTI
+            0x00, 0x00, 0x00, // padding
+
+            0x08, 0x00, // L = 8
+            0x05, 0x00, // T = 5 (IFLA_XDP_DRV_PROG_ID)
+            0x2a, 0x00, 0x00, 0x00, // V = 42
+
+            0x08, 0x00, // L = 8
+            0x06, 0x00, // T = 6 (IFLA_XDP_SKB_PROG_ID)
+            0x2b, 0x00, 0x00, 0x00, // V = 43
+
+            0x08, 0x00, // L = 8
+            0x03, 0x00, // T = 3 (IFLA_XDP_FLAGS)
+            0x02, 0x00, 0x00, 0x00, // V = XDP_FLAGS_SKB_MODE
+    ];
+
+    lazy_static! {
+        static ref XDP_MULTI_NLA: LinkNla = LinkNla::Xdp(vec![
+            LinkXdpNla::Attached(XDP_ATTACHED_MULTI),
+            LinkXdpNla::DrvProgId(42),
+            LinkXdpNla::SkbProgId(43),
+            LinkXdpNla::Flags(XDP_FLAGS_SKB_MODE),
+        ]);
+    }
+
+    #[test]
+    fn parse_xdp() {
+        let buffer = NlaBuffer::new_checked(&XDP_MULTI[..]).unwrap();
+        let nla = <NlaBuffer<_> as Parseable<LinkNla>>::parse(&buffer).unwrap();
+        assert_eq!(nla, *XDP_MULTI_NLA);
+    }
+
+    #[test]
+    fn emit_xdp() {
+        let mut buf = vec![0; 36];
+        assert_eq!(XDP_MULTI_NLA.buffer_len(), 36);
+        XDP_MULTI_NLA.emit(&mut buf[..]);
+        assert_eq!(&buf[..], &XDP_MULTI[..]);
+    }
+}
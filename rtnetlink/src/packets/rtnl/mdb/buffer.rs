@@ -0,0 +1,66 @@
+use byteorder::{ByteOrder, NativeEndian};
+
+use {Field, Index, NlaBuffer, NlasIterator, Rest, Result};
+
+const FAMILY: Index = 0;
+const IFINDEX: Field = 4..8;
+const ATTRIBUTES: Rest = 8..;
+
+pub const MDB_HEADER_LEN: usize = ATTRIBUTES.start;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct MdbBuffer<T> {
+    buffer: T,
+}
+
+impl<T: AsRef<[u8]>> MdbBuffer<T> {
+    pub fn new(buffer: T) -> MdbBuffer<T> {
+        MdbBuffer { buffer }
+    }
+
+    pub fn into_inner(self) -> T {
+        self.buffer
+    }
+
+    pub fn family(&self) -> u8 {
+        let data = self.buffer.as_ref();
+        data[FAMILY]
+    }
+
+    pub fn ifindex(&self) -> u32 {
+        let data = self.buffer.as_ref();
+        NativeEndian::read_u32(&data[IFINDEX])
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> MdbBuffer<&'a T> {
+    /// Return a pointer to the payload.
+    pub fn payload(&self) -> &'a [u8] {
+        let data = self.buffer.as_ref();
+        &data[ATTRIBUTES]
+    }
+
+    pub fn nlas(&self) -> impl Iterator<Item = Result<NlaBuffer<&'a [u8]>>> {
+        NlasIterator::new(self.payload())
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + AsMut<[u8]> + ?Sized> MdbBuffer<&'a mut T> {
+    /// Return a mutable pointer to the payload.
+    pub fn payload_mut(&mut self) -> &mut [u8] {
+        let data = self.buffer.as_mut();
+        &mut data[ATTRIBUTES]
+    }
+}
+
+impl<T: AsRef<[u8]> + AsMut<[u8]>> MdbBuffer<T> {
+    pub fn set_family(&mut self, value: u8) {
+        let data = self.buffer.as_mut();
+        data[FAMILY] = value
+    }
+
+    pub fn set_ifindex(&mut self, value: u32) {
+        let data = self.buffer.as_mut();
+        NativeEndian::write_u32(&mut data[IFINDEX], value)
+    }
+}
@@ -0,0 +1,156 @@
+use super::{MdbBuffer, MdbNla};
+use {Emitable, Parseable, Result, MDB_HEADER_LEN};
+
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct MdbMessage {
+    pub header: MdbHeader,
+    pub nlas: Vec<MdbNla>,
+}
+
+/// Header of the bridge multicast database messages (`struct br_port_msg`)
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct MdbHeader {
+    /// Address family, `AF_BRIDGE`
+    pub family: u8,
+    /// Index of the bridge
+    pub ifindex: u32,
+}
+
+impl Emitable for MdbHeader {
+    fn buffer_len(&self) -> usize {
+        MDB_HEADER_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut packet = MdbBuffer::new(buffer);
+        packet.set_family(self.family);
+        packet.set_ifindex(self.ifindex);
+    }
+}
+
+impl Emitable for MdbMessage {
+    fn buffer_len(&self) -> usize {
+        self.header.buffer_len() + self.nlas.as_slice().buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        self.header.emit(buffer);
+        self.nlas
+            .as_slice()
+            .emit(&mut buffer[self.header.buffer_len()..]);
+    }
+}
+
+impl<T: AsRef<[u8]>> Parseable<MdbHeader> for MdbBuffer<T> {
+    fn parse(&self) -> Result<MdbHeader> {
+        Ok(MdbHeader {
+            family: self.family(),
+            ifindex: self.ifindex(),
+        })
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + 'buffer> Parseable<MdbMessage> for MdbBuffer<&'buffer T> {
+    fn parse(&self) -> Result<MdbMessage> {
+        Ok(MdbMessage {
+            header: self.parse()?,
+            nlas: self.parse()?,
+        })
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + 'buffer> Parseable<Vec<MdbNla>> for MdbBuffer<&'buffer T> {
+    fn parse(&self) -> Result<Vec<MdbNla>> {
+        let mut nlas = vec![];
+        for nla_buf in self.nlas() {
+            nlas.push(nla_buf?.parse()?);
+        }
+        Ok(nlas)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use constants::*;
+    use std::net::{IpAddr, Ipv4Addr};
+    use {MdbEntry, MdbEntryAttrNla, MdbEntryNla, MdbGroupNla, MdbRouterNla, MdbRouterPortNla};
+
+    #[cfg_attr(nightly, rustfmt::skip)]
+    static MDB_DUMP: [u8; 84] = [
+        0x07, // address family = AF_BRIDGE
+        0x00, 0x00, 0x00, // padding
+        0x03, 0x00, 0x00, 0x00, // bridge index = 3
+
+        // nlas
+        0x30, 0x00, // L = 48
+        0x01, 0x00, // T = 1 (MDBA_MDB)
+            0x2c, 0x00, // L = 44
+            0x01, 0x00, // T = 1 (MDBA_MDB_ENTRY)
+                0x28, 0x00, // L = 40
+                0x01, 0x00, // T = 1 (MDBA_MDB_ENTRY_INFO)
+                0x04, 0x00, 0x00, 0x00, // port index = 4
+                0x00, // state = MDB_TEMPORARY
+                0x00, // flags
+                0x00, 0x00, // vid = 0
+                0xef, 0x01, 0x01, 0x01, // group = 239.1.1.1
+                0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00,
+                0x08, 0x00, // proto = ETH_P_IP
+                0x00, 0x00, // padding
+
+                    0x08, 0x00, // L = 8
+                    0x01, 0x00, // T = 1 (MDBA_MDB_EATTR_TIMER)
+                    0xa8, 0x61, 0x00, 0x00, // V = 25000
+
+        0x1c, 0x00, // L = 28
+        0x02, 0x00, // T = 2 (MDBA_ROUTER)
+            0x18, 0x00, // L = 24
+            0x01, 0x00, // T = 1 (MDBA_ROUTER_PORT)
+            0x05, 0x00, 0x00, 0x00, // port index = 5
+
+                0x08, 0x00, // L = 8
+                0x01, 0x00, // T = 1 (MDBA_ROUTER_PATTR_TIMER)
+                0x00, 0x00, 0x00, 0x00, // V = 0
+
+                0x05, 0x00, // L = 5
+                0x02, 0x00, // T = 2 (MDBA_ROUTER_PATTR_TYPE)
+                0x01, // V = 1
+                0x00, 0x00, 0x00, // padding
+    ];
+
+    lazy_static! {
+        static ref MDB_DUMP_MESSAGE: MdbMessage = MdbMessage {
+            header: MdbHeader {
+                family: AF_BRIDGE as u8,
+                ifindex: 3,
+            },
+            nlas: vec![
+                MdbNla::Mdb(vec![MdbGroupNla::Entry(vec![MdbEntryNla::Info(
+                    MdbEntry::new(4, IpAddr::V4(Ipv4Addr::new(239, 1, 1, 1))),
+                    vec![MdbEntryAttrNla::Timer(25000)],
+                )])]),
+                MdbNla::Router(vec![MdbRouterNla::Port(
+                    5,
+                    vec![MdbRouterPortNla::Timer(0), MdbRouterPortNla::Type(1)],
+                )]),
+            ],
+        };
+    }
+
+    #[test]
+    fn parse_mdb_dump() {
+        let buffer = MdbBuffer::new(&MDB_DUMP);
+        let msg = <MdbBuffer<_> as Parseable<MdbMessage>>::parse(&buffer).unwrap();
+        assert_eq!(msg, *MDB_DUMP_MESSAGE);
+    }
+
+    #[test]
+    fn emit_mdb_dump() {
+        let mut buf = vec![0; 84];
+        assert_eq!(MDB_DUMP_MESSAGE.buffer_len(), 84);
+        MDB_DUMP_MESSAGE.emit(&mut buf[..]);
+        assert_eq!(&buf[..], &MDB_DUMP[..]);
+    }
+}
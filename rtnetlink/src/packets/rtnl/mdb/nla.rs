@@ -0,0 +1,483 @@
+use std::mem::size_of;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use byteorder::{BigEndian, ByteOrder, NativeEndian};
+
+use constants::*;
+use utils::{parse_u32, parse_u8};
+use {DefaultNla, Emitable, Error, Nla, NlaBuffer, NlasIterator, Parseable, Result};
+
+const MDB_ENTRY_LEN: usize = 28;
+
+/// Multicast group membership of a bridge port (`struct br_mdb_entry`)
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct MdbEntry {
+    /// Index of the bridge port
+    pub ifindex: u32,
+    /// `MDB_TEMPORARY` or `MDB_PERMANENT`
+    pub state: u8,
+    /// A combination of the `MDB_FLAGS_*` flags
+    pub flags: u8,
+    pub vid: u16,
+    /// Address of the multicast group. IPv4 addresses only use the first four bytes.
+    pub address: [u8; 16],
+    /// Protocol of the group address: `ETH_P_IP` or `ETH_P_IPV6`
+    pub proto: u16,
+}
+
+impl MdbEntry {
+    pub fn new(ifindex: u32, group: IpAddr) -> Self {
+        let mut entry = MdbEntry {
+            ifindex,
+            ..Default::default()
+        };
+        match group {
+            IpAddr::V4(ip) => {
+                entry.address[..4].copy_from_slice(&ip.octets());
+                entry.proto = ETH_P_IP;
+            }
+            IpAddr::V6(ip) => {
+                entry.address.copy_from_slice(&ip.octets());
+                entry.proto = ETH_P_IPV6;
+            }
+        }
+        entry
+    }
+
+    /// Address of the multicast group, or `None` if the protocol is neither IPv4 nor IPv6
+    pub fn group(&self) -> Option<IpAddr> {
+        match self.proto {
+            ETH_P_IP => {
+                let mut octets = [0; 4];
+                octets.copy_from_slice(&self.address[..4]);
+                Some(IpAddr::V4(Ipv4Addr::from(octets)))
+            }
+            ETH_P_IPV6 => Some(IpAddr::V6(Ipv6Addr::from(self.address))),
+            _ => None,
+        }
+    }
+
+    pub fn is_permanent(&self) -> bool {
+        self.state == MDB_PERMANENT
+    }
+
+    fn from_bytes(buf: &[u8]) -> Result<Self> {
+        if buf.len() != MDB_ENTRY_LEN {
+            return Err(Error::MalformedNlaValue);
+        }
+        let mut address = [0; 16];
+        address.copy_from_slice(&buf[8..24]);
+        Ok(MdbEntry {
+            ifindex: NativeEndian::read_u32(&buf[0..4]),
+            state: buf[4],
+            flags: buf[5],
+            vid: NativeEndian::read_u16(&buf[6..8]),
+            address,
+            // the protocol is in network byte order
+            proto: BigEndian::read_u16(&buf[24..26]),
+        })
+    }
+
+    fn emit(&self, buf: &mut [u8]) {
+        NativeEndian::write_u32(&mut buf[0..4], self.ifindex);
+        buf[4] = self.state;
+        buf[5] = self.flags;
+        NativeEndian::write_u16(&mut buf[6..8], self.vid);
+        buf[8..24].copy_from_slice(&self.address[..]);
+        BigEndian::write_u16(&mut buf[24..26], self.proto);
+        // padding
+        buf[26] = 0;
+        buf[27] = 0;
+    }
+}
+
+/// Attributes of the bridge multicast database messages.
+///
+/// Messages sent by the kernel carry `MDBA_MDB` and `MDBA_ROUTER` attributes, while requests
+/// carry a `MDBA_SET_ENTRY` attribute. Since `MDBA_SET_ENTRY` and `MDBA_MDB` share the same
+/// value, the former is never produced by the parser.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum MdbNla {
+    Unspec(Vec<u8>),
+    /// Multicast groups of the bridge
+    Mdb(Vec<MdbGroupNla>),
+    /// Multicast router ports of the bridge
+    Router(Vec<MdbRouterNla>),
+    /// Group membership to add or delete
+    SetEntry(MdbEntry),
+    Other(DefaultNla),
+}
+
+impl Nla for MdbNla {
+    #[cfg_attr(nightly, rustfmt::skip)]
+    fn value_len(&self) -> usize {
+        use self::MdbNla::*;
+        match *self {
+            Unspec(ref bytes) => bytes.len(),
+            Mdb(ref nlas) => nlas.as_slice().buffer_len(),
+            Router(ref nlas) => nlas.as_slice().buffer_len(),
+            SetEntry(_) => MDB_ENTRY_LEN,
+            Other(ref nla) => nla.value_len(),
+        }
+    }
+
+    #[cfg_attr(nightly, rustfmt::skip)]
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::MdbNla::*;
+        match *self {
+            Unspec(ref bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            Mdb(ref nlas) => nlas.as_slice().emit(buffer),
+            Router(ref nlas) => nlas.as_slice().emit(buffer),
+            SetEntry(ref entry) => entry.emit(buffer),
+            Other(ref nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::MdbNla::*;
+        match *self {
+            Unspec(_) => MDBA_UNSPEC,
+            Mdb(_) => MDBA_MDB,
+            Router(_) => MDBA_ROUTER,
+            SetEntry(_) => MDBA_SET_ENTRY,
+            Other(ref nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<MdbNla> for NlaBuffer<&'buffer T> {
+    fn parse(&self) -> Result<MdbNla> {
+        use self::MdbNla::*;
+        let payload = self.value();
+        Ok(match self.kind() {
+            MDBA_UNSPEC => Unspec(payload.to_vec()),
+            MDBA_MDB => {
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    nlas.push(<NlaBuffer<_> as Parseable<MdbGroupNla>>::parse(&nla?)?);
+                }
+                Mdb(nlas)
+            }
+            MDBA_ROUTER => {
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    nlas.push(<NlaBuffer<_> as Parseable<MdbRouterNla>>::parse(&nla?)?);
+                }
+                Router(nlas)
+            }
+            _ => Other(<Self as Parseable<DefaultNla>>::parse(self)?),
+        })
+    }
+}
+
+/// Attributes of the `MDBA_MDB` attribute
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum MdbGroupNla {
+    Unspec(Vec<u8>),
+    /// A multicast group, with one `MdbEntryNla::Info` attribute per member port
+    Entry(Vec<MdbEntryNla>),
+    Other(DefaultNla),
+}
+
+impl Nla for MdbGroupNla {
+    #[cfg_attr(nightly, rustfmt::skip)]
+    fn value_len(&self) -> usize {
+        use self::MdbGroupNla::*;
+        match *self {
+            Unspec(ref bytes) => bytes.len(),
+            Entry(ref nlas) => nlas.as_slice().buffer_len(),
+            Other(ref nla) => nla.value_len(),
+        }
+    }
+
+    #[cfg_attr(nightly, rustfmt::skip)]
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::MdbGroupNla::*;
+        match *self {
+            Unspec(ref bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            Entry(ref nlas) => nlas.as_slice().emit(buffer),
+            Other(ref nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::MdbGroupNla::*;
+        match *self {
+            Unspec(_) => MDBA_MDB_UNSPEC,
+            Entry(_) => MDBA_MDB_ENTRY,
+            Other(ref nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<MdbGroupNla> for NlaBuffer<&'buffer T> {
+    fn parse(&self) -> Result<MdbGroupNla> {
+        use self::MdbGroupNla::*;
+        let payload = self.value();
+        Ok(match self.kind() {
+            MDBA_MDB_UNSPEC => Unspec(payload.to_vec()),
+            MDBA_MDB_ENTRY => {
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    nlas.push(<NlaBuffer<_> as Parseable<MdbEntryNla>>::parse(&nla?)?);
+                }
+                Entry(nlas)
+            }
+            _ => Other(<Self as Parseable<DefaultNla>>::parse(self)?),
+        })
+    }
+}
+
+/// Attributes of the `MDBA_MDB_ENTRY` attribute
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum MdbEntryNla {
+    Unspec(Vec<u8>),
+    /// Membership of a port, followed by its attributes
+    Info(MdbEntry, Vec<MdbEntryAttrNla>),
+    Other(DefaultNla),
+}
+
+impl Nla for MdbEntryNla {
+    #[cfg_attr(nightly, rustfmt::skip)]
+    fn value_len(&self) -> usize {
+        use self::MdbEntryNla::*;
+        match *self {
+            Unspec(ref bytes) => bytes.len(),
+            Info(_, ref nlas) => MDB_ENTRY_LEN + nlas.as_slice().buffer_len(),
+            Other(ref nla) => nla.value_len(),
+        }
+    }
+
+    #[cfg_attr(nightly, rustfmt::skip)]
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::MdbEntryNla::*;
+        match *self {
+            Unspec(ref bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            Info(ref entry, ref nlas) => {
+                entry.emit(&mut buffer[..MDB_ENTRY_LEN]);
+                nlas.as_slice().emit(&mut buffer[MDB_ENTRY_LEN..]);
+            }
+            Other(ref nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::MdbEntryNla::*;
+        match *self {
+            Unspec(_) => MDBA_MDB_ENTRY_UNSPEC,
+            Info(_, _) => MDBA_MDB_ENTRY_INFO,
+            Other(ref nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<MdbEntryNla> for NlaBuffer<&'buffer T> {
+    fn parse(&self) -> Result<MdbEntryNla> {
+        use self::MdbEntryNla::*;
+        let payload = self.value();
+        Ok(match self.kind() {
+            MDBA_MDB_ENTRY_UNSPEC => Unspec(payload.to_vec()),
+            MDBA_MDB_ENTRY_INFO => {
+                if payload.len() < MDB_ENTRY_LEN {
+                    return Err(Error::MalformedNlaValue);
+                }
+                let entry = MdbEntry::from_bytes(&payload[..MDB_ENTRY_LEN])?;
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(&payload[MDB_ENTRY_LEN..]) {
+                    nlas.push(<NlaBuffer<_> as Parseable<MdbEntryAttrNla>>::parse(&nla?)?);
+                }
+                Info(entry, nlas)
+            }
+            _ => Other(<Self as Parseable<DefaultNla>>::parse(self)?),
+        })
+    }
+}
+
+/// Attributes that follow a group membership in the `MDBA_MDB_ENTRY_INFO` attribute
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum MdbEntryAttrNla {
+    Unspec(Vec<u8>),
+    /// Remaining time before the membership expires, in hundredths of seconds
+    Timer(u32),
+    /// `MCAST_EXCLUDE` or `MCAST_INCLUDE`
+    GroupMode(u8),
+    /// Origin of the membership, one of the `RTPROT_*` constants
+    Protocol(u8),
+    Other(DefaultNla),
+}
+
+impl Nla for MdbEntryAttrNla {
+    #[cfg_attr(nightly, rustfmt::skip)]
+    fn value_len(&self) -> usize {
+        use self::MdbEntryAttrNla::*;
+        match *self {
+            Unspec(ref bytes) => bytes.len(),
+            Timer(_) => size_of::<u32>(),
+            GroupMode(_)
+                | Protocol(_)
+                => size_of::<u8>(),
+            Other(ref nla) => nla.value_len(),
+        }
+    }
+
+    #[cfg_attr(nightly, rustfmt::skip)]
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::MdbEntryAttrNla::*;
+        match *self {
+            Unspec(ref bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            Timer(ref value) => NativeEndian::write_u32(buffer, *value),
+            GroupMode(ref value)
+                | Protocol(ref value)
+                => buffer[0] = *value,
+            Other(ref nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::MdbEntryAttrNla::*;
+        match *self {
+            Unspec(_) => MDBA_MDB_EATTR_UNSPEC,
+            Timer(_) => MDBA_MDB_EATTR_TIMER,
+            GroupMode(_) => MDBA_MDB_EATTR_GROUP_MODE,
+            Protocol(_) => MDBA_MDB_EATTR_RTPROT,
+            Other(ref nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<MdbEntryAttrNla> for NlaBuffer<&'buffer T> {
+    fn parse(&self) -> Result<MdbEntryAttrNla> {
+        use self::MdbEntryAttrNla::*;
+        let payload = self.value();
+        Ok(match self.kind() {
+            MDBA_MDB_EATTR_UNSPEC => Unspec(payload.to_vec()),
+            MDBA_MDB_EATTR_TIMER => Timer(parse_u32(payload)?),
+            MDBA_MDB_EATTR_GROUP_MODE => GroupMode(parse_u8(payload)?),
+            MDBA_MDB_EATTR_RTPROT => Protocol(parse_u8(payload)?),
+            _ => Other(<Self as Parseable<DefaultNla>>::parse(self)?),
+        })
+    }
+}
+
+/// Attributes of the `MDBA_ROUTER` attribute
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum MdbRouterNla {
+    Unspec(Vec<u8>),
+    /// Index of a multicast router port, followed by its attributes. Kernels older than 4.13
+    /// don't send any attribute.
+    Port(u32, Vec<MdbRouterPortNla>),
+    Other(DefaultNla),
+}
+
+impl Nla for MdbRouterNla {
+    #[cfg_attr(nightly, rustfmt::skip)]
+    fn value_len(&self) -> usize {
+        use self::MdbRouterNla::*;
+        match *self {
+            Unspec(ref bytes) => bytes.len(),
+            Port(_, ref nlas) => size_of::<u32>() + nlas.as_slice().buffer_len(),
+            Other(ref nla) => nla.value_len(),
+        }
+    }
+
+    #[cfg_attr(nightly, rustfmt::skip)]
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::MdbRouterNla::*;
+        match *self {
+            Unspec(ref bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            Port(ref index, ref nlas) => {
+                NativeEndian::write_u32(&mut buffer[..4], *index);
+                nlas.as_slice().emit(&mut buffer[4..]);
+            }
+            Other(ref nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::MdbRouterNla::*;
+        match *self {
+            Unspec(_) => MDBA_ROUTER_UNSPEC,
+            Port(_, _) => MDBA_ROUTER_PORT,
+            Other(ref nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<MdbRouterNla> for NlaBuffer<&'buffer T> {
+    fn parse(&self) -> Result<MdbRouterNla> {
+        use self::MdbRouterNla::*;
+        let payload = self.value();
+        Ok(match self.kind() {
+            MDBA_ROUTER_UNSPEC => Unspec(payload.to_vec()),
+            MDBA_ROUTER_PORT => {
+                if payload.len() < 4 {
+                    return Err(Error::MalformedNlaValue);
+                }
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(&payload[4..]) {
+                    nlas.push(<NlaBuffer<_> as Parseable<MdbRouterPortNla>>::parse(&nla?)?);
+                }
+                Port(NativeEndian::read_u32(&payload[..4]), nlas)
+            }
+            _ => Other(<Self as Parseable<DefaultNla>>::parse(self)?),
+        })
+    }
+}
+
+/// Attributes that follow the port index in the `MDBA_ROUTER_PORT` attribute
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum MdbRouterPortNla {
+    Unspec(Vec<u8>),
+    /// Remaining time before the port stops being a router port, in hundredths of seconds
+    Timer(u32),
+    /// Multicast router type of the port (the `multicast_router` option of the port)
+    Type(u8),
+    Other(DefaultNla),
+}
+
+impl Nla for MdbRouterPortNla {
+    #[cfg_attr(nightly, rustfmt::skip)]
+    fn value_len(&self) -> usize {
+        use self::MdbRouterPortNla::*;
+        match *self {
+            Unspec(ref bytes) => bytes.len(),
+            Timer(_) => size_of::<u32>(),
+            Type(_) => size_of::<u8>(),
+            Other(ref nla) => nla.value_len(),
+        }
+    }
+
+    #[cfg_attr(nightly, rustfmt::skip)]
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::MdbRouterPortNla::*;
+        match *self {
+            Unspec(ref bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            Timer(ref value) => NativeEndian::write_u32(buffer, *value),
+            Type(ref value) => buffer[0] = *value,
+            Other(ref nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::MdbRouterPortNla::*;
+        match *self {
+            Unspec(_) => MDBA_ROUTER_PATTR_UNSPEC,
+            Timer(_) => MDBA_ROUTER_PATTR_TIMER,
+            Type(_) => MDBA_ROUTER_PATTR_TYPE,
+            Other(ref nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<MdbRouterPortNla> for NlaBuffer<&'buffer T> {
+    fn parse(&self) -> Result<MdbRouterPortNla> {
+        use self::MdbRouterPortNla::*;
+        let payload = self.value();
+        Ok(match self.kind() {
+            MDBA_ROUTER_PATTR_UNSPEC => Unspec(payload.to_vec()),
+            MDBA_ROUTER_PATTR_TIMER => Timer(parse_u32(payload)?),
+            MDBA_ROUTER_PATTR_TYPE => Type(parse_u8(payload)?),
+            _ => Other(<Self as Parseable<DefaultNla>>::parse(self)?),
+        })
+    }
+}
@@ -16,6 +16,20 @@ pub enum RtnlMessage {
     NewAddress(AddressMessage),
     DelAddress(AddressMessage),
     GetAddress(AddressMessage),
+    NewRoute(RouteMessage),
+    DelRoute(RouteMessage),
+    GetRoute(RouteMessage),
+    NewNeighbour(NeighbourMessage),
+    DelNeighbour(NeighbourMessage),
+    GetNeighbour(NeighbourMessage),
+    NewRule(RuleMessage),
+    DelRule(RuleMessage),
+    GetRule(RuleMessage),
+    NewMdb(MdbMessage),
+    DelMdb(MdbMessage),
+    GetMdb(MdbMessage),
+    NewStats(StatsMessage),
+    GetStats(StatsMessage),
     Other(Vec<u8>),
 }
 
@@ -107,4 +121,116 @@ impl RtnlMessage {
             false
         }
     }
+
+    pub fn is_new_route(&self) -> bool {
+        if let RtnlMessage::NewRoute(_) = *self {
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn is_del_route(&self) -> bool {
+        if let RtnlMessage::DelRoute(_) = *self {
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn is_get_route(&self) -> bool {
+        if let RtnlMessage::GetRoute(_) = *self {
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn is_new_neighbour(&self) -> bool {
+        if let RtnlMessage::NewNeighbour(_) = *self {
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn is_del_neighbour(&self) -> bool {
+        if let RtnlMessage::DelNeighbour(_) = *self {
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn is_get_neighbour(&self) -> bool {
+        if let RtnlMessage::GetNeighbour(_) = *self {
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn is_new_rule(&self) -> bool {
+        if let RtnlMessage::NewRule(_) = *self {
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn is_del_rule(&self) -> bool {
+        if let RtnlMessage::DelRule(_) = *self {
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn is_get_rule(&self) -> bool {
+        if let RtnlMessage::GetRule(_) = *self {
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn is_new_mdb(&self) -> bool {
+        if let RtnlMessage::NewMdb(_) = *self {
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn is_del_mdb(&self) -> bool {
+        if let RtnlMessage::DelMdb(_) = *self {
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn is_get_mdb(&self) -> bool {
+        if let RtnlMessage::GetMdb(_) = *self {
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn is_new_stats(&self) -> bool {
+        if let RtnlMessage::NewStats(_) = *self {
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn is_get_stats(&self) -> bool {
+        if let RtnlMessage::GetStats(_) = *self {
+            true
+        } else {
+            false
+        }
+    }
 }
@@ -1,10 +1,18 @@
 mod address;
 mod link;
+mod mdb;
+mod neighbour;
 mod route;
+mod rule;
+mod stats;
 
 pub use self::address::*;
 pub use self::link::*;
+pub use self::mdb::*;
+pub use self::neighbour::*;
 pub use self::route::*;
+pub use self::rule::*;
+pub use self::stats::*;
 
 mod message;
 pub use self::message::*;
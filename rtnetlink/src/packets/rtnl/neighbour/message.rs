@@ -0,0 +1,154 @@
+use super::{NeighbourBuffer, NeighbourNla};
+use {Emitable, Parseable, Result, NEIGHBOUR_HEADER_LEN};
+
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct NeighbourMessage {
+    pub header: NeighbourHeader,
+    pub nlas: Vec<NeighbourNla>,
+}
+
+/// Header of the neighbour messages (`struct ndmsg`)
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct NeighbourHeader {
+    /// Address family. `AF_BRIDGE` for bridge and VXLAN forwarding database entries.
+    pub family: u8,
+    /// Index of the link the neighbour is attached to
+    pub ifindex: u32,
+    /// A combination of `NUD_*` states
+    pub state: u16,
+    /// A combination of `NTF_*` flags
+    pub flags: u8,
+    /// Type of the neighbour, one of the `RTN_*` route types
+    pub ntype: u8,
+}
+
+impl Emitable for NeighbourHeader {
+    fn buffer_len(&self) -> usize {
+        NEIGHBOUR_HEADER_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut packet = NeighbourBuffer::new(buffer);
+        packet.set_family(self.family);
+        packet.set_ifindex(self.ifindex);
+        packet.set_state(self.state);
+        packet.set_flags(self.flags);
+        packet.set_ntype(self.ntype);
+    }
+}
+
+impl Emitable for NeighbourMessage {
+    fn buffer_len(&self) -> usize {
+        self.header.buffer_len() + self.nlas.as_slice().buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        self.header.emit(buffer);
+        self.nlas
+            .as_slice()
+            .emit(&mut buffer[self.header.buffer_len()..]);
+    }
+}
+
+impl<T: AsRef<[u8]>> Parseable<NeighbourHeader> for NeighbourBuffer<T> {
+    fn parse(&self) -> Result<NeighbourHeader> {
+        Ok(NeighbourHeader {
+            family: self.family(),
+            ifindex: self.ifindex(),
+            state: self.state(),
+            flags: self.flags(),
+            ntype: self.ntype(),
+        })
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + 'buffer> Parseable<NeighbourMessage>
+    for NeighbourBuffer<&'buffer T>
+{
+    fn parse(&self) -> Result<NeighbourMessage> {
+        Ok(NeighbourMessage {
+            header: self.parse()?,
+            nlas: self.parse()?,
+        })
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + 'buffer> Parseable<Vec<NeighbourNla>>
+    for NeighbourBuffer<&'buffer T>
+{
+    fn parse(&self) -> Result<Vec<NeighbourNla>> {
+        let mut nlas = vec![];
+        for nla_buf in self.nlas() {
+            nlas.push(nla_buf?.parse()?);
+        }
+        Ok(nlas)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use constants::*;
+
+    #[cfg_attr(nightly, rustfmt::skip)]
+    static VXLAN_FDB: [u8; 48] = [
+        0x07, // address family = AF_BRIDGE
+        0x00, 0x00, 0x00, // padding
+        0x09, 0x00, 0x00, 0x00, // interface index = 9
+        0x80, 0x00, // state = NUD_PERMANENT
+        0x02, // flags = NTF_SELF
+        0x00, // type
+
+        // nlas
+        0x0a, 0x00, // L = 10
+        0x02, 0x00, // T = 2 (NDA_LLADDR)
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // V = 00:00:00:00:00:00
+        0x00, 0x00, // padding
+
+        0x08, 0x00, // L = 8
+        0x01, 0x00, // T = 1 (NDA_DST)
+        0x0a, 0x00, 0x00, 0x02, // V = 10.0.0.2
+
+        0x08, 0x00, // L = 8
+        0x07, 0x00, // T = 7 (NDA_VNI)
+        0x64, 0x00, 0x00, 0x00, // V = 100
+
+        0x06, 0x00, // L = 6
+        0x06, 0x00, // T = 6 (NDA_PORT)
+        0x12, 0xb5, // V = 4789
+        0x00, 0x00, // padding
+    ];
+
+    lazy_static! {
+        static ref VXLAN_FDB_MESSAGE: NeighbourMessage = NeighbourMessage {
+            header: NeighbourHeader {
+                family: AF_BRIDGE as u8,
+                ifindex: 9,
+                state: NUD_PERMANENT,
+                flags: NTF_SELF,
+                ntype: 0,
+            },
+            nlas: vec![
+                NeighbourNla::LinkLayerAddress(vec![0; 6]),
+                NeighbourNla::Destination(vec![10, 0, 0, 2]),
+                NeighbourNla::Vni(100),
+                NeighbourNla::Port(4789),
+            ],
+        };
+    }
+
+    #[test]
+    fn parse_vxlan_fdb() {
+        let buffer = NeighbourBuffer::new(&VXLAN_FDB);
+        let msg = <NeighbourBuffer<_> as Parseable<NeighbourMessage>>::parse(&buffer).unwrap();
+        assert_eq!(msg, *VXLAN_FDB_MESSAGE);
+    }
+
+    #[test]
+    fn emit_vxlan_fdb() {
+        let mut buf = vec![0; 48];
+        assert_eq!(VXLAN_FDB_MESSAGE.buffer_len(), 48);
+        VXLAN_FDB_MESSAGE.emit(&mut buf[..]);
+        assert_eq!(&buf[..], &VXLAN_FDB[..]);
+    }
+}
@@ -0,0 +1,153 @@
+use byteorder::{BigEndian, ByteOrder, NativeEndian};
+use std::mem::size_of;
+
+use utils::{parse_i32, parse_u16, parse_u16_be, parse_u32, parse_u8};
+use {DefaultNla, NativeNla, Nla, NlaBuffer, Parseable, Result};
+
+use constants::*;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum NeighbourNla {
+    Unspec(Vec<u8>),
+    /// Network address of the neighbour. For VXLAN forwarding database entries, this is the IP
+    /// address of the remote VTEP.
+    Destination(Vec<u8>),
+    /// Link layer address of the neighbour
+    LinkLayerAddress(Vec<u8>),
+    CacheInfo(NeighbourCacheInfo),
+    Probes(u32),
+    Vlan(u16),
+    /// UDP destination port of a VXLAN forwarding database entry
+    Port(u16),
+    /// VXLAN network identifier of a VXLAN forwarding database entry
+    Vni(u32),
+    /// Index of the outgoing interface of a VXLAN forwarding database entry
+    IfIndex(u32),
+    /// Index of the master device (the bridge for instance)
+    Master(u32),
+    LinkNetNsId(i32),
+    SourceVni(u32),
+    Protocol(u8),
+    Other(DefaultNla),
+}
+
+impl Nla for NeighbourNla {
+    #[cfg_attr(nightly, rustfmt::skip)]
+    fn value_len(&self) -> usize {
+        use self::NeighbourNla::*;
+        match *self {
+            // Vec<u8>
+            Unspec(ref bytes)
+                | Destination(ref bytes)
+                | LinkLayerAddress(ref bytes) => bytes.len(),
+
+            // u8
+            Protocol(_) => size_of::<u8>(),
+
+            // u16
+            Vlan(_)
+                | Port(_) => size_of::<u16>(),
+
+            // u32 and i32
+            Probes(_)
+                | Vni(_)
+                | IfIndex(_)
+                | Master(_)
+                | LinkNetNsId(_)
+                | SourceVni(_) => size_of::<u32>(),
+
+            // Native
+            CacheInfo(_) => size_of::<NeighbourCacheInfo>(),
+
+            // Defaults
+            Other(ref attr)  => attr.value_len(),
+        }
+    }
+
+    #[cfg_attr(nightly, rustfmt::skip)]
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::NeighbourNla::*;
+        match *self {
+            // Vec<u8>
+            Unspec(ref bytes)
+                | Destination(ref bytes)
+                | LinkLayerAddress(ref bytes) => buffer.copy_from_slice(bytes.as_slice()),
+
+            // u8
+            Protocol(ref value) => buffer[0] = *value,
+
+            // u16
+            Vlan(ref value) => NativeEndian::write_u16(buffer, *value),
+            // the port is in network byte order
+            Port(ref value) => BigEndian::write_u16(buffer, *value),
+
+            // u32 and i32
+            Probes(ref value)
+                | Vni(ref value)
+                | IfIndex(ref value)
+                | Master(ref value)
+                | SourceVni(ref value) => NativeEndian::write_u32(buffer, *value),
+            LinkNetNsId(ref value) => NativeEndian::write_i32(buffer, *value),
+
+            // Native
+            CacheInfo(ref cacheinfo) => cacheinfo.to_bytes(buffer),
+
+            // Default
+            Other(ref attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::NeighbourNla::*;
+        match *self {
+            Unspec(_) => NDA_UNSPEC,
+            Destination(_) => NDA_DST,
+            LinkLayerAddress(_) => NDA_LLADDR,
+            CacheInfo(_) => NDA_CACHEINFO,
+            Probes(_) => NDA_PROBES,
+            Vlan(_) => NDA_VLAN,
+            Port(_) => NDA_PORT,
+            Vni(_) => NDA_VNI,
+            IfIndex(_) => NDA_IFINDEX,
+            Master(_) => NDA_MASTER,
+            LinkNetNsId(_) => NDA_LINK_NETNSID,
+            SourceVni(_) => NDA_SRC_VNI,
+            Protocol(_) => NDA_PROTOCOL,
+            Other(ref nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<NeighbourNla> for NlaBuffer<&'buffer T> {
+    fn parse(&self) -> Result<NeighbourNla> {
+        use self::NeighbourNla::*;
+        let payload = self.value();
+        Ok(match self.kind() {
+            NDA_UNSPEC => Unspec(payload.to_vec()),
+            NDA_DST => Destination(payload.to_vec()),
+            NDA_LLADDR => LinkLayerAddress(payload.to_vec()),
+            NDA_CACHEINFO => CacheInfo(NeighbourCacheInfo::from_bytes(payload)?),
+            NDA_PROBES => Probes(parse_u32(payload)?),
+            NDA_VLAN => Vlan(parse_u16(payload)?),
+            NDA_PORT => Port(parse_u16_be(payload)?),
+            NDA_VNI => Vni(parse_u32(payload)?),
+            NDA_IFINDEX => IfIndex(parse_u32(payload)?),
+            NDA_MASTER => Master(parse_u32(payload)?),
+            NDA_LINK_NETNSID => LinkNetNsId(parse_i32(payload)?),
+            NDA_SRC_VNI => SourceVni(parse_u32(payload)?),
+            NDA_PROTOCOL => Protocol(parse_u8(payload)?),
+            _ => Other(<Self as Parseable<DefaultNla>>::parse(self)?),
+        })
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct NeighbourCacheInfo {
+    pub confirmed: u32,
+    pub used: u32,
+    pub updated: u32,
+    pub refcnt: u32,
+}
+
+impl NativeNla for NeighbourCacheInfo {}
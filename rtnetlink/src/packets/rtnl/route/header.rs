@@ -350,6 +350,22 @@ pub struct RouteHeader {
     pub flags: RouteFlags,
 }
 
+impl Default for RouteHeader {
+    fn default() -> Self {
+        RouteHeader {
+            address_family: 0,
+            destination_length: 0,
+            source_length: 0,
+            tos: 0,
+            table: RouteTable::Unspec,
+            protocol: RouteProtocol::Unspec,
+            scope: RouteScope::Universe,
+            kind: RouteKind::Unspec,
+            flags: RouteFlags::new(),
+        }
+    }
+}
+
 impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<RouteHeader> for RouteBuffer<&'a T> {
     fn parse(&self) -> Result<RouteHeader> {
         Ok(RouteHeader {
@@ -1,7 +1,7 @@
 use super::{RouteBuffer, RouteHeader, RouteNla};
 use {Emitable, Parseable, Result};
 
-#[derive(Debug, PartialEq, Eq, Clone)]
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
 pub struct RouteMessage {
     pub header: RouteHeader,
     pub nlas: Vec<RouteNla>,
@@ -14,7 +14,9 @@ impl Emitable for RouteMessage {
 
     fn emit(&self, buffer: &mut [u8]) {
         self.header.emit(buffer);
-        self.nlas.as_slice().emit(buffer);
+        self.nlas
+            .as_slice()
+            .emit(&mut buffer[self.header.buffer_len()..]);
     }
 }
 
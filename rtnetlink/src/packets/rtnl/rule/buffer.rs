@@ -0,0 +1,122 @@
+use byteorder::{ByteOrder, NativeEndian};
+
+use {Field, Index, NlaBuffer, NlasIterator, Rest, Result};
+
+const FAMILY: Index = 0;
+const DST_LEN: Index = 1;
+const SRC_LEN: Index = 2;
+const TOS: Index = 3;
+const TABLE: Index = 4;
+// 5 and 6 are reserved
+const ACTION: Index = 7;
+const FLAGS: Field = 8..12;
+const ATTRIBUTES: Rest = 12..;
+
+pub const RULE_HEADER_LEN: usize = ATTRIBUTES.start;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct RuleBuffer<T> {
+    buffer: T,
+}
+
+impl<T: AsRef<[u8]>> RuleBuffer<T> {
+    pub fn new(buffer: T) -> RuleBuffer<T> {
+        RuleBuffer { buffer }
+    }
+
+    pub fn into_inner(self) -> T {
+        self.buffer
+    }
+
+    pub fn family(&self) -> u8 {
+        let data = self.buffer.as_ref();
+        data[FAMILY]
+    }
+
+    pub fn dst_len(&self) -> u8 {
+        let data = self.buffer.as_ref();
+        data[DST_LEN]
+    }
+
+    pub fn src_len(&self) -> u8 {
+        let data = self.buffer.as_ref();
+        data[SRC_LEN]
+    }
+
+    pub fn tos(&self) -> u8 {
+        let data = self.buffer.as_ref();
+        data[TOS]
+    }
+
+    pub fn table(&self) -> u8 {
+        let data = self.buffer.as_ref();
+        data[TABLE]
+    }
+
+    pub fn action(&self) -> u8 {
+        let data = self.buffer.as_ref();
+        data[ACTION]
+    }
+
+    pub fn flags(&self) -> u32 {
+        let data = self.buffer.as_ref();
+        NativeEndian::read_u32(&data[FLAGS])
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> RuleBuffer<&'a T> {
+    /// Return a pointer to the payload.
+    pub fn payload(&self) -> &'a [u8] {
+        let data = self.buffer.as_ref();
+        &data[ATTRIBUTES]
+    }
+
+    pub fn nlas(&self) -> impl Iterator<Item = Result<NlaBuffer<&'a [u8]>>> {
+        NlasIterator::new(self.payload())
+    }
+}
+
+impl<T: AsRef<[u8]> + AsMut<[u8]> + ?Sized> RuleBuffer<&mut T> {
+    /// Return a mutable pointer to the payload.
+    pub fn payload_mut(&mut self) -> &mut [u8] {
+        let data = self.buffer.as_mut();
+        &mut data[ATTRIBUTES]
+    }
+}
+
+impl<T: AsRef<[u8]> + AsMut<[u8]>> RuleBuffer<T> {
+    pub fn set_family(&mut self, value: u8) {
+        let data = self.buffer.as_mut();
+        data[FAMILY] = value
+    }
+
+    pub fn set_dst_len(&mut self, value: u8) {
+        let data = self.buffer.as_mut();
+        data[DST_LEN] = value
+    }
+
+    pub fn set_src_len(&mut self, value: u8) {
+        let data = self.buffer.as_mut();
+        data[SRC_LEN] = value
+    }
+
+    pub fn set_tos(&mut self, value: u8) {
+        let data = self.buffer.as_mut();
+        data[TOS] = value
+    }
+
+    pub fn set_table(&mut self, value: u8) {
+        let data = self.buffer.as_mut();
+        data[TABLE] = value
+    }
+
+    pub fn set_action(&mut self, value: u8) {
+        let data = self.buffer.as_mut();
+        data[ACTION] = value
+    }
+
+    pub fn set_flags(&mut self, value: u32) {
+        let data = self.buffer.as_mut();
+        NativeEndian::write_u32(&mut data[FLAGS], value)
+    }
+}
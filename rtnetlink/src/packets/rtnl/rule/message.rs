@@ -0,0 +1,173 @@
+use super::{RuleBuffer, RuleNla};
+use {Emitable, Parseable, Result, RULE_HEADER_LEN};
+
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct RuleMessage {
+    pub header: RuleHeader,
+    pub nlas: Vec<RuleNla>,
+}
+
+/// Header of the routing rule messages (`struct fib_rule_hdr`)
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct RuleHeader {
+    /// Address family of the rule
+    pub family: u8,
+    /// Length of the destination prefix
+    pub dst_len: u8,
+    /// Length of the source prefix
+    pub src_len: u8,
+    /// TOS matched by the rule
+    pub tos: u8,
+    /// Routing table of the rule, or `RT_TABLE_UNSPEC` if the table is above 255 (see the
+    /// `FRA_TABLE` attribute)
+    pub table: u8,
+    /// Action of the rule, one of the `FR_ACT_*` values
+    pub action: u8,
+    /// A combination of `FIB_RULE_*` flags
+    pub flags: u32,
+}
+
+impl Emitable for RuleHeader {
+    fn buffer_len(&self) -> usize {
+        RULE_HEADER_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut packet = RuleBuffer::new(buffer);
+        packet.set_family(self.family);
+        packet.set_dst_len(self.dst_len);
+        packet.set_src_len(self.src_len);
+        packet.set_tos(self.tos);
+        packet.set_table(self.table);
+        packet.set_action(self.action);
+        packet.set_flags(self.flags);
+    }
+}
+
+impl Emitable for RuleMessage {
+    fn buffer_len(&self) -> usize {
+        self.header.buffer_len() + self.nlas.as_slice().buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        self.header.emit(buffer);
+        self.nlas
+            .as_slice()
+            .emit(&mut buffer[self.header.buffer_len()..]);
+    }
+}
+
+impl<T: AsRef<[u8]>> Parseable<RuleHeader> for RuleBuffer<T> {
+    fn parse(&self) -> Result<RuleHeader> {
+        Ok(RuleHeader {
+            family: self.family(),
+            dst_len: self.dst_len(),
+            src_len: self.src_len(),
+            tos: self.tos(),
+            table: self.table(),
+            action: self.action(),
+            flags: self.flags(),
+        })
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + 'buffer> Parseable<RuleMessage> for RuleBuffer<&'buffer T> {
+    fn parse(&self) -> Result<RuleMessage> {
+        Ok(RuleMessage {
+            header: self.parse()?,
+            nlas: self.parse()?,
+        })
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + 'buffer> Parseable<Vec<RuleNla>> for RuleBuffer<&'buffer T> {
+    fn parse(&self) -> Result<Vec<RuleNla>> {
+        let mut nlas = vec![];
+        for nla_buf in self.nlas() {
+            nlas.push(nla_buf?.parse()?);
+        }
+        Ok(nlas)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use constants::*;
+    use RuleUidRange;
+
+    // ip rule add from 10.0.0.0/8 iif eth0 uidrange 0-4294967295 table 100 pref 1000
+    #[cfg_attr(nightly, rustfmt::skip)]
+    static RULE: [u8; 60] = [
+        0x02, // address family = AF_INET
+        0x00, // destination length
+        0x08, // source length
+        0x00, // tos
+        0x64, // table = 100
+        0x00, 0x00, // reserved
+        0x01, // action = FR_ACT_TO_TBL
+        0x00, 0x00, 0x00, 0x00, // flags
+
+        // nlas
+        0x08, 0x00, // L = 8
+        0x0f, 0x00, // T = 15 (FRA_TABLE)
+        0x64, 0x00, 0x00, 0x00, // V = 100
+
+        0x08, 0x00, // L = 8
+        0x06, 0x00, // T = 6 (FRA_PRIORITY)
+        0xe8, 0x03, 0x00, 0x00, // V = 1000
+
+        0x08, 0x00, // L = 8
+        0x02, 0x00, // T = 2 (FRA_SRC)
+        0x0a, 0x00, 0x00, 0x00, // V = 10.0.0.0
+
+        0x09, 0x00, // L = 9
+        0x03, 0x00, // T = 3 (FRA_IIFNAME)
+        0x65, 0x74, 0x68, 0x30, 0x00, // V = "eth0"
+        0x00, 0x00, 0x00, // padding
+
+        0x0c, 0x00, // L = 12
+        0x14, 0x00, // T = 20 (FRA_UID_RANGE)
+        0x00, 0x00, 0x00, 0x00, // start = 0
+        0xff, 0xff, 0xff, 0xff, // end = 4294967295
+    ];
+
+    lazy_static! {
+        static ref RULE_MESSAGE: RuleMessage = RuleMessage {
+            header: RuleHeader {
+                family: AF_INET as u8,
+                dst_len: 0,
+                src_len: 8,
+                tos: 0,
+                table: 100,
+                action: FR_ACT_TO_TBL,
+                flags: 0,
+            },
+            nlas: vec![
+                RuleNla::Table(100),
+                RuleNla::Priority(1000),
+                RuleNla::Source(vec![10, 0, 0, 0]),
+                RuleNla::IifName("eth0".into()),
+                RuleNla::UidRange(RuleUidRange {
+                    start: 0,
+                    end: 0xffff_ffff,
+                }),
+            ],
+        };
+    }
+
+    #[test]
+    fn parse_rule() {
+        let buffer = RuleBuffer::new(&RULE);
+        let msg = <RuleBuffer<_> as Parseable<RuleMessage>>::parse(&buffer).unwrap();
+        assert_eq!(msg, *RULE_MESSAGE);
+    }
+
+    #[test]
+    fn emit_rule() {
+        let mut buf = [0; 60];
+        assert_eq!(RULE_MESSAGE.buffer_len(), 60);
+        RULE_MESSAGE.emit(&mut buf[..]);
+        assert_eq!(&buf[..], &RULE[..]);
+    }
+}
@@ -0,0 +1,211 @@
+use byteorder::{ByteOrder, NativeEndian};
+use std::mem::size_of;
+
+use utils::{parse_string, parse_u32, parse_u64, parse_u8};
+use {DefaultNla, NativeNla, Nla, NlaBuffer, Parseable, Result};
+
+use constants::*;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum RuleNla {
+    Unspec(Vec<u8>),
+    /// Destination prefix matched by the rule
+    Destination(Vec<u8>),
+    /// Source prefix matched by the rule
+    Source(Vec<u8>),
+    /// Name of the input interface matched by the rule
+    IifName(String),
+    /// Priority of the rule to jump to, for `FR_ACT_GOTO` rules
+    Goto(u32),
+    /// Priority of the rule. Rules are evaluated by increasing priority.
+    Priority(u32),
+    FwMark(u32),
+    Flow(u32),
+    TunId(u64),
+    SuppressIfGroup(u32),
+    SuppressPrefixLen(u32),
+    /// Routing table of the rule. Unlike the table of the header, it can be above 255.
+    Table(u32),
+    FwMask(u32),
+    /// Name of the output interface matched by the rule
+    OifName(String),
+    Pad(Vec<u8>),
+    /// If not 0, the routing table is the table of the VRF device the packet goes through
+    L3MDev(u8),
+    UidRange(RuleUidRange),
+    /// Protocol that installed the rule, one of the `RTPROT_*` values
+    Protocol(u8),
+    /// IP protocol matched by the rule
+    IpProto(u8),
+    SourcePortRange(RulePortRange),
+    DestinationPortRange(RulePortRange),
+    Other(DefaultNla),
+}
+
+impl Nla for RuleNla {
+    #[cfg_attr(nightly, rustfmt::skip)]
+    fn value_len(&self) -> usize {
+        use self::RuleNla::*;
+        match *self {
+            // Vec<u8>
+            Unspec(ref bytes)
+                | Destination(ref bytes)
+                | Source(ref bytes)
+                | Pad(ref bytes) => bytes.len(),
+
+            // strings: +1 because we need to append a nul byte
+            IifName(ref string)
+                | OifName(ref string) => string.len() + 1,
+
+            // u8
+            L3MDev(_)
+                | Protocol(_)
+                | IpProto(_) => size_of::<u8>(),
+
+            // u32
+            Goto(_)
+                | Priority(_)
+                | FwMark(_)
+                | Flow(_)
+                | SuppressIfGroup(_)
+                | SuppressPrefixLen(_)
+                | Table(_)
+                | FwMask(_) => size_of::<u32>(),
+
+            // u64
+            TunId(_) => size_of::<u64>(),
+
+            // Native
+            UidRange(_) => size_of::<RuleUidRange>(),
+            SourcePortRange(_)
+                | DestinationPortRange(_) => size_of::<RulePortRange>(),
+
+            // Defaults
+            Other(ref attr) => attr.value_len(),
+        }
+    }
+
+    #[cfg_attr(nightly, rustfmt::skip)]
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::RuleNla::*;
+        match *self {
+            // Vec<u8>
+            Unspec(ref bytes)
+                | Destination(ref bytes)
+                | Source(ref bytes)
+                | Pad(ref bytes) => buffer.copy_from_slice(bytes.as_slice()),
+
+            // String
+            IifName(ref string)
+                | OifName(ref string) => {
+                    buffer[..string.len()].copy_from_slice(string.as_bytes());
+                    buffer[string.len()] = 0;
+                }
+
+            // u8
+            L3MDev(ref value)
+                | Protocol(ref value)
+                | IpProto(ref value) => buffer[0] = *value,
+
+            // u32
+            Goto(ref value)
+                | Priority(ref value)
+                | FwMark(ref value)
+                | Flow(ref value)
+                | SuppressIfGroup(ref value)
+                | SuppressPrefixLen(ref value)
+                | Table(ref value)
+                | FwMask(ref value) => NativeEndian::write_u32(buffer, *value),
+
+            // u64
+            TunId(ref value) => NativeEndian::write_u64(buffer, *value),
+
+            // Native
+            UidRange(ref range) => range.to_bytes(buffer),
+            SourcePortRange(ref range)
+                | DestinationPortRange(ref range) => range.to_bytes(buffer),
+
+            // Default
+            Other(ref attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::RuleNla::*;
+        match *self {
+            Unspec(_) => FRA_UNSPEC,
+            Destination(_) => FRA_DST,
+            Source(_) => FRA_SRC,
+            IifName(_) => FRA_IIFNAME,
+            Goto(_) => FRA_GOTO,
+            Priority(_) => FRA_PRIORITY,
+            FwMark(_) => FRA_FWMARK,
+            Flow(_) => FRA_FLOW,
+            TunId(_) => FRA_TUN_ID,
+            SuppressIfGroup(_) => FRA_SUPPRESS_IFGROUP,
+            SuppressPrefixLen(_) => FRA_SUPPRESS_PREFIXLEN,
+            Table(_) => FRA_TABLE,
+            FwMask(_) => FRA_FWMASK,
+            OifName(_) => FRA_OIFNAME,
+            Pad(_) => FRA_PAD,
+            L3MDev(_) => FRA_L3MDEV,
+            UidRange(_) => FRA_UID_RANGE,
+            Protocol(_) => FRA_PROTOCOL,
+            IpProto(_) => FRA_IP_PROTO,
+            SourcePortRange(_) => FRA_SPORT_RANGE,
+            DestinationPortRange(_) => FRA_DPORT_RANGE,
+            Other(ref nla) => nla.kind(),
+        }
+    }
+}
+
+impl<T: AsRef<[u8]> + ?Sized> Parseable<RuleNla> for NlaBuffer<&T> {
+    fn parse(&self) -> Result<RuleNla> {
+        use self::RuleNla::*;
+        let payload = self.value();
+        Ok(match self.kind() {
+            FRA_UNSPEC => Unspec(payload.to_vec()),
+            FRA_DST => Destination(payload.to_vec()),
+            FRA_SRC => Source(payload.to_vec()),
+            FRA_IIFNAME => IifName(parse_string(payload)?),
+            FRA_GOTO => Goto(parse_u32(payload)?),
+            FRA_PRIORITY => Priority(parse_u32(payload)?),
+            FRA_FWMARK => FwMark(parse_u32(payload)?),
+            FRA_FLOW => Flow(parse_u32(payload)?),
+            FRA_TUN_ID => TunId(parse_u64(payload)?),
+            FRA_SUPPRESS_IFGROUP => SuppressIfGroup(parse_u32(payload)?),
+            FRA_SUPPRESS_PREFIXLEN => SuppressPrefixLen(parse_u32(payload)?),
+            FRA_TABLE => Table(parse_u32(payload)?),
+            FRA_FWMASK => FwMask(parse_u32(payload)?),
+            FRA_OIFNAME => OifName(parse_string(payload)?),
+            FRA_PAD => Pad(payload.to_vec()),
+            FRA_L3MDEV => L3MDev(parse_u8(payload)?),
+            FRA_UID_RANGE => UidRange(RuleUidRange::from_bytes(payload)?),
+            FRA_PROTOCOL => Protocol(parse_u8(payload)?),
+            FRA_IP_PROTO => IpProto(parse_u8(payload)?),
+            FRA_SPORT_RANGE => SourcePortRange(RulePortRange::from_bytes(payload)?),
+            FRA_DPORT_RANGE => DestinationPortRange(RulePortRange::from_bytes(payload)?),
+            _ => Other(<Self as Parseable<DefaultNla>>::parse(self)?),
+        })
+    }
+}
+
+/// Range of user IDs matched by a rule (`struct fib_rule_uid_range`)
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct RuleUidRange {
+    pub start: u32,
+    pub end: u32,
+}
+
+impl NativeNla for RuleUidRange {}
+
+/// Range of ports matched by a rule (`struct fib_rule_port_range`)
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct RulePortRange {
+    pub start: u16,
+    pub end: u16,
+}
+
+impl NativeNla for RulePortRange {}
@@ -0,0 +1,77 @@
+use byteorder::{ByteOrder, NativeEndian};
+
+use {Field, Index, NlaBuffer, NlasIterator, Rest, Result};
+
+const FAMILY: Index = 0;
+const IFINDEX: Field = 4..8;
+const FILTER_MASK: Field = 8..12;
+const ATTRIBUTES: Rest = 12..;
+
+pub const STATS_HEADER_LEN: usize = ATTRIBUTES.start;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct StatsBuffer<T> {
+    buffer: T,
+}
+
+impl<T: AsRef<[u8]>> StatsBuffer<T> {
+    pub fn new(buffer: T) -> StatsBuffer<T> {
+        StatsBuffer { buffer }
+    }
+
+    pub fn into_inner(self) -> T {
+        self.buffer
+    }
+
+    pub fn family(&self) -> u8 {
+        let data = self.buffer.as_ref();
+        data[FAMILY]
+    }
+
+    pub fn ifindex(&self) -> u32 {
+        let data = self.buffer.as_ref();
+        NativeEndian::read_u32(&data[IFINDEX])
+    }
+
+    pub fn filter_mask(&self) -> u32 {
+        let data = self.buffer.as_ref();
+        NativeEndian::read_u32(&data[FILTER_MASK])
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> StatsBuffer<&'a T> {
+    /// Return a pointer to the payload.
+    pub fn payload(&self) -> &'a [u8] {
+        let data = self.buffer.as_ref();
+        &data[ATTRIBUTES]
+    }
+
+    pub fn nlas(&self) -> impl Iterator<Item = Result<NlaBuffer<&'a [u8]>>> {
+        NlasIterator::new(self.payload())
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + AsMut<[u8]> + ?Sized> StatsBuffer<&'a mut T> {
+    /// Return a mutable pointer to the payload.
+    pub fn payload_mut(&mut self) -> &mut [u8] {
+        let data = self.buffer.as_mut();
+        &mut data[ATTRIBUTES]
+    }
+}
+
+impl<T: AsRef<[u8]> + AsMut<[u8]>> StatsBuffer<T> {
+    pub fn set_family(&mut self, value: u8) {
+        let data = self.buffer.as_mut();
+        data[FAMILY] = value
+    }
+
+    pub fn set_ifindex(&mut self, value: u32) {
+        let data = self.buffer.as_mut();
+        NativeEndian::write_u32(&mut data[IFINDEX], value)
+    }
+
+    pub fn set_filter_mask(&mut self, value: u32) {
+        let data = self.buffer.as_mut();
+        NativeEndian::write_u32(&mut data[FILTER_MASK], value)
+    }
+}
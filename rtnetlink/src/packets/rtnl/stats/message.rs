@@ -0,0 +1,180 @@
+use super::{StatsBuffer, StatsNla};
+use {Emitable, Parseable, Result, STATS_HEADER_LEN};
+
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct StatsMessage {
+    pub header: StatsHeader,
+    pub nlas: Vec<StatsNla>,
+}
+
+/// Header of the link statistics messages (`struct if_stats_msg`)
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct StatsHeader {
+    /// Address family. The kernel only handles `AF_UNSPEC`.
+    pub family: u8,
+    /// Index of the link. In dump requests, 0 means all the links.
+    pub ifindex: u32,
+    /// The statistics to retrieve, as a combination of the `IFLA_STATS_FILTER_*` bits. The kernel
+    /// only includes the attributes whose bit is set in its responses.
+    pub filter_mask: u32,
+}
+
+impl Emitable for StatsHeader {
+    fn buffer_len(&self) -> usize {
+        STATS_HEADER_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut packet = StatsBuffer::new(buffer);
+        packet.set_family(self.family);
+        packet.set_ifindex(self.ifindex);
+        packet.set_filter_mask(self.filter_mask);
+    }
+}
+
+impl Emitable for StatsMessage {
+    fn buffer_len(&self) -> usize {
+        self.header.buffer_len() + self.nlas.as_slice().buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        self.header.emit(buffer);
+        self.nlas
+            .as_slice()
+            .emit(&mut buffer[self.header.buffer_len()..]);
+    }
+}
+
+impl<T: AsRef<[u8]>> Parseable<StatsHeader> for StatsBuffer<T> {
+    fn parse(&self) -> Result<StatsHeader> {
+        Ok(StatsHeader {
+            family: self.family(),
+            ifindex: self.ifindex(),
+            filter_mask: self.filter_mask(),
+        })
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + 'buffer> Parseable<StatsMessage> for StatsBuffer<&'buffer T> {
+    fn parse(&self) -> Result<StatsMessage> {
+        Ok(StatsMessage {
+            header: self.parse()?,
+            nlas: self.parse()?,
+        })
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + 'buffer> Parseable<Vec<StatsNla>> for StatsBuffer<&'buffer T> {
+    fn parse(&self) -> Result<Vec<StatsNla>> {
+        let mut nlas = vec![];
+        for nla_buf in self.nlas() {
+            nlas.push(nla_buf?.parse()?);
+        }
+        Ok(nlas)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use constants::*;
+    use LinkStats64;
+
+    #[cfg_attr(nightly, rustfmt::skip)]
+    static STATS_LO: [u8; 208] = [
+        0x00, // address family = AF_UNSPEC
+        0x00, 0x00, 0x00, // padding
+        0x01, 0x00, 0x00, 0x00, // link index = 1
+        0x01, 0x00, 0x00, 0x00, // filter mask = IFLA_STATS_FILTER_LINK_64
+
+        // nlas
+        0xc4, 0x00, // L = 196
+        0x01, 0x00, // T = 1 (IFLA_STATS_LINK_64)
+            0x06, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // rx_packets = 6
+            0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // tx_packets = 4
+            0x82, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // rx_bytes = 1410
+            0x4c, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // tx_bytes = 588
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // rx_errors = 0
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // tx_errors = 0
+            0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // rx_dropped = 1
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // tx_dropped = 0
+            0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // multicast = 2
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // collisions = 0
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // rx_length_errors = 0
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // rx_over_errors = 0
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // rx_crc_errors = 0
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // rx_frame_errors = 0
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // rx_fifo_errors = 0
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // rx_missed_errors = 0
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // tx_aborted_errors = 0
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // tx_carrier_errors = 0
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // tx_fifo_errors = 0
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // tx_heartbeat_errors = 0
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // tx_window_errors = 0
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // rx_compressed = 0
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // tx_compressed = 0
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // rx_nohandler = 0
+    ];
+
+    lazy_static! {
+        static ref STATS_LO_MESSAGE: StatsMessage = StatsMessage {
+            header: StatsHeader {
+                family: 0,
+                ifindex: 1,
+                filter_mask: IFLA_STATS_FILTER_LINK_64,
+            },
+            nlas: vec![StatsNla::Link64(LinkStats64 {
+                rx_packets: 6,
+                tx_packets: 4,
+                rx_bytes: 1410,
+                tx_bytes: 588,
+                rx_errors: 0,
+                tx_errors: 0,
+                rx_dropped: 1,
+                tx_dropped: 0,
+                multicast: 2,
+                collisions: 0,
+                rx_length_errors: 0,
+                rx_over_errors: 0,
+                rx_crc_errors: 0,
+                rx_frame_errors: 0,
+                rx_fifo_errors: 0,
+                rx_missed_errors: 0,
+                tx_aborted_errors: 0,
+                tx_carrier_errors: 0,
+                tx_fifo_errors: 0,
+                tx_heartbeat_errors: 0,
+                tx_window_errors: 0,
+                rx_compressed: 0,
+                tx_compressed: 0,
+                rx_nohandler: 0,
+            })],
+        };
+    }
+
+    #[test]
+    fn parse_stats() {
+        let buffer = StatsBuffer::new(&STATS_LO);
+        let msg = <StatsBuffer<_> as Parseable<StatsMessage>>::parse(&buffer).unwrap();
+        assert_eq!(msg, *STATS_LO_MESSAGE);
+    }
+
+    #[test]
+    fn emit_stats() {
+        let mut buf = vec![0; 208];
+        assert_eq!(STATS_LO_MESSAGE.buffer_len(), 208);
+        STATS_LO_MESSAGE.emit(&mut buf[..]);
+        assert_eq!(&buf[..], &STATS_LO[..]);
+    }
+
+    #[test]
+    fn parse_stats_with_unknown_counters() {
+        // newer kernels have more counters than `LinkStats64` (here, `rx_otherhost_dropped`)
+        let mut bytes = STATS_LO.to_vec();
+        bytes[STATS_HEADER_LEN] += 8;
+        bytes.extend_from_slice(&[0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]);
+        let buffer = StatsBuffer::new(&bytes);
+        let msg = <StatsBuffer<_> as Parseable<StatsMessage>>::parse(&buffer).unwrap();
+        assert_eq!(msg, *STATS_LO_MESSAGE);
+    }
+}
@@ -0,0 +1,8 @@
+mod buffer;
+pub use self::buffer::*;
+
+mod message;
+pub use self::message::*;
+
+mod nla;
+pub use self::nla::*;
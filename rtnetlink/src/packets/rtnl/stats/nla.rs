@@ -0,0 +1,144 @@
+use std::mem::size_of;
+
+use constants::*;
+use {
+    DefaultNla, Emitable, LinkStats64, NativeNla, Nla, NlaBuffer, NlasIterator, Parseable, Result,
+};
+
+/// Attributes of the `RTM_NEWSTATS` messages. Each attribute is only present if the corresponding
+/// bit was set in the filter mask of the request.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum StatsNla {
+    Unspec(Vec<u8>),
+    /// 64 bits counters of the link (`IFLA_STATS_LINK_64`)
+    Link64(LinkStats64),
+    /// Extended statistics specific to the link type, for instance bridge multicast statistics.
+    /// These are nested attributes whose format depends on the link type, so they are not parsed.
+    LinkXstats(Vec<u8>),
+    /// Extended statistics specific to the type of the link master (for instance, the bridge of a
+    /// bridge port). These are not parsed.
+    LinkXstatsSlave(Vec<u8>),
+    /// Statistics of the traffic handled by the hardware the link is offloaded to
+    LinkOffloadXstats(Vec<OffloadXstatsNla>),
+    /// Statistics specific to each address family. These are not parsed.
+    AfSpec(Vec<u8>),
+    Other(DefaultNla),
+}
+
+impl Nla for StatsNla {
+    #[cfg_attr(nightly, rustfmt::skip)]
+    fn value_len(&self) -> usize {
+        use self::StatsNla::*;
+        match *self {
+            | Unspec(ref bytes)
+            | LinkXstats(ref bytes)
+            | LinkXstatsSlave(ref bytes)
+            | AfSpec(ref bytes)
+            => bytes.len(),
+            Link64(_) => size_of::<LinkStats64>(),
+            LinkOffloadXstats(ref nlas) => nlas.as_slice().buffer_len(),
+            Other(ref nla) => nla.value_len(),
+        }
+    }
+
+    #[cfg_attr(nightly, rustfmt::skip)]
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::StatsNla::*;
+        match *self {
+            | Unspec(ref bytes)
+            | LinkXstats(ref bytes)
+            | LinkXstatsSlave(ref bytes)
+            | AfSpec(ref bytes)
+            => buffer.copy_from_slice(bytes.as_slice()),
+            Link64(ref stats) => stats.to_bytes(buffer),
+            LinkOffloadXstats(ref nlas) => nlas.as_slice().emit(buffer),
+            Other(ref nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::StatsNla::*;
+        match *self {
+            Unspec(_) => IFLA_STATS_UNSPEC,
+            Link64(_) => IFLA_STATS_LINK_64,
+            LinkXstats(_) => IFLA_STATS_LINK_XSTATS,
+            LinkXstatsSlave(_) => IFLA_STATS_LINK_XSTATS_SLAVE,
+            LinkOffloadXstats(_) => IFLA_STATS_LINK_OFFLOAD_XSTATS,
+            AfSpec(_) => IFLA_STATS_AF_SPEC,
+            Other(ref nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<StatsNla> for NlaBuffer<&'buffer T> {
+    fn parse(&self) -> Result<StatsNla> {
+        use self::StatsNla::*;
+        let payload = self.value();
+        Ok(match self.kind() {
+            IFLA_STATS_UNSPEC => Unspec(payload.to_vec()),
+            IFLA_STATS_LINK_64 => Link64(LinkStats64::from_counters::<u64>(payload)?),
+            IFLA_STATS_LINK_XSTATS => LinkXstats(payload.to_vec()),
+            IFLA_STATS_LINK_XSTATS_SLAVE => LinkXstatsSlave(payload.to_vec()),
+            IFLA_STATS_LINK_OFFLOAD_XSTATS => {
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    nlas.push(<NlaBuffer<_> as Parseable<OffloadXstatsNla>>::parse(&nla?)?);
+                }
+                LinkOffloadXstats(nlas)
+            }
+            IFLA_STATS_AF_SPEC => AfSpec(payload.to_vec()),
+            _ => Other(<Self as Parseable<DefaultNla>>::parse(self)?),
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum OffloadXstatsNla {
+    Unspec(Vec<u8>),
+    /// Counters of the packets that hit the CPU instead of being handled by the hardware
+    CpuHit(LinkStats64),
+    Other(DefaultNla),
+}
+
+impl Nla for OffloadXstatsNla {
+    #[cfg_attr(nightly, rustfmt::skip)]
+    fn value_len(&self) -> usize {
+        use self::OffloadXstatsNla::*;
+        match *self {
+            Unspec(ref bytes) => bytes.len(),
+            CpuHit(_) => size_of::<LinkStats64>(),
+            Other(ref nla) => nla.value_len(),
+        }
+    }
+
+    #[cfg_attr(nightly, rustfmt::skip)]
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::OffloadXstatsNla::*;
+        match *self {
+            Unspec(ref bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            CpuHit(ref stats) => stats.to_bytes(buffer),
+            Other(ref nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::OffloadXstatsNla::*;
+        match *self {
+            Unspec(_) => IFLA_OFFLOAD_XSTATS_UNSPEC,
+            CpuHit(_) => IFLA_OFFLOAD_XSTATS_CPU_HIT,
+            Other(ref nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<OffloadXstatsNla> for NlaBuffer<&'buffer T> {
+    fn parse(&self) -> Result<OffloadXstatsNla> {
+        use self::OffloadXstatsNla::*;
+        let payload = self.value();
+        Ok(match self.kind() {
+            IFLA_OFFLOAD_XSTATS_UNSPEC => Unspec(payload.to_vec()),
+            IFLA_OFFLOAD_XSTATS_CPU_HIT => CpuHit(LinkStats64::from_counters::<u64>(payload)?),
+            _ => Other(<Self as Parseable<DefaultNla>>::parse(self)?),
+        })
+    }
+}
@@ -1,4 +1,4 @@
-use byteorder::{ByteOrder, NativeEndian};
+use byteorder::{BigEndian, ByteOrder, NativeEndian};
 use std::mem::size_of;
 use {Error, Result};
 
@@ -61,6 +61,20 @@ pub fn parse_u16(payload: &[u8]) -> Result<u16> {
     Ok(NativeEndian::read_u16(payload))
 }
 
+pub fn parse_u16_be(payload: &[u8]) -> Result<u16> {
+    if payload.len() != size_of::<u16>() {
+        return Err(Error::MalformedNlaValue);
+    }
+    Ok(BigEndian::read_u16(payload))
+}
+
+pub fn parse_u32_be(payload: &[u8]) -> Result<u32> {
+    if payload.len() != size_of::<u32>() {
+        return Err(Error::MalformedNlaValue);
+    }
+    Ok(BigEndian::read_u32(payload))
+}
+
 pub fn parse_i32(payload: &[u8]) -> Result<i32> {
     if payload.len() != 4 {
         return Err(Error::MalformedNlaValue);